@@ -4,12 +4,14 @@
 
 use std::collections::HashMap;
 use std::fmt::{self, Display};
+use std::num::NonZeroUsize;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Condvar, Mutex};
 use std::{mem, thread};
 
 use scheduler::{
-    Pid, ProcessState, Scheduler, SchedulingDecision, StopReason, Syscall, SyscallResult,
+    Name, Pid, ProcessState, Scheduler, SchedulerError, SchedulingDecision, StopReason, Syscall,
+    SyscallResult,
 };
 
 /// Running iteration log
@@ -19,7 +21,7 @@ pub struct Log {
     pub decision: SchedulingDecision,
 
     /// The reason that a process has stopped.
-    pub stop_reason: Option<(StopReason, SyscallResult)>,
+    pub stop_reason: Option<(StopReason, Result<SyscallResult, SchedulerError>)>,
 
     /// The list of processes and their corresponding states
     /// returned by the scheduler.
@@ -29,7 +31,7 @@ pub struct Log {
 impl Log {
     fn new(
         decision: SchedulingDecision,
-        stop_reason: Option<(StopReason, SyscallResult)>,
+        stop_reason: Option<(StopReason, Result<SyscallResult, SchedulerError>)>,
         processes: HashMap<Pid, ProcessInfo>,
     ) -> Log {
         Log {
@@ -50,7 +52,7 @@ impl Display for Log {
         for pid in pids.into_iter() {
             writeln!(f, "{}", self.processes.get(pid).unwrap()).unwrap();
         }
-        if let Some(log) = self.stop_reason {
+        if let Some(log) = &self.stop_reason {
             writeln!(f, "{} -> {:?}", log.0, (log.1)).unwrap();
         }
         writeln!(f)
@@ -82,6 +84,12 @@ pub struct ProcessInfo {
 
     /// Extra details about the process
     pub extra: String,
+
+    /// The time the process has spent in the ready queue since it was last scheduled
+    pub wait_time: usize,
+
+    /// The name of the process
+    pub name: Option<String>,
 }
 
 impl ProcessInfo {
@@ -91,6 +99,8 @@ impl ProcessInfo {
         timings: (usize, usize, usize),
         priority: i8,
         extra: String,
+        wait_time: usize,
+        name: Option<String>,
     ) -> ProcessInfo {
         ProcessInfo {
             pid,
@@ -98,16 +108,21 @@ impl ProcessInfo {
             timings,
             priority,
             extra,
+            wait_time,
+            name,
         }
     }
 }
 
 impl Display for ProcessInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{} ({})", self.pid, name)?,
+            None => write!(f, "{}", self.pid)?,
+        }
         write!(
             f,
-            "{}\t{}\t\t{}\t{}\t{}\t{}\t{}",
-            self.pid,
+            "\t{}\t\t{}\t{}\t{}\t{}\t{}",
             self.state,
             self.priority,
             self.timings.0,
@@ -125,6 +140,8 @@ pub struct Processor<S: Scheduler + 'static> {
     remaining: AtomicUsize,
     logs: Mutex<Vec<Log>>,
     running: AtomicBool,
+    // operatiile I/O in desfasurare, ca perechi (dispozitiv, momentul terminarii)
+    devices: Mutex<Vec<(u32, usize)>>,
 }
 
 impl<S: Scheduler + 'static> Processor<S> {
@@ -132,7 +149,7 @@ impl<S: Scheduler + 'static> Processor<S> {
     ///
     /// * `scheduler` - the scheduler to use for the simulation.
     /// * `f` - a function with the instructions for the process with
-    ///         PID 1.
+    ///   PID 1.
     ///
     /// ## Example
     ///
@@ -168,9 +185,12 @@ impl<S: Scheduler + 'static> Processor<S> {
             remaining: AtomicUsize::new(1),
             logs: Mutex::new(vec![]),
             running: AtomicBool::new(true),
+            devices: Mutex::new(vec![]),
         });
 
-        let SyscallResult::Pid(pid) = processor.scheduler(StopReason::syscall(Syscall::Fork(0))) else {
+        let Ok(SyscallResult::Pid(pid)) =
+            processor.scheduler(StopReason::syscall(Syscall::Fork(0)))
+        else {
             panic!("Fork did not return a pid");
         };
 
@@ -205,18 +225,30 @@ impl<S: Scheduler + 'static> Processor<S> {
         }
     }
 
-    fn scheduler(&self, mut reason: StopReason) -> SyscallResult {
+    fn scheduler(&self, mut reason: StopReason) -> Result<SyscallResult, SchedulerError> {
         if self.is_running() {
             self.remaining.fetch_sub(1, Ordering::Relaxed);
             let mut scheduler = self.scheduler.lock().unwrap();
             reason.set_remaining(self.remaining.load(Ordering::Relaxed));
             let result = scheduler.stop(reason);
+            if let StopReason::Syscall {
+                syscall:
+                    Syscall::IoWait {
+                        device_id,
+                        expected_duration,
+                    },
+                ..
+            } = reason
+            {
+                let completion = scheduler.current_time() + expected_duration.max(1);
+                self.devices.lock().unwrap().push((device_id, completion));
+            }
             {
                 let mut logs = self.logs.lock().unwrap();
                 let len = logs.len();
                 if len > 0 {
                     if let Some(log) = logs.get_mut(len - 1) {
-                        log.stop_reason = Some((reason, result));
+                        log.stop_reason = Some((reason, result.clone()));
                     };
                 }
             }
@@ -224,6 +256,19 @@ impl<S: Scheduler + 'static> Processor<S> {
             let mut current_process = self.current_process.0.lock().unwrap();
             *current_process = None;
             while self.is_running() && current_process.is_none() {
+                // operatiile I/O terminate pana la momentul curent sunt raportate planificatorului
+                let now = scheduler.current_time();
+                let mut completed = vec![];
+                self.devices.lock().unwrap().retain(|&(device_id, completion)| {
+                    if completion <= now {
+                        completed.push(device_id);
+                    }
+                    completion > now
+                });
+                for device_id in completed {
+                    let _ = scheduler.stop(StopReason::IoComplete { device_id });
+                }
+
                 let next = scheduler.next();
                 let mut process_map = HashMap::new();
                 for process in scheduler.list() {
@@ -235,13 +280,25 @@ impl<S: Scheduler + 'static> Processor<S> {
                             process.timings(),
                             process.priority(),
                             process.extra(),
+                            process.wait_time(),
+                            process.name().map(String::from),
                         ),
                     );
                 }
-                (*self.logs.lock().unwrap()).push(Log::new(next, None, process_map));
+                (*self.logs.lock().unwrap()).push(Log::new(next.clone(), None, process_map));
                 // println!("{}", next);
                 match next {
-                    SchedulingDecision::Run { pid, timeslice } => {
+                    // procesul idle nu are un thread, asa ca este oprit imediat
+                    SchedulingDecision::Run { pid: Pid::IDLE, .. } => {
+                        println!("IDLE");
+                        let mut reason = StopReason::expired();
+                        reason.set_remaining(0);
+                        let result = scheduler.stop(reason);
+                        if let Some(log) = self.logs.lock().unwrap().last_mut() {
+                            log.stop_reason = Some((reason, result.clone()));
+                        }
+                    }
+                    SchedulingDecision::Run { pid, timeslice, .. } => {
                         self.remaining.store(timeslice.into(), Ordering::Relaxed);
                         *current_process = Some(pid);
                         self.current_process.1.notify_all();
@@ -249,7 +306,16 @@ impl<S: Scheduler + 'static> Processor<S> {
                     SchedulingDecision::Sleep(time) => {
                         println!("SLEEP {time}");
                     }
-                    SchedulingDecision::Deadlock => {
+                    SchedulingDecision::Deadlines(missed) => {
+                        println!("DEADLINES MISSED {missed:?}");
+                    }
+                    SchedulingDecision::UtilizationExceeded { utilization, bound } => {
+                        println!("UTILIZATION {utilization:.3} EXCEEDS {bound:.3}");
+                    }
+                    SchedulingDecision::DeliverSignal(pid, signum) => {
+                        println!("DELIVER SIGNAL {signum} TO {pid}");
+                    }
+                    SchedulingDecision::Deadlock(_) => {
                         println!("DEADLOCK");
                         self.stop();
                     }
@@ -265,10 +331,15 @@ impl<S: Scheduler + 'static> Processor<S> {
             }
             result
         } else {
-            SyscallResult::NoRunningProcess
+            Err(SchedulerError::NoRunningProcess)
         }
     }
 
+    // rezultatul apelurilor de sistem care nu intorc nimic procesului este pastrat doar in log
+    fn notify(&self, reason: StopReason) {
+        let _ = self.scheduler(reason);
+    }
+
     fn get_logs(&self) -> Vec<Log> {
         let mut logs = self.logs.lock().unwrap();
         let mut res = vec![];
@@ -311,7 +382,7 @@ impl<S: Scheduler + 'static> Process<S> {
         println!("{}: EXEC", self.pid);
         if !self.processor.exec() {
             println!("PREEMPTED {}", self.pid);
-            self.processor.scheduler(StopReason::expired());
+            self.processor.notify(StopReason::expired());
             self.suspend();
         }
     }
@@ -321,7 +392,25 @@ impl<S: Scheduler + 'static> Process<S> {
     where
         F: FnOnce(&Process<S>) + Send + 'static,
     {
-        let SyscallResult::Pid(pid) = self.processor.scheduler(StopReason::syscall(Syscall::Fork(priority))) else {
+        self.spawn(f, Syscall::Fork(priority))
+    }
+
+    /// Send a [`Syscall::ForkWithDeadline`] system call.
+    ///
+    /// * `deadline` - the number of time units, starting from now, by which the new process should exit.
+    pub fn fork_with_deadline<F>(&self, f: F, priority: i8, deadline: usize) -> Pid
+    where
+        F: FnOnce(&Process<S>) + Send + 'static,
+    {
+        self.spawn(f, Syscall::ForkWithDeadline { priority, deadline })
+    }
+
+    fn spawn<F>(&self, f: F, syscall: Syscall) -> Pid
+    where
+        F: FnOnce(&Process<S>) + Send + 'static,
+    {
+        let Ok(SyscallResult::Pid(pid)) = self.processor.scheduler(StopReason::syscall(syscall))
+        else {
             panic!("Fork did not return a pid");
         };
 
@@ -350,7 +439,121 @@ impl<S: Scheduler + 'static> Process<S> {
     pub fn wait(&self, event: usize) {
         println!("{}: WAIT {}", self.pid, event);
         self.processor
-            .scheduler(StopReason::syscall(Syscall::Wait(event)));
+            .notify(StopReason::syscall(Syscall::Wait(event)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::WaitChild`] system call, again after every wake up, until
+    /// the exit code of a child is collected.
+    ///
+    /// Returns the exit code of the child, or [`None`] if the process has no children.
+    pub fn wait_child(&self) -> Option<i32> {
+        println!("{}: WAIT CHILD", self.pid);
+        self.collect_child(Syscall::WaitChild)
+    }
+
+    /// Send a [`Syscall::WaitPid`] system call, like [`Process::wait_child`].
+    ///
+    /// * `child` - the PID of the child to wait for.
+    pub fn wait_pid(&self, child: Pid) -> Option<i32> {
+        println!("{}: WAIT CHILD {}", self.pid, child);
+        self.collect_child(Syscall::WaitPid(child))
+    }
+
+    // procesul blocat este trezit la terminarea unui copil si preia codul de iesire
+    // la urmatorul apel de sistem
+    fn collect_child(&self, syscall: Syscall) -> Option<i32> {
+        loop {
+            let result = self.processor.scheduler(StopReason::syscall(syscall));
+            self.suspend();
+            match result {
+                Ok(SyscallResult::ExitCode(code)) => return Some(code),
+                Ok(SyscallResult::Success) if self.processor.is_running() => (),
+                _ => return None,
+            }
+        }
+    }
+
+    /// Send a [`Syscall::WaitNamed`] system call.
+    ///
+    /// * `name` - the name of the event to wait for.
+    pub fn wait_named(&self, name: &str) {
+        println!("{}: WAIT {}", self.pid, name);
+        self.processor
+            .notify(StopReason::syscall(Syscall::WaitNamed(Name::new(name))));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::SignalNamed`] system call.
+    ///
+    /// * `name` - the name of the event to signal.
+    pub fn signal_named(&self, name: &str) {
+        println!("{}: SIGNAL {}", self.pid, name);
+        self.processor
+            .notify(StopReason::syscall(Syscall::SignalNamed(Name::new(name))));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::IoWait`] system call.
+    ///
+    /// The processor reports the completion of the operation to the scheduler
+    /// after `expected_duration` time units.
+    ///
+    /// * `device_id` - the device that performs the operation.
+    /// * `expected_duration` - the number of time units the operation takes.
+    pub fn io_wait(&self, device_id: u32, expected_duration: usize) {
+        println!("{}: IO WAIT {} {}", self.pid, device_id, expected_duration);
+        self.processor.notify(StopReason::syscall(Syscall::IoWait {
+            device_id,
+            expected_duration,
+        }));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::SemWait`] system call.
+    ///
+    /// * `semaphore` - the semaphore ID.
+    pub fn sem_wait(&self, semaphore: u32) {
+        println!("{}: SEM WAIT {}", self.pid, semaphore);
+        self.processor
+            .notify(StopReason::syscall(Syscall::SemWait(semaphore)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::SemPost`] system call.
+    ///
+    /// * `semaphore` - the semaphore ID.
+    pub fn sem_post(&self, semaphore: u32) {
+        println!("{}: SEM POST {}", self.pid, semaphore);
+        self.processor
+            .notify(StopReason::syscall(Syscall::SemPost(semaphore)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::MutexLock`] system call.
+    ///
+    /// * `mutex` - the mutex ID.
+    pub fn mutex_lock(&self, mutex: u32) {
+        println!("{}: LOCK {}", self.pid, mutex);
+        self.processor
+            .notify(StopReason::syscall(Syscall::MutexLock(mutex)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::MutexUnlock`] system call.
+    ///
+    /// * `mutex` - the mutex ID.
+    pub fn mutex_unlock(&self, mutex: u32) {
+        println!("{}: UNLOCK {}", self.pid, mutex);
+        self.processor
+            .notify(StopReason::syscall(Syscall::MutexUnlock(mutex)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::Yield`] system call.
+    pub fn yield_now(&self) {
+        println!("{}: YIELD", self.pid);
+        self.processor.notify(StopReason::syscall(Syscall::Yield));
         self.suspend();
     }
 
@@ -360,7 +563,7 @@ impl<S: Scheduler + 'static> Process<S> {
     pub fn signal(&self, event: usize) {
         println!("{}: SIGNAL {}", self.pid, event);
         self.processor
-            .scheduler(StopReason::syscall(Syscall::Signal(event)));
+            .notify(StopReason::syscall(Syscall::Signal(event)));
         self.suspend();
     }
 
@@ -370,13 +573,198 @@ impl<S: Scheduler + 'static> Process<S> {
     pub fn sleep(&self, timeslice: usize) {
         println!("{}: SLEEP {}", self.pid, timeslice);
         self.processor
-            .scheduler(StopReason::syscall(Syscall::Sleep(timeslice)));
+            .notify(StopReason::syscall(Syscall::Sleep(timeslice)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::SetBurstHint`] system call.
+    ///
+    /// * `burst` - the expected length of the next execution burst.
+    pub fn burst_hint(&self, burst: usize) {
+        println!("{}: BURST HINT {}", self.pid, burst);
+        self.processor
+            .notify(StopReason::syscall(Syscall::SetBurstHint(burst)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::SetTickets`] system call.
+    ///
+    /// * `tickets` - the number of lottery tickets of the process.
+    pub fn tickets(&self, tickets: usize) {
+        println!("{}: TICKETS {}", self.pid, tickets);
+        self.processor
+            .notify(StopReason::syscall(Syscall::SetTickets(tickets)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::SetDeadline`] system call.
+    ///
+    /// * `deadline` - the absolute time by which the process should exit.
+    pub fn deadline(&self, deadline: usize) {
+        println!("{}: DEADLINE {}", self.pid, deadline);
+        self.processor
+            .notify(StopReason::syscall(Syscall::SetDeadline(deadline)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::SetPeriod`] system call.
+    ///
+    /// * `period` - the number of time units between two activations of the process.
+    pub fn period(&self, period: NonZeroUsize) {
+        println!("{}: PERIOD {}", self.pid, period);
+        self.processor
+            .notify(StopReason::syscall(Syscall::SetPeriod(period)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::WillSignal`] system call.
+    ///
+    /// * `event` - the event number that the process will signal.
+    pub fn will_signal(&self, event: usize) {
+        println!("{}: WILL SIGNAL {}", self.pid, event);
+        self.processor
+            .notify(StopReason::syscall(Syscall::WillSignal(event)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::SendSignal`] system call.
+    ///
+    /// * `target` - the PID of the process that receives the signal.
+    /// * `signum` - the signal number.
+    pub fn send_signal(&self, target: Pid, signum: u8) {
+        println!("{}: SEND SIGNAL {} TO {}", self.pid, signum, target);
+        self.processor
+            .notify(StopReason::syscall(Syscall::SendSignal { target, signum }));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::SetProcessGroup`] system call.
+    ///
+    /// * `pgid` - the PID of the leader of the process group.
+    pub fn process_group(&self, pgid: Pid) {
+        println!("{}: PROCESS GROUP {}", self.pid, pgid);
+        self.processor
+            .notify(StopReason::syscall(Syscall::SetProcessGroup(pgid)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::KillGroup`] system call.
+    ///
+    /// * `pgid` - the PID of the leader of the process group.
+    pub fn kill_group(&self, pgid: Pid) {
+        println!("{}: KILL GROUP {}", self.pid, pgid);
+        self.processor
+            .notify(StopReason::syscall(Syscall::KillGroup(pgid)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::SuspendGroup`] system call.
+    ///
+    /// * `pgid` - the PID of the leader of the process group.
+    pub fn suspend_group(&self, pgid: Pid) {
+        println!("{}: SUSPEND GROUP {}", self.pid, pgid);
+        self.processor
+            .notify(StopReason::syscall(Syscall::SuspendGroup(pgid)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::ResumeGroup`] system call.
+    ///
+    /// * `pgid` - the PID of the leader of the process group.
+    pub fn resume_group(&self, pgid: Pid) {
+        println!("{}: RESUME GROUP {}", self.pid, pgid);
+        self.processor
+            .notify(StopReason::syscall(Syscall::ResumeGroup(pgid)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::Suspend`] system call.
+    ///
+    /// * `target` - the PID of the process to suspend.
+    pub fn suspend_process(&self, target: Pid) {
+        println!("{}: SUSPEND {}", self.pid, target);
+        self.processor
+            .notify(StopReason::syscall(Syscall::Suspend(target)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::Resume`] system call.
+    ///
+    /// * `target` - the PID of the process to resume.
+    pub fn resume_process(&self, target: Pid) {
+        println!("{}: RESUME {}", self.pid, target);
+        self.processor
+            .notify(StopReason::syscall(Syscall::Resume(target)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::SetName`] system call.
+    ///
+    /// * `name` - the new name of the process.
+    pub fn set_name(&self, name: &str) {
+        println!("{}: SET NAME {}", self.pid, name);
+        self.processor
+            .notify(StopReason::syscall(Syscall::SetName(Name::new(name))));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::SetAffinity`] system call.
+    ///
+    /// * `mask` - the cores on which the process may run, bit `i` allows core `i`.
+    pub fn set_affinity(&self, mask: u64) {
+        println!("{}: SET AFFINITY {:#b}", self.pid, mask);
+        self.processor
+            .notify(StopReason::syscall(Syscall::SetAffinity(mask)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::Renice`] system call.
+    ///
+    /// * `nice` - the new nice value of the process, between -20 and 19.
+    pub fn renice(&self, nice: i8) {
+        println!("{}: RENICE {}", self.pid, nice);
+        self.processor
+            .notify(StopReason::syscall(Syscall::Renice(nice)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::SetCpuQuota`] system call.
+    ///
+    /// * `quota` - the fraction of the processor time that the process can use in every
+    ///   accounting period, or [`None`] to remove the quota.
+    pub fn set_cpu_quota(&self, quota: Option<f64>) {
+        println!("{}: CPU QUOTA {:?}", self.pid, quota);
+        self.processor
+            .notify(StopReason::syscall(Syscall::SetCpuQuota(quota)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::SetResources`] system call.
+    ///
+    /// * `resources` - the new resource counter of the process.
+    pub fn set_resources(&self, resources: usize) {
+        println!("{}: RESOURCES {}", self.pid, resources);
+        self.processor
+            .notify(StopReason::syscall(Syscall::SetResources(resources)));
+        self.suspend();
+    }
+
+    /// Send a [`Syscall::Exec`] system call.
+    ///
+    /// * `new_priority` - the new priority of the process, if it changes.
+    pub fn exec_image(&self, new_priority: Option<i8>) {
+        match new_priority {
+            Some(priority) => println!("{}: EXEC IMAGE {}", self.pid, priority),
+            None => println!("{}: EXEC IMAGE", self.pid),
+        }
+        self.processor
+            .notify(StopReason::syscall(Syscall::Exec { new_priority }));
         self.suspend();
     }
 
     fn exit(&self) {
         println!("{}: EXIT", self.pid);
-        self.processor.scheduler(StopReason::syscall(Syscall::Exit));
+        self.processor.notify(StopReason::syscall(Syscall::Exit));
     }
 }
 
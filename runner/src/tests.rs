@@ -1 +1,4360 @@
 // Do not delete this file
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+
+use processor::Processor;
+use scheduler::{
+    cfs, custom, edf, fcfs, gang, lottery, mlfq, mlfq_with_timeslices, multicore_round_robin,
+    priority_nonpreemptive, priority_queue, rate_monotonic, round_robin, round_robin_multicore,
+    round_robin_work_stealing, rt_fifo, shortest_job_first, sjf, sjf_with_estimate, srtf, stride,
+    BuildError, BurstHistogram, CfsBuilder, HierarchicalScheduler, MultiScheduler, Name,
+    OrphanPolicy, Pid, PidPolicy, Policy, PriorityQueueBuilder, PriorityQueueRoundRobin, Process,
+    ProcessComparator, ProcessState, ReplayError, ReplayScheduler, RoundRobin, RoundRobinBuilder,
+    Scheduler, SchedulerBuilder, SchedulerConfigError, SchedulerError, SchedulerEvent,
+    SchedulerSnapshot, SchedulingDecision, SpawnRequest, StarvationDetector, StopReason, Syscall,
+    SyscallResult, TieBreak, TimeUnit, TracingScheduler, CFS, NICE_RANGE, SIGKILL,
+};
+
+#[test]
+fn aging_schedules_low_priority_process() {
+    let logs = Processor::run(
+        priority_queue(NonZeroUsize::new(2).unwrap(), 1, 2),
+        |process| {
+            for _ in 0..2 {
+                process.fork(
+                    |process| {
+                        for _ in 0..20 {
+                            process.exec();
+                            process.signal(0);
+                        }
+                    },
+                    5,
+                );
+            }
+            process.exec();
+        },
+    );
+
+    // procesul 1 are prioritate 0 si ruleaza inainte ca procesele cu prioritate 5 sa se termine
+    assert!(logs.iter().any(|log| {
+        log.decision
+            == SchedulingDecision::Run {
+                pid: Pid::new(1),
+                timeslice: NonZeroUsize::new(2).unwrap(),
+                core: 0,
+            }
+            && log.processes.contains_key(&Pid::new(2))
+            && log.processes.contains_key(&Pid::new(3))
+    }));
+}
+
+#[test]
+fn observer_records_fork_and_exit() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    let recorder = Arc::clone(&events);
+    scheduler.set_observer(Box::new(move |event| recorder.lock().unwrap().push(event)));
+
+    Processor::run(scheduler, |process| {
+        process.fork(
+            |process| {
+                process.exec();
+            },
+            0,
+        );
+        process.sleep(5);
+    });
+
+    let scheduled = |pid, timeslice| SchedulerEvent::Scheduled {
+        pid: Pid::new(pid),
+        timeslice: NonZeroUsize::new(timeslice).unwrap(),
+    };
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            SchedulerEvent::Forked {
+                parent: None,
+                child: Pid::new(1),
+            },
+            scheduled(1, 3),
+            SchedulerEvent::Forked {
+                parent: Some(Pid::new(1)),
+                child: Pid::new(2),
+            },
+            scheduled(1, 2),
+            SchedulerEvent::Blocked {
+                pid: Pid::new(1),
+                event: None,
+            },
+            scheduled(2, 3),
+            SchedulerEvent::Exited { pid: Pid::new(2) },
+            scheduled(1, 3),
+            SchedulerEvent::Exited { pid: Pid::new(1) },
+        ]
+    );
+}
+
+// planificatorul ia o decizie, iar procesul planificat se opreste din motivul primit;
+// un apel de sistem este facut imediat ce procesul incepe sa ruleze
+fn step<S: Scheduler>(scheduler: &mut S, reason: StopReason) -> SchedulingDecision {
+    let decision = scheduler.next();
+    if let SchedulingDecision::Run { timeslice, .. } = decision {
+        let mut reason = reason;
+        reason.set_remaining(timeslice.get() - 1);
+        scheduler.stop(reason).unwrap();
+    }
+    decision
+}
+
+fn snapshot_steps() -> Vec<StopReason> {
+    vec![
+        StopReason::syscall(Syscall::Fork(1)),
+        StopReason::syscall(Syscall::Fork(2)),
+        StopReason::expired(),
+        StopReason::syscall(Syscall::Wait(1)),
+        StopReason::syscall(Syscall::Sleep(3)),
+        StopReason::expired(),
+        StopReason::syscall(Syscall::Signal(1)),
+        StopReason::expired(),
+        StopReason::syscall(Syscall::Exit),
+        StopReason::expired(),
+        StopReason::syscall(Syscall::Exit),
+        StopReason::expired(),
+        StopReason::syscall(Syscall::Exit),
+    ]
+}
+
+// dupa restaurarea unei stari salvate, planificatorul ia aceleasi decizii ca planificatorul original
+fn assert_restored_decisions<S: Scheduler>(
+    mut scheduler: S,
+    snapshot: impl Fn(&S) -> SchedulerSnapshot,
+    restore: impl Fn(SchedulerSnapshot) -> S,
+) {
+    let steps = snapshot_steps();
+    let (before, after) = steps.split_at(4);
+
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    for reason in before {
+        step(&mut scheduler, *reason);
+    }
+
+    let serialized = serde_json::to_string(&snapshot(&scheduler)).unwrap();
+    let mut restored = restore(serde_json::from_str(&serialized).unwrap());
+
+    for reason in after {
+        assert_eq!(step(&mut restored, *reason), step(&mut scheduler, *reason));
+    }
+    assert_eq!(restored.next(), scheduler.next());
+}
+
+#[test]
+fn snapshot_restores_round_robin() {
+    assert_restored_decisions(
+        round_robin(NonZeroUsize::new(3).unwrap(), 1),
+        RoundRobin::snapshot,
+        RoundRobin::restore,
+    );
+}
+
+#[test]
+fn snapshot_restores_cfs() {
+    assert_restored_decisions(
+        cfs(NonZeroUsize::new(6).unwrap(), 1),
+        CFS::snapshot,
+        CFS::restore,
+    );
+}
+
+#[test]
+fn snapshot_restores_priority_queue() {
+    assert_restored_decisions(
+        priority_queue(NonZeroUsize::new(3).unwrap(), 1, 2),
+        PriorityQueueRoundRobin::snapshot,
+        PriorityQueueRoundRobin::restore,
+    );
+}
+
+#[test]
+fn snapshot_displays_process_table() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(2)));
+    scheduler.next();
+    assert_eq!(
+        scheduler.snapshot().to_string(),
+        "PID   STATE       PRIORITY  EXECUTE  SYSCALL  TOTAL\n\
+         1     RUNNING     0         0        1        1\n\
+         2     READY       2         0        0        0\n\
+         remaining timeslice 2, context switches 1, processes spawned 2"
+    );
+}
+
+#[test]
+fn snapshot_is_none_for_schedulers_that_cannot_be_saved() {
+    assert!(Scheduler::snapshot(&fcfs()).is_none());
+    assert!(Scheduler::snapshot(&edf(1)).is_none());
+
+    // prin trait, planificatorii care pot fi salvati returneaza o copie
+    let scheduler: Box<dyn Scheduler> = Box::new(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    assert!(scheduler.snapshot().is_some());
+}
+
+#[test]
+fn decisions_round_trip_through_json() {
+    let decision = SchedulingDecision::Run {
+        pid: Pid::new(2),
+        timeslice: NonZeroUsize::new(3).unwrap(),
+        core: 1,
+    };
+    let json = serde_json::to_string(&decision).unwrap();
+    assert_eq!(json, r#"{"Run":{"pid":2,"timeslice":3,"core":1}}"#);
+    assert_eq!(
+        serde_json::from_str::<SchedulingDecision>(&json).unwrap(),
+        decision
+    );
+
+    let reason = StopReason::syscall(Syscall::SetName(Name::new("worker")));
+    let json = serde_json::to_string(&reason).unwrap();
+    assert!(json.contains(r#"{"SetName":"worker"}"#));
+    assert_eq!(serde_json::from_str::<StopReason>(&json).unwrap(), reason);
+
+    let result: Result<SyscallResult, SchedulerError> = Ok(SyscallResult::Pid(Pid::new(4)));
+    let json = serde_json::to_string(&result).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Result<SyscallResult, SchedulerError>>(&json).unwrap(),
+        result
+    );
+}
+
+#[test]
+fn cfs_favours_heavier_processes() {
+    let mut scheduler = cfs(NonZeroUsize::new(4).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(5)));
+
+    let mut runs = [0, 0];
+    for _ in 0..12 {
+        if let SchedulingDecision::Run { pid, timeslice, .. } =
+            step(&mut scheduler, StopReason::expired())
+        {
+            runs[usize::from(pid == 2)] += timeslice.get();
+        }
+    }
+
+    // process 2 has about three times the weight of process 1, so it runs three times
+    // as long for about the same virtual runtime
+    assert_eq!(runs, [6, 18]);
+    assert_eq!(scheduler.vruntime(Pid::new(1)), Some(7 * 1024));
+    assert!(scheduler.vruntime(Pid::new(2)).unwrap() < 7 * 1024);
+}
+
+#[test]
+fn cfs_reports_vruntime_in_process_info() {
+    let mut scheduler = cfs(NonZeroUsize::new(4).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::expired());
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+    // the new process starts with the minimum virtual runtime, that of its parent
+    let parent = scheduler.process_info(Pid::new(1)).unwrap();
+    let child = scheduler.process_info(Pid::new(2)).unwrap();
+    assert_eq!(parent.vruntime, Some(5 * 1024));
+    assert_eq!(child.vruntime, parent.vruntime);
+
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    assert_eq!(scheduler.process_info(Pid::new(1)).unwrap().vruntime, None);
+}
+
+#[test]
+fn processes_are_listed_in_pid_order() {
+    let mut scheduler: Box<dyn Scheduler> =
+        Box::new(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+    // process 1 is moved to the waiting queue, after the other processes
+    step(&mut scheduler, StopReason::syscall(Syscall::Sleep(5)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Wait(1)));
+    let processes: Vec<(Pid, ProcessState)> = scheduler
+        .processes()
+        .map(|info| (info.pid, info.state))
+        .collect();
+    assert_eq!(
+        processes,
+        [
+            (Pid::new(1), ProcessState::Waiting { event: None }),
+            (Pid::new(2), ProcessState::Waiting { event: Some(1) }),
+            (Pid::new(3), ProcessState::Ready),
+        ]
+    );
+}
+
+#[test]
+fn renice_weights_cfs_timeslice() {
+    let mut scheduler = cfs(NonZeroUsize::new(8).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    let reniced = step(&mut scheduler, StopReason::syscall(Syscall::Renice(-20)));
+    let SchedulingDecision::Run { pid: reniced, .. } = reniced else {
+        panic!("expected a process to run");
+    };
+
+    // procesul cu valoarea nice cea mai mica primeste aproape tot procesorul
+    let nice = |scheduler: &mut CFS, pid: Pid| {
+        scheduler
+            .list()
+            .iter()
+            .find(|process| process.pid() == pid)
+            .map(|process| process.nice())
+    };
+    assert_eq!(nice(&mut scheduler, reniced), Some(-20));
+    step(&mut scheduler, StopReason::expired());
+    let mut timeslices = Vec::new();
+    for _ in 0..3 {
+        if let SchedulingDecision::Run { pid, timeslice, .. } =
+            step(&mut scheduler, StopReason::expired())
+        {
+            timeslices.push((pid == reniced, timeslice.get()));
+        }
+    }
+    assert_eq!(timeslices, [(false, 1), (true, 7), (true, 7)]);
+}
+
+#[test]
+fn renice_rejects_values_outside_the_nice_range() {
+    let mut scheduler = cfs(NonZeroUsize::new(8).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    for nice in [
+        *NICE_RANGE.start() - 1,
+        *NICE_RANGE.end() + 1,
+        i8::MIN,
+        i8::MAX,
+    ] {
+        let SchedulingDecision::Run { timeslice, .. } = scheduler.next() else {
+            panic!("expected a process to run");
+        };
+        let mut reason = StopReason::syscall(Syscall::Renice(nice));
+        reason.set_remaining(timeslice.get() - 1);
+        assert_eq!(scheduler.stop(reason), Ok(SyscallResult::InvalidArgument));
+    }
+
+    // valoarea nice a procesului nu este schimbata, iar capetele intervalului sunt acceptate
+    assert_eq!(scheduler.list()[0].nice(), 0);
+    for nice in NICE_RANGE {
+        let SchedulingDecision::Run { timeslice, .. } = scheduler.next() else {
+            panic!("expected a process to run");
+        };
+        let mut reason = StopReason::syscall(Syscall::Renice(nice));
+        reason.set_remaining(timeslice.get() - 1);
+        assert_eq!(scheduler.stop(reason), Ok(SyscallResult::Success));
+        assert_eq!(scheduler.list()[0].nice(), nice);
+    }
+}
+
+#[test]
+fn renice_maps_nice_onto_the_priority_range() {
+    let mut scheduler = priority_queue(NonZeroUsize::new(3).unwrap(), 1, 0);
+    scheduler.set_priority_range(0..=10);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(5)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(5)));
+    let priority = |scheduler: &mut PriorityQueueRoundRobin, pid: usize| {
+        scheduler
+            .list()
+            .iter()
+            .find(|process| process.pid() == Pid::new(pid))
+            .map(|process| process.priority())
+            .unwrap()
+    };
+
+    // nice 19 aduce procesul 1 la prioritatea minima, iar dupa ce isi termina timeslice-ul
+    // ruleaza procesul 2
+    step(&mut scheduler, StopReason::syscall(Syscall::Renice(19)));
+    assert_eq!(priority(&mut scheduler, 1), 0);
+    step(&mut scheduler, StopReason::expired());
+    assert!(matches!(
+        step(&mut scheduler, StopReason::syscall(Syscall::Renice(-20))),
+        SchedulingDecision::Run { pid, .. } if pid == Pid::new(2)
+    ));
+    assert_eq!(priority(&mut scheduler, 2), 10);
+
+    // valorile intermediare sunt impartite uniform pe interval
+    step(&mut scheduler, StopReason::syscall(Syscall::Renice(0)));
+    assert_eq!(priority(&mut scheduler, 2), 5);
+}
+
+#[test]
+fn priority_queue_ages_up_to_the_top_of_the_priority_range() {
+    let mut scheduler = priority_queue(NonZeroUsize::new(2).unwrap(), 1, 1);
+    scheduler.set_priority_range(0..=20);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(20)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+    // procesul 1 coboara cu un nivel la fiecare timeslice, iar procesul 2 urca cu doua
+    // niveluri, peste nivelul 5, pana il depaseste dupa 7 rulari ale procesului 1
+    let runs: Vec<Pid> = (0..8)
+        .map(|_| match step(&mut scheduler, StopReason::expired()) {
+            SchedulingDecision::Run { pid, .. } => pid,
+            decision => panic!("unexpected decision {decision:?}"),
+        })
+        .collect();
+    assert_eq!(runs, [1, 1, 1, 1, 1, 1, 1, 2].map(Pid::new));
+}
+
+#[test]
+fn renice_scales_round_robin_timeslice() {
+    let mut scheduler = RoundRobinBuilder::new()
+        .timeslice(4)
+        .nice_timeslices(true)
+        .build()
+        .unwrap();
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::expired());
+
+    // process 2 lowers its nice value and receives twice the timeslice of process 1,
+    // but the processes still run in the order of the ready queue
+    let mut renice = StopReason::syscall(Syscall::Renice(-20));
+    renice.set_remaining(0);
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 2));
+    scheduler.stop(renice).unwrap();
+    let mut timeslices = Vec::new();
+    for _ in 0..4 {
+        if let SchedulingDecision::Run { pid, timeslice, .. } =
+            step(&mut scheduler, StopReason::expired())
+        {
+            timeslices.push((pid == 2, timeslice.get()));
+        }
+    }
+    assert_eq!(timeslices, [(false, 4), (true, 8), (false, 4), (true, 8)]);
+}
+
+#[test]
+fn edf_preempts_for_earlier_deadline() {
+    let logs = Processor::run(edf(1), |process| {
+        process.fork_with_deadline(
+            |process| {
+                process.exec();
+                process.fork_with_deadline(
+                    |process| {
+                        process.exec();
+                    },
+                    0,
+                    5,
+                );
+                process.exec();
+                process.exec();
+            },
+            0,
+            50,
+        );
+        process.sleep(20);
+    });
+
+    // imediat dupa crearea procesului 3, acesta il preempteaza pe procesul 2
+    let fork = logs
+        .iter()
+        .position(|log| {
+            matches!(
+                log.stop_reason,
+                Some((_, Ok(SyscallResult::Pid(pid)))) if pid == Pid::new(3)
+            )
+        })
+        .unwrap();
+    assert!(matches!(
+        logs[fork + 1].decision,
+        SchedulingDecision::Run { pid, .. } if pid == Pid::new(3)
+    ));
+    assert!(logs[fork + 1].processes.contains_key(&Pid::new(2)));
+}
+
+#[test]
+fn edf_reports_missed_deadlines() {
+    let logs = Processor::run(edf(1), |process| {
+        process.fork_with_deadline(
+            |process| {
+                for _ in 0..5 {
+                    process.exec();
+                }
+            },
+            0,
+            2,
+        );
+        process.sleep(10);
+    });
+
+    let missed: Vec<&Vec<(Pid, usize)>> = logs
+        .iter()
+        .filter_map(|log| match &log.decision {
+            SchedulingDecision::Deadlines(missed) => Some(missed),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(missed.len(), 1);
+    assert_eq!(missed[0].len(), 1);
+    assert_eq!(missed[0][0].0, Pid::new(2));
+}
+
+#[test]
+fn edf_emits_deadline_miss_events() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut scheduler = edf(1);
+    let recorder = Arc::clone(&events);
+    scheduler.set_observer(Box::new(move |event| recorder.lock().unwrap().push(event)));
+
+    Processor::run(scheduler, |process| {
+        process.fork_with_deadline(
+            |process| {
+                for _ in 0..5 {
+                    process.exec();
+                }
+            },
+            0,
+            2,
+        );
+        process.sleep(10);
+    });
+
+    // termenul depasit este raportat o singura data, chiar daca procesul mai ruleaza
+    let misses: Vec<SchedulerEvent> = events
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|event| matches!(event, SchedulerEvent::DeadlineMiss(_)))
+        .copied()
+        .collect();
+    assert_eq!(misses, vec![SchedulerEvent::DeadlineMiss(Pid::new(2))]);
+}
+
+// un proces cu timpii fixati, folosit pentru a verifica metodele implicite ale trasaturii Process
+struct FixedTimings {
+    total: usize,
+    execution: usize,
+}
+
+impl Process for FixedTimings {
+    fn pid(&self) -> Pid {
+        Pid::new(1)
+    }
+    fn state(&self) -> ProcessState {
+        ProcessState::Ready
+    }
+    fn timings(&self) -> (usize, usize, usize) {
+        (self.total, 0, self.execution)
+    }
+    fn priority(&self) -> i8 {
+        0
+    }
+    fn extra(&self) -> String {
+        String::new()
+    }
+}
+
+#[test]
+fn cpu_percentage_of_process() {
+    let process = FixedTimings {
+        total: 100,
+        execution: 30,
+    };
+    assert_eq!(process.cpu_percentage(), 30.0);
+
+    let process = FixedTimings {
+        total: 0,
+        execution: 0,
+    };
+    assert_eq!(process.cpu_percentage(), 0.0);
+}
+
+// procesul 1 creeaza procesul 2 cu 4 bilete si procesul 3 cu un bilet, apoi pastreaza un bilet
+fn lottery_scheduler_with_tickets(seed: u64) -> impl Scheduler {
+    let mut scheduler = lottery(NonZeroUsize::new(10).unwrap(), 1, seed);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    for syscall in [
+        Syscall::SetTickets(4),
+        Syscall::Fork(0),
+        Syscall::SetTickets(1),
+        Syscall::Fork(0),
+    ] {
+        step(&mut scheduler, StopReason::syscall(syscall));
+    }
+    scheduler
+}
+
+fn lottery_winners(scheduler: &mut impl Scheduler, rounds: usize) -> Vec<Pid> {
+    (0..rounds)
+        .map(|_| match step(scheduler, StopReason::expired()) {
+            SchedulingDecision::Run { pid, .. } => pid,
+            decision => panic!("unexpected decision {decision}"),
+        })
+        .collect()
+}
+
+#[test]
+fn lottery_seed_is_reproducible() {
+    let winners = lottery_winners(&mut lottery_scheduler_with_tickets(42), 10);
+    assert_eq!(
+        winners,
+        [1, 2, 1, 2, 3, 3, 2, 2, 2, 2].map(Pid::new).to_vec()
+    );
+    assert_eq!(
+        winners,
+        lottery_winners(&mut lottery_scheduler_with_tickets(42), 10)
+    );
+}
+
+#[test]
+fn lottery_favors_processes_with_more_tickets() {
+    let winners = lottery_winners(&mut lottery_scheduler_with_tickets(7), 600);
+    let wins = |pid| {
+        winners
+            .iter()
+            .filter(|winner| **winner == Pid::new(pid))
+            .count()
+    };
+
+    // procesul 2 detine 4 din cele 6 bilete
+    assert!(wins(2) > 3 * wins(1));
+    assert!(wins(2) > 3 * wins(3));
+    assert_eq!(wins(1) + wins(2) + wins(3), 600);
+}
+
+// procesul 1 creeaza procesul 2 si este preemptat, iar procesul 2 face apelul de sistem primit
+fn child_state_after(syscall: Syscall) -> ProcessState {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 3);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(syscall));
+
+    let child = scheduler
+        .list()
+        .into_iter()
+        .find(|process| process.pid() == Pid::new(2))
+        .map(|process| process.state());
+    child.unwrap()
+}
+
+#[test]
+fn sleeping_process_reports_no_event() {
+    let state = child_state_after(Syscall::Sleep(5));
+    assert_eq!(state, ProcessState::Waiting { event: None });
+    assert_eq!(state.to_string(), "SLEEP");
+}
+
+#[test]
+fn blocked_process_reports_its_event() {
+    let state = child_state_after(Syscall::Wait(7));
+    assert_eq!(state, ProcessState::Waiting { event: Some(7) });
+    assert_eq!(state.to_string(), "EVENT 7");
+}
+
+#[test]
+fn scheduling_decisions_display() {
+    let decisions = [
+        (
+            SchedulingDecision::Run {
+                pid: Pid::new(3),
+                timeslice: NonZeroUsize::new(5).unwrap(),
+                core: 0,
+            },
+            "Run 3 for 5 slices",
+        ),
+        (
+            SchedulingDecision::Run {
+                pid: Pid::new(3),
+                timeslice: NonZeroUsize::new(5).unwrap(),
+                core: 1,
+            },
+            "Run 3 for 5 slices on core 1",
+        ),
+        (
+            SchedulingDecision::Sleep(NonZeroUsize::new(10).unwrap()),
+            "Sleep for 10 slices",
+        ),
+        (
+            SchedulingDecision::Deadlock(vec![Pid::new(2), Pid::new(4)]),
+            "Deadlock, unable to schedule anymore processes [2, 4]",
+        ),
+        (
+            SchedulingDecision::Deadlines(vec![(Pid::new(2), 7)]),
+            "Deadlines missed [(2, 7)]",
+        ),
+        (
+            SchedulingDecision::UtilizationExceeded {
+                utilization: 0.9,
+                bound: 0.8284271247,
+            },
+            "Utilization 0.900 exceeds the bound 0.828",
+        ),
+        (SchedulingDecision::Panic, "Panic, process 1 has stopped"),
+        (SchedulingDecision::Done, "Done, no more processes"),
+    ];
+    for (decision, text) in decisions {
+        assert_eq!(decision.to_string(), text);
+    }
+}
+
+#[test]
+fn stop_reasons_display() {
+    let mut reason = StopReason::syscall(Syscall::Fork(2));
+    reason.set_remaining(3);
+    assert_eq!(reason.to_string(), "Syscall Fork(2), remaining 3");
+    assert_eq!(
+        StopReason::syscall(Syscall::Wait(7)).to_string(),
+        "Syscall Wait(7), remaining 0"
+    );
+    assert_eq!(StopReason::expired().to_string(), "Expired");
+    assert_eq!(StopReason::Killed(9).to_string(), "Killed by signal 9");
+}
+
+// procesul 1, cu prioritate 0, semnaleaza evenimentul asteptat de procesul 2, cu prioritate 5,
+// iar procesul 3, cu prioritate 3, ruleaza continuu
+fn priority_inversion_logs(announce: bool) -> Vec<processor::Log> {
+    Processor::run(
+        priority_queue(NonZeroUsize::new(3).unwrap(), 1, 0),
+        move |process| {
+            if announce {
+                process.will_signal(1);
+            } else {
+                process.exec();
+            }
+            process.fork(
+                |process| {
+                    process.wait(1);
+                },
+                5,
+            );
+            process.fork(
+                |process| {
+                    for _ in 0..10 {
+                        process.exec();
+                    }
+                },
+                3,
+            );
+            process.exec();
+            process.signal(1);
+            process.sleep(50);
+        },
+    )
+}
+
+// pozitia primei decizii de rulare a procesului 3, respectiv a semnalului trimis de procesul 1
+fn medium_run_and_signal(logs: &[processor::Log]) -> (usize, usize) {
+    let medium = logs
+        .iter()
+        .position(
+            |log| matches!(log.decision, SchedulingDecision::Run { pid, .. } if pid == Pid::new(3)),
+        )
+        .unwrap();
+    let signal = logs
+        .iter()
+        .position(|log| {
+            matches!(
+                log.stop_reason,
+                Some((
+                    StopReason::Syscall {
+                        syscall: Syscall::Signal(1),
+                        ..
+                    },
+                    _
+                ))
+            )
+        })
+        .unwrap();
+    (medium, signal)
+}
+
+#[test]
+fn signaler_inherits_waiter_priority() {
+    let (medium, signal) = medium_run_and_signal(&priority_inversion_logs(true));
+    assert!(signal < medium);
+
+    // fara mostenirea prioritatii, procesul 3 ruleaza inaintea procesului 1
+    let (medium, signal) = medium_run_and_signal(&priority_inversion_logs(false));
+    assert!(medium < signal);
+}
+
+// inaintea fiecarui pas, peek returneaza de fiecare data decizia pe care o ia next
+fn assert_peek_matches_next<S: Scheduler>(mut scheduler: S) {
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    for reason in snapshot_steps() {
+        let peeked = scheduler.peek();
+        assert_eq!(scheduler.peek(), peeked);
+        assert_eq!(step(&mut scheduler, reason), peeked);
+    }
+    assert_eq!(scheduler.peek(), scheduler.next());
+}
+
+#[test]
+fn peek_matches_next() {
+    assert_peek_matches_next(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    assert_peek_matches_next(priority_queue(NonZeroUsize::new(3).unwrap(), 1, 2));
+    assert_peek_matches_next(cfs(NonZeroUsize::new(6).unwrap(), 1));
+    assert_peek_matches_next(lottery(NonZeroUsize::new(3).unwrap(), 1, 42));
+    assert_peek_matches_next(mlfq(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(2).unwrap(),
+        10,
+        1,
+    ));
+}
+
+#[test]
+fn killed_process_is_never_requeued() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::expired());
+    scheduler.next();
+
+    assert_eq!(
+        scheduler.stop(StopReason::Killed(9)),
+        Ok(SyscallResult::Success)
+    );
+    let pids: Vec<Pid> = scheduler
+        .list()
+        .iter()
+        .map(|process| process.pid())
+        .collect();
+    assert_eq!(pids, vec![Pid::new(1)]);
+    assert_eq!(scheduler.statistics().processes_killed, 1);
+    assert_eq!(scheduler.statistics().processes_terminated, 1);
+    assert_eq!(scheduler.statistics().last_killed, Some((Pid::new(2), 9)));
+
+    // procesul ucis nu mai este planificat
+    for _ in 0..3 {
+        assert!(matches!(
+            step(&mut scheduler, StopReason::expired()),
+            SchedulingDecision::Run { pid, .. } if pid == Pid::new(1)
+        ));
+    }
+}
+
+#[test]
+fn exec_resets_the_process_image() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::expired());
+    let before = scheduler.process_info(Pid::new(1)).unwrap();
+    assert!(before.cpu_ticks_consumed > 0);
+
+    step(
+        &mut scheduler,
+        StopReason::syscall(Syscall::Exec {
+            new_priority: Some(3),
+        }),
+    );
+    let after = scheduler.process_info(Pid::new(1)).unwrap();
+    assert_eq!(after.cpu_ticks_consumed, 0);
+    assert_eq!(scheduler.list()[0].priority(), 3);
+
+    // procesul isi pastreaza pid-ul si continua sa ruleze
+    assert!(matches!(
+        scheduler.next(),
+        SchedulingDecision::Run { pid, .. } if pid == Pid::new(1)
+    ));
+}
+
+// trei procese dorm 5, 8, respectiv 3 unitati de timp, iar primul si ultimul dintre
+// ele se trezesc in acelasi moment
+fn assert_sleep_is_coalesced<S: Scheduler>(mut scheduler: S) {
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    let mut sleepers = Vec::new();
+    for time in [5, 8, 3] {
+        let SchedulingDecision::Run { pid, .. } =
+            step(&mut scheduler, StopReason::syscall(Syscall::Sleep(time)))
+        else {
+            panic!("a process should run before it sleeps");
+        };
+        sleepers.push(pid);
+    }
+
+    assert_eq!(
+        scheduler.next(),
+        SchedulingDecision::Sleep(NonZeroUsize::new(3).unwrap())
+    );
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { .. }));
+    for process in scheduler.list() {
+        let sleeping = process.state() == ProcessState::Waiting { event: None };
+        assert_eq!(sleeping, process.pid() == sleepers[1]);
+    }
+}
+
+#[test]
+fn sleep_is_coalesced() {
+    assert_sleep_is_coalesced(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    assert_sleep_is_coalesced(cfs(NonZeroUsize::new(3).unwrap(), 1));
+}
+
+#[test]
+fn builder_creates_a_round_robin_scheduler() {
+    let mut built = SchedulerBuilder::new()
+        .policy(Policy::RoundRobin)
+        .timeslice(NonZeroUsize::new(3).unwrap())
+        .min_remaining(1)
+        .build()
+        .unwrap();
+    let mut expected = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    built.stop(StopReason::syscall(Syscall::Fork(0))).unwrap();
+    expected
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    for reason in snapshot_steps() {
+        assert_eq!(step(&mut built, reason), step(&mut expected, reason));
+    }
+}
+
+#[test]
+fn builder_rejects_cpu_time_without_cfs() {
+    for policy in [Policy::RoundRobin, Policy::PriorityQueue] {
+        let error = SchedulerBuilder::new()
+            .policy(policy)
+            .timeslice(NonZeroUsize::new(3).unwrap())
+            .cpu_time(NonZeroUsize::new(6).unwrap())
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(
+            error,
+            BuildError::UnsupportedParameter {
+                policy,
+                parameter: "cpu_time"
+            }
+        );
+    }
+    assert_eq!(
+        SchedulerBuilder::new().policy(Policy::Cfs).build().err(),
+        Some(BuildError::MissingParameter {
+            policy: Policy::Cfs,
+            parameter: "cpu_time"
+        })
+    );
+}
+
+#[test]
+fn drained_scheduler_finishes_sleeping_processes() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Sleep(4)));
+    scheduler.drain();
+
+    // procesul 2 nu mai poate crea procese noi
+    assert!(matches!(
+        scheduler.next(),
+        SchedulingDecision::Run { pid, .. } if pid == Pid::new(2)
+    ));
+    let mut fork = StopReason::syscall(Syscall::Fork(0));
+    fork.set_remaining(2);
+    assert_eq!(scheduler.stop(fork), Ok(SyscallResult::Error));
+    assert_eq!(scheduler.list().len(), 2);
+    assert_eq!(scheduler.statistics().processes_spawned, 2);
+    let mut exit = StopReason::syscall(Syscall::Exit);
+    exit.set_remaining(1);
+    scheduler.stop(exit).unwrap();
+
+    // procesul 1 se trezeste si ruleaza pana la final
+    assert!(matches!(scheduler.next(), SchedulingDecision::Sleep(_)));
+    assert!(matches!(
+        scheduler.next(),
+        SchedulingDecision::Run { pid, .. } if pid == Pid::new(1)
+    ));
+    scheduler.stop(exit).unwrap();
+    assert_eq!(scheduler.next(), SchedulingDecision::Done);
+}
+
+// dupa reset, planificatorul ia aceleasi decizii ca unul nou
+fn assert_reset_restarts<S: Scheduler>(mut scheduler: S) {
+    let run = |scheduler: &mut S| {
+        // numerotarea PID-urilor reincepe de la 1
+        assert_eq!(
+            scheduler.stop(StopReason::syscall(Syscall::Fork(0))),
+            Ok(SyscallResult::Pid(Pid::new(1)))
+        );
+        let decisions: Vec<SchedulingDecision> = snapshot_steps()
+            .into_iter()
+            .map(|reason| step(scheduler, reason))
+            .collect();
+        (decisions, scheduler.statistics())
+    };
+    let first = run(&mut scheduler);
+    scheduler.reset();
+    assert!(scheduler.list().is_empty());
+    assert_eq!(scheduler.statistics(), Default::default());
+    assert_eq!(scheduler.current_time(), 0);
+    assert_eq!(run(&mut scheduler), first);
+}
+
+#[test]
+fn reset_restarts_the_scheduler() {
+    assert_reset_restarts(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    assert_reset_restarts(priority_queue(NonZeroUsize::new(3).unwrap(), 1, 2));
+    assert_reset_restarts(cfs(NonZeroUsize::new(6).unwrap(), 1));
+    assert_reset_restarts(mlfq(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(2).unwrap(),
+        10,
+        1,
+    ));
+
+    // planificatorii cu timeslice si wrapper-ele implementeaza reset la fel
+    let timeslice = || NonZeroUsize::new(3).unwrap();
+    let schedulers: Vec<Box<dyn Scheduler>> = vec![
+        Box::new(sjf(timeslice(), 1)),
+        Box::new(stride(timeslice(), 1)),
+        Box::new(gang(timeslice(), NonZeroUsize::new(2).unwrap(), 1)),
+        Box::new(round_robin_multicore(
+            NonZeroUsize::new(2).unwrap(),
+            timeslice(),
+            1,
+        )),
+        Box::new(round_robin_work_stealing(
+            NonZeroUsize::new(2).unwrap(),
+            timeslice(),
+            2,
+            1,
+        )),
+        Box::new(TracingScheduler::new(round_robin(timeslice(), 1))),
+        Box::new(StarvationDetector::new(
+            round_robin(timeslice(), 1),
+            10,
+            Box::new(|_, _| {}),
+        )),
+    ];
+    for scheduler in schedulers {
+        assert_reset_restarts(scheduler);
+    }
+}
+
+#[test]
+fn clock_counts_run_and_sleep_time() {
+    let mut scheduler = round_robin(NonZeroUsize::new(5).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    assert_eq!(scheduler.current_time(), 0);
+
+    // procesul ruleaza 5 unitati de timp, doarme 3, apoi mai ruleaza 2
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { .. }));
+    scheduler
+        .stop(StopReason::syscall(Syscall::Sleep(3)))
+        .unwrap();
+    assert_eq!(scheduler.current_time(), 5);
+    assert_eq!(
+        scheduler.next(),
+        SchedulingDecision::Sleep(NonZeroUsize::new(3).unwrap())
+    );
+    assert_eq!(scheduler.current_time(), 8);
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { .. }));
+    let mut exit = StopReason::syscall(Syscall::Exit);
+    exit.set_remaining(3);
+    scheduler.stop(exit).unwrap();
+    assert_eq!(scheduler.current_time(), 10);
+}
+
+#[test]
+fn tick_count_follows_the_clock() {
+    let schedulers: Vec<Box<dyn Scheduler>> = vec![
+        Box::new(round_robin(NonZeroUsize::new(3).unwrap(), 1)),
+        Box::new(priority_queue(NonZeroUsize::new(3).unwrap(), 1, 0)),
+        Box::new(cfs(NonZeroUsize::new(3).unwrap(), 1)),
+    ];
+    for mut scheduler in schedulers {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        for _ in 0..4 {
+            step(&mut scheduler, StopReason::expired());
+        }
+        assert_eq!(scheduler.tick_count(), scheduler.current_time());
+
+        // procesul 1 a fost creat la tick-ul 0 si de atunci a rulat sau a asteptat
+        let info = scheduler.process_info(Pid::new(1)).unwrap();
+        assert_eq!(
+            info.cpu_ticks_consumed + info.waiting_ticks,
+            scheduler.tick_count()
+        );
+        scheduler.reset();
+        assert_eq!(scheduler.tick_count(), 0);
+    }
+}
+
+// procesele 1, 2 si 3 au prioritatile 0, 1, respectiv 2, deci 1, 2, respectiv 3 bilete
+#[test]
+fn stride_shares_processor_by_tickets() {
+    let mut scheduler = stride(NonZeroUsize::new(1).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(1)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(2)));
+
+    // procesele sunt create la momente diferite, asa ca impartirea devine exacta
+    // dupa primele decizii
+    for _ in 0..6 {
+        step(&mut scheduler, StopReason::expired());
+    }
+    let mut runs = [0; 3];
+    for _ in 0..60 {
+        if let SchedulingDecision::Run { pid, .. } = step(&mut scheduler, StopReason::expired()) {
+            runs[(1..=3).position(|n| pid == n).unwrap()] += 1;
+        }
+    }
+    assert_eq!(runs, [10, 20, 30]);
+}
+
+#[test]
+fn round_robin_builder_limits_processes() {
+    let mut scheduler = RoundRobinBuilder::new()
+        .timeslice(4)
+        .minimum_remaining_timeslice(1)
+        .max_processes(2)
+        .build()
+        .unwrap();
+    assert_eq!(
+        scheduler.stop(StopReason::syscall(Syscall::Fork(0))),
+        Ok(SyscallResult::Pid(Pid::new(1)))
+    );
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { .. }));
+    let mut fork = StopReason::syscall(Syscall::Fork(0));
+    fork.set_remaining(2);
+    assert_eq!(scheduler.stop(fork), Ok(SyscallResult::NoMemory));
+    assert_eq!(scheduler.process_count(), 2);
+}
+
+#[test]
+fn minimum_remaining_timeslice_boundaries() {
+    // process 1 forks process 2 with `remaining` time units left from a timeslice of 5
+    let after_fork = |minimum, remaining| {
+        let mut scheduler = round_robin(NonZeroUsize::new(5).unwrap(), minimum);
+        scheduler.stop(StopReason::syscall(Syscall::Fork(0))).unwrap();
+        scheduler.next();
+        let mut fork = StopReason::syscall(Syscall::Fork(0));
+        fork.set_remaining(remaining);
+        scheduler.stop(fork).unwrap();
+        scheduler.next()
+    };
+    let run = |pid, timeslice| SchedulingDecision::Run {
+        pid: Pid::new(pid),
+        timeslice: NonZeroUsize::new(timeslice).unwrap(),
+        core: 0,
+    };
+    assert_eq!(after_fork(3, 2), run(2, 5));
+    assert_eq!(after_fork(3, 3), run(1, 3));
+    assert_eq!(after_fork(3, 4), run(1, 4));
+    // the minimum can be equal to the timeslice or 0 when the builder is not used; the
+    // system call takes a time unit, so a minimum equal to the timeslice is never reached
+    assert_eq!(after_fork(5, 4), run(2, 5));
+    assert_eq!(after_fork(0, 0), run(2, 5));
+}
+
+#[test]
+fn idle_process_runs_instead_of_sleeping() {
+    let build = || {
+        RoundRobinBuilder::new()
+            .timeslice(3)
+            .idle_process(true)
+            .build()
+            .unwrap()
+    };
+    let mut scheduler = build();
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Sleep(2)));
+
+    // the process sleeps for 2 time units, in which the idle process runs
+    let idle = SchedulingDecision::Run {
+        pid: Pid::IDLE,
+        timeslice: NonZeroUsize::new(1).unwrap(),
+        core: 0,
+    };
+    for _ in 0..2 {
+        assert_eq!(scheduler.next(), idle);
+        assert_eq!(scheduler.stop(StopReason::expired()), Ok(SyscallResult::Success));
+    }
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 1));
+    assert_eq!(scheduler.statistics().idle_ticks, 2);
+    assert_eq!(scheduler.current_time(), 3);
+
+    let logs = Processor::run(build(), |process| {
+        process.sleep(2);
+    });
+    let idle_runs = logs.iter().filter(|log| log.decision == idle).count();
+    assert_eq!(idle_runs, 2);
+}
+
+#[test]
+fn constructors_accept_time_units() {
+    let decisions = |mut scheduler: RoundRobin| {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        // the two processes run for 4 timeslices
+        let mut runs = 0;
+        scheduler.run_until(|_| {
+            runs += 1;
+            runs == 4
+        })
+    };
+    let expected = decisions(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    assert_eq!(
+        decisions(round_robin(TimeUnit::try_from(3).unwrap(), 1)),
+        expected
+    );
+    assert_eq!(
+        decisions(round_robin(TimeUnit::new(NonZeroUsize::new(3).unwrap()), 1)),
+        expected
+    );
+    assert!(TimeUnit::try_from(0).is_err());
+    let run = |pid, timeslice| SchedulingDecision::Run {
+        pid: Pid::new(pid),
+        timeslice: NonZeroUsize::new(timeslice).unwrap(),
+        core: 0,
+    };
+    assert_eq!(expected, [run(1, 2), run(2, 3), run(1, 3), run(2, 3)]);
+}
+
+#[test]
+fn timing_wheel_size_does_not_change_decisions() {
+    let decisions = |timing_wheel_size| {
+        let scheduler = RoundRobinBuilder::new()
+            .timeslice(2)
+            .timing_wheel_size(timing_wheel_size)
+            .build()
+            .unwrap();
+        let logs = Processor::run(scheduler, |process| {
+            for sleep in [1, 5, 9] {
+                process.fork(
+                    move |process| {
+                        process.sleep(sleep);
+                        process.exec();
+                    },
+                    0,
+                );
+            }
+            process.sleep(20);
+        });
+        logs.into_iter().map(|log| log.decision).collect::<Vec<_>>()
+    };
+
+    // the processes that sleep for longer than the wheel covers wake up at the same time
+    let expected = decisions(64);
+    assert!(expected.contains(&SchedulingDecision::Sleep(NonZeroUsize::new(7).unwrap())));
+    assert_eq!(expected.last(), Some(&SchedulingDecision::Done));
+    assert_eq!(decisions(2), expected);
+    assert_eq!(decisions(1), expected);
+}
+
+#[test]
+fn builders_validate_parameters() {
+    assert_eq!(
+        RoundRobinBuilder::new()
+            .timeslice(3)
+            .minimum_remaining_timeslice(3)
+            .build()
+            .err(),
+        Some(SchedulerConfigError::MinimumRemainingTooLarge {
+            minimum_remaining_timeslice: 3,
+            timeslice: 3
+        })
+    );
+    assert_eq!(
+        PriorityQueueBuilder::new().timeslice(0).build().err(),
+        Some(SchedulerConfigError::ZeroParameter("timeslice"))
+    );
+    assert_eq!(
+        CfsBuilder::new().build().err(),
+        Some(SchedulerConfigError::MissingParameter("cpu_time"))
+    );
+    assert!(CfsBuilder::new()
+        .cpu_time(6)
+        .max_processes(8)
+        .build()
+        .is_ok());
+}
+
+// comparatorul alege procesul cu cel mai mare timp total, adica procesul cel mai vechi,
+// iar procesele egale ruleaza in ordinea cozii de ready
+#[test]
+fn custom_comparator_selects_the_least_process() {
+    let cases: Vec<(ProcessComparator, [usize; 4])> = vec![
+        (Box::new(|a, b| b.timings.0.cmp(&a.timings.0)), [1, 1, 1, 1]),
+        (Box::new(|a, b| b.pid.cmp(&a.pid)), [3, 3, 3, 3]),
+        (Box::new(|_, _| Ordering::Equal), [2, 3, 1, 2]),
+    ];
+    for (cmp, expected) in cases {
+        let mut scheduler = custom(NonZeroUsize::new(2).unwrap(), 1, cmp);
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+        let mut pids = Vec::new();
+        for _ in 0..4 {
+            if let SchedulingDecision::Run { pid, .. } = step(&mut scheduler, StopReason::expired())
+            {
+                pids.push(pid);
+            }
+        }
+        assert_eq!(pids, expected.map(Pid::new));
+    }
+}
+
+// indicele Jain este aproape 1 cand procesele ruleaza la fel de mult si scade cand un
+// proces primeste mai mult timp, chiar daca celelalte procese s-au terminat
+#[test]
+fn fairness_index_compares_execution_times() {
+    let mut fair = round_robin(NonZeroUsize::new(2).unwrap(), 1);
+    let mut skewed = round_robin(NonZeroUsize::new(2).unwrap(), 1);
+    for scheduler in [&mut fair, &mut skewed] {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        step(scheduler, StopReason::syscall(Syscall::Fork(0)));
+    }
+    assert_eq!(fair.fairness_index(), 1.0);
+
+    for _ in 0..10 {
+        step(&mut fair, StopReason::expired());
+    }
+    // procesul 1 doarme, iar procesul 2 primeste tot procesorul si apoi se termina
+    step(&mut skewed, StopReason::syscall(Syscall::Sleep(100)));
+    for _ in 0..10 {
+        step(&mut skewed, StopReason::expired());
+    }
+    step(&mut skewed, StopReason::syscall(Syscall::Exit));
+    assert_eq!(skewed.statistics().exited.processes, 1);
+    assert!(fair.fairness_index() > 0.99);
+    assert!(skewed.fairness_index() < 0.7);
+}
+
+// timpul de turnaround este masurat de la sfarsitul apelului fork pana la terminarea
+// procesului, iar procesele care inca ruleaza nu sunt returnate
+#[test]
+fn turnaround_times_of_completed_processes() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    let arrival = scheduler.current_time();
+    assert!(scheduler.turnaround_times().is_empty());
+
+    let mut completion = None;
+    while completion.is_none() {
+        let reason = match scheduler.peek() {
+            SchedulingDecision::Run { pid, .. } if pid == 2 => StopReason::syscall(Syscall::Exit),
+            _ => StopReason::expired(),
+        };
+        step(&mut scheduler, reason);
+        if scheduler.process_count() == 1 {
+            completion = Some(scheduler.current_time());
+        }
+    }
+
+    let times = scheduler.turnaround_times();
+    assert_eq!(times.len(), 1);
+    assert_eq!(times[&Pid::new(2)], completion.unwrap() - arrival);
+    assert!(times[&Pid::new(2)] > 0);
+}
+
+// timpul de raspuns este masurat pana la prima planificare a procesului, chiar daca
+// procesul s-a terminat intre timp
+#[test]
+fn response_times_measure_the_first_run() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::ForkMany(2)));
+    let arrival = scheduler.current_time();
+
+    let mut first_runs = HashMap::new();
+    while first_runs.len() < 3 {
+        let SchedulingDecision::Run { pid, .. } = scheduler.peek() else {
+            panic!("a process should run");
+        };
+        first_runs.entry(pid).or_insert(scheduler.current_time());
+        let reason = if pid == 2 {
+            StopReason::syscall(Syscall::Exit)
+        } else {
+            StopReason::expired()
+        };
+        step(&mut scheduler, reason);
+    }
+
+    let times = scheduler.response_times();
+    assert_eq!(times.len(), 3);
+    let first_run = scheduler
+        .list()
+        .into_iter()
+        .find(|process| process.pid() == 3)
+        .and_then(|process| process.first_run_tick());
+    assert_eq!(first_run, Some(first_runs[&Pid::new(3)]));
+    assert_eq!(times[&Pid::new(1)], 0);
+    for pid in [2, 3] {
+        assert_eq!(times[&Pid::new(pid)], first_runs[&Pid::new(pid)] - arrival);
+    }
+    let total: usize = times.values().sum();
+    let stats = scheduler.statistics();
+    assert_eq!(stats.processes_started, 3);
+    assert_eq!(stats.average_response_time(), total as f64 / 3.0);
+}
+
+// fiecare apel de sistem si fiecare expirare a timeslice-ului incheie o rafala a
+// procesului care ruleaza
+#[test]
+fn burst_histogram_records_every_burst() {
+    let mut scheduler = round_robin(NonZeroUsize::new(5).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    for _ in 0..3 {
+        scheduler.next();
+        scheduler.stop(StopReason::expired()).unwrap();
+    }
+    scheduler.next();
+    let mut fork = StopReason::syscall(Syscall::Fork(0));
+    fork.set_remaining(4);
+    scheduler.stop(fork).unwrap();
+    scheduler.next();
+    scheduler.stop(StopReason::expired()).unwrap();
+
+    let histogram = scheduler.burst_histogram(Pid::new(1)).unwrap();
+    assert_eq!(histogram.bursts, vec![(1, 1), (4, 1), (5, 3)]);
+    assert_eq!(histogram.count(), 5);
+    assert_eq!(histogram.mean(), 4.0);
+    assert_eq!((histogram.p50(), histogram.p95(), histogram.p99()), (5, 5, 5));
+    assert_eq!(scheduler.burst_histogram(Pid::new(2)), Some(BurstHistogram::default()));
+    assert_eq!(scheduler.burst_histogram(Pid::new(3)), None);
+}
+
+// procesele care asteapta un eveniment apar in graful de asteptare, cele care dorm nu
+#[test]
+fn wait_graph_lists_the_events_of_waiting_processes() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::ForkMany(3)));
+    assert!(scheduler.wait_graph().is_empty());
+
+    while scheduler.waiting_len() < 4 {
+        let reason = match scheduler.peek() {
+            SchedulingDecision::Run { pid, .. } if pid == 1 => {
+                StopReason::syscall(Syscall::Sleep(100))
+            }
+            SchedulingDecision::Run { pid, .. } if pid == 2 => {
+                StopReason::syscall(Syscall::Wait(1))
+            }
+            _ => StopReason::syscall(Syscall::Wait(2)),
+        };
+        step(&mut scheduler, reason);
+    }
+
+    assert_eq!(
+        scheduler.wait_graph(),
+        vec![(Pid::new(2), 1), (Pid::new(3), 2), (Pid::new(4), 2)]
+    );
+}
+
+// grupurile primesc procesorul proportional cu numarul lor de parti, iar pid-urile
+// proceselor sunt unice in toata ierarhia
+#[test]
+fn hierarchical_scheduler_shares_the_processor_between_groups() {
+    let mut scheduler = HierarchicalScheduler::with_seed(
+        Box::new(round_robin(NonZeroUsize::new(1).unwrap(), 1)),
+        1,
+        7,
+    );
+    let group = scheduler.add_group(Box::new(round_robin(NonZeroUsize::new(1).unwrap(), 1)), 3);
+    assert_eq!(group, 1);
+    assert_eq!(scheduler.spawn(0, 0), Ok(SyscallResult::Pid(Pid::new(1))));
+    assert_eq!(scheduler.spawn(group, 0), Ok(SyscallResult::Pid(Pid::new(2))));
+    assert_eq!(scheduler.spawn(group, 0), Ok(SyscallResult::Error));
+
+    // procesul 2 creeaza un proces in grupul sau, care primeste pid-ul global 3
+    loop {
+        let SchedulingDecision::Run { pid, .. } = scheduler.next() else {
+            panic!("a process should be ready");
+        };
+        let reason = if pid == 2 {
+            StopReason::syscall(Syscall::Fork(0))
+        } else {
+            StopReason::expired()
+        };
+        if scheduler.stop(reason) == Ok(SyscallResult::Pid(Pid::new(3))) {
+            break;
+        }
+    }
+    assert_eq!(scheduler.group_of(Pid::new(3)), Some(group));
+    assert_eq!(scheduler.process_count(), 3);
+
+    let mut runs = [0; 2];
+    for _ in 0..400 {
+        if let SchedulingDecision::Run { pid, .. } = step(&mut scheduler, StopReason::expired()) {
+            runs[scheduler.group_of(pid).unwrap()] += 1;
+        }
+    }
+    assert_eq!(runs[0] + runs[1], 400);
+    assert!((70..130).contains(&runs[0]), "{runs:?}");
+}
+
+// un apel fork multiplu creeaza procesele cu pid-uri consecutive si consuma din
+// timeslice-ul parintelui la fel ca un singur apel fork
+#[test]
+fn fork_many_creates_consecutive_children() {
+    let schedulers: Vec<Box<dyn Scheduler>> = vec![
+        Box::new(round_robin(NonZeroUsize::new(5).unwrap(), 1)),
+        Box::new(priority_queue(NonZeroUsize::new(5).unwrap(), 1, 2)),
+        Box::new(cfs(NonZeroUsize::new(5).unwrap(), 1)),
+        Box::new(stride(NonZeroUsize::new(5).unwrap(), 1)),
+    ];
+    for mut scheduler in schedulers {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(3)))
+            .unwrap();
+        let SchedulingDecision::Run { timeslice, .. } = scheduler.next() else {
+            panic!("the first process should run");
+        };
+        let remaining = timeslice.get() - 2;
+        let result = scheduler.stop(StopReason::Syscall {
+            syscall: Syscall::ForkMany(5),
+            remaining,
+        });
+        let children: Vec<Pid> = (2..=6).map(Pid::new).collect();
+        assert_eq!(result, Ok(SyscallResult::Pids(children.clone())));
+        assert_eq!(scheduler.current_time(), 2);
+
+        let listed: Vec<(Pid, i8)> = scheduler
+            .list()
+            .into_iter()
+            .filter(|process| process.pid() != 1)
+            .map(|process| (process.pid(), process.priority()))
+            .collect();
+        let mut pids: Vec<Pid> = listed.iter().map(|&(pid, _)| pid).collect();
+        pids.sort();
+        assert_eq!(pids, children);
+        assert!(listed.iter().all(|&(_, priority)| priority == 3));
+
+        assert_eq!(
+            scheduler.stop(StopReason::Syscall {
+                syscall: Syscall::ForkMany(0),
+                remaining: remaining - 1,
+            }),
+            Ok(SyscallResult::InvalidArgument)
+        );
+    }
+}
+
+// procesele noi trebuie sa aiba o prioritate din intervalul planificatorului
+#[test]
+fn fork_priority_outside_of_range_is_refused() {
+    let schedulers: Vec<Box<dyn Scheduler>> = vec![
+        Box::new(round_robin(NonZeroUsize::new(3).unwrap(), 1)),
+        Box::new(priority_queue(NonZeroUsize::new(3).unwrap(), 1, 2)),
+        Box::new(cfs(NonZeroUsize::new(3).unwrap(), 1)),
+        Box::new(stride(NonZeroUsize::new(3).unwrap(), 1)),
+    ];
+    for mut scheduler in schedulers {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(99)))
+            .unwrap();
+        for (priority, range) in [(-1, 0..=99), (100, 0..=99), (0, 1..=10)] {
+            scheduler.set_priority_range(range);
+            let SchedulingDecision::Run { timeslice, .. } = scheduler.next() else {
+                panic!("process 1 should run");
+            };
+            let mut fork = StopReason::syscall(Syscall::Fork(priority));
+            fork.set_remaining(timeslice.get() - 1);
+            assert_eq!(scheduler.stop(fork), Ok(SyscallResult::InvalidArgument));
+        }
+        assert_eq!(scheduler.process_count(), 1);
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(10)));
+        assert_eq!(scheduler.process_count(), 2);
+    }
+}
+
+// un proces nou poate fi creat dupa ce se termina un proces existent
+#[test]
+fn max_processes_counts_living_processes() {
+    let mut scheduler = mlfq(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(4).unwrap(),
+        0,
+        1,
+    );
+    scheduler.set_max_processes(NonZeroUsize::new(2));
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    assert_eq!(scheduler.process_count(), 2);
+
+    let SchedulingDecision::Run { pid, .. } =
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)))
+    else {
+        panic!("process 1 should run");
+    };
+    assert_eq!(pid, Pid::new(1));
+    assert_eq!(scheduler.process_count(), 2);
+    step(&mut scheduler, StopReason::syscall(Syscall::Sleep(1)));
+    let mut exit = StopReason::syscall(Syscall::Exit);
+    exit.set_remaining(2);
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { .. }));
+    scheduler.stop(exit).unwrap();
+    assert_eq!(scheduler.process_count(), 1);
+
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { .. }));
+    let mut fork = StopReason::syscall(Syscall::Fork(0));
+    fork.set_remaining(2);
+    assert_eq!(scheduler.stop(fork), Ok(SyscallResult::Pid(Pid::new(3))));
+}
+
+#[test]
+fn process_name_survives_state_changes() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(
+        &mut scheduler,
+        StopReason::syscall(Syscall::SetName(Name::new("worker"))),
+    );
+    assert_eq!(scheduler.list()[0].name(), Some("worker"));
+
+    step(&mut scheduler, StopReason::syscall(Syscall::Sleep(2)));
+    let processes = scheduler.list();
+    assert_eq!(processes[0].state(), ProcessState::Waiting { event: None });
+    assert_eq!(processes[0].name(), Some("worker"));
+}
+
+#[test]
+fn gang_holds_group_while_member_is_blocked() {
+    let mut scheduler = gang(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(2).unwrap(),
+        1,
+    );
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    let groups: Vec<_> = scheduler
+        .list()
+        .iter()
+        .map(|process| process.pgid())
+        .collect();
+    assert_eq!(
+        groups,
+        vec![Some(Pid::new(1)), Some(Pid::new(1)), Some(Pid::new(3))]
+    );
+
+    step(&mut scheduler, StopReason::expired());
+    let run_pid = |decision| match decision {
+        SchedulingDecision::Run { pid, .. } => pid,
+        decision => panic!("expected a process to run, got {decision}"),
+    };
+    assert_eq!(
+        run_pid(step(&mut scheduler, StopReason::syscall(Syscall::Wait(1)))),
+        Pid::new(2)
+    );
+
+    // process 1 is held while the other member of its group is blocked
+    for _ in 0..3 {
+        assert_eq!(
+            run_pid(step(&mut scheduler, StopReason::expired())),
+            Pid::new(3)
+        );
+    }
+    assert_eq!(
+        run_pid(step(
+            &mut scheduler,
+            StopReason::syscall(Syscall::Signal(1))
+        )),
+        Pid::new(3)
+    );
+    assert_eq!(
+        run_pid(step(&mut scheduler, StopReason::expired())),
+        Pid::new(3)
+    );
+
+    assert_eq!(
+        run_pid(step(&mut scheduler, StopReason::expired())),
+        Pid::new(1)
+    );
+    assert_eq!(
+        run_pid(step(&mut scheduler, StopReason::expired())),
+        Pid::new(2)
+    );
+}
+
+#[test]
+fn exited_pids_are_recycled_after_delay() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler.set_pid_policy(PidPolicy::Recycle { delay: 2 });
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::expired());
+    assert_eq!(
+        step(&mut scheduler, StopReason::syscall(Syscall::Exit)),
+        SchedulingDecision::Run {
+            pid: Pid::new(2),
+            timeslice: NonZeroUsize::new(3).unwrap(),
+            core: 0,
+        }
+    );
+
+    step(&mut scheduler, StopReason::expired());
+
+    // PID 2 is released when process 1 collects it, and it cannot be reused yet
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 1));
+    let mut wait = StopReason::syscall(Syscall::WaitPid(Pid::new(2)));
+    wait.set_remaining(2);
+    assert_eq!(scheduler.stop(wait), Ok(SyscallResult::ExitCode(0)));
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 1));
+    let mut fork = StopReason::syscall(Syscall::Fork(0));
+    fork.set_remaining(1);
+    assert_eq!(scheduler.stop(fork), Ok(SyscallResult::Pid(Pid::new(4))));
+
+    step(&mut scheduler, StopReason::expired());
+
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 3));
+    fork.set_remaining(2);
+    assert_eq!(scheduler.stop(fork), Ok(SyscallResult::Pid(Pid::new(2))));
+}
+
+// pid-ul eliberat de un proces terminat este dat urmatorului proces creat doar cu
+// PidPolicy::Recycle
+#[test]
+fn pid_policy_is_set_at_construction() {
+    let forked_pid = |pid_policy| {
+        let mut scheduler = RoundRobinBuilder::new()
+            .timeslice(3)
+            .pid_policy(pid_policy)
+            .build()
+            .unwrap();
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        step(&mut scheduler, StopReason::expired());
+        step(&mut scheduler, StopReason::syscall(Syscall::Exit));
+        step(&mut scheduler, StopReason::syscall(Syscall::WaitChild));
+        let SchedulingDecision::Run { pid, timeslice, .. } = scheduler.next() else {
+            panic!("process 1 should run");
+        };
+        assert_eq!(pid, Pid::new(1));
+        let mut fork = StopReason::syscall(Syscall::Fork(0));
+        fork.set_remaining(timeslice.get() - 1);
+        scheduler.stop(fork).unwrap()
+    };
+    assert_eq!(
+        forked_pid(PidPolicy::Recycle { delay: 0 }),
+        SyscallResult::Pid(Pid::new(2))
+    );
+    assert_eq!(
+        forked_pid(PidPolicy::Monotonic),
+        SyscallResult::Pid(Pid::new(3))
+    );
+}
+
+// pid-ul unui proces zombie nu este refolosit pana cand parintele preia codul de iesire
+#[test]
+fn zombie_pid_is_reused_only_after_it_is_collected() {
+    let mut scheduler = round_robin(NonZeroUsize::new(10).unwrap(), 0);
+    scheduler.set_pid_policy(PidPolicy::Recycle { delay: 0 });
+    let state = |scheduler: &RoundRobin, pid: usize| {
+        scheduler.process_info(Pid::new(pid)).map(|info| info.state)
+    };
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::expired());
+    step(&mut scheduler, StopReason::syscall(Syscall::Exit));
+    assert_eq!(state(&scheduler, 2), Some(ProcessState::Zombie));
+
+    let fork = StopReason::syscall(Syscall::Fork(0));
+    assert_eq!(
+        step(&mut scheduler, fork),
+        SchedulingDecision::Run {
+            pid: Pid::new(1),
+            timeslice: NonZeroUsize::new(10).unwrap(),
+            core: 0,
+        }
+    );
+    assert_eq!(state(&scheduler, 2), Some(ProcessState::Zombie));
+    assert_eq!(state(&scheduler, 3), Some(ProcessState::Ready));
+
+    // dupa WaitPid, pid-ul 2 este dat urmatorului proces creat
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 1));
+    let mut wait = StopReason::syscall(Syscall::WaitPid(Pid::new(2)));
+    wait.set_remaining(8);
+    assert_eq!(scheduler.stop(wait), Ok(SyscallResult::ExitCode(0)));
+    assert_eq!(state(&scheduler, 2), None);
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 1));
+    let mut fork = StopReason::syscall(Syscall::Fork(0));
+    fork.set_remaining(7);
+    assert_eq!(scheduler.stop(fork), Ok(SyscallResult::Pid(Pid::new(2))));
+    assert_eq!(state(&scheduler, 2), Some(ProcessState::Ready));
+}
+
+// procesul 2 poate rula cel mult 2 unitati de timp din fiecare perioada de 10
+#[test]
+fn cpu_quota_throttles_until_the_next_period() {
+    let mut scheduler = RoundRobinBuilder::new()
+        .timeslice(2)
+        .quota_period(10)
+        .build()
+        .unwrap();
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::expired());
+    step(&mut scheduler, StopReason::syscall(Syscall::SetCpuQuota(Some(0.2))));
+
+    // procesul 2 si-a folosit cota la momentul 4 si la momentul 14
+    let mut pids = Vec::new();
+    for _ in 0..8 {
+        if let SchedulingDecision::Run { pid, .. } = step(&mut scheduler, StopReason::expired()) {
+            pids.push(pid);
+        }
+    }
+    assert_eq!(pids, [2, 1, 1, 1, 1, 2, 1, 1].map(Pid::new));
+    let info = scheduler.process_info(Pid::new(2)).unwrap();
+    assert_eq!(info.state, ProcessState::Throttled);
+    assert_eq!(info.cpu_ticks_consumed, 4);
+    assert_eq!(info.throttled_ticks, 10);
+    assert_eq!(scheduler.statistics().throttled_ticks, 10);
+
+    // daca toate procesele sunt oprite, procesorul doarme pana la perioada urmatoare
+    let mut scheduler = RoundRobinBuilder::new()
+        .timeslice(2)
+        .quota_period(10)
+        .build()
+        .unwrap();
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::SetCpuQuota(Some(0.5))));
+    for _ in 0..3 {
+        step(&mut scheduler, StopReason::expired());
+    }
+    assert_eq!(scheduler.current_time(), 6);
+    assert_eq!(
+        scheduler.next(),
+        SchedulingDecision::Sleep(NonZeroUsize::new(4).unwrap())
+    );
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 1));
+}
+
+// pid-urile eliberate sunt refolosite in ordine crescatoare, inaintea celor noi
+#[test]
+fn lowest_freed_pid_is_recycled_first() {
+    let mut scheduler = SchedulerBuilder::new()
+        .policy(Policy::RoundRobin)
+        .timeslice(NonZeroUsize::new(3).unwrap())
+        .pid_policy(PidPolicy::Recycle { delay: 0 })
+        .build()
+        .unwrap();
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::expired());
+    // procesul 3 se termina inaintea procesului 2
+    step(&mut scheduler, StopReason::expired());
+    step(&mut scheduler, StopReason::syscall(Syscall::Exit));
+    step(&mut scheduler, StopReason::expired());
+    step(&mut scheduler, StopReason::syscall(Syscall::Exit));
+    assert_eq!(scheduler.process_count(), 1);
+    // procesul 1 preia codurile de iesire, eliberand pid-urile 3 si 2 in aceasta ordine
+    for _ in 0..2 {
+        step(&mut scheduler, StopReason::syscall(Syscall::WaitChild));
+    }
+    step(&mut scheduler, StopReason::expired());
+
+    let mut pids = Vec::new();
+    for _ in 0..3 {
+        let SchedulingDecision::Run { pid, timeslice, .. } = scheduler.next() else {
+            panic!("process 1 should run");
+        };
+        assert_eq!(pid, Pid::new(1));
+        let mut fork = StopReason::syscall(Syscall::Fork(0));
+        fork.set_remaining(timeslice.get() - 1);
+        pids.push(scheduler.stop(fork).unwrap());
+    }
+    assert_eq!(pids, [2, 3, 4].map(|pid| SyscallResult::Pid(Pid::new(pid))));
+}
+
+#[test]
+fn multicore_balances_run_queues() {
+    let mut scheduler = round_robin_multicore(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        1,
+    );
+    let run = |pid, timeslice, core| SchedulingDecision::Run {
+        pid: Pid::new(pid),
+        timeslice: NonZeroUsize::new(timeslice).unwrap(),
+        core,
+    };
+    let fork = |remaining| StopReason::Syscall {
+        syscall: Syscall::Fork(0),
+        remaining,
+    };
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    assert_eq!(scheduler.next_batch(), vec![run(1, 3, 0)]);
+    scheduler.stop_core(0, fork(2)).unwrap();
+
+    // the new processes are added to the shortest run queue
+    assert_eq!(scheduler.next_batch(), vec![run(1, 2, 0), run(2, 3, 1)]);
+    scheduler.stop_core(0, fork(1)).unwrap();
+    scheduler.stop_core(1, fork(2)).unwrap();
+    assert_eq!(scheduler.queue_depths(), vec![2, 2]);
+
+    assert_eq!(scheduler.next_batch(), vec![run(1, 1, 0), run(2, 2, 1)]);
+    scheduler.stop_core(0, StopReason::expired()).unwrap();
+    scheduler.stop_core(1, StopReason::syscall(Syscall::Exit)).unwrap();
+    assert_eq!(scheduler.next_batch(), vec![run(3, 3, 0), run(4, 3, 1)]);
+    scheduler.stop_core(0, StopReason::expired()).unwrap();
+    scheduler.stop_core(1, StopReason::syscall(Syscall::Exit)).unwrap();
+    assert_eq!(scheduler.queue_depths(), vec![2, 0]);
+
+    // process 3 is migrated to the empty run queue of core 1
+    assert_eq!(scheduler.next_batch(), vec![run(1, 3, 0), run(3, 3, 1)]);
+    assert_eq!(scheduler.queue_depths(), vec![1, 1]);
+    assert_eq!(scheduler.migrations(), 1);
+}
+
+#[test]
+fn idle_core_steals_from_busiest_queue() {
+    let mut scheduler = round_robin_work_stealing(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        2,
+        1,
+    );
+    let run = |pid, core| SchedulingDecision::Run {
+        pid: Pid::new(pid),
+        timeslice: NonZeroUsize::new(3).unwrap(),
+        core,
+    };
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    scheduler.next_batch();
+    scheduler
+        .stop_core(0, StopReason::syscall(Syscall::ForkMany(5)))
+        .unwrap();
+    assert_eq!(scheduler.queue_depths(), vec![3, 3]);
+
+    // the processes of core 1 exit, the ones of core 0 expire
+    for (running, exiting) in [(3, 2), (5, 4), (1, 6)] {
+        assert_eq!(scheduler.next_batch(), vec![run(running, 0), run(exiting, 1)]);
+        scheduler.stop_core(0, StopReason::expired()).unwrap();
+        scheduler
+            .stop_core(1, StopReason::syscall(Syscall::Exit))
+            .unwrap();
+    }
+    assert_eq!(scheduler.queue_depths(), vec![3, 0]);
+    assert_eq!(scheduler.statistics().steals_performed, 0);
+
+    // core 1 steals process 1 from the tail of the run queue of core 0
+    assert_eq!(scheduler.next_batch(), vec![run(3, 0), run(1, 1)]);
+    assert_eq!(scheduler.queue_depths(), vec![2, 1]);
+    assert_eq!(scheduler.statistics().steals_performed, 1);
+    assert_eq!(scheduler.migrations(), 1);
+}
+
+#[test]
+fn multicore_batch_advances_the_clock_once() {
+    let mut scheduler = multicore_round_robin(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        1,
+    );
+    let run = |pid, core| SchedulingDecision::Run {
+        pid: Pid::new(pid),
+        timeslice: NonZeroUsize::new(3).unwrap(),
+        core,
+    };
+    assert_eq!(scheduler.cores(), 2);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    assert_eq!(scheduler.next_batch(), vec![run(1, 0)]);
+    scheduler
+        .stop_core(0, StopReason::syscall(Syscall::ForkMany(2)))
+        .unwrap();
+    assert_eq!(scheduler.current_time(), 3);
+
+    // processes 3 and 2 run in parallel, and the clock advances with the longest of them
+    assert_eq!(scheduler.next_batch(), vec![run(3, 0), run(2, 1)]);
+    scheduler.stop_core(0, StopReason::expired()).unwrap();
+    assert_eq!(scheduler.current_time(), 3);
+    assert_eq!(scheduler.next_batch(), vec![run(2, 1)]);
+    let exit = StopReason::Syscall {
+        syscall: Syscall::Exit,
+        remaining: 2,
+    };
+    scheduler.stop_core(1, exit).unwrap();
+    assert_eq!(scheduler.current_time(), 6);
+
+    // core 1 was idle during the first batch and during the last 2 units of the second one
+    let stats = scheduler.statistics();
+    assert_eq!(stats.execution_time, 7);
+    assert_eq!(stats.idle_time, 5);
+    assert_eq!(
+        stats.execution_time + stats.idle_time,
+        scheduler.current_time() * scheduler.cores()
+    );
+    assert_eq!(scheduler.next_batch(), vec![run(1, 0), run(3, 1)]);
+}
+
+#[test]
+fn killed_running_process_frees_its_core() {
+    let mut scheduler = round_robin_work_stealing(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        2,
+        1,
+    );
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    scheduler.next_batch();
+    scheduler
+        .stop_core(0, StopReason::syscall(Syscall::ForkMany(2)))
+        .unwrap();
+    assert_eq!(scheduler.next_batch().len(), 2);
+
+    // process 2 is killed while it runs on core 1, which can no longer be stopped
+    assert!(scheduler.kill(Pid::new(2)));
+    assert_eq!(
+        scheduler.stop_core(1, StopReason::expired()),
+        Err(SchedulerError::NoRunningProcess)
+    );
+    scheduler.stop_core(0, StopReason::Killed(SIGKILL)).unwrap();
+    assert_eq!(scheduler.process_count(), 1);
+    assert_eq!(
+        scheduler.next_batch(),
+        vec![SchedulingDecision::Run {
+            pid: Pid::new(1),
+            timeslice: NonZeroUsize::new(3).unwrap(),
+            core: 0,
+        }]
+    );
+}
+
+#[test]
+fn queue_lengths_match_list() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    for _ in 0..3 {
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    }
+    step(&mut scheduler, StopReason::syscall(Syscall::Sleep(5)));
+    scheduler.next();
+
+    let (waiting, ready): (Vec<_>, Vec<_>) = scheduler
+        .list()
+        .into_iter()
+        .partition(|process| matches!(process.state(), ProcessState::Waiting { .. }));
+    let (ready, waiting) = (ready.len(), waiting.len());
+    assert_eq!((ready, waiting), (3, 1));
+    assert_eq!(scheduler.ready_len(), ready);
+    assert_eq!(scheduler.waiting_len(), waiting);
+}
+
+#[test]
+fn tie_break_orders_equal_processes() {
+    let run = |pid, timeslice| SchedulingDecision::Run {
+        pid: Pid::new(pid),
+        timeslice: NonZeroUsize::new(timeslice).unwrap(),
+        core: 0,
+    };
+
+    // process 1 forks 2 and 3 with the same priority, then expires and the ready
+    // queue holds 2, 3, 1
+    for (tie_break, pid) in [
+        (TieBreak::Fifo, 2),
+        (TieBreak::LowestPid, 1),
+        (TieBreak::HighestPid, 3),
+    ] {
+        let mut scheduler = PriorityQueueBuilder::new()
+            .timeslice(3)
+            .tie_break(tie_break)
+            .build()
+            .unwrap();
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        step(&mut scheduler, StopReason::expired());
+        assert_eq!(step(&mut scheduler, StopReason::expired()), run(pid, 3));
+    }
+
+    // the processes forked by process 1 have the same virtual runtime
+    for (tie_break, pid) in [(TieBreak::LowestPid, 2), (TieBreak::HighestPid, 3)] {
+        let mut scheduler = CfsBuilder::new()
+            .cpu_time(6)
+            .tie_break(tie_break)
+            .build()
+            .unwrap();
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        step(&mut scheduler, StopReason::expired());
+        assert_eq!(step(&mut scheduler, StopReason::expired()), run(pid, 2));
+    }
+}
+
+#[test]
+fn remaining_sleep_counts_down() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+    // process 1 sleeps for 5 units, then process 2 sleeps for 10 units one unit later
+    step(&mut scheduler, StopReason::syscall(Syscall::Sleep(5)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Sleep(10)));
+    assert_eq!(scheduler.remaining_sleep(Pid::new(1)), Some(4));
+    assert_eq!(scheduler.remaining_sleep(Pid::new(2)), Some(10));
+
+    // process 3 runs for 3 units
+    step(&mut scheduler, StopReason::expired());
+    assert_eq!(scheduler.remaining_sleep(Pid::new(1)), Some(1));
+    assert_eq!(scheduler.remaining_sleep(Pid::new(2)), Some(7));
+    assert_eq!(scheduler.remaining_sleep(Pid::new(3)), None);
+}
+
+#[test]
+fn tracing_records_gantt_chart() {
+    let mut scheduler = TracingScheduler::new(round_robin_multicore(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        1,
+    ));
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::expired());
+    step(&mut scheduler, StopReason::expired());
+    step(&mut scheduler, StopReason::syscall(Syscall::Exit));
+
+    // the fork and the rest of the timeslice of process 1 are merged, and process 2
+    // runs on core 1 at the same time
+    assert_eq!(scheduler.gantt().len(), 3);
+    let mut csv = Vec::new();
+    scheduler.export_csv(&mut csv).unwrap();
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "pid,start_tick,end_tick,core\n1,0,3,0\n2,1,4,1\n1,4,5,0\n"
+    );
+}
+
+#[test]
+fn tracing_timeline_covers_the_idle_time() {
+    let mut scheduler = TracingScheduler::new(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Sleep(5)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Sleep(5)));
+    step(&mut scheduler, StopReason::expired());
+    step(&mut scheduler, StopReason::expired());
+
+    let timeline = scheduler.timeline();
+    let pids: Vec<Option<Pid>> = timeline.iter().map(|entry| entry.pid).collect();
+    assert_eq!(pids, [Some(1), Some(2), None, Some(1)].map(|pid| pid.map(Pid::new)));
+    let mut time = 0;
+    for entry in &timeline {
+        assert_eq!(entry.start, time);
+        time += entry.duration;
+    }
+    assert_eq!(time, scheduler.current_time());
+}
+
+#[test]
+fn replay_returns_the_recorded_decisions() {
+    let reasons = [
+        StopReason::syscall(Syscall::Fork(0)),
+        StopReason::syscall(Syscall::Sleep(5)),
+        StopReason::expired(),
+        StopReason::syscall(Syscall::Sleep(5)),
+        StopReason::syscall(Syscall::Exit),
+        StopReason::syscall(Syscall::Exit),
+    ];
+    let mut scheduler = TracingScheduler::new(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    for reason in reasons {
+        step(&mut scheduler, reason);
+    }
+    scheduler.next();
+
+    let mut replay = ReplayScheduler::new(scheduler.decisions().to_vec());
+    assert_eq!(
+        replay.stop(StopReason::syscall(Syscall::Fork(0))),
+        Ok(SyscallResult::Pid(Pid::new(1)))
+    );
+    let mut decisions: Vec<SchedulingDecision> = reasons
+        .into_iter()
+        .map(|reason| step(&mut replay, reason))
+        .collect();
+    decisions.push(replay.next());
+    assert_eq!(decisions, scheduler.decisions());
+    assert_eq!(replay.remaining(), 0);
+    assert_eq!(replay.next(), SchedulingDecision::Done);
+
+    // apelurile care nu se potrivesc cu trace-ul sunt refuzate
+    assert_eq!(
+        replay.stop(StopReason::expired()),
+        Err(SchedulerError::Replay(ReplayError::NoRunningProcess))
+    );
+    assert!(!replay.kill(Pid::new(9)));
+}
+
+#[test]
+fn kill_removes_processes_from_every_queue() {
+    fn pids(scheduler: &mut impl Scheduler) -> Vec<Pid> {
+        let mut pids: Vec<Pid> = scheduler
+            .list()
+            .iter()
+            .map(|process| process.pid())
+            .collect();
+        pids.sort();
+        pids
+    }
+
+    // procesul 1 doarme pana cand este planificat unul dintre copii, pentru ca terminarea
+    // procesului 1 ar opri planificarea
+    fn dispatch_child(scheduler: &mut impl Scheduler) -> (Pid, usize) {
+        loop {
+            let SchedulingDecision::Run { pid, timeslice, .. } = scheduler.next() else {
+                panic!("expected a process to run");
+            };
+            if pid != 1 {
+                return (pid, timeslice.get());
+            }
+            let mut reason = StopReason::syscall(Syscall::Sleep(1));
+            reason.set_remaining(timeslice.get() - 1);
+            scheduler.stop(reason).unwrap();
+        }
+    }
+
+    fn check(mut scheduler: impl Scheduler) {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        for _ in 0..4 {
+            step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        }
+        assert_eq!(pids(&mut scheduler), [1, 2, 3, 4, 5].map(Pid::new));
+
+        // un proces care doarme
+        let (sleeping, timeslice) = dispatch_child(&mut scheduler);
+        let mut reason = StopReason::syscall(Syscall::Sleep(10));
+        reason.set_remaining(timeslice - 1);
+        scheduler.stop(reason).unwrap();
+        assert!(scheduler.kill(sleeping));
+        assert!(!pids(&mut scheduler).contains(&sleeping));
+
+        // procesul care ruleaza, dupa care este planificat un alt proces
+        let (running, _) = dispatch_child(&mut scheduler);
+        assert!(scheduler.kill(running));
+        assert!(!pids(&mut scheduler).contains(&running));
+        let SchedulingDecision::Run { pid, .. } = scheduler.next() else {
+            panic!("expected a process to run");
+        };
+        assert_ne!(pid, running);
+        assert_eq!(
+            scheduler.process_info(pid).unwrap().state,
+            ProcessState::Running
+        );
+
+        // un proces ready
+        let ready = scheduler
+            .list()
+            .iter()
+            .find(|process| process.pid() != 1 && process.state() == ProcessState::Ready)
+            .map(|process| process.pid())
+            .unwrap();
+        assert!(scheduler.kill(ready));
+        assert!(!pids(&mut scheduler).contains(&ready));
+        assert_eq!(scheduler.process_count(), 2);
+        assert_eq!(scheduler.statistics().processes_killed, 3);
+
+        // un pid care nu mai exista este ignorat
+        assert!(!scheduler.kill(ready));
+        assert_eq!(scheduler.statistics().processes_killed, 3);
+        assert!(matches!(scheduler.next(), SchedulingDecision::Run { .. }));
+    }
+
+    let timeslice = || NonZeroUsize::new(3).unwrap();
+    check(round_robin(timeslice(), 1));
+    check(priority_queue(timeslice(), 1, 0));
+    check(cfs(timeslice(), 1));
+    check(fcfs());
+    check(rt_fifo(1));
+    check(sjf(timeslice(), 1));
+    check(srtf(1));
+    check(lottery(timeslice(), 1, 3));
+    check(stride(timeslice(), 1));
+    check(edf(1));
+    check(rate_monotonic(1));
+    check(gang(timeslice(), NonZeroUsize::new(2).unwrap(), 1));
+    check(mlfq(timeslice(), NonZeroUsize::new(2).unwrap(), 10, 1));
+    check(round_robin_multicore(
+        NonZeroUsize::new(2).unwrap(),
+        timeslice(),
+        1,
+    ));
+    check(round_robin_work_stealing(
+        NonZeroUsize::new(2).unwrap(),
+        timeslice(),
+        2,
+        1,
+    ));
+}
+
+#[test]
+fn kill_removes_blocked_and_suspended_processes() {
+    fn check(mut scheduler: impl Scheduler) {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        let (suspended, io_waiting) = (Pid::new(2), Pid::new(3));
+
+        // un copil este suspendat, iar celalalt asteapta un dispozitiv
+        step(&mut scheduler, StopReason::syscall(Syscall::Suspend(suspended)));
+        loop {
+            let SchedulingDecision::Run { pid, timeslice, .. } = scheduler.next() else {
+                panic!("expected a process to run");
+            };
+            let mut reason = if pid == io_waiting {
+                StopReason::syscall(Syscall::IoWait {
+                    device_id: 1,
+                    expected_duration: 5,
+                })
+            } else {
+                StopReason::expired()
+            };
+            reason.set_remaining(timeslice.get() - 1);
+            scheduler.stop(reason).unwrap();
+            if pid == io_waiting {
+                break;
+            }
+        }
+        for (pid, state) in [
+            (suspended, ProcessState::Suspended),
+            (io_waiting, ProcessState::IoWait { device_id: 1 }),
+        ] {
+            assert_eq!(scheduler.process_info(pid).unwrap().state, state);
+            assert!(scheduler.kill(pid));
+            // parintele nu a colectat inca copilul omorat
+            assert_eq!(scheduler.process_info(pid).unwrap().state, ProcessState::Zombie);
+        }
+        assert_eq!(scheduler.waiting_len(), 0);
+        assert_eq!(scheduler.statistics().processes_killed, 2);
+    }
+
+    check(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    check(cfs(NonZeroUsize::new(12).unwrap(), 1));
+    check(mlfq(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        0,
+        1,
+    ));
+}
+
+#[test]
+fn named_events_wake_all_waiting_processes() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+    let mut waiting = Vec::new();
+    for _ in 0..2 {
+        let reason = StopReason::syscall(Syscall::WaitNamed(Name::new("db-ready")));
+        if let SchedulingDecision::Run { pid, .. } = step(&mut scheduler, reason) {
+            waiting.push(pid);
+        }
+    }
+    // a numeric event does not wake the processes waiting for a name
+    step(&mut scheduler, StopReason::syscall(Syscall::Signal(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::SignalNamed(Name::new("db-other"))));
+    let states = |scheduler: &mut RoundRobin| {
+        scheduler
+            .list()
+            .iter()
+            .filter(|process| waiting.contains(&process.pid()))
+            .map(|process| process.state())
+            .collect::<Vec<_>>()
+    };
+    assert!(states(&mut scheduler)
+        .iter()
+        .all(|state| matches!(state, ProcessState::Waiting { event: Some(_) })));
+
+    step(&mut scheduler, StopReason::syscall(Syscall::SignalNamed(Name::new("db-ready"))));
+    assert_eq!(states(&mut scheduler), [ProcessState::Ready, ProcessState::Ready]);
+}
+
+#[test]
+fn semaphores_wake_waiters_in_order() {
+    fn check(mut scheduler: impl Scheduler) {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+        let mut blocked = Vec::new();
+        for _ in 0..2 {
+            let reason = StopReason::syscall(Syscall::SemWait(7));
+            if let SchedulingDecision::Run { pid, .. } = step(&mut scheduler, reason) {
+                blocked.push(pid);
+            }
+        }
+        let state = |scheduler: &mut dyn Scheduler, pid: Pid| {
+            scheduler
+                .list()
+                .iter()
+                .find(|process| process.pid() == pid)
+                .map(|process| process.state())
+        };
+        for &pid in &blocked {
+            assert_eq!(
+                state(&mut scheduler, pid),
+                Some(ProcessState::SemaphoreWait { semaphore: 7 })
+            );
+        }
+        assert_eq!(scheduler.waiting_len(), 2);
+
+        // only the first waiter is woken up
+        step(&mut scheduler, StopReason::syscall(Syscall::SemPost(7)));
+        assert_eq!(state(&mut scheduler, blocked[0]), Some(ProcessState::Ready));
+        assert_eq!(
+            state(&mut scheduler, blocked[1]),
+            Some(ProcessState::SemaphoreWait { semaphore: 7 })
+        );
+
+        // a semaphore with a positive value does not block
+        step(&mut scheduler, StopReason::syscall(Syscall::SemPost(8)));
+        let SchedulingDecision::Run { pid, .. } =
+            step(&mut scheduler, StopReason::syscall(Syscall::SemWait(8)))
+        else {
+            panic!("expected a process to run");
+        };
+        assert_ne!(
+            state(&mut scheduler, pid),
+            Some(ProcessState::SemaphoreWait { semaphore: 8 })
+        );
+    }
+
+    check(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    check(cfs(NonZeroUsize::new(12).unwrap(), 1));
+    check(mlfq(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        0,
+        1,
+    ));
+}
+
+#[test]
+fn mutex_holder_inherits_waiter_priority() {
+    let mut scheduler = priority_queue(NonZeroUsize::new(5).unwrap(), 1, 0);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    // the low priority process 1 locks the mutex and creates the processes 2 and 3
+    step(&mut scheduler, StopReason::syscall(Syscall::MutexLock(1)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(2)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(5)));
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 1));
+    scheduler.stop(StopReason::expired()).unwrap();
+
+    // the high priority process 3 blocks on the mutex
+    let decision = step(&mut scheduler, StopReason::syscall(Syscall::MutexLock(1)));
+    assert!(matches!(decision, SchedulingDecision::Run { pid, .. } if pid == 3));
+    let state = |scheduler: &mut PriorityQueueRoundRobin, pid: usize| {
+        scheduler
+            .list()
+            .iter()
+            .find(|process| process.pid() == pid)
+            .map(|process| process.state())
+    };
+    assert_eq!(state(&mut scheduler, 3), Some(ProcessState::MutexWait { mutex: 1 }));
+
+    // the holder runs before the medium priority process 2 and unlocks the mutex
+    let decision = step(&mut scheduler, StopReason::syscall(Syscall::MutexUnlock(1)));
+    assert!(matches!(decision, SchedulingDecision::Run { pid, .. } if pid == 1));
+    assert_eq!(state(&mut scheduler, 3), Some(ProcessState::Ready));
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 1));
+    scheduler.stop(StopReason::expired()).unwrap();
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 3));
+}
+
+#[test]
+fn orphan_policy_handles_children_of_exited_process() {
+    let children = |orphan_policy| {
+        let mut scheduler = RoundRobinBuilder::new()
+            .timeslice(5)
+            .orphan_policy(orphan_policy)
+            .build()
+            .unwrap();
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        // the process 1 creates the process 2 and sleeps
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        step(&mut scheduler, StopReason::syscall(Syscall::Sleep(100)));
+        // the process 2 creates two children and exits
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        step(&mut scheduler, StopReason::syscall(Syscall::Exit));
+
+        let mut children: Vec<(Pid, Option<Pid>)> = scheduler
+            .list()
+            .iter()
+            .filter(|process| process.pid() != 1)
+            .map(|process| (process.pid(), process.parent()))
+            .collect();
+        children.sort();
+        children
+    };
+
+    assert_eq!(
+        children(OrphanPolicy::Reparent),
+        [(Pid::new(3), Some(Pid::new(1))), (Pid::new(4), Some(Pid::new(1)))]
+    );
+    assert_eq!(children(OrphanPolicy::Cascade), []);
+}
+
+#[test]
+fn wait_child_collects_the_exit_code_of_a_zombie() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    let state = |scheduler: &RoundRobin, pid: usize| {
+        scheduler.process_info(Pid::new(pid)).map(|info| info.state)
+    };
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    // the process 1 creates the process 2 and waits for it
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::WaitChild));
+    assert_eq!(
+        state(&scheduler, 1),
+        Some(ProcessState::WaitingForChild { pid: None })
+    );
+
+    // the process 2 exits and stays a zombie until the process 1 collects it
+    step(&mut scheduler, StopReason::syscall(Syscall::Exit));
+    assert_eq!(state(&scheduler, 1), Some(ProcessState::Ready));
+    assert_eq!(state(&scheduler, 2), Some(ProcessState::Zombie));
+    assert_eq!(scheduler.process_count(), 1);
+
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 1));
+    let mut wait = StopReason::syscall(Syscall::WaitChild);
+    wait.set_remaining(2);
+    assert_eq!(scheduler.stop(wait), Ok(SyscallResult::ExitCode(0)));
+    assert_eq!(state(&scheduler, 2), None);
+    wait.set_remaining(1);
+    assert_eq!(scheduler.stop(wait), Ok(SyscallResult::Error));
+}
+
+#[test]
+fn wait_pid_waits_for_the_given_child() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    let state = |scheduler: &RoundRobin, pid: usize| {
+        scheduler.process_info(Pid::new(pid)).map(|info| info.state)
+    };
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::WaitPid(Pid::new(3))));
+
+    // the process 1 is woken up only by the process 3
+    step(&mut scheduler, StopReason::syscall(Syscall::Exit));
+    assert_eq!(
+        state(&scheduler, 1),
+        Some(ProcessState::WaitingForChild {
+            pid: Some(Pid::new(3))
+        })
+    );
+    step(&mut scheduler, StopReason::syscall(Syscall::Exit));
+    assert_eq!(state(&scheduler, 1), Some(ProcessState::Ready));
+
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 1));
+    for (remaining, child, result) in [
+        (2, 3, SyscallResult::ExitCode(0)),
+        (1, 2, SyscallResult::ExitCode(0)),
+        (0, 9, SyscallResult::Error),
+    ] {
+        let mut wait = StopReason::syscall(Syscall::WaitPid(Pid::new(child)));
+        wait.set_remaining(remaining);
+        assert_eq!(scheduler.stop(wait), Ok(result));
+    }
+}
+
+#[test]
+fn processes_wait_for_their_children() {
+    let schedulers: Vec<Box<dyn Scheduler>> = vec![
+        Box::new(round_robin(NonZeroUsize::new(2).unwrap(), 1)),
+        Box::new(cfs(NonZeroUsize::new(4).unwrap(), 1)),
+        Box::new(fcfs()),
+        Box::new(mlfq(NonZeroUsize::new(3).unwrap(), NonZeroUsize::new(2).unwrap(), 10, 1)),
+        Box::new(round_robin_multicore(
+            NonZeroUsize::new(2).unwrap(),
+            NonZeroUsize::new(2).unwrap(),
+            1,
+        )),
+    ];
+    for scheduler in schedulers {
+        let codes = Arc::new(Mutex::new(Vec::new()));
+        let report = codes.clone();
+        Processor::run(scheduler, move |process| {
+            let child = process.fork(
+                |process| {
+                    for _ in 0..3 {
+                        process.exec();
+                    }
+                },
+                0,
+            );
+            let waited = process.wait_pid(child);
+            let mut codes = report.lock().unwrap();
+            codes.push(waited);
+            codes.push(process.wait_child());
+        });
+        assert_eq!(*codes.lock().unwrap(), [Some(0), None]);
+    }
+}
+
+#[test]
+fn suspended_process_keeps_its_wake_up_time() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    let state = |scheduler: &mut RoundRobin, pid: usize| {
+        scheduler
+            .list()
+            .iter()
+            .find(|process| process.pid() == pid)
+            .map(|process| process.state())
+    };
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::expired());
+
+    // process 2 sleeps until time 8
+    step(&mut scheduler, StopReason::syscall(Syscall::Sleep(4)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Suspend(Pid::new(2))));
+    assert_eq!(state(&mut scheduler, 2), Some(ProcessState::Suspended));
+    assert_eq!(scheduler.remaining_sleep(Pid::new(2)), None);
+    step(&mut scheduler, StopReason::syscall(Syscall::Resume(Pid::new(2))));
+    assert_eq!(scheduler.current_time(), 6);
+    assert_eq!(scheduler.remaining_sleep(Pid::new(2)), Some(2));
+
+    // the wake up time passes while the process is suspended
+    step(&mut scheduler, StopReason::syscall(Syscall::Suspend(Pid::new(2))));
+    step(&mut scheduler, StopReason::expired());
+    assert_eq!(state(&mut scheduler, 2), Some(ProcessState::Suspended));
+    step(&mut scheduler, StopReason::syscall(Syscall::Resume(Pid::new(2))));
+    assert_eq!(state(&mut scheduler, 2), Some(ProcessState::Ready));
+
+    // a process that is ready is suspended until it is resumed
+    step(&mut scheduler, StopReason::syscall(Syscall::Suspend(Pid::new(2))));
+    for _ in 0..2 {
+        assert!(matches!(
+            step(&mut scheduler, StopReason::expired()),
+            SchedulingDecision::Run { pid, .. } if pid == 1
+        ));
+    }
+    step(&mut scheduler, StopReason::syscall(Syscall::Resume(Pid::new(2))));
+    assert_eq!(state(&mut scheduler, 2), Some(ProcessState::Ready));
+
+    assert_eq!(
+        scheduler.stop(StopReason::syscall(Syscall::Suspend(Pid::new(9)))),
+        Err(SchedulerError::InvalidPid(Pid::new(9)))
+    );
+}
+
+#[test]
+fn io_complete_wakes_only_its_device() {
+    let io_wait = |device_id| {
+        StopReason::syscall(Syscall::IoWait {
+            device_id,
+            expected_duration: 4,
+        })
+    };
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, io_wait(1));
+    step(&mut scheduler, io_wait(2));
+
+    // both processes wait for their devices, so the processor sleeps
+    assert_eq!(
+        scheduler.next(),
+        SchedulingDecision::Sleep(NonZeroUsize::new(4).unwrap())
+    );
+    let state = |scheduler: &mut RoundRobin, pid: usize| {
+        scheduler
+            .list()
+            .iter()
+            .find(|process| process.pid() == pid)
+            .map(|process| process.state())
+    };
+    assert_eq!(
+        state(&mut scheduler, 2),
+        Some(ProcessState::IoWait { device_id: 2 })
+    );
+
+    assert_eq!(
+        scheduler.stop(StopReason::IoComplete { device_id: 2 }),
+        Ok(SyscallResult::Success)
+    );
+    assert_eq!(state(&mut scheduler, 2), Some(ProcessState::Ready));
+    assert_eq!(
+        state(&mut scheduler, 1),
+        Some(ProcessState::IoWait { device_id: 1 })
+    );
+}
+
+#[test]
+fn processor_completes_io_operations() {
+    let logs = Processor::run(round_robin(NonZeroUsize::new(2).unwrap(), 1), |process| {
+        process.fork(
+            |process| {
+                process.io_wait(7, 3);
+                process.exec();
+            },
+            0,
+        );
+        process.io_wait(3, 5);
+        process.exec();
+    });
+
+    assert_eq!(logs.last().unwrap().decision, SchedulingDecision::Done);
+    assert!(logs.iter().any(|log| {
+        log.processes
+            .values()
+            .any(|process| process.state == ProcessState::IoWait { device_id: 3 })
+    }));
+}
+
+#[test]
+fn switches_are_counted_by_kind() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::expired());
+    step(&mut scheduler, StopReason::expired());
+    step(&mut scheduler, StopReason::syscall(Syscall::Signal(1)));
+
+    let processes = scheduler.list();
+    assert_eq!(processes[0].involuntary_switches(), 2);
+    assert_eq!(processes[0].voluntary_switches(), 1);
+}
+
+#[test]
+fn yield_gives_up_the_remaining_timeslice() {
+    fn check(mut scheduler: impl Scheduler) {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        let SchedulingDecision::Run { pid: yielded, .. } =
+            step(&mut scheduler, StopReason::syscall(Syscall::Yield))
+        else {
+            panic!("expected a process to run");
+        };
+
+        // the other process runs, although the timeslice was not used up
+        let SchedulingDecision::Run { pid, .. } = scheduler.next() else {
+            panic!("expected a process to run");
+        };
+        assert_ne!(pid, yielded);
+        assert_eq!(scheduler.statistics().yields, 1);
+        let processes = scheduler.list();
+        let process = processes
+            .iter()
+            .find(|process| process.pid() == yielded)
+            .unwrap();
+        assert_eq!(process.state(), ProcessState::Ready);
+        assert_eq!(process.involuntary_switches(), 0);
+    }
+
+    check(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    check(fcfs());
+    check(mlfq(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        0,
+        1,
+    ));
+}
+
+#[test]
+fn circular_mutex_waits_are_reported_as_deadlock() {
+    fn check(mut scheduler: impl Scheduler) {
+        // process 1 holds mutex 1 and waits for mutex 2, process 2 holds mutex 2 and
+        // waits for mutex 1
+        let mut scripts = [
+            vec![Syscall::Fork(0), Syscall::MutexLock(1), Syscall::Yield, Syscall::MutexLock(2)],
+            vec![Syscall::MutexLock(2), Syscall::Yield, Syscall::MutexLock(1)],
+        ]
+        .map(|script| script.into_iter());
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        for _ in 0..20 {
+            match scheduler.next() {
+                SchedulingDecision::Run { pid, timeslice, .. } => {
+                    let script = &mut scripts[if pid == 1 { 0 } else { 1 }];
+                    let mut reason = StopReason::syscall(script.next().unwrap_or(Syscall::Exit));
+                    reason.set_remaining(timeslice.get() - 1);
+                    scheduler.stop(reason).unwrap();
+                }
+                decision => {
+                    let deadlock = SchedulingDecision::Deadlock(vec![Pid::new(1), Pid::new(2)]);
+                    assert_eq!(decision, deadlock);
+                    return;
+                }
+            }
+        }
+        panic!("expected a deadlock");
+    }
+
+    check(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    check(fcfs());
+    check(cfs(NonZeroUsize::new(6).unwrap(), 1));
+    check(mlfq(
+        NonZeroUsize::new(3).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        0,
+        1,
+    ));
+}
+
+#[test]
+fn next_wakeup_reports_the_earliest_sleeper() {
+    fn check(mut scheduler: impl Scheduler) {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        assert_eq!(scheduler.next_wakeup(), None);
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+        // process 1 sleeps until time 2 + 7, process 2 until time 3 + 4
+        let sleeper = step(&mut scheduler, StopReason::syscall(Syscall::Sleep(7)));
+        assert!(matches!(sleeper, SchedulingDecision::Run { pid, .. } if pid == 1));
+        assert_eq!(scheduler.next_wakeup(), Some((Pid::new(1), 9)));
+        step(&mut scheduler, StopReason::syscall(Syscall::Sleep(4)));
+        assert_eq!(scheduler.current_time(), 3);
+        assert_eq!(scheduler.next_wakeup(), Some((Pid::new(2), 7)));
+
+        // the wake up time does not change when the processor sleeps until it
+        assert_eq!(scheduler.next(), SchedulingDecision::Sleep(NonZeroUsize::new(4).unwrap()));
+        assert_eq!(scheduler.next_wakeup(), Some((Pid::new(2), 7)));
+        assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 2));
+        assert_eq!(scheduler.next_wakeup(), Some((Pid::new(1), 9)));
+    }
+
+    check(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    check(Box::new(cfs(NonZeroUsize::new(6).unwrap(), 1)) as Box<dyn Scheduler>);
+    check(fcfs());
+}
+
+#[test]
+fn run_until_collects_decisions() {
+    let run = |pid, timeslice| SchedulingDecision::Run {
+        pid: Pid::new(pid),
+        timeslice: NonZeroUsize::new(timeslice).unwrap(),
+        core: 0,
+    };
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+    let mut runs = 0;
+    let decisions = scheduler.run_until(|decision| {
+        if let SchedulingDecision::Run { .. } = decision {
+            runs += 1;
+        }
+        runs == 5
+    });
+    assert_eq!(
+        decisions,
+        [run(1, 2), run(2, 3), run(1, 3), run(2, 3), run(1, 3)]
+    );
+
+    // a sleeping scheduler continues after the sleep, and the run ends with Done
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Sleep(5)));
+    let decisions =
+        scheduler.run_until(|decision| matches!(decision, SchedulingDecision::Run { .. }));
+    assert_eq!(
+        decisions,
+        [SchedulingDecision::Sleep(NonZeroUsize::new(5).unwrap()), run(1, 3)]
+    );
+    step(&mut scheduler, StopReason::syscall(Syscall::Exit));
+    assert_eq!(scheduler.run_until(|_| false), [SchedulingDecision::Done]);
+}
+
+#[test]
+fn run_until_drives_every_scheduler() {
+    let timeslice = || NonZeroUsize::new(3).unwrap();
+    let cmp: ProcessComparator = Box::new(|a, b| a.pid.cmp(&b.pid));
+    let schedulers: Vec<Box<dyn Scheduler>> = vec![
+        Box::new(round_robin(timeslice(), 1)),
+        Box::new(custom(timeslice(), 1, cmp)),
+        Box::new(priority_queue(timeslice(), 1, 0)),
+        Box::new(cfs(timeslice(), 1)),
+        Box::new(fcfs()),
+        Box::new(rt_fifo(1)),
+        Box::new(priority_nonpreemptive(1)),
+        Box::new(sjf(timeslice(), 1)),
+        Box::new(sjf_with_estimate(timeslice(), 1, 2)),
+        Box::new(shortest_job_first(1)),
+        Box::new(mlfq(NonZeroUsize::new(3).unwrap(), NonZeroUsize::new(2).unwrap(), 10, 1)),
+        Box::new(mlfq_with_timeslices(
+            NonZeroUsize::new(2).unwrap(),
+            &[NonZeroUsize::new(2).unwrap(), NonZeroUsize::new(4).unwrap()],
+            1,
+            10,
+        )),
+        Box::new(srtf(1)),
+        Box::new(lottery(timeslice(), 1, 3)),
+        Box::new(lottery(timeslice(), 1, 7)),
+        Box::new(stride(timeslice(), 1)),
+        Box::new(edf(1)),
+        Box::new(rate_monotonic(1)),
+        Box::new(gang(timeslice(), NonZeroUsize::new(2).unwrap(), 1)),
+        Box::new(round_robin_multicore(
+            NonZeroUsize::new(2).unwrap(),
+            timeslice(),
+            1,
+        )),
+        Box::new(round_robin_work_stealing(
+            NonZeroUsize::new(2).unwrap(),
+            timeslice(),
+            2,
+            1,
+        )),
+    ];
+    for mut scheduler in schedulers {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+        // fiecare proces planificat ruleaza un timeslice intreg, iar un timeslice
+        // nelimitat incheie rularea
+        let mut runs = 0;
+        let decisions = scheduler.run_until(|decision| {
+            if let SchedulingDecision::Run { .. } = decision {
+                runs += 1;
+            }
+            runs == 4
+        });
+        assert!(decisions
+            .iter()
+            .all(|decision| matches!(decision, SchedulingDecision::Run { .. })));
+        let unbounded = decisions.iter().position(|decision| match decision {
+            SchedulingDecision::Run { timeslice, .. } => *timeslice == NonZeroUsize::MAX,
+            _ => false,
+        });
+        assert_eq!(unbounded.map_or(4, |index| index + 1), decisions.len());
+    }
+}
+
+#[test]
+fn starvation_detector_reports_waiting_processes() {
+    let starved = Arc::new(Mutex::new(Vec::new()));
+    let report = starved.clone();
+    let mut scheduler = StarvationDetector::new(
+        priority_queue(NonZeroUsize::new(3).unwrap(), 1, 0),
+        4,
+        Box::new(move |pid, ticks| report.lock().unwrap().push((pid, ticks))),
+    );
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(5)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::expired());
+    step(&mut scheduler, StopReason::expired());
+
+    // the low priority process waits while process 1 keeps running
+    assert_eq!(scheduler.ticks_since_last_run(Pid::new(1)), Some(0));
+    assert_eq!(scheduler.ticks_since_last_run(Pid::new(2)), Some(5));
+    scheduler.next();
+    let starved = starved.lock().unwrap();
+    assert_eq!(starved.len(), 1);
+    assert!(starved[0].0 == 2 && starved[0].1 == 5);
+}
+
+#[test]
+fn rt_fifo_preempted_process_keeps_head_of_level() {
+    let run = |pid| SchedulingDecision::Run {
+        pid: Pid::new(pid),
+        timeslice: NonZeroUsize::MAX,
+        core: 0,
+    };
+    let mut scheduler = rt_fifo(1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(1)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(1)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(5)));
+
+    // process 3 preempts process 1, which runs again before process 2
+    assert_eq!(
+        step(&mut scheduler, StopReason::syscall(Syscall::Exit)),
+        run(3)
+    );
+    assert_eq!(scheduler.next(), run(1));
+}
+
+#[test]
+fn rt_fifo_wakeup_preempts_lower_priority() {
+    let mut scheduler = rt_fifo(1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(1)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(5)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Sleep(5)));
+
+    // process 1 runs only until process 2 wakes up
+    assert_eq!(
+        step(&mut scheduler, StopReason::expired()),
+        SchedulingDecision::Run {
+            pid: Pid::new(1),
+            timeslice: NonZeroUsize::new(5).unwrap(),
+            core: 0,
+        }
+    );
+    assert_eq!(
+        scheduler.next(),
+        SchedulingDecision::Run {
+            pid: Pid::new(2),
+            timeslice: NonZeroUsize::MAX,
+            core: 0,
+        }
+    );
+}
+
+// fara preemptare, procesul cu prioritate mare trezit din sleep asteapta pana cand
+// procesul care ruleaza cedeaza procesorul
+#[test]
+fn priority_nonpreemptive_runs_the_woken_process_after_a_yield() {
+    let mut scheduler = priority_nonpreemptive(1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(5)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(1)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Sleep(5)));
+
+    // procesul 2 primeste un timeslice nelimitat, chiar daca procesul 1 se trezeste
+    let SchedulingDecision::Run { pid, timeslice, .. } = scheduler.next() else {
+        panic!("process 2 should run");
+    };
+    assert_eq!((pid, timeslice), (Pid::new(2), NonZeroUsize::MAX));
+    let mut signal = StopReason::syscall(Syscall::Signal(7));
+    signal.set_remaining(timeslice.get() - 10);
+    scheduler.stop(signal).unwrap();
+    assert_eq!(
+        scheduler.process_info(Pid::new(1)).unwrap().state,
+        ProcessState::Ready
+    );
+    let SchedulingDecision::Run { pid, timeslice, .. } = scheduler.next() else {
+        panic!("process 2 should continue");
+    };
+    assert_eq!(pid, 2);
+
+    let mut yield_processor = StopReason::syscall(Syscall::Yield);
+    yield_processor.set_remaining(timeslice.get() - 1);
+    scheduler.stop(yield_processor).unwrap();
+    assert_eq!(
+        scheduler.next(),
+        SchedulingDecision::Run {
+            pid: Pid::new(1),
+            timeslice: NonZeroUsize::MAX,
+            core: 0,
+        }
+    );
+}
+
+#[test]
+fn priority_nonpreemptive_does_not_preempt_for_a_forked_process() {
+    let run_pid = |decision| match decision {
+        SchedulingDecision::Run { pid, .. } => pid,
+        decision => panic!("expected a process to run, got {decision}"),
+    };
+    let mut scheduler = priority_nonpreemptive(1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(1)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(5)));
+
+    // procesul 1 continua dupa fork, iar procesul 2 ruleaza abia dupa ce acesta doarme
+    assert_eq!(
+        run_pid(step(&mut scheduler, StopReason::syscall(Syscall::Sleep(3)))),
+        Pid::new(1)
+    );
+    assert_eq!(run_pid(scheduler.next()), Pid::new(2));
+}
+
+// un apel Sleep(0) este tratat ca un apel Yield: procesul ramane ready si este
+// readaugat la finalul cozii
+#[test]
+fn zero_length_sleep_yields_the_processor() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+    assert_eq!(
+        step(&mut scheduler, StopReason::syscall(Syscall::Sleep(0))),
+        SchedulingDecision::Run {
+            pid: Pid::new(1),
+            timeslice: NonZeroUsize::new(1).unwrap(),
+            core: 0,
+        }
+    );
+    assert_eq!(scheduler.waiting_len(), 0);
+    let states: Vec<(Pid, ProcessState)> = scheduler
+        .list()
+        .into_iter()
+        .map(|process| (process.pid(), process.state()))
+        .collect();
+    assert!(states.contains(&(Pid::new(1), ProcessState::Ready)));
+    assert_eq!(scheduler.statistics().yields, 1);
+
+    // procesele 2 si 3 ruleaza inaintea procesului 1
+    for pid in [2, 3, 1] {
+        let SchedulingDecision::Run { pid: running, .. } =
+            step(&mut scheduler, StopReason::expired())
+        else {
+            panic!("a process should run");
+        };
+        assert_eq!(running, pid);
+    }
+}
+
+// procesele create deodata primesc pid-uri consecutive si sunt toate ready inaintea
+// primei decizii a planificatorului
+#[test]
+fn spawn_batch_creates_ready_processes_with_consecutive_pids() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    let requests = (0..100)
+        .map(|index| SpawnRequest {
+            priority: index % 5,
+            ..SpawnRequest::default()
+        })
+        .collect();
+    let pids = scheduler.spawn_batch(requests);
+    assert_eq!(pids, (1..=100).map(Pid::new).collect::<Vec<Pid>>());
+    assert_eq!(scheduler.ready_len(), 100);
+    assert_eq!(scheduler.current_time(), 0);
+    let listed = scheduler.list();
+    assert!(listed
+        .iter()
+        .all(|process| process.state() == ProcessState::Ready));
+    assert_eq!(listed[7].priority(), 2);
+    assert_eq!(
+        scheduler.next(),
+        SchedulingDecision::Run {
+            pid: Pid::new(1),
+            timeslice: NonZeroUsize::new(3).unwrap(),
+            core: 0,
+        }
+    );
+
+    // sunt create doar atatea procese cate mai accepta planificatorul
+    scheduler.set_max_processes(NonZeroUsize::new(102));
+    let pids = scheduler.spawn_batch(vec![SpawnRequest::default(); 5]);
+    assert_eq!(pids, vec![Pid::new(101), Pid::new(102)]);
+    assert_eq!(scheduler.statistics().processes_spawned, 102);
+}
+
+#[test]
+fn spawn_batch_sets_the_burst_hints() {
+    let mut scheduler = sjf(NonZeroUsize::new(10).unwrap(), 1);
+    let requests = [9, 2, 5]
+        .into_iter()
+        .map(|burst| SpawnRequest {
+            burst_hint: Some(burst),
+            ..SpawnRequest::default()
+        })
+        .collect();
+    assert_eq!(scheduler.spawn_batch(requests).len(), 3);
+    let SchedulingDecision::Run { pid, .. } = scheduler.next() else {
+        panic!("a process should run");
+    };
+    assert_eq!(pid, 2);
+}
+
+// contorul de resurse al unui proces se pastreaza dupa preemptare, iar procesele create
+// prin fork pornesc de la 0
+#[test]
+fn resources_are_kept_after_a_preemption() {
+    let resources = |scheduler: &mut RoundRobin| -> Vec<(Pid, usize)> {
+        let mut resources: Vec<(Pid, usize)> = scheduler
+            .list()
+            .into_iter()
+            .map(|process| (process.pid(), process.resources()))
+            .collect();
+        resources.sort();
+        resources
+    };
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::SetResources(42)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    assert_eq!(resources(&mut scheduler), vec![(Pid::new(1), 42), (Pid::new(2), 0)]);
+
+    // procesul 1 este preemptat, ruleaza procesul 2, apoi procesul 1 este replanificat
+    step(&mut scheduler, StopReason::expired());
+    step(&mut scheduler, StopReason::expired());
+    assert_eq!(
+        step(&mut scheduler, StopReason::expired()),
+        SchedulingDecision::Run {
+            pid: Pid::new(1),
+            timeslice: NonZeroUsize::new(3).unwrap(),
+            core: 0,
+        }
+    );
+    assert_eq!(resources(&mut scheduler), vec![(Pid::new(1), 42), (Pid::new(2), 0)]);
+}
+
+#[test]
+fn stop_rejects_invalid_sequences() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    assert_eq!(
+        scheduler.stop(StopReason::expired()),
+        Err(SchedulerError::NoRunningProcess)
+    );
+
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    let mut signal = StopReason::syscall(Syscall::SendSignal {
+        target: Pid::new(9),
+        signum: SIGKILL,
+    });
+    signal.set_remaining(1);
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { .. }));
+    let statistics = scheduler.statistics();
+    assert_eq!(
+        scheduler.stop(signal),
+        Err(SchedulerError::InvalidPid(Pid::new(9)))
+    );
+    assert_eq!(scheduler.statistics(), statistics);
+    assert_eq!(scheduler.process_count(), 2);
+
+    // the process that has exited cannot exit again
+    let mut exit = StopReason::syscall(Syscall::Exit);
+    exit.set_remaining(0);
+    assert_eq!(scheduler.stop(exit), Ok(SyscallResult::Success));
+    assert_eq!(scheduler.stop(exit), Err(SchedulerError::DoubleExit));
+    assert_eq!(
+        scheduler.stop(StopReason::syscall(Syscall::Signal(1))),
+        Err(SchedulerError::NoRunningProcess)
+    );
+}
+
+#[test]
+fn fork_fails_above_process_cap() {
+    let mut scheduler = priority_queue(NonZeroUsize::new(5).unwrap(), 1, 0);
+    scheduler.set_max_processes(NonZeroUsize::new(3));
+    let mut fork = StopReason::syscall(Syscall::Fork(0));
+    assert_eq!(scheduler.stop(fork), Ok(SyscallResult::Pid(Pid::new(1))));
+    for (remaining, pid) in [(4, 2), (3, 3)] {
+        assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 1));
+        fork.set_remaining(remaining);
+        assert_eq!(scheduler.stop(fork), Ok(SyscallResult::Pid(Pid::new(pid))));
+    }
+
+    // the parent keeps running after the failed fork
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, .. } if pid == 1));
+    fork.set_remaining(2);
+    assert_eq!(scheduler.stop(fork), Ok(SyscallResult::NoMemory));
+    assert_eq!(scheduler.process_count(), 3);
+    assert_eq!(
+        scheduler.next(),
+        SchedulingDecision::Run {
+            pid: Pid::new(1),
+            timeslice: NonZeroUsize::new(2).unwrap(),
+            core: 0,
+        }
+    );
+}
+
+#[test]
+fn affinity_restricts_cores() {
+    let mut scheduler = round_robin_multicore(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        1,
+    );
+    let run = |pid, timeslice, core| SchedulingDecision::Run {
+        pid: Pid::new(pid),
+        timeslice: NonZeroUsize::new(timeslice).unwrap(),
+        core,
+    };
+    let affinity = |mask, remaining| StopReason::Syscall {
+        syscall: Syscall::SetAffinity(mask),
+        remaining,
+    };
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+    assert_eq!(scheduler.next_batch(), vec![run(1, 2, 0), run(2, 3, 1)]);
+    assert_eq!(
+        scheduler.stop_core(0, affinity(0b100, 1)),
+        Ok(SyscallResult::InvalidArgument)
+    );
+    scheduler.stop_core(1, StopReason::expired()).unwrap();
+
+    // process 1 may only run on core 1 from now on
+    assert_eq!(scheduler.next_batch(), vec![run(1, 1, 0), run(2, 3, 1)]);
+    assert_eq!(scheduler.stop_core(0, affinity(0b10, 0)), Ok(SyscallResult::Success));
+    assert_eq!(scheduler.list()[0].cpu_affinity(), 0b10);
+    scheduler.stop_core(1, StopReason::expired()).unwrap();
+
+    assert_eq!(scheduler.next_batch(), vec![run(2, 3, 1)]);
+    scheduler.stop_core(1, StopReason::expired()).unwrap();
+    assert_eq!(scheduler.queue_depths(), vec![0, 2]);
+
+    // only process 2 can be migrated to the empty run queue of core 0
+    assert_eq!(scheduler.next_batch(), vec![run(2, 3, 0), run(1, 3, 1)]);
+
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { .. }));
+    assert_eq!(
+        scheduler.stop(affinity(0b10, 2)),
+        Ok(SyscallResult::InvalidArgument)
+    );
+}
+
+#[test]
+fn processes_migrate_to_an_idle_core() {
+    let mut scheduler = round_robin_multicore(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        1,
+    );
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::SetAffinity(0b1)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    assert_eq!(scheduler.queue_depths(), vec![3, 0]);
+    assert_eq!(scheduler.migrations(), 0);
+
+    // process 2 may run on both cores from now on
+    let mut affinity = StopReason::syscall(Syscall::SetAffinity(0b11));
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { pid, core: 0, .. } if pid == 2));
+    affinity.set_remaining(0);
+    scheduler.stop(affinity).unwrap();
+    assert_eq!(scheduler.migrations(), 0);
+
+    // the preempted process 2 is migrated to the empty run queue of core 1, and runs
+    // in parallel with process 3
+    let run = |pid, core| SchedulingDecision::Run {
+        pid: Pid::new(pid),
+        timeslice: NonZeroUsize::new(3).unwrap(),
+        core,
+    };
+    assert_eq!(scheduler.next_batch(), vec![run(3, 0), run(2, 1)]);
+    assert_eq!(scheduler.queue_depths(), vec![2, 1]);
+    assert_eq!(scheduler.migrations(), 1);
+}
+
+#[test]
+fn multicore_round_robin_alternates_cores() {
+    let mut scheduler = round_robin_multicore(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        1,
+    );
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+    // procesele din acelasi lot ruleaza in paralel, deciziile lotului sunt returnate pe
+    // rand, iar un proces ramane pe core-ul sau
+    let decisions: Vec<(Pid, usize)> = (0..6)
+        .map(|_| match step(&mut scheduler, StopReason::expired()) {
+            SchedulingDecision::Run { pid, core, .. } => (pid, core),
+            decision => panic!("unexpected decision {decision:?}"),
+        })
+        .collect();
+    let expected: Vec<(Pid, usize)> = [(2, 1), (1, 0), (2, 1), (3, 0), (2, 1), (1, 0)]
+        .into_iter()
+        .map(|(pid, core)| (Pid::new(pid), core))
+        .collect();
+    assert_eq!(decisions, expected);
+    assert_eq!(scheduler.queue_depths(), vec![2, 1]);
+}
+
+#[test]
+fn forked_processes_inherit_affinity() {
+    let mut scheduler = round_robin_multicore(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        1,
+    );
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::SetAffinity(0b1)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+    assert_eq!(scheduler.queue_depths(), vec![3, 0]);
+    assert!(scheduler
+        .list()
+        .iter()
+        .all(|process| process.cpu_affinity() == 0b1));
+
+    // copiii nu sunt mutati niciodata pe core-ul 1
+    for pid in [2, 3, 1, 2] {
+        assert_eq!(
+            step(&mut scheduler, StopReason::expired()),
+            SchedulingDecision::Run {
+                pid: Pid::new(pid),
+                timeslice: NonZeroUsize::new(3).unwrap(),
+                core: 0,
+            }
+        );
+    }
+    assert_eq!(scheduler.queue_depths(), vec![3, 0]);
+}
+
+#[test]
+fn fcfs_runs_jobs_in_arrival_order() {
+    let logs = Processor::run(fcfs(), |process| {
+        for _ in 0..2 {
+            process.fork(
+                |process| {
+                    for _ in 0..20 {
+                        process.exec();
+                    }
+                },
+                0,
+            );
+        }
+        while process.wait_child().is_some() {}
+    });
+
+    // fiecare copil ruleaza o singura data, in ordinea sosirii, pana la terminare
+    let runs: Vec<Pid> = logs
+        .iter()
+        .filter_map(|log| match log.decision {
+            SchedulingDecision::Run { pid, .. } => Some(pid),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(runs, [1, 1, 1, 2, 3, 1, 1, 1, 1].map(Pid::new));
+    assert!(logs
+        .iter()
+        .all(|log| !matches!(log.stop_reason, Some((StopReason::Expired, _)))));
+    let exits: Vec<Pid> = logs
+        .iter()
+        .filter_map(|log| match (&log.decision, &log.stop_reason) {
+            (
+                SchedulingDecision::Run { pid, .. },
+                Some((
+                    StopReason::Syscall {
+                        syscall: Syscall::Exit,
+                        ..
+                    },
+                    _,
+                )),
+            ) => Some(*pid),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(exits, [2, 3, 1].map(Pid::new));
+}
+
+#[test]
+fn fcfs_long_job_keeps_the_processor() {
+    let mut scheduler = fcfs();
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+    // procesul 1 ruleaza mult mai mult decat orice timeslice, fara sa fie preemptat, iar
+    // copilul creat de el asteapta in coada
+    for _ in 0..3 {
+        let SchedulingDecision::Run { pid, timeslice, .. } = scheduler.next() else {
+            panic!("expected a process to run");
+        };
+        assert_eq!((pid, timeslice), (Pid::new(1), NonZeroUsize::MAX));
+        let mut reason = StopReason::syscall(Syscall::Signal(1));
+        reason.set_remaining(timeslice.get() - 1000);
+        scheduler.stop(reason).unwrap();
+    }
+    let info = scheduler.process_info(Pid::new(1)).unwrap();
+    assert!(info.cpu_ticks_consumed >= 3000);
+    assert_eq!(info.timeslice_preemptions, 0);
+    assert_eq!(
+        scheduler.process_info(Pid::new(2)).unwrap().state,
+        ProcessState::Ready
+    );
+
+    // copilul ruleaza abia dupa ce procesul 1 asteapta terminarea lui
+    step(&mut scheduler, StopReason::syscall(Syscall::WaitChild));
+    assert!(matches!(
+        scheduler.next(),
+        SchedulingDecision::Run { pid, .. } if pid == Pid::new(2)
+    ));
+}
+
+// un proces care face un apel de sistem cu mai putin de `minimum_remaining_timeslice`
+// unitati ramase isi incheie rafala, iar estimarea devine (rafala + estimare) / 2
+#[test]
+fn sjf_short_job_overtakes_after_estimates_converge() {
+    let mut scheduler = sjf_with_estimate(NonZeroUsize::new(8).unwrap(), 8, 4);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    let mut runs = Vec::new();
+    for _ in 0..8 {
+        let SchedulingDecision::Run { pid, timeslice, .. } = scheduler.next() else {
+            panic!("expected a process to run");
+        };
+        // procesul 1 creeaza procesul 2, apoi are rafale lungi, iar procesul 2 rafale scurte
+        let (ticks, syscall) = if runs.is_empty() {
+            (1, Syscall::Fork(0))
+        } else if pid == Pid::new(1) {
+            (7, Syscall::Signal(0))
+        } else {
+            (1, Syscall::Signal(0))
+        };
+        runs.push(pid);
+        let mut reason = StopReason::syscall(syscall);
+        reason.set_remaining(timeslice.get() - ticks);
+        scheduler.stop(reason).unwrap();
+    }
+
+    // estimarea procesului 1 creste de la 2 la 4, la egalitate cu procesul 2, apoi la 5,
+    // iar de acolo procesul 2 ruleaza inaintea lui
+    assert_eq!(runs, [1, 1, 1, 2, 2, 2, 2, 2].map(Pid::new));
+}
+
+#[test]
+fn sjf_short_job_runs_first_once_the_long_job_blocks() {
+    // procesul planificat ruleaza `ticks` unitati de timp, apoi se opreste cu `reason`
+    fn run(scheduler: &mut impl Scheduler, ticks: usize, mut reason: StopReason) -> Pid {
+        let SchedulingDecision::Run { pid, timeslice, .. } = scheduler.next() else {
+            panic!("expected a process to run");
+        };
+        reason.set_remaining(timeslice.get() - ticks);
+        scheduler.stop(reason).unwrap();
+        pid
+    }
+
+    let mut scheduler = sjf_with_estimate(NonZeroUsize::new(8).unwrap(), 8, 8);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+
+    // procesul 2 asteapta in coada cat timp procesul 1, cu o estimare mai mica, ruleaza
+    let runs = [
+        run(&mut scheduler, 1, StopReason::syscall(Syscall::Fork(0))),
+        run(&mut scheduler, 8, StopReason::expired()),
+        run(&mut scheduler, 8, StopReason::expired()),
+        run(&mut scheduler, 7, StopReason::syscall(Syscall::Wait(1))),
+    ];
+    assert_eq!(runs, [1, 1, 1, 1].map(Pid::new));
+
+    // dupa blocarea procesului 1, procesul 2 are o rafala scurta si il trezeste, iar
+    // estimarea lui mai mica il planifica inaintea procesului 1
+    let short = run(&mut scheduler, 1, StopReason::syscall(Syscall::Signal(1)));
+    assert_eq!(short, Pid::new(2));
+    assert_eq!(
+        scheduler.process_info(Pid::new(1)).unwrap().state,
+        ProcessState::Ready
+    );
+    assert!(matches!(
+        scheduler.next(),
+        SchedulingDecision::Run { pid, .. } if pid == Pid::new(2)
+    ));
+}
+
+// procesele create prin spawn_batch cu duratele anuntate ale rafalelor
+fn hinted_requests(bursts: &[Option<usize>]) -> Vec<SpawnRequest> {
+    bursts
+        .iter()
+        .map(|&burst_hint| SpawnRequest {
+            burst_hint,
+            ..SpawnRequest::default()
+        })
+        .collect()
+}
+
+#[test]
+fn shortest_job_first_runs_the_shortest_hinted_job_without_preemption() {
+    let mut scheduler = shortest_job_first(1);
+    scheduler.spawn_batch(hinted_requests(&[Some(9), Some(2), Some(5)]));
+
+    // procesul 2 ruleaza primul si pastreaza procesorul, chiar daca apare un proces mai scurt
+    let mut runs = Vec::new();
+    for stop in [
+        StopReason::syscall(Syscall::Signal(0)),
+        StopReason::syscall(Syscall::Exit),
+        StopReason::syscall(Syscall::Exit),
+        StopReason::syscall(Syscall::Exit),
+        StopReason::syscall(Syscall::Exit),
+    ] {
+        let SchedulingDecision::Run { pid, timeslice, .. } = scheduler.next() else {
+            panic!("expected a process to run");
+        };
+        if runs.is_empty() {
+            scheduler.spawn_batch(hinted_requests(&[Some(1)]));
+        }
+        runs.push(pid);
+        let mut reason = stop;
+        reason.set_remaining(timeslice.get() - 100);
+        scheduler.stop(reason).unwrap();
+    }
+    assert_eq!(runs, [2, 2, 4, 3, 1].map(Pid::new));
+    assert_eq!(scheduler.next(), SchedulingDecision::Done);
+}
+
+#[test]
+fn shortest_job_first_runs_jobs_without_hints_in_arrival_order() {
+    let logs = Processor::run(shortest_job_first(1), |process| {
+        for _ in 0..3 {
+            process.fork(
+                |process| {
+                    for _ in 0..5 {
+                        process.exec();
+                    }
+                },
+                0,
+            );
+        }
+        process.fork(
+            |process| {
+                process.exec();
+            },
+            0,
+        );
+        while process.wait_child().is_some() {}
+    });
+
+    // procesele fara durata anuntata au aceeasi estimare, deci ruleaza in ordinea pid-urilor,
+    // fara sa conteze cat de lungi sunt rafalele lor
+    let children: Vec<Pid> = logs
+        .iter()
+        .filter_map(|log| match log.decision {
+            SchedulingDecision::Run { pid, .. } if pid != Pid::new(1) => Some(pid),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(children, [2, 3, 4, 5].map(Pid::new));
+}
+
+// functie care returneaza timeslice-ul din decizia urmatoare, acesta arata nivelul procesului
+fn next_timeslice<S: Scheduler>(scheduler: &mut S) -> usize {
+    match scheduler.next() {
+        SchedulingDecision::Run { timeslice, .. } => timeslice.get(),
+        decision => panic!("expected a process to run, got {decision}"),
+    }
+}
+
+#[test]
+fn mlfq_demotes_processes_that_use_their_whole_timeslice() {
+    let mut scheduler = mlfq(NonZeroUsize::new(3).unwrap(), NonZeroUsize::new(2).unwrap(), 0, 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+
+    // timeslice-ul se dubleaza la fiecare nivel, iar ultimul nivel pastreaza procesul
+    for (timeslice, depths) in [(2, vec![0, 1, 0]), (4, vec![0, 0, 1]), (8, vec![0, 0, 1])] {
+        assert_eq!(next_timeslice(&mut scheduler), timeslice);
+        scheduler.stop(StopReason::expired()).unwrap();
+        assert_eq!(scheduler.queue_depths(), depths);
+    }
+}
+
+#[test]
+fn mlfq_keeps_the_level_of_processes_that_block() {
+    let mut scheduler = mlfq(NonZeroUsize::new(3).unwrap(), NonZeroUsize::new(2).unwrap(), 0, 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    assert_eq!(next_timeslice(&mut scheduler), 2);
+    scheduler.stop(StopReason::expired()).unwrap();
+
+    // procesul doarme inainte de sfarsitul timeslice-ului, asa ca ramane pe nivelul 1
+    assert_eq!(next_timeslice(&mut scheduler), 4);
+    let mut sleep = StopReason::syscall(Syscall::Sleep(1));
+    sleep.set_remaining(2);
+    scheduler.stop(sleep).unwrap();
+    assert!(matches!(scheduler.next(), SchedulingDecision::Sleep(_)));
+
+    // la fel si dupa ce asteapta un dispozitiv
+    assert_eq!(next_timeslice(&mut scheduler), 4);
+    let mut io = StopReason::syscall(Syscall::IoWait {
+        device_id: 1,
+        expected_duration: 3,
+    });
+    io.set_remaining(2);
+    scheduler.stop(io).unwrap();
+    assert!(matches!(scheduler.next(), SchedulingDecision::Sleep(_)));
+    scheduler
+        .stop(StopReason::IoComplete { device_id: 1 })
+        .unwrap();
+    assert_eq!(scheduler.queue_depths(), vec![0, 1, 0]);
+    assert_eq!(next_timeslice(&mut scheduler), 4);
+}
+
+#[test]
+fn mlfq_boost_moves_processes_back_to_the_first_level() {
+    let mut scheduler = mlfq(NonZeroUsize::new(3).unwrap(), NonZeroUsize::new(2).unwrap(), 6, 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    for timeslice in [2, 4] {
+        assert_eq!(next_timeslice(&mut scheduler), timeslice);
+        scheduler.stop(StopReason::expired()).unwrap();
+    }
+    assert_eq!(scheduler.queue_depths(), vec![0, 0, 1]);
+
+    // dupa 6 unitati de timp procesul revine pe primul nivel
+    assert_eq!(next_timeslice(&mut scheduler), 2);
+    assert_eq!(scheduler.queue_depths(), vec![1, 0, 0]);
+}
+
+#[test]
+fn mlfq_with_timeslices_uses_the_timeslice_of_every_level() {
+    let mut scheduler = mlfq_with_timeslices(
+        NonZeroUsize::new(4).unwrap(),
+        &[NonZeroUsize::new(3).unwrap(), NonZeroUsize::new(5).unwrap()],
+        1,
+        0,
+    );
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+
+    // nivelurile fara timeslice folosesc ultima valoare din lista
+    for (timeslice, depths) in [
+        (3, vec![0, 1, 0, 0]),
+        (5, vec![0, 0, 1, 0]),
+        (5, vec![0, 0, 0, 1]),
+    ] {
+        assert_eq!(next_timeslice(&mut scheduler), timeslice);
+        scheduler.stop(StopReason::expired()).unwrap();
+        assert_eq!(scheduler.queue_depths(), depths);
+    }
+
+    // procesul nou intra pe primul nivel si primeste timeslice-ul acestuia
+    assert_eq!(next_timeslice(&mut scheduler), 5);
+    let mut fork = StopReason::syscall(Syscall::Fork(0));
+    fork.set_remaining(3);
+    assert_eq!(scheduler.stop(fork), Ok(SyscallResult::Pid(Pid::new(2))));
+    assert_eq!(
+        scheduler.next(),
+        SchedulingDecision::Run {
+            pid: Pid::new(2),
+            timeslice: NonZeroUsize::new(3).unwrap(),
+            core: 0,
+        }
+    );
+    assert_eq!(scheduler.queue_depths(), vec![1, 0, 0, 1]);
+}
+
+#[test]
+fn srtf_preempts_the_running_job_for_a_shorter_arrival() {
+    let mut scheduler = srtf(1);
+    scheduler.spawn_batch(hinted_requests(&[Some(8)]));
+    assert_eq!(
+        scheduler.next(),
+        SchedulingDecision::Run {
+            pid: Pid::new(1),
+            timeslice: NonZeroUsize::new(8).unwrap(),
+            core: 0,
+        }
+    );
+
+    // procesul 2 ajunge in timp ce procesul 1 ruleaza, iar rafala lui este mai scurta
+    // decat cele 5 unitati ramase din rafala procesului 1
+    assert_eq!(
+        scheduler.spawn_batch(hinted_requests(&[Some(2)])),
+        vec![Pid::new(2)]
+    );
+    let mut signal = StopReason::syscall(Syscall::Signal(0));
+    signal.set_remaining(4);
+    scheduler.stop(signal).unwrap();
+
+    let mut runs = Vec::new();
+    for _ in 0..2 {
+        let SchedulingDecision::Run { pid, timeslice, .. } = scheduler.next() else {
+            panic!("expected a process to run");
+        };
+        runs.push((pid, timeslice.get()));
+        let mut exit = StopReason::syscall(Syscall::Exit);
+        exit.set_remaining(timeslice.get() - 1);
+        scheduler.stop(exit).unwrap();
+    }
+    assert_eq!(runs, vec![(Pid::new(2), 2), (Pid::new(1), 5)]);
+    assert_eq!(scheduler.next(), SchedulingDecision::Done);
+}
+
+#[test]
+fn set_timeslice_applies_from_the_next_dispatch() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+    // procesul 1 ruleaza in continuare pentru restul timeslice-ului vechi
+    scheduler.set_timeslice(NonZeroUsize::new(5).unwrap());
+    assert_eq!(scheduler.timeslice(), NonZeroUsize::new(5).unwrap());
+    let SchedulingDecision::Run { pid, timeslice, .. } = scheduler.next() else {
+        panic!("process 1 should run");
+    };
+    assert_eq!((pid, timeslice.get()), (Pid::new(1), 2));
+    scheduler.stop(StopReason::expired()).unwrap();
+    assert_eq!(
+        scheduler.next(),
+        SchedulingDecision::Run {
+            pid: Pid::new(2),
+            timeslice: NonZeroUsize::new(5).unwrap(),
+            core: 0,
+        }
+    );
+
+    let mut scheduler = priority_queue(NonZeroUsize::new(3).unwrap(), 1, 0);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    assert!(matches!(scheduler.next(), SchedulingDecision::Run { .. }));
+    scheduler.set_timeslice(NonZeroUsize::new(4).unwrap());
+    scheduler.stop(StopReason::expired()).unwrap();
+    assert_eq!(
+        scheduler.next(),
+        SchedulingDecision::Run {
+            pid: Pid::new(1),
+            timeslice: NonZeroUsize::new(4).unwrap(),
+            core: 0,
+        }
+    );
+}
+
+#[test]
+fn set_timeslice_rejects_zero() {
+    let mut scheduler = round_robin(NonZeroUsize::new(3).unwrap(), 1);
+    // un timeslice de 0 nu poate fi convertit intr-un TimeUnit
+    if let Ok(timeslice) = TimeUnit::try_from(0) {
+        scheduler.set_timeslice(timeslice);
+    }
+    assert_eq!(scheduler.timeslice().get(), 3);
+}
+
+#[test]
+fn circular_event_waits_are_reported_as_deadlock() {
+    fn check(mut scheduler: impl Scheduler) {
+        // procesul 2 asteapta evenimentul 1, pe care l-ar semnala procesul 3 dupa
+        // evenimentul 2, pe care l-ar semnala procesul 2, iar procesul 1 asteapta un
+        // eveniment pe care nu il semnaleaza nimeni
+        let mut scripts = [
+            vec![Syscall::Fork(0), Syscall::Fork(0), Syscall::Wait(3)],
+            vec![Syscall::Wait(1), Syscall::Signal(2)],
+            vec![Syscall::Wait(2), Syscall::Signal(1)],
+        ]
+        .map(|script| script.into_iter());
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        for _ in 0..20 {
+            match scheduler.next() {
+                SchedulingDecision::Run { pid, timeslice, .. } => {
+                    let script = &mut scripts[(1..=3).position(|index| pid == index).unwrap()];
+                    let mut reason = StopReason::syscall(script.next().unwrap_or(Syscall::Exit));
+                    reason.set_remaining(timeslice.get() - 1);
+                    scheduler.stop(reason).unwrap();
+                }
+                decision => {
+                    let deadlock =
+                        SchedulingDecision::Deadlock(vec![Pid::new(1), Pid::new(2), Pid::new(3)]);
+                    assert_eq!(decision, deadlock);
+                    assert_eq!(scheduler.ready_len(), 0);
+                    return;
+                }
+            }
+        }
+        panic!("expected a deadlock");
+    }
+
+    check(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    check(priority_queue(NonZeroUsize::new(3).unwrap(), 1, 0));
+    check(cfs(NonZeroUsize::new(6).unwrap(), 1));
+}
+
+#[test]
+fn rate_monotonic_runs_the_shorter_period_and_reactivates_periods() {
+    let period = |period| Syscall::SetPeriod(NonZeroUsize::new(period).unwrap());
+    let mut scheduler = rate_monotonic(1);
+    scheduler
+        .stop(StopReason::syscall(Syscall::Fork(0)))
+        .unwrap();
+    // pasii fiecarui proces, None inseamna ca procesul isi consuma tot timeslice-ul
+    let mut scripts = [
+        vec![
+            Some((period(100), 1)),
+            Some((Syscall::Fork(0), 1)),
+            Some((Syscall::Sleep(3), 1)),
+        ],
+        vec![Some((period(5), 1)), None, Some((Syscall::Sleep(10), 1))],
+    ]
+    .map(|script| script.into_iter());
+    let mut runs = Vec::new();
+    for _ in 0..10 {
+        let decision = scheduler.next();
+        let SchedulingDecision::Run { pid, timeslice, .. } = decision else {
+            panic!("expected a process to run, got {decision}");
+        };
+        runs.push((pid, timeslice.get()));
+        let script = &mut scripts[(1..=2).position(|index| pid == index).unwrap()];
+        let reason = match script.next().flatten() {
+            Some((syscall, used)) => {
+                let mut reason = StopReason::syscall(syscall);
+                reason.set_remaining(timeslice.get() - used);
+                reason
+            }
+            None => StopReason::expired(),
+        };
+        scheduler.stop(reason).unwrap();
+    }
+
+    // la momentul 6 procesul 1 se trezeste, dar procesul 2 are perioada mai scurta; timpul
+    // primit de procesul 1 se termina la reactivarile procesului 2 (9 si 14), iar la
+    // momentul 17 procesul 2 se trezeste si il preempteaza pe procesul 1
+    let expected = [
+        (1, usize::MAX),
+        (1, 100),
+        (1, 99),
+        (2, 3),
+        (2, 2),
+        (2, 3),
+        (1, 2),
+        (1, 5),
+        (1, 3),
+        (2, 2),
+    ]
+    .map(|(pid, timeslice)| (Pid::new(pid), timeslice));
+    assert_eq!(runs, expected);
+}
+
+// planificatorul ruleaza procesele pana cand este planificat procesul `pid`, care face
+// apelul de sistem primit, iar celelalte procese isi consuma tot timeslice-ul; se
+// returneaza rezultatul apelului de sistem
+fn run_syscall_as<S: Scheduler>(scheduler: &mut S, pid: usize, syscall: Syscall) -> SyscallResult {
+    for _ in 0..20 {
+        let decision = scheduler.next();
+        let SchedulingDecision::Run {
+            pid: running,
+            timeslice,
+            ..
+        } = decision
+        else {
+            panic!("expected a process to run, got {decision}");
+        };
+        let mut reason = if running == pid {
+            StopReason::syscall(syscall)
+        } else {
+            StopReason::expired()
+        };
+        reason.set_remaining(timeslice.get() - 1);
+        let result = scheduler.stop(reason).unwrap();
+        if running == pid {
+            return result;
+        }
+    }
+    panic!("process {pid} was never scheduled");
+}
+
+#[test]
+fn process_groups_are_inherited_suspended_and_killed() {
+    fn check(mut scheduler: impl Scheduler) {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+        // procesul 2 creeaza un grup, iar copilul sau (3) devine membru al grupului;
+        // procesul 4, creat de procesul 1, nu face parte din grup
+        run_syscall_as(&mut scheduler, 2, Syscall::SetProcessGroup(Pid::new(2)));
+        run_syscall_as(&mut scheduler, 2, Syscall::Fork(0));
+        run_syscall_as(&mut scheduler, 1, Syscall::Fork(0));
+        let mut groups: Vec<(Pid, Option<Pid>)> = scheduler
+            .list()
+            .iter()
+            .map(|process| (process.pid(), process.pgid()))
+            .collect();
+        groups.sort();
+        let expected = [(1, None), (2, Some(2)), (3, Some(2)), (4, None)]
+            .map(|(pid, pgid)| (Pid::new(pid), pgid.map(Pid::new)));
+        assert_eq!(groups, expected);
+
+        // membrii suspendati ai grupului nu mai sunt planificati pana la reluare
+        run_syscall_as(&mut scheduler, 1, Syscall::SuspendGroup(Pid::new(2)));
+        for pid in [2, 3] {
+            assert_eq!(
+                scheduler.process_info(Pid::new(pid)).unwrap().state,
+                ProcessState::Suspended
+            );
+        }
+        for _ in 0..4 {
+            let SchedulingDecision::Run { pid, .. } = step(&mut scheduler, StopReason::expired())
+            else {
+                panic!("expected a process to run");
+            };
+            assert!(pid == 1 || pid == 4);
+        }
+        run_syscall_as(&mut scheduler, 4, Syscall::ResumeGroup(Pid::new(2)));
+        for pid in [2, 3] {
+            assert_eq!(
+                scheduler.process_info(Pid::new(pid)).unwrap().state,
+                ProcessState::Ready
+            );
+        }
+        run_syscall_as(&mut scheduler, 3, Syscall::Yield);
+
+        // doar membrii grupului sunt terminati
+        run_syscall_as(&mut scheduler, 4, Syscall::KillGroup(Pid::new(2)));
+        let mut pids: Vec<Pid> = scheduler
+            .list()
+            .iter()
+            .map(|process| process.pid())
+            .collect();
+        pids.sort();
+        assert_eq!(pids, [1, 4].map(Pid::new));
+        assert_eq!(scheduler.ready_len(), 2);
+    }
+
+    check(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    check(priority_queue(NonZeroUsize::new(3).unwrap(), 1, 0));
+    check(cfs(NonZeroUsize::new(12).unwrap(), 1));
+}
+
+#[test]
+fn send_signal_interrupts_the_blocked_target() {
+    fn check(mut scheduler: impl Scheduler) {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+
+        // procesul 2 doarme, iar procesul 3 asteapta un eveniment
+        run_syscall_as(&mut scheduler, 2, Syscall::Sleep(50));
+        run_syscall_as(&mut scheduler, 3, Syscall::Wait(7));
+
+        // procesul care trimite semnalul primeste Success, iar apelul de sistem al
+        // procesului trezit intoarce Interrupted o singura data
+        for (target, signum) in [(2, 10), (3, 12)] {
+            let signal = Syscall::SendSignal {
+                target: Pid::new(target),
+                signum,
+            };
+            assert_eq!(
+                run_syscall_as(&mut scheduler, 1, signal),
+                SyscallResult::Success
+            );
+            assert_eq!(scheduler.take_wakeup_result(Pid::new(1)), None);
+            assert_eq!(
+                scheduler.take_wakeup_result(Pid::new(target)),
+                Some(SyscallResult::Interrupted)
+            );
+            assert_eq!(scheduler.take_wakeup_result(Pid::new(target)), None);
+            assert_eq!(
+                scheduler.process_info(Pid::new(target)).unwrap().state,
+                ProcessState::Ready
+            );
+
+            // semnalul este raportat la urmatoarea decizie
+            assert_eq!(
+                scheduler.next(),
+                SchedulingDecision::DeliverSignal(Pid::new(target), signum)
+            );
+        }
+
+        // semnalul trimis unui proces ready nu il intrerupe
+        let signal = Syscall::SendSignal {
+            target: Pid::new(2),
+            signum: 10,
+        };
+        assert_eq!(
+            run_syscall_as(&mut scheduler, 1, signal),
+            SyscallResult::Success
+        );
+        assert_eq!(scheduler.take_wakeup_result(Pid::new(2)), None);
+    }
+
+    check(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    check(priority_queue(NonZeroUsize::new(3).unwrap(), 1, 0));
+    check(cfs(NonZeroUsize::new(12).unwrap(), 1));
+    check(mlfq(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        0,
+        1,
+    ));
+    check(stride(NonZeroUsize::new(3).unwrap(), 1));
+}
+
+#[test]
+fn send_signal_sigkill_finishes_the_target() {
+    fn check(mut scheduler: impl Scheduler) {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        step(&mut scheduler, StopReason::syscall(Syscall::Fork(0)));
+        run_syscall_as(&mut scheduler, 3, Syscall::Sleep(50));
+
+        // procesul 2 este ready, iar procesul 3 doarme; ambii raman zombie pana cand
+        // procesul 1 ii colecteaza
+        for target in [2, 3] {
+            let signal = Syscall::SendSignal {
+                target: Pid::new(target),
+                signum: SIGKILL,
+            };
+            assert_eq!(
+                run_syscall_as(&mut scheduler, 1, signal),
+                SyscallResult::Success
+            );
+            assert_eq!(
+                scheduler.process_info(Pid::new(target)).unwrap().state,
+                ProcessState::Zombie
+            );
+            assert_eq!(scheduler.take_wakeup_result(Pid::new(target)), None);
+        }
+        assert_eq!(scheduler.process_count(), 1);
+        assert_eq!(scheduler.ready_len(), 1);
+    }
+
+    check(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+    check(priority_queue(NonZeroUsize::new(3).unwrap(), 1, 0));
+    check(cfs(NonZeroUsize::new(12).unwrap(), 1));
+    check(mlfq(
+        NonZeroUsize::new(2).unwrap(),
+        NonZeroUsize::new(3).unwrap(),
+        0,
+        1,
+    ));
+    check(stride(NonZeroUsize::new(3).unwrap(), 1));
+}
+
+#[test]
+fn statistics_count_a_scripted_run() {
+    // `time` este momentul la care apare deadlock-ul, `waiting` este timpul petrecut de
+    // procese in coada de ready, iar `ticks` sunt timpii de procesor si de asteptare ai
+    // procesului 1 si ai copilului sau terminat
+    fn check(
+        mut scheduler: impl Scheduler,
+        time: usize,
+        waiting: usize,
+        ticks: [(usize, usize); 2],
+    ) {
+        scheduler
+            .stop(StopReason::syscall(Syscall::Fork(0)))
+            .unwrap();
+        // fiecare apel de sistem este facut dupa o unitate de timp, iar procesorul
+        // doarme de doua ori, cat timp ambele procese dorm sau asteapta
+        let script = [
+            (1, Some(Syscall::Fork(0))),
+            (1, None),
+            (2, Some(Syscall::Sleep(5))),
+            (1, Some(Syscall::Sleep(2))),
+            (1, Some(Syscall::Wait(1))),
+            (2, Some(Syscall::Signal(1))),
+            (2, Some(Syscall::Exit)),
+            (1, Some(Syscall::Wait(2))),
+        ];
+        let mut sleeps = Vec::new();
+        for (expected, syscall) in script {
+            let mut decision = scheduler.next();
+            if let SchedulingDecision::Sleep(time) = decision {
+                sleeps.push(time.get());
+                decision = scheduler.next();
+            }
+            let SchedulingDecision::Run { pid, timeslice, .. } = decision else {
+                panic!("expected a process to run, got {decision}");
+            };
+            assert_eq!(pid, expected);
+            let reason = match syscall {
+                Some(syscall) => {
+                    let mut reason = StopReason::syscall(syscall);
+                    reason.set_remaining(timeslice.get() - 1);
+                    reason
+                }
+                None => StopReason::expired(),
+            };
+            scheduler.stop(reason).unwrap();
+        }
+        assert_eq!(sleeps, [2, 1]);
+
+        // deadlock-ul nu este numarat ca timp in care procesorul doarme
+        for _ in 0..2 {
+            assert_eq!(
+                scheduler.next(),
+                SchedulingDecision::Deadlock(vec![Pid::new(1)])
+            );
+        }
+        assert_eq!(scheduler.current_time(), time);
+        let stats = scheduler.statistics();
+        assert_eq!(stats.context_switches, 6);
+        assert_eq!(stats.idle_time, 3);
+        assert_eq!(stats.idle_ticks, 0);
+        assert_eq!(stats.execution_time, time - 3);
+        assert_eq!(stats.waiting_time, waiting);
+        assert_eq!(stats.processes_spawned, 2);
+        assert_eq!(stats.processes_terminated, 1);
+
+        // procesul 1 a fost preemptat o data, iar Fork, Sleep si cele doua Wait au
+        // fost schimbari de context voluntare
+        let processes = scheduler.list();
+        assert_eq!(processes.len(), 1);
+        assert_eq!(processes[0].voluntary_switches(), 4);
+        assert_eq!(processes[0].involuntary_switches(), 1);
+        let info = [1, 2].map(|pid| scheduler.process_info(Pid::new(pid)).unwrap());
+        assert_eq!(info[0].timeslice_preemptions, 1);
+        assert_eq!(info[1].timeslice_preemptions, 0);
+        assert_eq!(info[1].state, ProcessState::Zombie);
+        assert_eq!(
+            info.map(|info| (info.cpu_ticks_consumed, info.waiting_ticks)),
+            ticks
+        );
+    }
+
+    check(
+        round_robin(NonZeroUsize::new(3).unwrap(), 1),
+        12,
+        4,
+        [(6, 6), (3, 7)],
+    );
+    check(
+        priority_queue(NonZeroUsize::new(3).unwrap(), 1, 0),
+        12,
+        4,
+        [(6, 6), (3, 7)],
+    );
+    // cele doua procese impart timeslice-ul CFS, asa ca procesul 1 este preemptat dupa 3
+    check(
+        cfs(NonZeroUsize::new(6).unwrap(), 1),
+        13,
+        5,
+        [(7, 6), (3, 8)],
+    );
+}
+
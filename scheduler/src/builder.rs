@@ -0,0 +1,610 @@
+use std::fmt::Display;
+use std::num::NonZeroUsize;
+
+use crate::schedulers::{TimingWheel, QUOTA_PERIOD, TIMING_WHEEL_SIZE};
+use crate::{
+    cfs, priority_queue, round_robin, OrphanPolicy, PidPolicy, PriorityQueueRoundRobin, RoundRobin,
+    Scheduler, TieBreak, CFS,
+};
+
+/// The scheduling policy of a scheduler created with a [`SchedulerBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// A round robin scheduler, see [`round_robin`].
+    RoundRobin,
+    /// A priority queue scheduler, see [`priority_queue`].
+    PriorityQueue,
+    /// A simplified cfs scheduler, see [`cfs`].
+    Cfs,
+}
+
+impl Display for Policy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Policy::RoundRobin => write!(f, "round robin"),
+            Policy::PriorityQueue => write!(f, "priority queue"),
+            Policy::Cfs => write!(f, "cfs"),
+        }
+    }
+}
+
+/// The error returned by [`SchedulerBuilder::build`] when the parameters do not
+/// describe a valid scheduler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildError {
+    /// No policy was set.
+    MissingPolicy,
+
+    /// The policy requires a parameter that was not set.
+    MissingParameter {
+        policy: Policy,
+        parameter: &'static str,
+    },
+
+    /// A parameter was set that the policy does not use.
+    UnsupportedParameter {
+        policy: Policy,
+        parameter: &'static str,
+    },
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildError::MissingPolicy => write!(f, "no scheduling policy was set"),
+            BuildError::MissingParameter { policy, parameter } => {
+                write!(
+                    f,
+                    "the {policy} policy requires the `{parameter}` parameter"
+                )
+            }
+            BuildError::UnsupportedParameter { policy, parameter } => {
+                write!(
+                    f,
+                    "the `{parameter}` parameter cannot be used with the {policy} policy"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Creates a scheduler from a policy and the parameters that the policy uses.
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use scheduler::{Policy, SchedulerBuilder};
+///
+/// let scheduler = SchedulerBuilder::new()
+///     .policy(Policy::RoundRobin)
+///     .timeslice(NonZeroUsize::new(3).unwrap())
+///     .build()
+///     .unwrap();
+/// ```
+///
+/// Parameters that are not set take these values:
+/// * `min_remaining` - 1
+/// * `aging_interval` - 0, the priority queue scheduler does not use aging
+#[derive(Debug, Clone, Default)]
+pub struct SchedulerBuilder {
+    policy: Option<Policy>,
+    timeslice: Option<NonZeroUsize>,
+    min_remaining: Option<usize>,
+    cpu_time: Option<NonZeroUsize>,
+    aging_interval: Option<usize>,
+    max_processes: Option<NonZeroUsize>,
+    pid_policy: PidPolicy,
+    orphan_policy: OrphanPolicy,
+}
+
+impl SchedulerBuilder {
+    /// Returns a builder without any parameter set.
+    pub fn new() -> SchedulerBuilder {
+        SchedulerBuilder::default()
+    }
+
+    /// Sets the scheduling policy.
+    pub fn policy(mut self, policy: Policy) -> SchedulerBuilder {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Sets the time quanta that a process can run before it is preempted.
+    ///
+    /// Required by the round robin and the priority queue policies.
+    pub fn timeslice(mut self, timeslice: NonZeroUsize) -> SchedulerBuilder {
+        self.timeslice = Some(timeslice);
+        self
+    }
+
+    /// Sets the minimum remaining timeslice for which a process that made a
+    /// system call is scheduled again.
+    pub fn min_remaining(mut self, min_remaining: usize) -> SchedulerBuilder {
+        self.min_remaining = Some(min_remaining);
+        self
+    }
+
+    /// Sets the total time units that the cpu has for an iteration.
+    ///
+    /// Required by the cfs policy.
+    pub fn cpu_time(mut self, cpu_time: NonZeroUsize) -> SchedulerBuilder {
+        self.cpu_time = Some(cpu_time);
+        self
+    }
+
+    /// Sets the number of time units a process has to wait in the ready queue
+    /// for its priority to increase by one.
+    ///
+    /// Used only by the priority queue policy.
+    pub fn aging_interval(mut self, aging_interval: usize) -> SchedulerBuilder {
+        self.aging_interval = Some(aging_interval);
+        self
+    }
+
+    /// Sets the maximum number of living processes, see [`Scheduler::set_max_processes`].
+    ///
+    /// Used by all the policies.
+    pub fn max_processes(mut self, max_processes: NonZeroUsize) -> SchedulerBuilder {
+        self.max_processes = Some(max_processes);
+        self
+    }
+
+    /// Sets whether the scheduler reuses the PIDs of the processes that have exited,
+    /// see [`Scheduler::set_pid_policy`].
+    ///
+    /// Used by all the policies.
+    pub fn pid_policy(mut self, pid_policy: PidPolicy) -> SchedulerBuilder {
+        self.pid_policy = pid_policy;
+        self
+    }
+
+    /// Sets what happens to the children of a process that exits, see
+    /// [`Scheduler::set_orphan_policy`].
+    ///
+    /// Used by all the policies.
+    pub fn orphan_policy(mut self, orphan_policy: OrphanPolicy) -> SchedulerBuilder {
+        self.orphan_policy = orphan_policy;
+        self
+    }
+
+    /// Returns the scheduler, or an error if the parameters cannot be used together.
+    pub fn build(self) -> Result<Box<dyn Scheduler>, BuildError> {
+        let policy = self.policy.ok_or(BuildError::MissingPolicy)?;
+        let min_remaining = self.min_remaining.unwrap_or(1);
+
+        // parametrii setati trebuie sa fie folositi de politica aleasa
+        let unsupported = match policy {
+            Policy::RoundRobin if self.cpu_time.is_some() => Some("cpu_time"),
+            Policy::RoundRobin | Policy::Cfs if self.aging_interval.is_some() => {
+                Some("aging_interval")
+            }
+            Policy::PriorityQueue if self.cpu_time.is_some() => Some("cpu_time"),
+            Policy::Cfs if self.timeslice.is_some() => Some("timeslice"),
+            _ => None,
+        };
+        if let Some(parameter) = unsupported {
+            return Err(BuildError::UnsupportedParameter { policy, parameter });
+        }
+
+        let missing = |parameter| BuildError::MissingParameter { policy, parameter };
+        let mut scheduler: Box<dyn Scheduler> = match policy {
+            Policy::RoundRobin => Box::new(round_robin(
+                self.timeslice.ok_or_else(|| missing("timeslice"))?,
+                min_remaining,
+            )),
+            Policy::PriorityQueue => Box::new(priority_queue(
+                self.timeslice.ok_or_else(|| missing("timeslice"))?,
+                min_remaining,
+                self.aging_interval.unwrap_or(0),
+            )),
+            Policy::Cfs => Box::new(cfs(
+                self.cpu_time.ok_or_else(|| missing("cpu_time"))?,
+                min_remaining,
+            )),
+        };
+        scheduler.set_max_processes(self.max_processes);
+        scheduler.set_pid_policy(self.pid_policy);
+        scheduler.set_orphan_policy(self.orphan_policy);
+        Ok(scheduler)
+    }
+}
+
+/// The error returned by the builders of the [`RoundRobin`], [`PriorityQueueRoundRobin`]
+/// and [`CFS`] schedulers when a parameter has an invalid value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchedulerConfigError {
+    /// A required parameter was not set.
+    MissingParameter(&'static str),
+
+    /// A parameter that has to be positive was set to 0.
+    ZeroParameter(&'static str),
+
+    /// The minimum remaining timeslice is not smaller than the timeslice, so a
+    /// process could never be scheduled again after a system call.
+    MinimumRemainingTooLarge {
+        minimum_remaining_timeslice: usize,
+        timeslice: usize,
+    },
+}
+
+impl Display for SchedulerConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchedulerConfigError::MissingParameter(parameter) => {
+                write!(f, "the `{parameter}` parameter is required")
+            }
+            SchedulerConfigError::ZeroParameter(parameter) => {
+                write!(f, "the `{parameter}` parameter cannot be 0")
+            }
+            SchedulerConfigError::MinimumRemainingTooLarge {
+                minimum_remaining_timeslice,
+                timeslice,
+            } => write!(
+                f,
+                "the minimum remaining timeslice {minimum_remaining_timeslice} has to be smaller than the timeslice {timeslice}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SchedulerConfigError {}
+
+// functie care verifica parametrii comuni ai planificatoarelor si ii returneaza
+fn validate(
+    timeslice_name: &'static str,
+    timeslice: Option<usize>,
+    minimum_remaining_timeslice: usize,
+    max_processes: Option<usize>,
+) -> Result<(NonZeroUsize, usize, Option<NonZeroUsize>), SchedulerConfigError> {
+    let timeslice = timeslice.ok_or(SchedulerConfigError::MissingParameter(timeslice_name))?;
+    let timeslice =
+        NonZeroUsize::new(timeslice).ok_or(SchedulerConfigError::ZeroParameter(timeslice_name))?;
+    if minimum_remaining_timeslice == 0 {
+        return Err(SchedulerConfigError::ZeroParameter(
+            "minimum_remaining_timeslice",
+        ));
+    }
+    if minimum_remaining_timeslice >= timeslice.get() {
+        return Err(SchedulerConfigError::MinimumRemainingTooLarge {
+            minimum_remaining_timeslice,
+            timeslice: timeslice.get(),
+        });
+    }
+    let max_processes = match max_processes {
+        Some(max) => Some(
+            NonZeroUsize::new(max).ok_or(SchedulerConfigError::ZeroParameter("max_processes"))?,
+        ),
+        None => None,
+    };
+    Ok((timeslice, minimum_remaining_timeslice, max_processes))
+}
+
+/// Creates a [`RoundRobin`] scheduler, see [`round_robin`].
+///
+/// ```
+/// use scheduler::RoundRobinBuilder;
+///
+/// let scheduler = RoundRobinBuilder::new()
+///     .timeslice(4)
+///     .minimum_remaining_timeslice(1)
+///     .max_processes(64)
+///     .build()
+///     .unwrap();
+/// ```
+///
+/// The `timeslice` is required. The `minimum_remaining_timeslice` is 1 if it is not
+/// set, and has to be smaller than the `timeslice`. Without `max_processes`, the
+/// number of processes is not limited.
+#[derive(Debug, Clone)]
+pub struct RoundRobinBuilder {
+    timeslice: Option<usize>,
+    minimum_remaining_timeslice: usize,
+    max_processes: Option<usize>,
+    orphan_policy: OrphanPolicy,
+    pid_policy: PidPolicy,
+    nice_timeslices: bool,
+    idle_process: bool,
+    timing_wheel_size: usize,
+    quota_period: usize,
+}
+
+impl Default for RoundRobinBuilder {
+    fn default() -> RoundRobinBuilder {
+        RoundRobinBuilder {
+            timeslice: None,
+            minimum_remaining_timeslice: 1,
+            max_processes: None,
+            orphan_policy: OrphanPolicy::Reparent,
+            pid_policy: PidPolicy::Monotonic,
+            nice_timeslices: false,
+            idle_process: false,
+            timing_wheel_size: TIMING_WHEEL_SIZE,
+            quota_period: QUOTA_PERIOD,
+        }
+    }
+}
+
+impl RoundRobinBuilder {
+    /// Returns a builder with the default parameters.
+    pub fn new() -> RoundRobinBuilder {
+        RoundRobinBuilder::default()
+    }
+
+    /// Sets the time quanta that a process can run before it is preempted.
+    pub fn timeslice(mut self, timeslice: usize) -> RoundRobinBuilder {
+        self.timeslice = Some(timeslice);
+        self
+    }
+
+    /// Sets the minimum remaining timeslice for which a process that made a
+    /// system call is scheduled again.
+    pub fn minimum_remaining_timeslice(mut self, minimum: usize) -> RoundRobinBuilder {
+        self.minimum_remaining_timeslice = minimum;
+        self
+    }
+
+    /// Sets the maximum number of living processes, see [`Scheduler::set_max_processes`].
+    pub fn max_processes(mut self, max_processes: usize) -> RoundRobinBuilder {
+        self.max_processes = Some(max_processes);
+        self
+    }
+
+    /// Sets what happens to the children of a process that exits, see
+    /// [`Scheduler::set_orphan_policy`]. The default is [`OrphanPolicy::Reparent`].
+    pub fn orphan_policy(mut self, orphan_policy: OrphanPolicy) -> RoundRobinBuilder {
+        self.orphan_policy = orphan_policy;
+        self
+    }
+
+    /// Sets whether the scheduler reuses the PIDs of the processes that have exited,
+    /// see [`Scheduler::set_pid_policy`]. The default is [`PidPolicy::Monotonic`].
+    pub fn pid_policy(mut self, pid_policy: PidPolicy) -> RoundRobinBuilder {
+        self.pid_policy = pid_policy;
+        self
+    }
+
+    /// Sets whether the time quanta of a process is scaled with its nice value, see
+    /// [`RoundRobin::set_nice_timeslices`]. This is disabled by default.
+    pub fn nice_timeslices(mut self, nice_timeslices: bool) -> RoundRobinBuilder {
+        self.nice_timeslices = nice_timeslices;
+        self
+    }
+
+    /// Sets whether the idle process runs when all the processes sleep, see
+    /// [`RoundRobin::set_idle_process`]. This is disabled by default.
+    pub fn idle_process(mut self, idle_process: bool) -> RoundRobinBuilder {
+        self.idle_process = idle_process;
+        self
+    }
+
+    /// Sets the number of time units covered by the timing wheel that wakes up the
+    /// sleeping processes. A process that sleeps for longer is kept in a separate list,
+    /// which is checked once every `timing_wheel_size` time units. The default is 64;
+    /// the decisions of the scheduler do not depend on this value.
+    pub fn timing_wheel_size(mut self, timing_wheel_size: usize) -> RoundRobinBuilder {
+        self.timing_wheel_size = timing_wheel_size;
+        self
+    }
+
+    /// Sets the length of the accounting period of the CPU quotas, see
+    /// [`RoundRobin::set_quota_period`]. The default is 100.
+    pub fn quota_period(mut self, quota_period: usize) -> RoundRobinBuilder {
+        self.quota_period = quota_period;
+        self
+    }
+
+    /// Returns the scheduler, or an error if a parameter has an invalid value.
+    pub fn build(self) -> Result<RoundRobin, SchedulerConfigError> {
+        let (timeslice, minimum_remaining_timeslice, max_processes) = validate(
+            "timeslice",
+            self.timeslice,
+            self.minimum_remaining_timeslice,
+            self.max_processes,
+        )?;
+        let mut scheduler = round_robin(timeslice, minimum_remaining_timeslice);
+        scheduler.max_processes = max_processes;
+        scheduler.orphan_policy = self.orphan_policy;
+        scheduler.pids.set_policy(self.pid_policy);
+        scheduler.nice_timeslices = self.nice_timeslices;
+        scheduler.idle_process = self.idle_process;
+        scheduler.timing_wheel = TimingWheel::new(
+            NonZeroUsize::new(self.timing_wheel_size)
+                .ok_or(SchedulerConfigError::ZeroParameter("timing_wheel_size"))?,
+        );
+        scheduler.quota_period = NonZeroUsize::new(self.quota_period)
+            .ok_or(SchedulerConfigError::ZeroParameter("quota_period"))?;
+        Ok(scheduler)
+    }
+}
+
+/// Creates a [`PriorityQueueRoundRobin`] scheduler, see [`priority_queue`].
+///
+/// The parameters are the same as for the [`RoundRobinBuilder`]. The `aging_interval`
+/// is 0 if it is not set, which disables aging.
+#[derive(Debug, Clone)]
+pub struct PriorityQueueBuilder {
+    timeslice: Option<usize>,
+    minimum_remaining_timeslice: usize,
+    max_processes: Option<usize>,
+    aging_interval: usize,
+    tie_break: TieBreak,
+    orphan_policy: OrphanPolicy,
+    pid_policy: PidPolicy,
+}
+
+impl Default for PriorityQueueBuilder {
+    fn default() -> PriorityQueueBuilder {
+        PriorityQueueBuilder {
+            timeslice: None,
+            minimum_remaining_timeslice: 1,
+            max_processes: None,
+            aging_interval: 0,
+            tie_break: TieBreak::Fifo,
+            orphan_policy: OrphanPolicy::Reparent,
+            pid_policy: PidPolicy::Monotonic,
+        }
+    }
+}
+
+impl PriorityQueueBuilder {
+    /// Returns a builder with the default parameters.
+    pub fn new() -> PriorityQueueBuilder {
+        PriorityQueueBuilder::default()
+    }
+
+    /// Sets the time quanta that a process can run before it is preempted.
+    pub fn timeslice(mut self, timeslice: usize) -> PriorityQueueBuilder {
+        self.timeslice = Some(timeslice);
+        self
+    }
+
+    /// Sets the minimum remaining timeslice for which a process that made a
+    /// system call is scheduled again.
+    pub fn minimum_remaining_timeslice(mut self, minimum: usize) -> PriorityQueueBuilder {
+        self.minimum_remaining_timeslice = minimum;
+        self
+    }
+
+    /// Sets the maximum number of living processes, see [`Scheduler::set_max_processes`].
+    pub fn max_processes(mut self, max_processes: usize) -> PriorityQueueBuilder {
+        self.max_processes = Some(max_processes);
+        self
+    }
+
+    /// Sets what happens to the children of a process that exits, see
+    /// [`Scheduler::set_orphan_policy`]. The default is [`OrphanPolicy::Reparent`].
+    pub fn orphan_policy(mut self, orphan_policy: OrphanPolicy) -> PriorityQueueBuilder {
+        self.orphan_policy = orphan_policy;
+        self
+    }
+
+    /// Sets whether the scheduler reuses the PIDs of the processes that have exited,
+    /// see [`Scheduler::set_pid_policy`]. The default is [`PidPolicy::Monotonic`].
+    pub fn pid_policy(mut self, pid_policy: PidPolicy) -> PriorityQueueBuilder {
+        self.pid_policy = pid_policy;
+        self
+    }
+
+    /// Sets the number of time units a process has to wait in the ready queue
+    /// for its priority to increase by one.
+    pub fn aging_interval(mut self, aging_interval: usize) -> PriorityQueueBuilder {
+        self.aging_interval = aging_interval;
+        self
+    }
+
+    /// Sets the order of the ready processes that have the same priority.
+    /// The default is [`TieBreak::Fifo`].
+    pub fn tie_break(mut self, tie_break: TieBreak) -> PriorityQueueBuilder {
+        self.tie_break = tie_break;
+        self
+    }
+
+    /// Returns the scheduler, or an error if a parameter has an invalid value.
+    pub fn build(self) -> Result<PriorityQueueRoundRobin, SchedulerConfigError> {
+        let (timeslice, minimum_remaining_timeslice, max_processes) = validate(
+            "timeslice",
+            self.timeslice,
+            self.minimum_remaining_timeslice,
+            self.max_processes,
+        )?;
+        let mut scheduler =
+            priority_queue(timeslice, minimum_remaining_timeslice, self.aging_interval);
+        scheduler.max_processes = max_processes;
+        scheduler.orphan_policy = self.orphan_policy;
+        scheduler.pids.set_policy(self.pid_policy);
+        scheduler.tie_break = self.tie_break;
+        Ok(scheduler)
+    }
+}
+
+/// Creates a [`CFS`] scheduler, see [`cfs`].
+///
+/// The `cpu_time` is required. The `minimum_remaining_timeslice` is 1 if it is not
+/// set, and has to be smaller than the `cpu_time`. Without `max_processes`, the
+/// number of processes is not limited.
+#[derive(Debug, Clone)]
+pub struct CfsBuilder {
+    cpu_time: Option<usize>,
+    minimum_remaining_timeslice: usize,
+    max_processes: Option<usize>,
+    tie_break: TieBreak,
+    orphan_policy: OrphanPolicy,
+    pid_policy: PidPolicy,
+}
+
+impl Default for CfsBuilder {
+    fn default() -> CfsBuilder {
+        CfsBuilder {
+            cpu_time: None,
+            minimum_remaining_timeslice: 1,
+            max_processes: None,
+            tie_break: TieBreak::LowestPid,
+            orphan_policy: OrphanPolicy::Reparent,
+            pid_policy: PidPolicy::Monotonic,
+        }
+    }
+}
+
+impl CfsBuilder {
+    /// Returns a builder with the default parameters.
+    pub fn new() -> CfsBuilder {
+        CfsBuilder::default()
+    }
+
+    /// Sets the total time units that the cpu has for an iteration.
+    pub fn cpu_time(mut self, cpu_time: usize) -> CfsBuilder {
+        self.cpu_time = Some(cpu_time);
+        self
+    }
+
+    /// Sets the minimum remaining timeslice for which a process that made a
+    /// system call is scheduled again.
+    pub fn minimum_remaining_timeslice(mut self, minimum: usize) -> CfsBuilder {
+        self.minimum_remaining_timeslice = minimum;
+        self
+    }
+
+    /// Sets the maximum number of living processes, see [`Scheduler::set_max_processes`].
+    pub fn max_processes(mut self, max_processes: usize) -> CfsBuilder {
+        self.max_processes = Some(max_processes);
+        self
+    }
+
+    /// Sets what happens to the children of a process that exits, see
+    /// [`Scheduler::set_orphan_policy`]. The default is [`OrphanPolicy::Reparent`].
+    pub fn orphan_policy(mut self, orphan_policy: OrphanPolicy) -> CfsBuilder {
+        self.orphan_policy = orphan_policy;
+        self
+    }
+
+    /// Sets whether the scheduler reuses the PIDs of the processes that have exited,
+    /// see [`Scheduler::set_pid_policy`]. The default is [`PidPolicy::Monotonic`].
+    pub fn pid_policy(mut self, pid_policy: PidPolicy) -> CfsBuilder {
+        self.pid_policy = pid_policy;
+        self
+    }
+
+    /// Sets the order of the ready processes that have the same virtual runtime.
+    /// The default is [`TieBreak::LowestPid`].
+    pub fn tie_break(mut self, tie_break: TieBreak) -> CfsBuilder {
+        self.tie_break = tie_break;
+        self
+    }
+
+    /// Returns the scheduler, or an error if a parameter has an invalid value.
+    pub fn build(self) -> Result<CFS, SchedulerConfigError> {
+        let (cpu_time, minimum_remaining_timeslice, max_processes) = validate(
+            "cpu_time",
+            self.cpu_time,
+            self.minimum_remaining_timeslice,
+            self.max_processes,
+        )?;
+        let mut scheduler = cfs(cpu_time, minimum_remaining_timeslice);
+        scheduler.max_processes = max_processes;
+        scheduler.orphan_policy = self.orphan_policy;
+        scheduler.pids.set_policy(self.pid_policy);
+        scheduler.tie_break = self.tie_break;
+        Ok(scheduler)
+    }
+}
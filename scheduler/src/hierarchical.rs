@@ -0,0 +1,659 @@
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+
+use crate::schedulers::Random;
+use crate::{
+    BurstHistogram, OrphanPolicy, Pid, PidPolicy, Process, ProcessInfo, ProcessState, Scheduler,
+    SchedulerError, SchedulerStats, SchedulingDecision, SpawnRequest, StopReason, Syscall,
+    SyscallResult,
+};
+
+/// A scheduler that divides the processor between groups of processes, each group
+/// being scheduled by its own scheduler, like the control groups of Linux.
+///
+/// Group 0 is the root scheduler, the other groups are added with
+/// [`HierarchicalScheduler::add_group`]. When a new process has to be scheduled, `next`
+/// draws one of the groups that have a ready process, by lottery, with the CPU shares of
+/// the groups as weights, and returns the decision of its scheduler. A process that is
+/// scheduled again by its group, because its timeslice did not end, keeps running.
+/// When no group has a ready process, the shortest sleep of the groups is returned.
+///
+/// The PIDs of the groups are translated to PIDs that are unique in the whole
+/// hierarchy and are never reused. A process created with [`Syscall::Fork`] joins the
+/// group of its parent, and the first process of a group is created with
+/// [`HierarchicalScheduler::spawn`]. The system calls that refer to other processes only
+/// reach the processes of the same group.
+///
+/// Every group keeps its own clock, which advances only while the group runs or sleeps,
+/// so the sleep of a process is measured in the time of its group.
+/// [`Scheduler::current_time`] is the sum of the clocks of the groups. Observers and
+/// snapshots are not supported.
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use scheduler::{round_robin, HierarchicalScheduler, Scheduler, SchedulingDecision};
+///
+/// let timeslice = NonZeroUsize::new(2).unwrap();
+/// let mut scheduler = HierarchicalScheduler::with_seed(Box::new(round_robin(timeslice, 1)), 1, 7);
+/// let group = scheduler.add_group(Box::new(round_robin(timeslice, 1)), 3);
+/// scheduler.spawn(0, 0).unwrap();
+/// scheduler.spawn(group, 0).unwrap();
+/// assert_eq!(scheduler.process_count(), 2);
+/// assert!(matches!(scheduler.next(), SchedulingDecision::Run { .. }));
+/// ```
+pub struct HierarchicalScheduler {
+    root: Box<dyn Scheduler>,
+    root_share: usize,
+    groups: Vec<(Box<dyn Scheduler>, usize)>,
+    random: Random,
+    // pid-ul global al fiecarui proces, dupa grupul si pid-ul sau din grup, si invers
+    global_pids: HashMap<(usize, Pid), Pid>,
+    local_pids: HashMap<Pid, (usize, Pid)>,
+    next_pid: usize,
+    // grupul si pid-ul local al ultimului proces planificat, care primeste apelul stop
+    last: Option<(usize, Pid)>,
+    // grupul care a omorat ultimul proces, pentru statistici
+    last_kill_group: Option<usize>,
+    listed: Vec<ListedProcess>,
+}
+
+impl HierarchicalScheduler {
+    /// Returns a scheduler with the `root` group, which receives `root_share` shares of
+    /// the processor.
+    pub fn new(root: Box<dyn Scheduler>, root_share: usize) -> HierarchicalScheduler {
+        HierarchicalScheduler::with_random(root, root_share, Random::from_entropy())
+    }
+
+    /// Returns a scheduler with the `root` group, that draws the groups with a
+    /// pseudo-random generator initialised with `seed`, so that the decisions can be
+    /// reproduced.
+    pub fn with_seed(
+        root: Box<dyn Scheduler>,
+        root_share: usize,
+        seed: u64,
+    ) -> HierarchicalScheduler {
+        HierarchicalScheduler::with_random(root, root_share, Random::new(seed))
+    }
+
+    fn with_random(
+        root: Box<dyn Scheduler>,
+        root_share: usize,
+        random: Random,
+    ) -> HierarchicalScheduler {
+        HierarchicalScheduler {
+            root,
+            root_share,
+            groups: Vec::new(),
+            random,
+            global_pids: HashMap::new(),
+            local_pids: HashMap::new(),
+            next_pid: 1,
+            last: None,
+            last_kill_group: None,
+            listed: Vec::new(),
+        }
+    }
+
+    /// Adds a group scheduled by `scheduler`, which receives `cpu_share` shares of the
+    /// processor, and returns the index of the group.
+    ///
+    /// A group with 0 shares only runs when no other group has a ready process.
+    pub fn add_group(&mut self, scheduler: Box<dyn Scheduler>, cpu_share: usize) -> usize {
+        self.groups.push((scheduler, cpu_share));
+        self.groups.len()
+    }
+
+    /// Creates the first process of the group with index `group`, with the priority
+    /// `priority`, and returns [`SyscallResult::Pid`] with its PID.
+    ///
+    /// The other results of a [`Syscall::Fork`] are returned if the group refuses the
+    /// process, and [`SyscallResult::Error`] if the group already has processes, which
+    /// have to be created by the group with [`Syscall::Fork`]. Panics if the group does
+    /// not exist.
+    pub fn spawn(&mut self, group: usize, priority: i8) -> Result<SyscallResult, SchedulerError> {
+        if self.group(group).process_count() > 0 {
+            return Ok(SyscallResult::Error);
+        }
+        let result = self
+            .group_mut(group)
+            .stop(StopReason::syscall(Syscall::Fork(priority)))?;
+        Ok(self.register(group, result))
+    }
+
+    /// Returns the index of the group of the process with PID `pid`, or [`None`] if
+    /// the process does not exist.
+    pub fn group_of(&self, pid: Pid) -> Option<usize> {
+        self.local(pid).map(|(group, _)| group)
+    }
+
+    fn group_count(&self) -> usize {
+        self.groups.len() + 1
+    }
+
+    fn group(&self, group: usize) -> &dyn Scheduler {
+        match group {
+            0 => self.root.as_ref(),
+            _ => self.groups[group - 1].0.as_ref(),
+        }
+    }
+
+    fn group_mut(&mut self, group: usize) -> &mut dyn Scheduler {
+        match group {
+            0 => self.root.as_mut(),
+            _ => self.groups[group - 1].0.as_mut(),
+        }
+    }
+
+    fn share(&self, group: usize) -> usize {
+        match group {
+            0 => self.root_share,
+            _ => self.groups[group - 1].1,
+        }
+    }
+
+    // pid-ul global al procesului cu pid-ul local primit; pid-ul procesului idle ramane
+    // neschimbat
+    fn global(&self, group: usize, pid: Pid) -> Pid {
+        self.global_pids.get(&(group, pid)).copied().unwrap_or(pid)
+    }
+
+    // grupul si pid-ul local al procesului; intrarile raman cat timp pid-ul local nu a
+    // fost refolosit de grup
+    fn local(&self, pid: Pid) -> Option<(usize, Pid)> {
+        let &(group, local) = self.local_pids.get(&pid)?;
+        (self.global_pids.get(&(group, local)) == Some(&pid)).then_some((group, local))
+    }
+
+    // procesul nou creat de un grup primeste urmatorul pid global
+    fn register(&mut self, group: usize, result: SyscallResult) -> SyscallResult {
+        match result {
+            SyscallResult::Pid(local) => SyscallResult::Pid(self.register_pid(group, local)),
+            SyscallResult::Pids(locals) => SyscallResult::Pids(
+                locals
+                    .into_iter()
+                    .map(|local| self.register_pid(group, local))
+                    .collect(),
+            ),
+            result => result,
+        }
+    }
+
+    fn register_pid(&mut self, group: usize, local: Pid) -> Pid {
+        let pid = Pid::new(self.next_pid);
+        self.next_pid += 1;
+        self.global_pids.insert((group, local), pid);
+        self.local_pids.insert(pid, (group, local));
+        pid
+    }
+
+    // pid-urile din apelurile de sistem sunt traduse in pid-uri ale grupului; procesele
+    // altor grupuri sunt inlocuite cu un pid pe care grupul nu il foloseste
+    fn translate_syscall(&self, group: usize, syscall: Syscall) -> Syscall {
+        let local = |pid| match self.local(pid) {
+            Some((pid_group, local)) if pid_group == group => local,
+            _ => Pid::new(Pid::MAX),
+        };
+        match syscall {
+            Syscall::SetProcessGroup(pgid) => Syscall::SetProcessGroup(local(pgid)),
+            Syscall::KillGroup(pgid) => Syscall::KillGroup(local(pgid)),
+            Syscall::SuspendGroup(pgid) => Syscall::SuspendGroup(local(pgid)),
+            Syscall::ResumeGroup(pgid) => Syscall::ResumeGroup(local(pgid)),
+            Syscall::Suspend(target) => Syscall::Suspend(local(target)),
+            Syscall::Resume(target) => Syscall::Resume(local(target)),
+            Syscall::WaitPid(child) => Syscall::WaitPid(local(child)),
+            Syscall::SendSignal { target, signum } => Syscall::SendSignal {
+                target: local(target),
+                signum,
+            },
+            syscall => syscall,
+        }
+    }
+
+    fn translate_decision(&self, group: usize, decision: SchedulingDecision) -> SchedulingDecision {
+        match decision {
+            SchedulingDecision::Run {
+                pid,
+                timeslice,
+                core,
+            } => SchedulingDecision::Run {
+                pid: self.global(group, pid),
+                timeslice,
+                core,
+            },
+            SchedulingDecision::Deadlock(pids) => SchedulingDecision::Deadlock(
+                pids.into_iter()
+                    .map(|pid| self.global(group, pid))
+                    .collect(),
+            ),
+            SchedulingDecision::Deadlines(missed) => SchedulingDecision::Deadlines(
+                missed
+                    .into_iter()
+                    .map(|(pid, deadline)| (self.global(group, pid), deadline))
+                    .collect(),
+            ),
+            SchedulingDecision::DeliverSignal(pid, signum) => {
+                SchedulingDecision::DeliverSignal(self.global(group, pid), signum)
+            }
+            decision => decision,
+        }
+    }
+
+    // grupul al carui planificator ia urmatoarea decizie, sau None daca toate grupurile
+    // au terminat
+    fn choose(&self, random: &mut Random) -> Option<usize> {
+        // procesul care nu si-a terminat timeslice-ul continua sa ruleze
+        if let Some((group, pid)) = self.last {
+            let info = self.group(group).process_info(pid);
+            if info.is_some_and(|info| info.state == ProcessState::Running) {
+                return Some(group);
+            }
+        }
+
+        let decisions: Vec<SchedulingDecision> = (0..self.group_count())
+            .map(|group| self.group(group).peek())
+            .collect();
+        // semnalele si rapoartele sunt transmise inaintea planificarii
+        let report = decisions.iter().position(|decision| {
+            matches!(
+                decision,
+                SchedulingDecision::DeliverSignal(..)
+                    | SchedulingDecision::Deadlines(_)
+                    | SchedulingDecision::UtilizationExceeded { .. }
+            )
+        });
+        if report.is_some() {
+            return report;
+        }
+
+        let ready: Vec<usize> = (0..self.group_count())
+            .filter(|&group| matches!(decisions[group], SchedulingDecision::Run { .. }))
+            .collect();
+        let total: usize = ready.iter().map(|&group| self.share(group)).sum();
+        if total > 0 {
+            let mut ticket = random.below(total);
+            for &group in &ready {
+                if ticket < self.share(group) {
+                    return Some(group);
+                }
+                ticket -= self.share(group);
+            }
+        }
+        if let Some(&group) = ready.first() {
+            return Some(group);
+        }
+
+        // niciun grup nu poate rula, asa ca grupul cu cel mai scurt sleep doarme
+        let sleep = (0..self.group_count())
+            .filter_map(|group| match decisions[group] {
+                SchedulingDecision::Sleep(time) => Some((time, group)),
+                _ => None,
+            })
+            .min();
+        if let Some((_, group)) = sleep {
+            return Some(group);
+        }
+        decisions
+            .iter()
+            .position(|decision| *decision != SchedulingDecision::Done)
+    }
+
+    // grupul care a omorat un proces in timpul apelului este retinut pentru statistici
+    fn track_kills(&mut self, group: usize, killed_before: usize) {
+        if self.group(group).statistics().processes_killed > killed_before {
+            self.last_kill_group = Some(group);
+        }
+    }
+}
+
+impl Scheduler for HierarchicalScheduler {
+    fn next(&mut self) -> SchedulingDecision {
+        let mut random = self.random.clone();
+        let group = self.choose(&mut random);
+        self.random = random;
+        let Some(group) = group else {
+            self.last = None;
+            return SchedulingDecision::Done;
+        };
+
+        let decision = self.group_mut(group).next();
+        self.last = match decision {
+            SchedulingDecision::Run { pid, .. } if pid != Pid::IDLE => Some((group, pid)),
+            _ => None,
+        };
+        self.translate_decision(group, decision)
+    }
+
+    fn peek(&self) -> SchedulingDecision {
+        match self.choose(&mut self.random.clone()) {
+            Some(group) => self.translate_decision(group, self.group(group).peek()),
+            None => SchedulingDecision::Done,
+        }
+    }
+
+    fn stop(&mut self, reason: StopReason) -> Result<SyscallResult, SchedulerError> {
+        // fara un proces planificat, apelul este primit de grupul radacina
+        let group = self.last.map_or(0, |(group, _)| group);
+        let reason = match reason {
+            StopReason::Syscall { syscall, remaining } => StopReason::Syscall {
+                syscall: self.translate_syscall(group, syscall),
+                remaining,
+            },
+            reason => reason,
+        };
+        let killed = self.group(group).statistics().processes_killed;
+        let result = self.group_mut(group).stop(reason)?;
+        self.track_kills(group, killed);
+        Ok(self.register(group, result))
+    }
+
+    fn drain(&mut self) {
+        for group in 0..self.group_count() {
+            self.group_mut(group).drain();
+        }
+    }
+
+    fn kill(&mut self, pid: Pid) -> bool {
+        let Some((group, local)) = self.local(pid) else {
+            return false;
+        };
+        let killed = self.group(group).statistics().processes_killed;
+        if !self.group_mut(group).kill(local) {
+            return false;
+        }
+        self.track_kills(group, killed);
+        true
+    }
+
+    fn process_count(&self) -> usize {
+        (0..self.group_count())
+            .map(|group| self.group(group).process_count())
+            .sum()
+    }
+
+    // limitele sunt aplicate fiecarui grup adaugat
+    fn set_max_processes(&mut self, max_processes: Option<NonZeroUsize>) {
+        for group in 0..self.group_count() {
+            self.group_mut(group).set_max_processes(max_processes);
+        }
+    }
+
+    fn set_priority_range(&mut self, priority_range: RangeInclusive<i8>) {
+        for group in 0..self.group_count() {
+            self.group_mut(group)
+                .set_priority_range(priority_range.clone());
+        }
+    }
+
+    // pid-urile globale nu sunt refolosite, chiar daca grupurile refolosesc pid-urile locale
+    fn set_pid_policy(&mut self, pid_policy: PidPolicy) {
+        for group in 0..self.group_count() {
+            self.group_mut(group).set_pid_policy(pid_policy);
+        }
+    }
+
+    fn set_orphan_policy(&mut self, orphan_policy: OrphanPolicy) {
+        for group in 0..self.group_count() {
+            self.group_mut(group).set_orphan_policy(orphan_policy);
+        }
+    }
+
+    fn reset(&mut self) {
+        for group in 0..self.group_count() {
+            self.group_mut(group).reset();
+        }
+        self.global_pids.clear();
+        self.local_pids.clear();
+        self.next_pid = 1;
+        self.last = None;
+        self.last_kill_group = None;
+        self.listed.clear();
+    }
+
+    fn list(&mut self) -> Vec<&dyn Process> {
+        let mut listed = Vec::new();
+        for group in 0..self.group_count() {
+            let processes: Vec<ListedProcess> = self
+                .group_mut(group)
+                .list()
+                .into_iter()
+                .map(ListedProcess::new)
+                .collect();
+            for mut process in processes {
+                process.pid = self.global(group, process.pid);
+                process.pgid = process.pgid.map(|pgid| self.global(group, pgid));
+                process.parent = process.parent.map(|parent| self.global(group, parent));
+                listed.push(process);
+            }
+        }
+        listed.sort_by_key(|process| process.pid);
+        self.listed = listed;
+        self.listed
+            .iter()
+            .map(|process| process as &dyn Process)
+            .collect()
+    }
+
+    fn ready_len(&self) -> usize {
+        (0..self.group_count())
+            .map(|group| self.group(group).ready_len())
+            .sum()
+    }
+
+    fn waiting_len(&self) -> usize {
+        (0..self.group_count())
+            .map(|group| self.group(group).waiting_len())
+            .sum()
+    }
+
+    // statisticile sunt adunate pentru toate grupurile
+    fn statistics(&self) -> SchedulerStats {
+        let mut total = SchedulerStats::default();
+        for group in 0..self.group_count() {
+            let stats = self.group(group).statistics();
+            total.context_switches += stats.context_switches;
+            total.idle_time += stats.idle_time;
+            total.idle_ticks += stats.idle_ticks;
+            total.throttled_ticks += stats.throttled_ticks;
+            total.processes_spawned += stats.processes_spawned;
+            total.processes_terminated += stats.processes_terminated;
+            total.execution_time += stats.execution_time;
+            total.waiting_time += stats.waiting_time;
+            total.processes_killed += stats.processes_killed;
+            total.steals_performed += stats.steals_performed;
+            total.migrations += stats.migrations;
+            total.yields += stats.yields;
+            total.exited.processes += stats.exited.processes;
+            total.exited.cpu_ticks += stats.exited.cpu_ticks;
+            total.exited.squared_cpu_ticks += stats.exited.squared_cpu_ticks;
+            total.processes_started += stats.processes_started;
+            total.response_time += stats.response_time;
+        }
+        total.last_killed = self.last_kill_group.and_then(|group| {
+            let (pid, signum) = self.group(group).statistics().last_killed?;
+            Some((self.global(group, pid), signum))
+        });
+        total
+    }
+
+    fn current_time(&self) -> usize {
+        (0..self.group_count())
+            .map(|group| self.group(group).current_time())
+            .sum()
+    }
+
+    fn process_info(&self, pid: Pid) -> Option<ProcessInfo> {
+        let (group, local) = self.local(pid)?;
+        let mut info = self.group(group).process_info(local)?;
+        info.pid = pid;
+        Some(info)
+    }
+
+    fn processes(&self) -> Box<dyn Iterator<Item = ProcessInfo> + '_> {
+        let mut processes: Vec<ProcessInfo> = (0..self.group_count())
+            .flat_map(|group| {
+                self.group(group).processes().map(move |mut info| {
+                    info.pid = self.global(group, info.pid);
+                    info
+                })
+            })
+            .collect();
+        processes.sort_by_key(|info| info.pid);
+        Box::new(processes.into_iter())
+    }
+
+    fn remaining_sleep(&self, pid: Pid) -> Option<usize> {
+        let (group, local) = self.local(pid)?;
+        self.group(group).remaining_sleep(local)
+    }
+
+    fn take_wakeup_result(&mut self, pid: Pid) -> Option<SyscallResult> {
+        let (group, local) = self.local(pid)?;
+        self.group_mut(group).take_wakeup_result(local)
+    }
+
+    fn burst_histogram(&self, pid: Pid) -> Option<BurstHistogram> {
+        let (group, local) = self.local(pid)?;
+        self.group(group).burst_histogram(local)
+    }
+
+    // procesele sunt create in grupul radacina
+    fn spawn_batch(&mut self, requests: Vec<SpawnRequest>) -> Vec<Pid> {
+        let locals = self.root.spawn_batch(requests);
+        locals
+            .into_iter()
+            .map(|local| self.register_pid(0, local))
+            .collect()
+    }
+
+    // momentul trezirii este estimat adaugand timpul ramas de dormit in grup la timpul curent
+    fn next_wakeup(&self) -> Option<(Pid, usize)> {
+        let now = self.current_time();
+        (0..self.group_count())
+            .filter_map(|group| {
+                let scheduler = self.group(group);
+                let (pid, time) = scheduler.next_wakeup()?;
+                let remaining = time.saturating_sub(scheduler.current_time());
+                Some((now + remaining, self.global(group, pid)))
+            })
+            .min()
+            .map(|(time, pid)| (pid, time))
+    }
+
+    fn queue_depths(&self) -> Vec<usize> {
+        (0..self.group_count())
+            .flat_map(|group| self.group(group).queue_depths())
+            .collect()
+    }
+
+    fn turnaround_times(&self) -> HashMap<Pid, usize> {
+        (0..self.group_count())
+            .flat_map(|group| {
+                self.group(group)
+                    .turnaround_times()
+                    .into_iter()
+                    .map(move |(pid, time)| (self.global(group, pid), time))
+            })
+            .collect()
+    }
+
+    fn response_times(&self) -> HashMap<Pid, usize> {
+        (0..self.group_count())
+            .flat_map(|group| {
+                self.group(group)
+                    .response_times()
+                    .into_iter()
+                    .map(move |(pid, time)| (self.global(group, pid), time))
+            })
+            .collect()
+    }
+}
+
+// copia informatiilor unui proces dintr-un grup, cu pid-urile traduse in pid-uri globale
+struct ListedProcess {
+    pid: Pid,
+    state: ProcessState,
+    timings: (usize, usize, usize),
+    priority: i8,
+    extra: String,
+    wait_time: usize,
+    pgid: Option<Pid>,
+    parent: Option<Pid>,
+    name: Option<String>,
+    cpu_affinity: u64,
+    nice: i8,
+    voluntary_switches: usize,
+    involuntary_switches: usize,
+    cpu_quota: Option<f64>,
+    resources: usize,
+}
+
+impl ListedProcess {
+    fn new(process: &dyn Process) -> ListedProcess {
+        ListedProcess {
+            pid: process.pid(),
+            state: process.state(),
+            timings: process.timings(),
+            priority: process.priority(),
+            extra: process.extra(),
+            wait_time: process.wait_time(),
+            pgid: process.pgid(),
+            parent: process.parent(),
+            name: process.name().map(String::from),
+            cpu_affinity: process.cpu_affinity(),
+            nice: process.nice(),
+            voluntary_switches: process.voluntary_switches(),
+            involuntary_switches: process.involuntary_switches(),
+            cpu_quota: process.cpu_quota(),
+            resources: process.resources(),
+        }
+    }
+}
+
+impl Process for ListedProcess {
+    fn pid(&self) -> Pid {
+        self.pid
+    }
+    fn state(&self) -> ProcessState {
+        self.state
+    }
+    fn timings(&self) -> (usize, usize, usize) {
+        self.timings
+    }
+    fn priority(&self) -> i8 {
+        self.priority
+    }
+    fn extra(&self) -> String {
+        self.extra.clone()
+    }
+    fn wait_time(&self) -> usize {
+        self.wait_time
+    }
+    fn pgid(&self) -> Option<Pid> {
+        self.pgid
+    }
+    fn parent(&self) -> Option<Pid> {
+        self.parent
+    }
+    fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+    fn cpu_affinity(&self) -> u64 {
+        self.cpu_affinity
+    }
+    fn nice(&self) -> i8 {
+        self.nice
+    }
+    fn voluntary_switches(&self) -> usize {
+        self.voluntary_switches
+    }
+    fn involuntary_switches(&self) -> usize {
+        self.involuntary_switches
+    }
+    fn cpu_quota(&self) -> Option<f64> {
+        self.cpu_quota
+    }
+    fn resources(&self) -> usize {
+        self.resources
+    }
+}
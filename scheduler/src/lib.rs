@@ -3,16 +3,45 @@
 //! This library provides the traits and structures necessary
 //! to implement a process scheduler.
 //!
+//! With the `serde` feature, the decisions, stop reasons, system calls and their
+//! results, as well as the process information, can be serialized and deserialized.
+//!
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::num::NonZeroUsize;
 
+mod builder;
+mod hierarchical;
+mod replay;
 mod scheduler;
+mod starvation;
+mod tracing;
+
+use schedulers::{
+    Balancing, Comparator, EdfPolicy, FcfsPolicy, Gang, GangPolicy, Lottery, LotteryPolicy,
+    Mutexes, Observer, PidAllocator, Random, RateMonotonic, RateMonotonicPolicy, RealTimeFifo,
+    RealTimeFifoPolicy, Semaphores, SjfPolicy, SrtfPolicy, Stride, StridePolicy, TimingWheel,
+    Zombies, EDF, FCFS, MLFQ, QUOTA_PERIOD, SJF, SRTF,
+};
 
-use schedulers::{PriorityQueueRoundRobin, RoundRobin, CFS};
+pub use builder::{
+    BuildError, CfsBuilder, Policy, PriorityQueueBuilder, RoundRobinBuilder, SchedulerBuilder,
+    SchedulerConfigError,
+};
+pub use hierarchical::HierarchicalScheduler;
+pub use replay::{ReplayError, ReplayScheduler};
+pub use starvation::StarvationDetector;
+pub use tracing::{GanttEntry, TimelineEntry, TracingScheduler};
+pub use schedulers::{
+    MultiCoreRoundRobin, PriorityQueueRoundRobin, RoundRobin, SchedulerSnapshot,
+    WorkStealingRoundRobin, CFS,
+};
 
 pub use crate::scheduler::{
-    Pid, Process, ProcessState, Scheduler, SchedulingDecision, StopReason, Syscall, SyscallResult,
+    BurstHistogram, ExecutionHistory, MultiScheduler, Name, OrphanPolicy, Pid, PidPolicy, Process,
+    ProcessComparator, ProcessInfo, ProcessState, ProcessView, Scheduler, SchedulerError,
+    SchedulerEvent, SchedulerStats, SchedulingDecision, SpawnRequest, StopReason, Syscall,
+    SyscallResult, TieBreak, TimeUnit, DEFAULT_PRIORITY_RANGE, NICE_RANGE, SIGKILL,
 };
 
 mod schedulers;
@@ -24,67 +53,657 @@ mod schedulers;
 ///
 /// * `timeslice` - the time quanta that a process can run before it is preempted
 /// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
-///                                 has to decode whether to schedule it again for the
-///                                 remaining time of its quanta, or to schedule a new
-///                                 process. The scheduler will schedule the process
-///                                 again of the remaining quanta is greater or equal to
-///                                 the `minimum_remaining_timeslice` value.
-
-pub fn round_robin(timeslice: NonZeroUsize, minimum_remaining_timeslice: usize) -> impl Scheduler {
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+pub fn round_robin(
+    timeslice: impl Into<TimeUnit>,
+    minimum_remaining_timeslice: usize,
+) -> RoundRobin {
+    let timeslice = timeslice.into().get();
     RoundRobin {
         ready_processes: VecDeque::new(),
         waiting_processes: VecDeque::new(),
-       timeslice,
-       minimum_remaining_timeslice,
+        timeslice,
+        minimum_remaining_timeslice,
         remaining_timeslice: 0,
-        next_pid: 1,
+        pids: PidAllocator::default(),
         sleep: false,
         sleep_time: 0,
+        stats: SchedulerStats::default(),
+        observer: Observer::default(),
+        draining: false,
+        max_processes: None,
+        priority_range: DEFAULT_PRIORITY_RANGE,
+        completed: HashMap::new(),
+        responses: HashMap::new(),
+        semaphores: Semaphores::default(),
+        mutexes: Mutexes::default(),
+        zombies: Zombies::default(),
+        orphan_policy: OrphanPolicy::Reparent,
+        nice_timeslices: false,
+        idle_process: false,
+        timing_wheel: TimingWheel::default(),
+        comparator: Comparator::default(),
+        quota_period: NonZeroUsize::new(QUOTA_PERIOD).unwrap(),
+        period_start: 0,
     }
 }
 
+/// Returns a structure that implements the `Scheduler` trait with a round robin scheduler
+/// policy, in which the order of the ready processes is given by a comparator
+///
+/// Every time a new process has to be scheduled, the ready process that is the least
+/// according to `cmp` runs, with the same timeslice rules as [`round_robin`]. Processes
+/// that are equal according to `cmp` run in the order of the ready queue. The comparator
+/// is not saved by [`Scheduler::snapshot`], so a restored scheduler uses the order of the
+/// ready queue.
+///
+/// * `timeslice` - the time quanta that a process can run before it is preempted
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+/// * `cmp` - the function that compares two ready processes.
+pub fn custom(
+    timeslice: impl Into<TimeUnit>,
+    minimum_remaining_timeslice: usize,
+    cmp: ProcessComparator,
+) -> impl Scheduler {
+    let mut scheduler = round_robin(timeslice, minimum_remaining_timeslice);
+    scheduler.comparator = Comparator::new(cmp);
+    scheduler
+}
+
 /// Returns a structure that implements the `Scheduler` trait with a priority queue scheduler policy
+///
+/// To prevent starvation, the priority of a process that waits in the ready queue increases
+/// by one for every `aging_interval` time units it spends there, up to the highest priority
+/// level. When the process is scheduled, its priority returns to the value it had before
+/// aging. The time spent sleeping or waiting for events does not count, but the time waited
+/// in the ready queue before sleeping is kept until the process is scheduled.
+///
+/// The priority levels are the priorities of [`Scheduler::set_priority_range`]: a process
+/// does not age above the end of the range, and an expired timeslice does not lower its
+/// priority below the start. A [`Syscall::Renice`] system call maps the nice value linearly
+/// onto the range, from the highest priority for -20 to the lowest one for 19.
+///
 /// * `timeslice` - the time quanta that a process can run before it is preempted
 /// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
-///                                 has to decode whether to schedule it again for the
-///                                 remaining time of its quanta, or to schedule a new
-///                                 process. The scheduler will schedule the process
-///                                 again of the remaining quanta is greater or equal to
-///                                 the `minimum_remaining_timeslice` value.
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+/// * `aging_interval` - the number of time units a process has to wait in the ready queue
+///   for its priority to increase by one. A value of 0 disables aging.
 pub fn priority_queue(
-    timeslice: NonZeroUsize,
+    timeslice: impl Into<TimeUnit>,
     minimum_remaining_timeslice: usize,
-) -> impl Scheduler {
+    aging_interval: usize,
+) -> PriorityQueueRoundRobin {
+    let timeslice = timeslice.into().get();
     PriorityQueueRoundRobin {
         ready_processes: VecDeque::new(),
         waiting_processes: VecDeque::new(),
         timeslice,
         minimum_remaining_timeslice,
         remaining_timeslice: 0,
-        next_pid: 1,
+        pids: PidAllocator::default(),
         sleep: false,
         sleep_time: 0,
+        stats: SchedulerStats::default(),
+        observer: Observer::default(),
+        draining: false,
+        max_processes: None,
+        priority_range: DEFAULT_PRIORITY_RANGE,
+        completed: HashMap::new(),
+        responses: HashMap::new(),
+        semaphores: Semaphores::default(),
+        mutexes: Mutexes::default(),
+        zombies: Zombies::default(),
+        orphan_policy: OrphanPolicy::Reparent,
+        aging_interval,
+        tie_break: TieBreak::Fifo,
     }
 }
 
 /// Returns a structure that implements the `Scheduler` trait with a simplified [cfs](https://opensource.com/article/19/2/fair-scheduling-linux) scheduler policy
 /// * `cpu_time` - the total time units that the cpu has for an iteration, this is used to compute
-///                    the `timeslice` of each process.
-/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
-///                                 has to decode whether to schedule it again for the
-///                                 remaining time of its quanta, or to schedule a new
-///                                 process. The scheduler will schedule the process
-///                                 again of the remaining quanta is greater or equal to
-///                                 the `minimum_remaining_timeslice` value.
-pub fn cfs(cpu_time: NonZeroUsize, minimum_remaining_timeslice: usize) -> impl Scheduler {
+///   the `timeslice` of each process.
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+pub fn cfs(cpu_time: impl Into<TimeUnit>, minimum_remaining_timeslice: usize) -> CFS {
+    let cpu_time = cpu_time.into().get();
     CFS {
         ready_processes: VecDeque::new(),
         waiting_processes: VecDeque::new(),
         cpu_time,
         minimum_remaining_timeslice,
         remaining_timeslice: 0,
-        next_pid: 1,
+        pids: PidAllocator::default(),
         sleep: false,
         sleep_time: 0,
+        stats: SchedulerStats::default(),
+        tie_break: TieBreak::LowestPid,
+        observer: Observer::default(),
+        draining: false,
+        max_processes: None,
+        priority_range: DEFAULT_PRIORITY_RANGE,
+        completed: HashMap::new(),
+        responses: HashMap::new(),
+        semaphores: Semaphores::default(),
+        mutexes: Mutexes::default(),
+        zombies: Zombies::default(),
+        orphan_policy: OrphanPolicy::Reparent,
     }
 }
+
+/// Returns a structure that implements the `Scheduler` trait with a non-preemptive
+/// first come first served scheduler policy
+///
+/// Processes are scheduled in the order in which they arrived in the ready queue and
+/// run until they exit, sleep or wait for an event. A process is never preempted, the
+/// scheduled process always receives a timeslice of [`NonZeroUsize::MAX`].
+///
+/// A process that forks keeps running, while the new process is placed at the end
+/// of the ready queue, so processes forked one after the other run in PID order.
+pub fn fcfs() -> impl Scheduler {
+    FCFS::new(0, FcfsPolicy)
+}
+
+/// Returns a structure that implements the `Scheduler` trait with a real-time first in
+/// first out scheduler policy, like `SCHED_FIFO` in Linux
+///
+/// The process with the highest priority runs until it exits, sleeps or waits for an
+/// event, there is no timeslice expiry between processes with the same priority. Processes
+/// with the same priority run in the order in which they became ready.
+///
+/// A process is preempted as soon as a process with a strictly higher priority becomes
+/// ready, and is placed at the head of its priority level, not at its end as with round
+/// robin. A running process receives a timeslice that ends when the first sleeping process
+/// with a higher priority wakes up, so that the preemption happens at the following
+/// `next` call. Without such a process, the timeslice is [`NonZeroUsize::MAX`].
+///
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   continues it for the remaining time of its timeslice if the remaining time is
+///   greater or equal to this value, otherwise it receives a new timeslice.
+pub fn rt_fifo(minimum_remaining_timeslice: usize) -> impl Scheduler {
+    RealTimeFifo::new(
+        minimum_remaining_timeslice,
+        RealTimeFifoPolicy { preemptive: true },
+    )
+}
+
+/// Returns a structure that implements the `Scheduler` trait with a non-preemptive priority
+/// scheduler policy
+///
+/// Once a process is scheduled, it runs until it exits, sleeps, waits for an event or
+/// yields the processor with [`Syscall::Yield`], even if a process with a higher priority
+/// becomes ready, so the timeslice is always [`NonZeroUsize::MAX`]. The process with the
+/// highest priority is scheduled next, processes with the same priority run in the order in
+/// which they became ready.
+///
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   continues it for the remaining time of its timeslice if the remaining time is
+///   greater or equal to this value, otherwise it receives a new timeslice.
+pub fn priority_nonpreemptive(minimum_remaining_timeslice: usize) -> impl Scheduler {
+    RealTimeFifo::new(
+        minimum_remaining_timeslice,
+        RealTimeFifoPolicy { preemptive: false },
+    )
+}
+
+/// Returns a structure that implements the `Scheduler` trait with a shortest job first scheduler policy
+///
+/// The length of the next execution burst of every process is predicted as the exponential
+/// average of its previous bursts. Processes that have not run yet start with an estimate
+/// equal to `timeslice`.
+///
+/// * `timeslice` - the time quanta that a process can run before it is preempted
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+pub fn sjf(timeslice: impl Into<TimeUnit>, minimum_remaining_timeslice: usize) -> impl Scheduler {
+    let timeslice = timeslice.into().get();
+    sjf_with_estimate(timeslice, minimum_remaining_timeslice, timeslice.get())
+}
+
+/// Returns a structure that implements the `Scheduler` trait with a shortest job first scheduler policy
+/// * `timeslice` - the time quanta that a process can run before it is preempted
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+/// * `default_estimate` - the predicted burst length of a process that has not run yet.
+pub fn sjf_with_estimate(
+    timeslice: impl Into<TimeUnit>,
+    minimum_remaining_timeslice: usize,
+    default_estimate: usize,
+) -> impl Scheduler {
+    let timeslice = timeslice.into().get();
+    SJF::new(
+        minimum_remaining_timeslice,
+        SjfPolicy {
+            timeslice,
+            default_estimate,
+            preemptive: true,
+        },
+    )
+}
+
+/// Returns a structure that implements the `Scheduler` trait with a non-preemptive
+/// shortest job first scheduler policy
+///
+/// Processes announce the length of their next burst with [`Syscall::SetBurstHint`].
+/// The ready process with the shortest announced burst runs first, and processes that
+/// did not announce their burst run after them, in the order of their arrival. A running
+/// process is never preempted, it runs until it exits, sleeps or waits for an event.
+///
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+pub fn shortest_job_first(minimum_remaining_timeslice: usize) -> impl Scheduler {
+    SJF::new(
+        minimum_remaining_timeslice,
+        SjfPolicy {
+            timeslice: NonZeroUsize::MAX,
+            default_estimate: 0,
+            preemptive: false,
+        },
+    )
+}
+
+/// Returns a structure that implements the `Scheduler` trait with a multi-level feedback queue scheduler policy
+///
+/// New processes start on the first (highest priority) level. A process that uses its
+/// whole timeslice is moved one level down, while a process that makes a system call
+/// stays on its level. The scheduler always runs a process from the highest level that
+/// has ready processes.
+///
+/// * `levels` - the number of priority levels
+/// * `base_timeslice` - the time quanta of the first level, every level has double the
+///   time quanta of the level above it
+/// * `boost_interval` - the number of time units after which all the processes are moved
+///   back to the first level, `0` disables the boost
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+pub fn mlfq(
+    levels: NonZeroUsize,
+    base_timeslice: impl Into<TimeUnit>,
+    boost_interval: usize,
+    minimum_remaining_timeslice: usize,
+) -> impl Scheduler {
+    let base_timeslice = base_timeslice.into().get();
+    // timeslice-ul se dubleaza de la un nivel la urmatorul
+    let timeslices: Vec<NonZeroUsize> = (0..levels.get())
+        .map(|level| {
+            let timeslice = 2_usize
+                .checked_pow(level as u32)
+                .and_then(|factor| base_timeslice.get().checked_mul(factor))
+                .unwrap_or(usize::MAX);
+            NonZeroUsize::new(timeslice).unwrap()
+        })
+        .collect();
+    mlfq_with_timeslices(
+        levels,
+        &timeslices,
+        minimum_remaining_timeslice,
+        boost_interval,
+    )
+}
+
+/// Returns a structure that implements the `Scheduler` trait with a multi-level feedback queue scheduler policy
+/// where every level has its own time quanta
+///
+/// See [`mlfq`] for the scheduling rules.
+///
+/// * `levels` - the number of priority levels
+/// * `timeslices` - the time quanta of every level, starting with the first level; levels
+///   without a value use the last value of the list. The list cannot be empty.
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+/// * `boost_interval` - the number of time units after which all the processes are moved
+///   back to the first level, `0` disables the boost
+pub fn mlfq_with_timeslices(
+    levels: NonZeroUsize,
+    timeslices: &[NonZeroUsize],
+    minimum_remaining_timeslice: usize,
+    boost_interval: usize,
+) -> impl Scheduler {
+    let last_timeslice = *timeslices.last().expect("timeslices cannot be empty");
+    MLFQ {
+        ready_processes: vec![VecDeque::new(); levels.get()],
+        waiting_processes: VecDeque::new(),
+        timeslices: (0..levels.get())
+            .map(|level| timeslices.get(level).copied().unwrap_or(last_timeslice))
+            .collect(),
+        boost_interval,
+        minimum_remaining_timeslice,
+        remaining_timeslice: 0,
+        pids: PidAllocator::default(),
+        sleep: false,
+        sleep_time: 0,
+        stats: SchedulerStats::default(),
+        observer: Observer::default(),
+        draining: false,
+        max_processes: None,
+        priority_range: DEFAULT_PRIORITY_RANGE,
+        completed: HashMap::new(),
+        responses: HashMap::new(),
+        semaphores: Semaphores::default(),
+        mutexes: Mutexes::default(),
+        zombies: Zombies::default(),
+        orphan_policy: OrphanPolicy::Reparent,
+        running_level: None,
+        time_since_boost: 0,
+    }
+}
+
+/// Returns a structure that implements the `Scheduler` trait with a shortest remaining time first scheduler policy
+///
+/// Processes announce the length of their next burst with [`Syscall::SetBurstHint`]. The
+/// ready process with the shortest remaining burst runs first, ties are broken by PID.
+/// The running process is preempted as soon as a process with a shorter remaining burst
+/// becomes ready. Processes that did not announce their burst run after the others.
+///
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+pub fn srtf(minimum_remaining_timeslice: usize) -> impl Scheduler {
+    SRTF::new(minimum_remaining_timeslice, SrtfPolicy)
+}
+
+/// Returns a structure that implements the `Scheduler` trait with a lottery scheduler policy
+///
+/// Every time a process has to be scheduled, the scheduler draws a random ticket out of all
+/// the tickets of the ready processes and runs the process that holds it. Processes hold one
+/// ticket by default and can change it with [`Syscall::SetTickets`]; forked processes start
+/// with the tickets of their parent.
+///
+/// The scheduling is fair only in a probabilistic sense: a process that holds `t` out of the
+/// `T` tickets wins every draw with the probability `t / T`, so the share of draws it wins
+/// converges to `t / T` as the number of draws grows. In the short run, a process can win
+/// more or fewer draws than its share.
+///
+/// The tickets are drawn using a random generator initialized with `seed`, so two
+/// schedulers created with the same seed take the same decisions for the same processes.
+///
+/// * `timeslice` - the time quanta that a process can run before it is preempted
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+/// * `seed` - the seed of the random generator
+pub fn lottery(
+    timeslice: impl Into<TimeUnit>,
+    minimum_remaining_timeslice: usize,
+    seed: u64,
+) -> impl Scheduler {
+    let timeslice = timeslice.into().get();
+    new_lottery(timeslice, minimum_remaining_timeslice, Random::new(seed))
+}
+
+fn new_lottery(
+    timeslice: NonZeroUsize,
+    minimum_remaining_timeslice: usize,
+    random: Random,
+) -> Lottery {
+    Lottery::new(
+        minimum_remaining_timeslice,
+        LotteryPolicy { timeslice, random },
+    )
+}
+
+/// Returns a structure that implements the `Scheduler` trait with a stride scheduler policy
+///
+/// Like the lottery scheduler, every process receives a share of the processor proportional
+/// to the number of tickets it holds (see [`Syscall::SetTickets`]), but the decisions are
+/// deterministic. Every process has a stride, inversely proportional to its tickets, and a
+/// pass value. The process with the smallest pass runs, and its pass grows with its stride for
+/// every time unit it spends on the processor. Ties are broken by PID.
+///
+/// A process created with the priority `p` starts with `p + 1` tickets, processes with a
+/// negative priority start with one ticket.
+///
+/// New processes start with the smallest pass among the ready processes, and a process that
+/// wakes up cannot have a pass smaller than the ready processes, so processes cannot gather
+/// credit while they are not ready.
+///
+/// * `timeslice` - the time quanta that a process can run before it is preempted
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+pub fn stride(
+    timeslice: impl Into<TimeUnit>,
+    minimum_remaining_timeslice: usize,
+) -> impl Scheduler {
+    let timeslice = timeslice.into().get();
+    Stride::new(minimum_remaining_timeslice, StridePolicy { timeslice })
+}
+
+/// Returns a structure that implements the `Scheduler` trait with an earliest deadline first policy
+///
+/// Processes set their absolute deadline with [`Syscall::SetDeadline`]. The scheduler always runs
+/// the ready process with the earliest deadline, preempting the running process when a process
+/// with an earlier deadline becomes ready. Processes without a deadline are best-effort and run
+/// only when no process with a deadline is ready. Ties are broken by PID.
+///
+/// Processes that are still alive when their deadline passes are reported once, through
+/// [`SchedulingDecision::Deadlines`].
+///
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+pub fn edf(minimum_remaining_timeslice: usize) -> impl Scheduler {
+    EDF::new(minimum_remaining_timeslice, EdfPolicy::default())
+}
+
+/// Returns a structure that implements the `Scheduler` trait with a rate monotonic policy
+///
+/// Processes become periodic with [`Syscall::SetPeriod`]. Priorities are static: the ready
+/// process with the shortest period always runs, preempting the running process when a
+/// process with a shorter period becomes ready. Processes without a period run only when
+/// no periodic process is ready. Ties are broken by PID.
+///
+/// Every periodic process is activated again at the start of each period. When the
+/// utilization of the periodic processes exceeds `n(2^(1/n) - 1)`, the scheduler reports
+/// it through [`SchedulingDecision::UtilizationExceeded`].
+///
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+pub fn rate_monotonic(minimum_remaining_timeslice: usize) -> impl Scheduler {
+    RateMonotonic::new(
+        minimum_remaining_timeslice,
+        RateMonotonicPolicy::default(),
+    )
+}
+
+/// Returns a structure that implements the `Scheduler` trait with a gang scheduling policy
+///
+/// Related processes are scheduled together, as a group. Every process is a member of the
+/// group of its process group leader, see [`Syscall::SetProcessGroup`]. A forked process
+/// joins the group of its parent, unless the group already has `group_size` members, in
+/// which case it becomes the leader of a new group.
+///
+/// A group runs only when all its members are ready: its members are scheduled one after
+/// the other, in the order of the ready queue, each of them for a whole timeslice. When a
+/// member sleeps or waits for an event, the whole group yields the processor and the other
+/// members are held in the ready queue until the blocked member is ready again.
+///
+/// * `timeslice` - the time quanta that a process can run before it is preempted
+/// * `group_size` - the maximum number of members of a group created by forks
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+pub fn gang(
+    timeslice: impl Into<TimeUnit>,
+    group_size: NonZeroUsize,
+    minimum_remaining_timeslice: usize,
+) -> impl Scheduler {
+    let timeslice = timeslice.into().get();
+    Gang::new(
+        minimum_remaining_timeslice,
+        GangPolicy {
+            timeslice,
+            group_size,
+            gang: VecDeque::new(),
+        },
+    )
+}
+
+/// Returns a structure that implements the `Scheduler` trait with a round robin policy
+/// on `num_cpus` processor cores
+///
+/// Every core has its own run queue, and [`SchedulingDecision::Run`] tells on which
+/// core the process runs. The scheduler also implements [`MultiScheduler`]: the
+/// decisions are taken in batches, one for every idle core that has a ready process,
+/// the processes of a batch run in parallel and the clock advances once per batch.
+/// [`Scheduler::next`] returns the decisions of a batch one by one. A process is only
+/// at the front of the queue of one core, so it never runs on two cores at once.
+///
+/// Forked processes inherit the affinity mask of their parent and are added to the
+/// allowed core with the shortest run queue, and woken up processes return to their
+/// core. When the longest run queue has more than `balance_threshold` processes than
+/// the shortest one, a ready process is migrated from the longest queue to the
+/// shortest one, see [`MultiCoreRoundRobin::set_balance_threshold`].
+///
+/// * `num_cpus` - the number of processor cores
+/// * `timeslice` - the time quanta that a process can run before it is preempted
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+pub fn round_robin_multicore(
+    num_cpus: NonZeroUsize,
+    timeslice: impl Into<TimeUnit>,
+    minimum_remaining_timeslice: usize,
+) -> MultiCoreRoundRobin {
+    let timeslice = timeslice.into().get();
+    MultiCoreRoundRobin {
+        ready_processes: vec![VecDeque::new(); num_cpus.get()],
+        waiting_processes: VecDeque::new(),
+        timeslice,
+        minimum_remaining_timeslice,
+        remaining_timeslices: vec![0; num_cpus.get()],
+        pids: PidAllocator::default(),
+        sleep: false,
+        sleep_time: 0,
+        stats: SchedulerStats::default(),
+        running_core: None,
+        running_cores: vec![false; num_cpus.get()],
+        current_core: None,
+        batch_elapsed: vec![0; num_cpus.get()],
+        batch_times: HashMap::new(),
+        time: 0,
+        balancing: Balancing::Migration(1),
+        observer: Observer::default(),
+        draining: false,
+        max_processes: None,
+        priority_range: DEFAULT_PRIORITY_RANGE,
+        completed: HashMap::new(),
+        responses: HashMap::new(),
+        semaphores: Semaphores::default(),
+        mutexes: Mutexes::default(),
+        zombies: Zombies::default(),
+        orphan_policy: OrphanPolicy::Reparent,
+    }
+}
+
+/// Returns a structure that implements the `MultiScheduler` trait with a round robin
+/// policy on `cores` processor cores
+///
+/// This is the scheduler of [`round_robin_multicore`], seen through the
+/// [`MultiScheduler`] trait: [`MultiScheduler::next_batch`] returns the decisions of
+/// all the idle cores at once, and [`MultiScheduler::stop_core`] stops the process of
+/// one core.
+///
+/// * `cores` - the number of processor cores
+/// * `timeslice` - the time quanta that a process can run before it is preempted
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+pub fn multicore_round_robin(
+    cores: NonZeroUsize,
+    timeslice: impl Into<TimeUnit>,
+    minimum_remaining_timeslice: usize,
+) -> impl MultiScheduler {
+    round_robin_multicore(cores, timeslice, minimum_remaining_timeslice)
+}
+
+/// Returns a structure that implements the `Scheduler` trait with a round robin policy
+/// on `num_cpus` processor cores, that balances the run queues by work stealing
+///
+/// The cores are scheduled as by [`round_robin_multicore`], in batches. Instead of
+/// migrating one process at a time, a core whose run queue becomes empty steals half of
+/// the processes of the longest run queue, starting with the most recently added ones,
+/// from the end of the queue. Every steal is counted in
+/// [`SchedulerStats::steals_performed`].
+///
+/// * `num_cpus` - the number of processor cores
+/// * `timeslice` - the time quanta that a process can run before it is preempted
+/// * `steal_threshold` - the minimum number of processes in the run queue of a core
+///   before other cores may steal processes from it
+/// * `minimum_remaining_timeslice` - when a process makes a system call, the scheduler
+///   has to decode whether to schedule it again for the
+///   remaining time of its quanta, or to schedule a new
+///   process. The scheduler will schedule the process
+///   again of the remaining quanta is greater or equal to
+///   the `minimum_remaining_timeslice` value.
+pub fn round_robin_work_stealing(
+    num_cpus: NonZeroUsize,
+    timeslice: impl Into<TimeUnit>,
+    steal_threshold: usize,
+    minimum_remaining_timeslice: usize,
+) -> WorkStealingRoundRobin {
+    let mut scheduler = round_robin_multicore(num_cpus, timeslice, minimum_remaining_timeslice);
+    scheduler.balancing = Balancing::WorkStealing(steal_threshold);
+    scheduler
+}
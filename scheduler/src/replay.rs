@@ -0,0 +1,199 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+
+use crate::{
+    OrphanPolicy, Pid, PidPolicy, Process, Scheduler, SchedulerError, SchedulingDecision,
+    StopReason, Syscall, SyscallResult,
+};
+
+/// The difference between the calls made to a [`ReplayScheduler`] and the recorded trace.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReplayError {
+    /// The scheduler was stopped while the trace has no running process, for example
+    /// twice after the same [`SchedulingDecision::Run`] decision.
+    NoRunningProcess,
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayError::NoRunningProcess => write!(f, "the trace has no running process"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// A scheduler that returns the decisions of a recorded trace, in order, so that an
+/// execution can be reproduced exactly.
+///
+/// The trace is usually recorded with [`crate::TracingScheduler::decisions`]. The
+/// scheduler keeps no processes, every `next` call returns the following decision of
+/// the trace and [`SchedulingDecision::Done`] after the last one. A `stop` call ends
+/// the run of the process scheduled by the last decision and returns
+/// [`SchedulerError::Replay`] if no process is running, without panicking. The first
+/// process can be forked before the first decision, as for the other schedulers.
+///
+/// The results of the system calls are not recorded. The forks return increasing PIDs,
+/// starting from 1, as the other schedulers do when PIDs are not reused, and every
+/// other system call returns [`SyscallResult::Success`].
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use scheduler::{
+///     round_robin, ReplayScheduler, Scheduler, StopReason, Syscall, TracingScheduler,
+/// };
+///
+/// let mut scheduler = TracingScheduler::new(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+/// scheduler.stop(StopReason::syscall(Syscall::Fork(0))).unwrap();
+/// scheduler.next();
+/// scheduler.stop(StopReason::syscall(Syscall::Exit)).unwrap();
+/// scheduler.next();
+///
+/// let mut replay = ReplayScheduler::new(scheduler.decisions().to_vec());
+/// replay.stop(StopReason::syscall(Syscall::Fork(0))).unwrap();
+/// assert_eq!(replay.next(), scheduler.decisions()[0]);
+/// replay.stop(StopReason::syscall(Syscall::Exit)).unwrap();
+/// assert_eq!(replay.next(), scheduler.decisions()[1]);
+/// assert!(replay.stop(StopReason::expired()).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReplayScheduler {
+    trace: Vec<SchedulingDecision>,
+    // indicele urmatoarei decizii din trace
+    position: usize,
+    running: Option<Pid>,
+    // procesele planificate de trace, singurele care pot fi oprite cu kill
+    pids: HashSet<Pid>,
+    last_pid: usize,
+}
+
+impl ReplayScheduler {
+    /// Returns a scheduler that replays the decisions of `trace`.
+    pub fn new(trace: Vec<SchedulingDecision>) -> ReplayScheduler {
+        let pids = trace
+            .iter()
+            .filter_map(|decision| match decision {
+                SchedulingDecision::Run { pid, .. } => Some(*pid),
+                _ => None,
+            })
+            .collect();
+        ReplayScheduler {
+            trace,
+            position: 0,
+            running: None,
+            pids,
+            last_pid: 0,
+        }
+    }
+
+    /// Returns the number of decisions that have not been replayed yet.
+    pub fn remaining(&self) -> usize {
+        self.trace.len() - self.position
+    }
+
+    // functie care aloca PID-urile proceselor create prin fork
+    fn fork(&mut self) -> Pid {
+        self.last_pid += 1;
+        Pid::new(self.last_pid)
+    }
+}
+
+impl Scheduler for ReplayScheduler {
+    fn next(&mut self) -> SchedulingDecision {
+        let decision = self.peek();
+        if self.position < self.trace.len() {
+            self.position += 1;
+        }
+        self.running = match decision {
+            SchedulingDecision::Run { pid, .. } => Some(pid),
+            _ => None,
+        };
+        decision
+    }
+
+    fn peek(&self) -> SchedulingDecision {
+        self.trace
+            .get(self.position)
+            .cloned()
+            .unwrap_or(SchedulingDecision::Done)
+    }
+
+    fn stop(&mut self, reason: StopReason) -> Result<SyscallResult, SchedulerError> {
+        match reason {
+            // dispozitivele pot termina operatiile si cand nu ruleaza niciun proces
+            StopReason::IoComplete { .. } => return Ok(SyscallResult::Success),
+            // primul proces este creat inainte de prima decizie
+            StopReason::Syscall {
+                syscall: Syscall::Fork(_) | Syscall::ForkWithDeadline { .. },
+                ..
+            } if self.position == 0 && self.last_pid == 0 => {}
+            _ => {
+                if self.running.take().is_none() {
+                    return Err(SchedulerError::Replay(ReplayError::NoRunningProcess));
+                }
+            }
+        }
+
+        let result = match reason {
+            StopReason::Syscall { syscall, .. } => match syscall {
+                Syscall::Fork(_) | Syscall::ForkWithDeadline { .. } => {
+                    SyscallResult::Pid(self.fork())
+                }
+                Syscall::ForkMany(0) => SyscallResult::InvalidArgument,
+                Syscall::ForkMany(count) => {
+                    SyscallResult::Pids((0..count).map(|_| self.fork()).collect())
+                }
+                _ => SyscallResult::Success,
+            },
+            _ => SyscallResult::Success,
+        };
+        Ok(result)
+    }
+
+    fn drain(&mut self) {}
+
+    fn kill(&mut self, pid: Pid) -> bool {
+        if !self.pids.contains(&pid) {
+            return false;
+        }
+        if self.running == Some(pid) {
+            self.running = None;
+        }
+        true
+    }
+
+    fn process_count(&self) -> usize {
+        0
+    }
+
+    fn set_max_processes(&mut self, _max_processes: Option<NonZeroUsize>) {}
+
+    fn set_priority_range(&mut self, _priority_range: RangeInclusive<i8>) {}
+
+    fn set_pid_policy(&mut self, _pid_policy: PidPolicy) {}
+
+    fn set_orphan_policy(&mut self, _orphan_policy: OrphanPolicy) {}
+
+    // trace-ul este reluat de la inceput
+    fn reset(&mut self) {
+        self.position = 0;
+        self.running = None;
+        self.last_pid = 0;
+    }
+
+    fn list(&mut self) -> Vec<&dyn Process> {
+        Vec::new()
+    }
+
+    fn ready_len(&self) -> usize {
+        0
+    }
+
+    fn waiting_len(&self) -> usize {
+        0
+    }
+}
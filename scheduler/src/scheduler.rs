@@ -1,23 +1,47 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::{self, Display};
-use std::num::NonZeroUsize;
-use std::ops::Add;
+use std::num::{NonZeroUsize, TryFromIntError};
+use std::ops::{Add, RangeInclusive};
+use std::sync::{Arc, Mutex};
+
+use crate::{ReplayError, SchedulerSnapshot};
 
 /// The PID of a process
 ///
-/// The PID cannot be 0, PIDs start from 1.
+/// PIDs start from 1, the PID 0 is reserved for the idle process, see [`Pid::IDLE`].
+/// The largest PID is [`Pid::MAX`].
 #[derive(PartialEq, Eq, Copy, Clone, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
-pub struct Pid(NonZeroUsize);
+pub struct Pid(usize);
 
 impl Pid {
+    /// The largest PID that a scheduler can allocate. After it was allocated, new
+    /// processes can only receive recycled PIDs, see [`Scheduler::set_pid_policy`].
+    pub const MAX: usize = usize::MAX;
+
+    /// The PID of the idle process, which runs when no other process is ready, see
+    /// [`crate::RoundRobin::set_idle_process`].
+    pub const IDLE: Pid = Pid(0);
+
+    /// Returns the PID `pid`.
+    ///
+    /// Panics if `pid` is 0, use [`Pid::IDLE`] for the idle process.
     pub fn new(pid: usize) -> Pid {
-        Pid(NonZeroUsize::new(pid).unwrap())
+        assert_ne!(pid, 0, "the PID 0 is reserved for the idle process");
+        Pid(pid)
+    }
+
+    // numarul pid-ului, folosit de planificatori pentru a elibera pid-ul
+    pub(crate) fn as_usize(self) -> usize {
+        self.0
     }
 }
 
 impl PartialEq<usize> for Pid {
     fn eq(&self, other: &usize) -> bool {
-        self.0.get() == *other
+        self.0 == *other
     }
 }
 
@@ -37,24 +61,213 @@ impl Add<usize> for Pid {
     type Output = Pid;
 
     fn add(self, rhs: usize) -> Self::Output {
-        Pid::new(self.0.get() + rhs)
+        Pid::new(self.0 + rhs)
+    }
+}
+
+// numele sunt pastrate o singura data, in ordinea in care au aparut, iar un Name retine
+// doar pozitia numelui in tabel
+static NAMES: Mutex<Vec<Arc<str>>> = Mutex::new(Vec::new());
+
+/// The name of a process or of a named event, see [`Syscall::SetName`] and
+/// [`Syscall::WaitNamed`]
+///
+/// Names are interned in a table shared by all the schedulers: equal strings get the
+/// same [`Name`], which only holds the position of the string in the table, so that
+/// the system calls stay [`Copy`]. Every distinct string is stored once, also when it
+/// is deserialized with the `serde` feature.
+#[derive(PartialEq, Eq, Copy, Clone, Hash)]
+pub struct Name(usize);
+
+impl Name {
+    /// Returns the name `name`, adding it to the table of names if it is new.
+    pub fn new(name: &str) -> Name {
+        let mut names = NAMES.lock().unwrap();
+        match names.iter().position(|known| **known == *name) {
+            Some(index) => Name(index),
+            None => {
+                names.push(Arc::from(name));
+                Name(names.len() - 1)
+            }
+        }
+    }
+
+    /// Returns the string of the name.
+    pub fn as_str(&self) -> Arc<str> {
+        NAMES.lock().unwrap()[self.0].clone()
+    }
+
+    // pozitia numelui in tabel, folosita pentru numarul evenimentelor cu nume
+    pub(crate) fn as_usize(self) -> usize {
+        self.0
+    }
+}
+
+impl From<&str> for Name {
+    fn from(name: &str) -> Name {
+        Name::new(name)
+    }
+}
+
+impl PartialEq<&str> for Name {
+    fn eq(&self, other: &&str) -> bool {
+        *self.as_str() == **other
+    }
+}
+
+impl Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+// numele este serializat ca sir, pozitia sa din tabel fiind diferita intre executii
+#[cfg(feature = "serde")]
+impl serde::Serialize for Name {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Name {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Name, D::Error> {
+        let name: String = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Name::new(&name))
+    }
+}
+
+/// The priorities that the processes of a scheduler can have, unless it is changed with
+/// [`Scheduler::set_priority_range`]. This is the range of the real-time priorities of
+/// POSIX `sched_setparam`.
+pub const DEFAULT_PRIORITY_RANGE: RangeInclusive<i8> = 0..=99;
+
+/// The nice values that a process can have, see [`Syscall::Renice`].
+pub const NICE_RANGE: RangeInclusive<i8> = -20..=19;
+
+/// A positive number of time units of the simulation.
+///
+/// The schedulers do not measure real time. A time unit is one step of the simulated
+/// processor, in which a process executes one instruction or makes one system call,
+/// so it has no fixed duration in milliseconds. All the times of the schedulers, the
+/// timeslices, the sleep times and the [`Scheduler::current_time`] clock, use this unit.
+///
+/// The constructors that receive a timeslice accept a `TimeUnit` or a [`NonZeroUsize`],
+/// so a timeslice of 0 is rejected at compile time. A `usize` is converted with
+/// `TimeUnit::try_from`, which fails for 0.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct TimeUnit(NonZeroUsize);
+
+impl TimeUnit {
+    /// Returns `units` time units.
+    pub fn new(units: NonZeroUsize) -> TimeUnit {
+        TimeUnit(units)
+    }
+
+    /// Returns the number of time units.
+    pub fn get(self) -> NonZeroUsize {
+        self.0
+    }
+}
+
+impl From<NonZeroUsize> for TimeUnit {
+    fn from(units: NonZeroUsize) -> TimeUnit {
+        TimeUnit(units)
+    }
+}
+
+/// Fails if `units` is 0.
+impl TryFrom<usize> for TimeUnit {
+    type Error = TryFromIntError;
+
+    fn try_from(units: usize) -> Result<TimeUnit, TryFromIntError> {
+        NonZeroUsize::try_from(units).map(TimeUnit)
+    }
+}
+
+impl From<TimeUnit> for NonZeroUsize {
+    fn from(units: TimeUnit) -> NonZeroUsize {
+        units.0
+    }
+}
+
+impl Display for TimeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
     }
 }
 
 /// The action that the scheduler asks the OS to take.
 ///
 /// This is returned by the [`Scheduler::next`] function.
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SchedulingDecision {
-    /// Run the process with PID `pid` for a maximum of `timeslice` time units.
-    Run { pid: Pid, timeslice: NonZeroUsize },
+    /// Run the process with PID `pid` for a maximum of `timeslice` time units,
+    /// on the processor core with index `core`.
+    ///
+    /// Single core schedulers always use core 0, see [`crate::round_robin_multicore`]
+    /// for a scheduler that uses several cores.
+    Run {
+        pid: Pid,
+        timeslice: NonZeroUsize,
+        core: usize,
+    },
     /// Sleep the amount of specified time units.
     Sleep(NonZeroUsize),
     /// The OS cannot continue anymore, as all the processes are waiting for events.
     ///
     /// In this case there is no other process that can fie any events, which means
-    /// that all the processes will wait indefinitely.
-    Deadlock,
+    /// that all the processes will wait indefinitely. This includes the processes that
+    /// wait for each other's semaphores or mutexes and the suspended processes, but
+    /// not the processes that sleep, so the decision is never taken while a process
+    /// can still wake up. The wait chains themselves are not checked.
+    Deadlock(
+        /// The PIDs of the processes that wait for events, in ascending order.
+        Vec<Pid>,
+    ),
+
+    /// Some processes have missed their deadlines, see [`Syscall::SetDeadline`].
+    ///
+    /// Every process is reported only once. The OS can continue to ask the
+    /// scheduler for decisions.
+    Deadlines(
+        /// The PIDs of the processes and the deadlines that they have missed,
+        /// in ascending order of the PIDs.
+        Vec<(Pid, usize)>,
+    ),
+
+    /// The processor utilization of the periodic processes exceeds the bound
+    /// under which the rate monotonic schedule is guaranteed to be feasible,
+    /// see [`Syscall::SetPeriod`].
+    ///
+    /// This is reported once every time the bound is exceeded. The OS can
+    /// continue to ask the scheduler for decisions.
+    UtilizationExceeded {
+        /// The sum of `Ci / Ti` over all the periodic processes.
+        utilization: f64,
+        /// The bound `n(2^(1/n) - 1)` for `n` periodic processes.
+        bound: f64,
+    },
+
+    /// A process has received a signal, see [`Syscall::SendSignal`].
+    ///
+    /// Every signal is reported only once. The OS can continue to ask the
+    /// scheduler for decisions.
+    DeliverSignal(
+        /// The PID of the process that received the signal.
+        Pid,
+        /// The signal number.
+        u8,
+    ),
 
     /// The process with PID 1 has stopped.
     Panic,
@@ -66,14 +279,34 @@ pub enum SchedulingDecision {
 impl Display for SchedulingDecision {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            SchedulingDecision::Run { pid, timeslice } => {
+            SchedulingDecision::Run {
+                pid,
+                timeslice,
+                core: 0,
+            } => {
                 write!(f, "Run {} for {} slices", pid, timeslice)
             }
+            SchedulingDecision::Run {
+                pid,
+                timeslice,
+                core,
+            } => {
+                write!(f, "Run {} for {} slices on core {}", pid, timeslice, core)
+            }
             SchedulingDecision::Sleep(amount) => {
                 write!(f, "Sleep for {} slices", amount)
             }
-            SchedulingDecision::Deadlock => {
-                write!(f, "Deadlock, unable to schedule anymore processes")
+            SchedulingDecision::Deadlock(pids) => {
+                write!(f, "Deadlock, unable to schedule anymore processes {:?}", pids)
+            }
+            SchedulingDecision::Deadlines(missed) => {
+                write!(f, "Deadlines missed {:?}", missed)
+            }
+            SchedulingDecision::UtilizationExceeded { utilization, bound } => {
+                write!(f, "Utilization {:.3} exceeds the bound {:.3}", utilization, bound)
+            }
+            SchedulingDecision::DeliverSignal(pid, signum) => {
+                write!(f, "Deliver signal {} to {}", signum, pid)
             }
             SchedulingDecision::Panic => {
                 write!(f, "Panic, process 1 has stopped")
@@ -87,6 +320,7 @@ impl Display for SchedulingDecision {
 
 /// A system call that processes make towards the scheduler.
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Syscall {
     /// Create a new process and return its PID.
     Fork(
@@ -94,7 +328,35 @@ pub enum Syscall {
         i8,
     ),
 
+    /// Create a new process that has to finish before a deadline and return its PID.
+    ///
+    /// Real-time schedulers use the deadline as described for [`Syscall::SetDeadline`].
+    /// Other scheduling algorithms handle this like a [`Syscall::Fork`].
+    ForkWithDeadline {
+        /// The process's priority. Some scheduling algorithms can ignore this value.
+        priority: i8,
+        /// The number of time units, starting from the moment of the fork, by which
+        /// the new process should exit.
+        deadline: usize,
+    },
+
+    /// Create several new processes at once and return their PIDs.
+    ///
+    /// The new processes have the priority of the process that makes the system call
+    /// and are added to the ready queue in the order of their PIDs. The time of the
+    /// system call is counted once, as for a single [`Syscall::Fork`]. If the scheduler
+    /// cannot keep track of all the processes, see [`Scheduler::set_max_processes`],
+    /// only the processes it can keep track of are created. A value of 0 is refused
+    /// with [`SyscallResult::InvalidArgument`].
+    ForkMany(
+        /// The number of processes to create.
+        usize,
+    ),
+
     /// Ask the scheduler to suspend for an amount of time
+    ///
+    /// A sleep of 0 time units is handled as a [`Syscall::Yield`]: the process stays in
+    /// the [`ProcessState::Ready`] state and is placed back in the ready queue.
     Sleep(
         /// The amount of time that the process should sleep. The process
         /// will be placed in the [`ProcessState::Waiting`] state for this
@@ -110,6 +372,92 @@ pub enum Syscall {
         usize,
     ),
 
+    /// Wait for any child of the process to exit.
+    ///
+    /// If a child has already exited, its exit code is collected and returned as
+    /// [`SyscallResult::ExitCode`], and the process continues to run. Otherwise the
+    /// process is placed in the [`ProcessState::WaitingForChild`] state until one of
+    /// its children exits, and then issues the system call again to collect the exit
+    /// code. A process without children receives [`SyscallResult::Error`].
+    WaitChild,
+
+    /// Wait for the child with the given PID to exit, like [`Syscall::WaitChild`].
+    WaitPid(
+        /// The PID of the child. A process that is not a child of the calling
+        /// process is refused with [`SyscallResult::Error`].
+        Pid,
+    ),
+
+    /// Wait for a named event.
+    ///
+    /// The name is mapped to an event number, the process is placed in the
+    /// [`ProcessState::Waiting`] state until another process issues a
+    /// [`Syscall::SignalNamed`] system call with the same name. Named events
+    /// never match the numeric events of [`Syscall::Wait`].
+    WaitNamed(Name),
+
+    /// Signal all processes that wait for a named event, see [`Syscall::WaitNamed`].
+    SignalNamed(Name),
+
+    /// Wait for an I/O operation on a device to complete.
+    ///
+    /// The process is placed in the [`ProcessState::IoWait`] state until the OS
+    /// calls [`Scheduler::stop`] with a [`StopReason::IoComplete`] for the same
+    /// device. While all the other processes are blocked, the scheduler sleeps for
+    /// at most `expected_duration` time units, after which the OS is expected to
+    /// report the completion.
+    IoWait {
+        /// The device that performs the I/O operation.
+        device_id: u32,
+        /// The number of time units the operation is expected to take.
+        expected_duration: usize,
+    },
+
+    /// Decrement the value of a semaphore.
+    ///
+    /// Semaphores start with the value 0. If the value becomes negative, the process
+    /// is placed in the [`ProcessState::SemaphoreWait`] state until another process
+    /// issues a [`Syscall::SemPost`] system call for the same semaphore.
+    SemWait(
+        /// The semaphore ID.
+        u32,
+    ),
+
+    /// Increment the value of a semaphore and wake up the first process that waits
+    /// for it, see [`Syscall::SemWait`].
+    SemPost(
+        /// The semaphore ID.
+        u32,
+    ),
+
+    /// Lock a mutex.
+    ///
+    /// If the mutex is held by another process, the process is placed in the
+    /// [`ProcessState::MutexWait`] state until it receives the mutex. Schedulers
+    /// that use priorities run the holder with the highest priority of the
+    /// processes that wait for the mutex, until it unlocks the mutex.
+    MutexLock(
+        /// The mutex ID.
+        u32,
+    ),
+
+    /// Unlock a mutex held by the process, see [`Syscall::MutexLock`].
+    ///
+    /// The mutex is given to the first process that waits for it. Unlocking a
+    /// mutex held by another process has no effect.
+    MutexUnlock(
+        /// The mutex ID.
+        u32,
+    ),
+
+    /// Give up the rest of the timeslice.
+    ///
+    /// The process is placed back in the ready queue, behind the other ready
+    /// processes that the scheduler would run before it, and the scheduler chooses
+    /// the next process to run. The process is not preempted, the yield is counted
+    /// as a voluntary context switch.
+    Yield,
+
     /// Signal all processes that wait for an event.
     Signal(
         /// The event number. All processes that are waiting for this event
@@ -117,6 +465,184 @@ pub enum Syscall {
         usize,
     ),
 
+    /// Announce the expected length of the next execution burst of the process.
+    ///
+    /// Shortest job first schedulers use this value instead of their own
+    /// estimation. Other scheduling algorithms can ignore this value.
+    SetBurstHint(
+        /// The expected number of time units the process will run.
+        usize,
+    ),
+
+    /// Set the number of lottery tickets that the process holds.
+    ///
+    /// Lottery schedulers run a process with a probability proportional to
+    /// the number of tickets that it holds. Processes created afterwards
+    /// by this process start with the same number of tickets. A process
+    /// always holds at least one ticket. Other scheduling algorithms can
+    /// ignore this value.
+    SetTickets(
+        /// The number of tickets.
+        usize,
+    ),
+
+    /// Set the deadline of the process.
+    ///
+    /// Real-time schedulers always run the process with the earliest
+    /// deadline and report the processes that are still running after
+    /// their deadline. Other scheduling algorithms can ignore this value.
+    SetDeadline(
+        /// The absolute time, in time units since the start of the
+        /// simulation, by which the process should exit.
+        usize,
+    ),
+
+    /// Make the process periodic.
+    ///
+    /// Rate monotonic schedulers give a higher priority to the processes with
+    /// shorter periods and activate the process again at the start of every
+    /// period. The execution time of the process in one period is the value
+    /// set with [`Syscall::SetBurstHint`], or the longest time the process ran
+    /// in one period. Other scheduling algorithms can ignore this value.
+    SetPeriod(
+        /// The number of time units between two activations of the process.
+        NonZeroUsize,
+    ),
+
+    /// Announce that the process will signal an event.
+    ///
+    /// Priority schedulers use this to avoid priority inversion: until the process
+    /// sends a [`Syscall::Signal`] system call for the event, it runs with the
+    /// highest priority of the processes that wait for the event, if that is higher
+    /// than its own. Other scheduling algorithms can ignore this value.
+    WillSignal(
+        /// The event number.
+        usize,
+    ),
+
+    /// Make the process a member of a process group.
+    ///
+    /// Processes created afterwards by this process are members of the same
+    /// group. A process that was never added to a group is not a member of
+    /// any group.
+    SetProcessGroup(
+        /// The PID of the group leader. A process creates a new group by
+        /// using its own PID.
+        Pid,
+    ),
+
+    /// Finish all the processes of a process group.
+    ///
+    /// The processes are deleted from the list of processes the scheduler keeps
+    /// track of, as if they had sent a [`Syscall::Exit`] system call. If the
+    /// calling process is a member of the group, it is finished as well.
+    KillGroup(
+        /// The PID of the group leader.
+        Pid,
+    ),
+
+    /// Suspend the processes of a process group that are ready to run.
+    ///
+    /// The processes are placed in the [`ProcessState::Suspended`] state and
+    /// are not scheduled until the group is resumed with [`Syscall::ResumeGroup`].
+    /// If the calling process is a member of the group, it is suspended as well.
+    /// Members of the group that wait for an event or sleep are not affected.
+    SuspendGroup(
+        /// The PID of the group leader.
+        Pid,
+    ),
+
+    /// Resume all the suspended processes of a process group.
+    ///
+    /// The processes are placed in the [`ProcessState::Ready`] state.
+    ResumeGroup(
+        /// The PID of the group leader.
+        Pid,
+    ),
+
+    /// Suspend a process, as with a `SIGSTOP` signal.
+    ///
+    /// A process that is ready to run or sleeps is placed in the
+    /// [`ProcessState::Suspended`] state and is not scheduled until it is resumed
+    /// with [`Syscall::Resume`]. A sleeping process keeps the time at which it wakes
+    /// up. Processes that wait for an event are not affected. Suspending a process
+    /// that does not exist is refused with [`SchedulerError::InvalidPid`].
+    Suspend(
+        /// The PID of the process. A process can suspend itself.
+        Pid,
+    ),
+
+    /// Resume a process suspended with [`Syscall::Suspend`], as with a `SIGCONT` signal.
+    ///
+    /// The process returns to the state it had before it was suspended. A process
+    /// that was sleeping becomes ready if its wake up time has passed in the meantime.
+    /// Resuming a process that does not exist is refused with
+    /// [`SchedulerError::InvalidPid`].
+    Resume(
+        /// The PID of the process.
+        Pid,
+    ),
+
+    /// Send a signal to a process.
+    ///
+    /// The signal [`SIGKILL`] finishes the target process, as if it had sent a
+    /// [`Syscall::Exit`] system call. Any other signal wakes up the target
+    /// process if it sleeps or waits for an event, in which case the system
+    /// call that blocked the target returns [`SyscallResult::Interrupted`], read
+    /// with [`Scheduler::take_wakeup_result`], and is reported with a
+    /// [`SchedulingDecision::DeliverSignal`] decision. The sender always receives
+    /// [`SyscallResult::Success`]. Signals sent to processes that do not exist
+    /// are ignored.
+    SendSignal {
+        /// The PID of the process that receives the signal.
+        target: Pid,
+        /// The signal number.
+        signum: u8,
+    },
+
+    /// Replace the image of the current process, keeping its PID.
+    ///
+    /// The execution times and the burst history of the process are reset and
+    /// the process continues to run as if it had just been created.
+    Exec {
+        /// The new priority of the process, if it changes.
+        new_priority: Option<i8>,
+    },
+
+    /// Set the name of the current process, see [`Process::name`].
+    SetName(Name),
+
+    /// Set the CPU affinity mask of the current process, see [`Process::cpu_affinity`].
+    ///
+    /// Bit `i` of the mask allows the process to run on core `i`. A mask that
+    /// does not allow any of the cores of the scheduler is refused with
+    /// [`SyscallResult::InvalidArgument`]. Single core schedulers only have core 0.
+    SetAffinity(u64),
+
+    /// Set the nice value of the current process, see [`Process::nice`].
+    ///
+    /// A value outside of [`NICE_RANGE`] is refused with
+    /// [`SyscallResult::InvalidArgument`] and the nice value is not changed. The CFS
+    /// scheduler uses it to weight the timeslice and the virtual runtime of the process,
+    /// the round robin scheduler scales the timeslice with it if
+    /// [`crate::RoundRobin::set_nice_timeslices`] is enabled, and the priority queue
+    /// scheduler maps it onto its priority range, see [`crate::priority_queue`]. The
+    /// other schedulers ignore it.
+    Renice(i8),
+
+    /// Set the CPU quota of the current process, see [`Process::cpu_quota`].
+    ///
+    /// The quota is clamped to `0.0..=1.0`, and [`None`] or a NaN value removes it.
+    /// Only the round robin scheduler enforces the quota, see
+    /// [`crate::RoundRobin::set_quota_period`]; the other schedulers ignore it.
+    SetCpuQuota(Option<f64>),
+
+    /// Set the resource counter of the current process, see [`Process::resources`].
+    ///
+    /// The counter holds any quantity the simulation attaches to the process, such as
+    /// the memory pages it holds or its open files. The schedulers ignore it.
+    SetResources(usize),
+
     /// Ask the scheduler to finish the process.
     ///
     /// The process will never be scheduled again and will be deleted
@@ -130,26 +656,106 @@ pub enum Syscall {
 /// the minimum value of the sleeping times.
  */
 
+/// The signal number that finishes a process, see [`Syscall::SendSignal`].
+pub const SIGKILL: u8 = 9;
+
 /// The result returned by a system call.
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SyscallResult {
     /// Returned after a [`Syscall::Fork`] system call.
     Pid(
         /// The PID of the new process.
         Pid,
     ),
+    /// Returned after a [`Syscall::ForkMany`] system call.
+    Pids(
+        /// The PIDs of the new processes, in ascending order.
+        Vec<Pid>,
+    ),
+    /// Returned after a [`Syscall::WaitChild`] or [`Syscall::WaitPid`] system call
+    /// that collected a child which has exited.
+    ExitCode(
+        /// The exit code of the child: 0 after a [`Syscall::Exit`] and -1 for a
+        /// process that was killed.
+        i32,
+    ),
     /// The system call was successful.
     ///
     /// This is the value returned by most system calls.
     Success,
 
-    /// The system call was issues while no process was scheduled.
+    /// The system call was refused by the scheduler.
+    ///
+    /// This is returned after a [`Syscall::WaitChild`] or [`Syscall::WaitPid`]
+    /// system call sent by a process that has no such child, and after a
+    /// [`Syscall::Fork`] system call sent to a scheduler that is draining, see
+    /// [`Scheduler::drain`].
+    Error,
+
+    /// A [`Syscall::Fork`] system call was refused because the scheduler already
+    /// keeps track of the maximum number of processes, see
+    /// [`Scheduler::set_max_processes`], or because no PID is left: [`Pid::MAX`]
+    /// was already allocated and no recycled PID can be reused yet. No PID is allocated.
+    NoMemory,
+
+    /// Returned by [`Scheduler::take_wakeup_result`] to a sleeping or waiting
+    /// process that was woken up by a [`Syscall::SendSignal`] system call.
+    Interrupted,
+
+    /// A [`Syscall::SetAffinity`] system call was refused because the mask does
+    /// not allow any of the cores of the scheduler, in which case the mask is not
+    /// changed, or a [`Syscall::Fork`] system call was refused because the priority is
+    /// not in the range set with [`Scheduler::set_priority_range`].
+    InvalidArgument,
+}
+
+/// The error returned by [`Scheduler::stop`] when the stop reason does not match
+/// the state of the scheduler.
+///
+/// The scheduler is not changed by a stop reason that returns an error.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SchedulerError {
+    /// The scheduler was stopped while no process was running, for example twice
+    /// after the same [`SchedulingDecision::Run`] decision.
     NoRunningProcess,
+
+    /// A [`Syscall::SendSignal`] system call was sent to a process that does not exist.
+    InvalidPid(
+        /// The PID of the target process.
+        Pid,
+    ),
+
+    /// A [`Syscall::Exit`] system call was issued while no process was running,
+    /// for example a second time for a process that has already exited.
+    DoubleExit,
+
+    /// A [`crate::ReplayScheduler`] was called in a way that does not match the
+    /// recorded trace.
+    Replay(
+        /// The difference from the trace.
+        ReplayError,
+    ),
+}
+
+impl Display for SchedulerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchedulerError::NoRunningProcess => write!(f, "no process is running"),
+            SchedulerError::InvalidPid(pid) => write!(f, "there is no process with PID {pid}"),
+            SchedulerError::DoubleExit => write!(f, "exit was called while no process is running"),
+            SchedulerError::Replay(error) => write!(f, "the replay has diverged: {error}"),
+        }
+    }
 }
 
+impl std::error::Error for SchedulerError {}
+
 /// The reason that a process has stopped and the OS
 /// has called the scheduler.
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StopReason {
     /// The process sent a [`Syscall`] system call.
     Syscall {
@@ -163,6 +769,25 @@ pub enum StopReason {
     /// The timeslice allocated for the process has expired and the process
     /// has been preempted.
     Expired,
+
+    /// The process was killed by the OS.
+    ///
+    /// The process is finished immediately and is never scheduled again. The
+    /// time it ran since it was scheduled is not accounted.
+    Killed(
+        /// The number of the signal that killed the process.
+        u8,
+    ),
+
+    /// A device has finished an I/O operation, see [`Syscall::IoWait`].
+    ///
+    /// All the processes that wait for the device are woken up, other processes
+    /// are not affected. Unlike the other reasons, this does not require a
+    /// process to be running and the running process continues to run.
+    IoComplete {
+        /// The device that finished the operation.
+        device_id: u32,
+    },
 }
 
 impl Display for StopReason {
@@ -174,6 +799,12 @@ impl Display for StopReason {
             StopReason::Expired => {
                 write!(f, "Expired")
             }
+            StopReason::Killed(signum) => {
+                write!(f, "Killed by signal {signum}")
+            }
+            StopReason::IoComplete { device_id } => {
+                write!(f, "I/O complete on device {device_id}")
+            }
         }
     }
 }
@@ -197,54 +828,939 @@ impl StopReason {
     }
 }
 
-/// The trait that any scheduler has to implement.
-pub trait Scheduler: Send {
-    /// Returns the action that the OS has to perform next.
-    fn next(&mut self) -> SchedulingDecision;
+/// Cumulative statistics about the activity of a scheduler.
+///
+/// This is returned by the [`Scheduler::statistics`] function.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchedulerStats {
+    /// The number of times a process was scheduled to run in place of another one.
+    pub context_switches: usize,
 
-    /// The scheduler is informed about the stopping of a process
-    /// and the reason.
-    fn stop(&mut self, reason: StopReason) -> SyscallResult;
+    /// The number of time units the processor slept, as all the processes were sleeping.
+    ///
+    /// This is the sum of all the [`SchedulingDecision::Sleep`] decisions and of the
+    /// time units in which the idle process ran, see `idle_ticks`. On several processor
+    /// cores, the idle time of every core is counted, including the time in which a core
+    /// waits for the other cores to end a batch of [`MultiScheduler::next_batch`].
+    pub idle_time: usize,
 
-    /// Returns the list of processes.
-    fn list(&mut self) -> Vec<&dyn Process>;
-}
+    /// The number of time units in which the idle process, with PID [`Pid::IDLE`], ran
+    /// in place of a [`SchedulingDecision::Sleep`] decision, see
+    /// [`crate::RoundRobin::set_idle_process`].
+    pub idle_ticks: usize,
 
-/// The state of a process.
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub enum ProcessState {
-    /// The process is ready to be scheduled.
-    Ready,
+    /// The number of time units the processes spent throttled, after using up their
+    /// CPU quota, summed over all the processes. The time of every process is in
+    /// [`ProcessInfo::throttled_ticks`].
+    pub throttled_ticks: usize,
 
-    /// The process is currently scheduled.
-    Running,
+    /// The number of processes created by [`Syscall::Fork`] system calls.
+    pub processes_spawned: usize,
 
-    /// The process is waiting for an event or sleeping.
-    Waiting {
-        /// The event that the process is waiting for.
-        ///
-        /// If the event is [`None`], the process is sleeping.
-        event: Option<usize>,
-    },
+    /// The number of processes that finished with a [`Syscall::Exit`] system call.
+    pub processes_terminated: usize,
+
+    /// The number of time units the processes ran, including the system calls.
+    pub execution_time: usize,
+
+    /// The number of time units the processes spent in the ready queue waiting to be
+    /// scheduled, summed over all the processes.
+    pub waiting_time: usize,
+
+    /// The number of processes killed by a signal, either with a [`StopReason::Killed`]
+    /// reason or with a [`SIGKILL`] sent by a [`Syscall::SendSignal`] system call.
+    ///
+    /// These processes are also counted in `processes_terminated`.
+    pub processes_killed: usize,
+
+    /// The PID of the last process killed by a signal and the signal number.
+    pub last_killed: Option<(Pid, u8)>,
+
+    /// The number of times that a core with an empty run queue has stolen processes
+    /// from the run queue of another core, see [`crate::round_robin_work_stealing`].
+    pub steals_performed: usize,
+
+    /// The number of processes moved from the run queue of one core to the run queue
+    /// of another core, by the load balancing of [`crate::round_robin_multicore`] or
+    /// by the steals of [`crate::round_robin_work_stealing`].
+    pub migrations: usize,
+
+    /// The number of times a process gave up the processor with a [`Syscall::Yield`]
+    /// system call.
+    ///
+    /// The context switches that follow the yields are also counted in
+    /// `context_switches`, the other context switches are caused by preemptions or
+    /// by processes that block, sleep or exit.
+    pub yields: usize,
+
+    /// The execution times of the processes that ran and then terminated, which are
+    /// used by [`Scheduler::fairness_index`].
+    pub exited: ExecutionHistory,
+
+    /// The number of processes that were scheduled at least once, see
+    /// [`Scheduler::response_times`].
+    pub processes_started: usize,
+
+    /// The time between the creation of every process and the first time it was
+    /// scheduled, summed over all the processes that were scheduled at least once.
+    pub response_time: usize,
 }
 
-impl Display for ProcessState {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ProcessState::Ready => write!(f, "READY"),
-            ProcessState::Running => write!(f, "RUNNING"),
-            ProcessState::Waiting { event } => {
-                if let Some(event) = event {
-                    write!(f, "EVENT {}", event)
-                } else {
-                    write!(f, "SLEEP")
-                }
-            }
+impl SchedulerStats {
+    /// Returns the average time a process spent in the ready queue waiting to be scheduled,
+    /// or 0 if no process was created.
+    pub fn average_waiting_time(&self) -> f64 {
+        if self.processes_spawned == 0 {
+            return 0.0;
         }
+        self.waiting_time as f64 / self.processes_spawned as f64
     }
-}
 
-/// The trait that the Process Control Block (PCB) has to implement.
+    /// Returns the average time between the creation of a process and the first time it
+    /// was scheduled, or 0 if no process was scheduled.
+    pub fn average_response_time(&self) -> f64 {
+        if self.processes_started == 0 {
+            return 0.0;
+        }
+        self.response_time as f64 / self.processes_started as f64
+    }
+
+    pub(crate) fn record_response(&mut self, response_time: usize) {
+        self.processes_started += 1;
+        self.response_time += response_time;
+    }
+
+    pub(crate) fn record_kill(&mut self, pid: Pid, signum: u8) {
+        self.processes_killed += 1;
+        self.last_killed = Some((pid, signum));
+    }
+
+    pub(crate) fn record_exit(&mut self, cpu_ticks: usize) {
+        self.processes_terminated += 1;
+        self.exited.record(cpu_ticks);
+    }
+}
+
+/// The sums of the execution times of a set of processes, from which Jain's fairness
+/// index of the set is computed.
+///
+/// The processes that never ran are not counted.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecutionHistory {
+    /// The number of processes.
+    pub processes: usize,
+
+    /// The sum of the number of time units the processes ran.
+    pub cpu_ticks: usize,
+
+    /// The sum of the squares of the number of time units the processes ran.
+    pub squared_cpu_ticks: u128,
+}
+
+impl ExecutionHistory {
+    /// Adds a process that ran for `cpu_ticks` time units, including its system calls.
+    pub fn record(&mut self, cpu_ticks: usize) {
+        if cpu_ticks == 0 {
+            return;
+        }
+        self.processes += 1;
+        self.cpu_ticks += cpu_ticks;
+        self.squared_cpu_ticks += (cpu_ticks as u128) * (cpu_ticks as u128);
+    }
+
+    /// Returns Jain's fairness index of the execution times, `(sum x)^2 / (n * sum x^2)`,
+    /// which is 1.0 if all the processes ran for the same time and 1/n if a single
+    /// process ran, or 1.0 if there are no processes.
+    pub fn fairness_index(&self) -> f32 {
+        if self.processes == 0 {
+            return 1.0;
+        }
+        let sum = self.cpu_ticks as f64;
+        (sum * sum / (self.processes as f64 * self.squared_cpu_ticks as f64)) as f32
+    }
+}
+
+/// The lengths of the CPU bursts of a process, the times it ran from the moment it was
+/// scheduled until it sent a system call or was preempted.
+///
+/// This is returned by the [`Scheduler::burst_histogram`] function.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BurstHistogram {
+    /// The length of the bursts and the number of bursts with that length, in ascending
+    /// order of the lengths.
+    pub bursts: Vec<(usize, usize)>,
+}
+
+impl BurstHistogram {
+    /// Adds a burst of `length` time units, including the system call that ended it.
+    pub fn record(&mut self, length: usize) {
+        match self.bursts.binary_search_by_key(&length, |&(length, _)| length) {
+            Ok(index) => self.bursts[index].1 += 1,
+            Err(index) => self.bursts.insert(index, (length, 1)),
+        }
+    }
+
+    /// Returns the number of bursts.
+    pub fn count(&self) -> usize {
+        self.bursts.iter().map(|&(_, count)| count).sum()
+    }
+
+    /// Returns the average length of the bursts, or 0 if there are no bursts.
+    pub fn mean(&self) -> f64 {
+        let count = self.count();
+        if count == 0 {
+            return 0.0;
+        }
+        let total: usize = self.bursts.iter().map(|&(length, count)| length * count).sum();
+        total as f64 / count as f64
+    }
+
+    /// Returns the median length of the bursts, or 0 if there are no bursts.
+    pub fn p50(&self) -> usize {
+        self.percentile(50)
+    }
+
+    /// Returns the length that 95% of the bursts do not exceed, or 0 if there are no
+    /// bursts.
+    pub fn p95(&self) -> usize {
+        self.percentile(95)
+    }
+
+    /// Returns the length that 99% of the bursts do not exceed, or 0 if there are no
+    /// bursts.
+    pub fn p99(&self) -> usize {
+        self.percentile(99)
+    }
+
+    // cea mai mica lungime pentru care cel putin percent% dintre rafale nu sunt mai lungi
+    fn percentile(&self, percent: usize) -> usize {
+        let rank = (self.count() * percent).div_ceil(100).max(1);
+        let mut seen = 0;
+        for &(length, count) in &self.bursts {
+            seen += count;
+            if seen >= rank {
+                return length;
+            }
+        }
+        0
+    }
+}
+
+/// A process created by [`Scheduler::spawn_batch`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpawnRequest {
+    /// The priority of the process, clamped to the priority range of the scheduler, see
+    /// [`Scheduler::set_priority_range`].
+    pub priority: i8,
+
+    /// The expected length of the first burst of the process, as set by a
+    /// [`Syscall::SetBurstHint`] system call.
+    pub burst_hint: Option<usize>,
+
+    /// The nice value of the process, clamped to [`NICE_RANGE`].
+    pub nice: i8,
+}
+
+/// Information about the execution of a process.
+///
+/// This is returned by the [`Scheduler::process_info`] function.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProcessInfo {
+    /// The PID of the process.
+    pub pid: Pid,
+
+    /// The state of the process.
+    pub state: ProcessState,
+
+    /// The number of time units the process ran, including its system calls.
+    pub cpu_ticks_consumed: usize,
+
+    /// The number of time units the process did not run, either waiting in the
+    /// ready queue or being blocked by a [`Syscall::Sleep`] or [`Syscall::Wait`].
+    pub waiting_ticks: usize,
+
+    /// The number of times the process used its whole timeslice and was preempted.
+    pub timeslice_preemptions: usize,
+
+    /// The number of time units the process spent throttled, after using up its CPU
+    /// quota, see [`Syscall::SetCpuQuota`]. They are also counted in `waiting_ticks`.
+    pub throttled_ticks: usize,
+
+    /// The virtual runtime of the process, measured in 1/1024 of a time unit and
+    /// weighted by the priority and the nice value of the process.
+    ///
+    /// Only the CFS scheduler keeps track of the virtual runtime, the other
+    /// schedulers return [`None`].
+    pub vruntime: Option<u64>,
+}
+
+/// A read-only view of a ready process, given to the comparator of a [`crate::custom`]
+/// scheduler.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ProcessView {
+    /// The PID of the process.
+    pub pid: Pid,
+
+    /// The priority of the process.
+    pub priority: i8,
+
+    /// The timings of the process, as a tuple of (total, syscalls, execution), see
+    /// [`Process::timings`].
+    pub timings: (usize, usize, usize),
+
+    /// The state of the process.
+    pub state: ProcessState,
+}
+
+/// The function that orders the ready processes of a [`crate::custom`] scheduler.
+pub type ProcessComparator = Box<dyn Fn(&ProcessView, &ProcessView) -> Ordering + Send + Sync>;
+
+/// An event that happened inside a scheduler.
+///
+/// The events are sent to the observer registered with [`Scheduler::set_observer`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SchedulerEvent {
+    /// The process with PID `pid` was scheduled to run for a maximum of `timeslice` time units.
+    Scheduled { pid: Pid, timeslice: NonZeroUsize },
+
+    /// The process with PID `pid` was removed from the processor, but it is still ready to run.
+    Preempted { pid: Pid },
+
+    /// The process with PID `parent` has created the process with PID `child`.
+    ///
+    /// The first process of the system has no parent.
+    Forked { parent: Option<Pid>, child: Pid },
+
+    /// The process with PID `pid` has finished.
+    Exited { pid: Pid },
+
+    /// The process with PID `pid` waits for the event `event`, or sleeps if `event` is `None`.
+    Blocked { pid: Pid, event: Option<usize> },
+
+    /// The process with PID `pid` waits for an I/O operation on the device `device_id`.
+    BlockedOnDevice { pid: Pid, device_id: u32 },
+
+    /// The process with PID `pid` has passed its deadline without finishing.
+    ///
+    /// The event is sent once for every missed deadline, together with the
+    /// [`SchedulingDecision::Deadlines`] decision that reports it.
+    DeadlineMiss(Pid),
+}
+
+/// The trait that any scheduler has to implement.
+pub trait Scheduler: Send {
+    /// Returns the action that the OS has to perform next.
+    fn next(&mut self) -> SchedulingDecision;
+
+    /// Returns the action that [`Scheduler::next`] would return, without changing
+    /// the state of the scheduler.
+    ///
+    /// No events are sent to the observer.
+    fn peek(&self) -> SchedulingDecision;
+
+    /// The scheduler is informed about the stopping of a process
+    /// and the reason.
+    ///
+    /// Returns an error, without changing the scheduler, if no process is running
+    /// or if the system call refers to a process that does not exist.
+    fn stop(&mut self, reason: StopReason) -> Result<SyscallResult, SchedulerError>;
+
+    /// Stops the scheduler from accepting new processes.
+    ///
+    /// Every following [`Syscall::Fork`] system call is refused with
+    /// [`SyscallResult::Error`] and does not allocate a PID, while the existing
+    /// processes are scheduled as before. When all of them have finished,
+    /// [`Scheduler::next`] returns [`SchedulingDecision::Done`].
+    fn drain(&mut self);
+
+    /// Finishes the process with PID `pid`, whether it is running, ready or blocked,
+    /// as if it had received the [`SIGKILL`] signal.
+    ///
+    /// The process is removed from the scheduler and, like after a [`Syscall::Exit`],
+    /// a process with a parent stays a zombie until the parent collects it. Its
+    /// children are handled as set with [`Scheduler::set_orphan_policy`]. Returns
+    /// whether the process existed, a PID that is not known to the scheduler is
+    /// ignored.
+    fn kill(&mut self, pid: Pid) -> bool;
+
+    /// Returns the number of living processes, ready, running, sleeping,
+    /// waiting or suspended.
+    fn process_count(&self) -> usize;
+
+    /// Limits the number of living processes, `None` removes the limit.
+    ///
+    /// A [`Syscall::Fork`] system call is refused with [`SyscallResult::NoMemory`]
+    /// while the scheduler keeps track of `max_processes` processes. Processes that
+    /// already exist are not affected. Schedulers are created without a limit.
+    fn set_max_processes(&mut self, max_processes: Option<NonZeroUsize>);
+
+    /// Sets the priorities that new processes can have.
+    ///
+    /// A [`Syscall::Fork`] or [`Syscall::ForkWithDeadline`] system call with a priority
+    /// outside of `priority_range` is refused with [`SyscallResult::InvalidArgument`].
+    /// Processes that already exist are not affected. Schedulers are created with the
+    /// [`DEFAULT_PRIORITY_RANGE`].
+    fn set_priority_range(&mut self, priority_range: RangeInclusive<i8>);
+
+    /// Sets whether the scheduler reuses the PIDs of the processes that have exited.
+    ///
+    /// With [`PidPolicy::Recycle`], the PID of a process that has exited or was killed
+    /// is released once it is no longer a [`ProcessState::Zombie`]: when its parent
+    /// collects the exit code with [`Syscall::WaitChild`] or [`Syscall::WaitPid`],
+    /// when its parent exits, or right away for a process without a parent. A
+    /// released PID can be allocated to a new process after `delay` time units, so
+    /// that a PID is not reused while other processes may still refer to the process
+    /// that has exited. Recycled PIDs are allocated before new ones, the lowest first,
+    /// and PID 1 is never reused. Switching to [`PidPolicy::Monotonic`] forgets the
+    /// released PIDs. Schedulers are created with [`PidPolicy::Monotonic`].
+    fn set_pid_policy(&mut self, pid_policy: PidPolicy);
+
+    /// Sets what happens to the children of a process that exits or is killed.
+    ///
+    /// Schedulers are created with [`OrphanPolicy::Reparent`].
+    fn set_orphan_policy(&mut self, orphan_policy: OrphanPolicy);
+
+    /// Removes all the processes and clears the statistics, so that the scheduler
+    /// can run a new workload as if it had just been created.
+    ///
+    /// The parameters given when the scheduler was created are kept, and so is
+    /// the observer. The wrappers reset the scheduler inside them, together
+    /// with their own state.
+    fn reset(&mut self);
+
+    /// Returns a copy of the state of the scheduler, that can be displayed, saved or
+    /// compared with another snapshot, or `None` if the scheduler cannot be saved.
+    ///
+    /// The [`crate::RoundRobin`], [`crate::PriorityQueueRoundRobin`] and [`crate::CFS`]
+    /// schedulers return a snapshot and can be restored from it, as can the wrappers
+    /// around them. The default implementation returns `None`.
+    fn snapshot(&self) -> Option<SchedulerSnapshot> {
+        None
+    }
+
+    /// Returns the list of processes.
+    fn list(&mut self) -> Vec<&dyn Process>;
+
+    /// Returns the number of processes in the ready queues, including the running process.
+    ///
+    /// Unlike [`Scheduler::list`], this does not allocate.
+    fn ready_len(&self) -> usize;
+
+    /// Returns the number of processes that are sleeping or waiting for an event.
+    fn waiting_len(&self) -> usize;
+
+    /// Returns the statistics collected since the scheduler was created.
+    fn statistics(&self) -> SchedulerStats {
+        SchedulerStats::default()
+    }
+
+    /// Returns the number of time units elapsed since the scheduler was created or
+    /// since the last [`Scheduler::reset`].
+    ///
+    /// This is the global clock of the simulation: the times of the
+    /// [`ProcessInfo`] structures, the deadlines and the run intervals recorded by a
+    /// [`crate::TracingScheduler`] are measured with it. The clock advances with the
+    /// time the processes ran, when they stop, and with the whole sleep time, when a
+    /// [`SchedulingDecision::Sleep`] decision is taken. On several processor cores, the
+    /// clock advances once for every batch of [`MultiScheduler::next_batch`], with the
+    /// longest time that a process of the batch ran.
+    /// The default implementation adds the execution and the idle time of the
+    /// [`Scheduler::statistics`].
+    fn current_time(&self) -> usize {
+        let stats = self.statistics();
+        stats.execution_time + stats.idle_time
+    }
+
+    /// Returns the number of ticks elapsed since the scheduler was created or since
+    /// the last [`Scheduler::reset`].
+    ///
+    /// A tick is one time unit of the global clock, so the count can be compared
+    /// with the waiting and execution ticks of [`Scheduler::process_info`]. The
+    /// default implementation returns [`Scheduler::current_time`].
+    fn tick_count(&self) -> usize {
+        self.current_time()
+    }
+
+    /// Returns information about the process with PID `pid`, or [`None`] if the
+    /// scheduler does not know about this process.
+    ///
+    /// The default implementation returns [`None`].
+    fn process_info(&self, _pid: Pid) -> Option<ProcessInfo> {
+        None
+    }
+
+    /// Returns information about every process of the scheduler, whatever its state,
+    /// in ascending order of the PIDs.
+    ///
+    /// The default implementation returns no processes.
+    fn processes(&self) -> Box<dyn Iterator<Item = ProcessInfo> + '_> {
+        Box::new(std::iter::empty())
+    }
+
+    /// Returns the number of time units until the process with PID `pid` wakes up,
+    /// or [`None`] if the process is not sleeping after a [`Syscall::Sleep`].
+    ///
+    /// The default implementation returns [`None`].
+    fn remaining_sleep(&self, _pid: Pid) -> Option<usize> {
+        None
+    }
+
+    /// Returns the result of the system call that blocked the process with PID `pid`,
+    /// if the process was woken up by a signal, or [`None`] otherwise.
+    ///
+    /// A process that sleeps after a [`Syscall::Sleep`] or waits after a
+    /// [`Syscall::Wait`] and is the target of a [`Syscall::SendSignal`] is woken up,
+    /// and its blocking system call returns [`SyscallResult::Interrupted`]. The result
+    /// is returned only once.
+    ///
+    /// The default implementation returns [`None`].
+    fn take_wakeup_result(&mut self, _pid: Pid) -> Option<SyscallResult> {
+        None
+    }
+
+    /// Returns the PID of the sleeping process that wakes up first and the time at
+    /// which it wakes up, measured with [`Scheduler::current_time`], or [`None`] if no
+    /// process is sleeping after a [`Syscall::Sleep`].
+    ///
+    /// If several processes wake up at the same time, the one with the smallest PID is
+    /// returned. After a [`SchedulingDecision::Sleep`] decision, the returned time is
+    /// the end of the sleep, if the process wakes up then. The default implementation
+    /// returns [`None`].
+    fn next_wakeup(&self) -> Option<(Pid, usize)> {
+        None
+    }
+
+    /// Returns the number of processes in each ready queue, including the running process.
+    ///
+    /// Schedulers with several ready queues return one value for every queue, ordered
+    /// from the highest priority queue to the lowest one. The default implementation
+    /// returns an empty list.
+    fn queue_depths(&self) -> Vec<usize> {
+        Vec::new()
+    }
+
+    /// Returns Jain's fairness index of the execution times of all the processes that
+    /// have ever run, both the terminated processes and the existing ones.
+    ///
+    /// The index is 1.0 if all the processes ran for the same time and approaches 1/n,
+    /// for n processes, as the processor is given to fewer processes, see
+    /// [`ExecutionHistory::fairness_index`]. The default implementation combines the
+    /// [`SchedulerStats::exited`] history with the times of [`Scheduler::processes`].
+    fn fairness_index(&self) -> f32 {
+        let mut history = self.statistics().exited;
+        for info in self.processes() {
+            history.record(info.cpu_ticks_consumed);
+        }
+        history.fairness_index()
+    }
+
+    /// Returns the turnaround time of every process that has terminated, the time
+    /// between its creation and its termination, measured with
+    /// [`Scheduler::current_time`].
+    ///
+    /// The processes that still exist are not returned. If a PID has been reused,
+    /// only the last process with that PID is returned. The default implementation
+    /// returns an empty map.
+    fn turnaround_times(&self) -> HashMap<Pid, usize> {
+        HashMap::new()
+    }
+
+    /// Returns the response time of every process that was scheduled at least once, the
+    /// time between its creation and the first time it ran, measured with
+    /// [`Scheduler::current_time`].
+    ///
+    /// The terminated processes are also returned. If a PID has been reused, only the
+    /// last process with that PID that ran is returned. The default implementation
+    /// returns an empty map.
+    fn response_times(&self) -> HashMap<Pid, usize> {
+        HashMap::new()
+    }
+
+    /// Returns the lengths of the CPU bursts of the process with PID `pid`, or [`None`]
+    /// if the scheduler does not know about this process.
+    ///
+    /// A burst ends when the process sends a system call or its timeslice expires, the
+    /// processes that are killed do not record their last burst. The default
+    /// implementation returns [`None`].
+    fn burst_histogram(&self, _pid: Pid) -> Option<BurstHistogram> {
+        None
+    }
+
+    /// Creates a process for every request, without a parent, and adds all of them to
+    /// the ready queues before the next scheduling decision, returning their PIDs.
+    ///
+    /// The PIDs are consecutive, unless PIDs of terminated processes are reused, see
+    /// [`Scheduler::set_pid_policy`]. The processes are added in the order of the
+    /// requests and no process is charged for their creation. As with a
+    /// [`Syscall::ForkMany`] system call, only as many processes as the scheduler accepts
+    /// are created. The default implementation creates no processes.
+    fn spawn_batch(&mut self, _requests: Vec<SpawnRequest>) -> Vec<Pid> {
+        Vec::new()
+    }
+
+    /// Returns the PID of every process that waits for an event, together with the
+    /// event, in ascending order of the PIDs.
+    ///
+    /// Sleeping processes are not returned. Knowing which processes signal which
+    /// events, the result shows why a simulation is blocked. The default implementation
+    /// uses the states returned by [`Scheduler::processes`].
+    fn wait_graph(&self) -> Vec<(Pid, usize)> {
+        self.processes()
+            .filter_map(|info| match info.state {
+                ProcessState::Waiting { event: Some(event) } => Some((info.pid, event)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Registers a function that is called for every [`SchedulerEvent`].
+    ///
+    /// A scheduler has no observer when it is created, in which case it does not
+    /// generate any events. The default implementation ignores the observer.
+    fn set_observer(&mut self, _observer: Box<dyn FnMut(SchedulerEvent) + Send>) {}
+
+    /// Drives the scheduler and returns the decisions it made, until `stop` returns
+    /// `true` for a decision or the scheduler can not continue.
+    ///
+    /// Every scheduled process runs for its whole timeslice, as if it was stopped with
+    /// [`StopReason::expired`]. Sleep decisions are followed by another call to
+    /// [`Scheduler::next`], which advances the time. The run ends after a
+    /// [`SchedulingDecision::Done`], [`SchedulingDecision::Deadlock`] or
+    /// [`SchedulingDecision::Panic`] decision, or after a [`SchedulingDecision::Run`]
+    /// decision with a timeslice of [`NonZeroUsize::MAX`], which never expires. The
+    /// decision for which `stop` returns `true` is included in the result and, if it
+    /// is a [`SchedulingDecision::Run`] decision, the process is left running.
+    fn run_until<F: FnMut(&SchedulingDecision) -> bool>(
+        &mut self,
+        mut stop: F,
+    ) -> Vec<SchedulingDecision>
+    where
+        Self: Sized,
+    {
+        let mut decisions = Vec::new();
+        loop {
+            let decision = self.next();
+            // un proces planificat fara limita de timp nu este oprit niciodata cu Expired
+            let unbounded = matches!(
+                decision,
+                SchedulingDecision::Run { timeslice, .. } if timeslice == NonZeroUsize::MAX
+            );
+            let finished = stop(&decision)
+                || unbounded
+                || matches!(
+                    decision,
+                    SchedulingDecision::Done
+                        | SchedulingDecision::Deadlock(_)
+                        | SchedulingDecision::Panic
+                );
+            let running = matches!(decision, SchedulingDecision::Run { .. });
+            decisions.push(decision);
+            if finished {
+                return decisions;
+            }
+            if running {
+                // procesul planificat ruleaza pana la expirarea timeslice-ului
+                let _ = self.stop(StopReason::expired());
+            }
+        }
+    }
+}
+
+/// The trait of the schedulers that run processes on several processor cores at once.
+///
+/// The decisions are taken in batches: a batch holds one decision for every idle core
+/// that has a ready process, and the processes of a batch run in parallel, starting at
+/// the same time. The clock of the scheduler advances once per batch, after all the
+/// processes of the batch have stopped, by the longest time that one of them ran. The
+/// cores that ran less, or had no process to run, are idle for the rest of the batch
+/// and their time is added to [`SchedulerStats::idle_time`].
+///
+/// A [`MultiScheduler`] is also a [`Scheduler`]: [`Scheduler::next`] returns the
+/// decisions of a batch one by one, and [`Scheduler::stop`] stops the process of the
+/// last decision returned, so that the same runners and wrappers can drive it.
+pub trait MultiScheduler: Scheduler {
+    /// Returns the number of processor cores.
+    fn cores(&self) -> usize;
+
+    /// Returns the decisions of the next batch, in ascending order of the cores.
+    ///
+    /// Every core that has a ready process receives a [`SchedulingDecision::Run`]
+    /// decision, which tells its core. If no process can run, the batch holds a single
+    /// [`SchedulingDecision::Sleep`], [`SchedulingDecision::Deadlock`],
+    /// [`SchedulingDecision::Panic`] or [`SchedulingDecision::Done`] decision. A
+    /// [`SchedulingDecision::DeliverSignal`] decision is returned alone, before the
+    /// next batch is scheduled.
+    ///
+    /// If some processes of the current batch have not been stopped yet, their
+    /// decisions are returned again.
+    fn next_batch(&mut self) -> Vec<SchedulingDecision>;
+
+    /// Stops the process that runs on core `core`, with the same results as
+    /// [`Scheduler::stop`].
+    fn stop_core(&mut self, core: usize, reason: StopReason)
+        -> Result<SyscallResult, SchedulerError>;
+}
+
+impl<S: Scheduler + ?Sized> Scheduler for Box<S> {
+    fn next(&mut self) -> SchedulingDecision {
+        (**self).next()
+    }
+
+    fn peek(&self) -> SchedulingDecision {
+        (**self).peek()
+    }
+
+    fn stop(&mut self, reason: StopReason) -> Result<SyscallResult, SchedulerError> {
+        (**self).stop(reason)
+    }
+
+    fn drain(&mut self) {
+        (**self).drain()
+    }
+
+    fn kill(&mut self, pid: Pid) -> bool {
+        (**self).kill(pid)
+    }
+
+    fn process_count(&self) -> usize {
+        (**self).process_count()
+    }
+
+    fn set_max_processes(&mut self, max_processes: Option<NonZeroUsize>) {
+        (**self).set_max_processes(max_processes)
+    }
+
+    fn set_priority_range(&mut self, priority_range: RangeInclusive<i8>) {
+        (**self).set_priority_range(priority_range)
+    }
+
+    fn set_pid_policy(&mut self, pid_policy: PidPolicy) {
+        (**self).set_pid_policy(pid_policy)
+    }
+
+    fn set_orphan_policy(&mut self, orphan_policy: OrphanPolicy) {
+        (**self).set_orphan_policy(orphan_policy)
+    }
+
+    fn reset(&mut self) {
+        (**self).reset()
+    }
+
+    fn snapshot(&self) -> Option<SchedulerSnapshot> {
+        (**self).snapshot()
+    }
+
+    fn list(&mut self) -> Vec<&dyn Process> {
+        (**self).list()
+    }
+
+    fn ready_len(&self) -> usize {
+        (**self).ready_len()
+    }
+
+    fn waiting_len(&self) -> usize {
+        (**self).waiting_len()
+    }
+
+    fn statistics(&self) -> SchedulerStats {
+        (**self).statistics()
+    }
+
+    fn current_time(&self) -> usize {
+        (**self).current_time()
+    }
+
+    fn tick_count(&self) -> usize {
+        (**self).tick_count()
+    }
+
+    fn process_info(&self, pid: Pid) -> Option<ProcessInfo> {
+        (**self).process_info(pid)
+    }
+
+    fn processes(&self) -> Box<dyn Iterator<Item = ProcessInfo> + '_> {
+        (**self).processes()
+    }
+
+    fn remaining_sleep(&self, pid: Pid) -> Option<usize> {
+        (**self).remaining_sleep(pid)
+    }
+
+    fn take_wakeup_result(&mut self, pid: Pid) -> Option<SyscallResult> {
+        (**self).take_wakeup_result(pid)
+    }
+
+    fn next_wakeup(&self) -> Option<(Pid, usize)> {
+        (**self).next_wakeup()
+    }
+
+    fn queue_depths(&self) -> Vec<usize> {
+        (**self).queue_depths()
+    }
+
+    fn fairness_index(&self) -> f32 {
+        (**self).fairness_index()
+    }
+
+    fn turnaround_times(&self) -> HashMap<Pid, usize> {
+        (**self).turnaround_times()
+    }
+
+    fn response_times(&self) -> HashMap<Pid, usize> {
+        (**self).response_times()
+    }
+
+    fn burst_histogram(&self, pid: Pid) -> Option<BurstHistogram> {
+        (**self).burst_histogram(pid)
+    }
+
+    fn spawn_batch(&mut self, requests: Vec<SpawnRequest>) -> Vec<Pid> {
+        (**self).spawn_batch(requests)
+    }
+
+    fn wait_graph(&self) -> Vec<(Pid, usize)> {
+        (**self).wait_graph()
+    }
+
+    fn set_observer(&mut self, observer: Box<dyn FnMut(SchedulerEvent) + Send>) {
+        (**self).set_observer(observer)
+    }
+}
+
+/// The state of a process.
+/// The order in which a scheduler picks between ready processes that are
+/// otherwise equally eligible to run.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TieBreak {
+    /// The process that has been in the ready queue the longest is picked first.
+    Fifo,
+
+    /// The process with the lowest PID is picked first.
+    LowestPid,
+
+    /// The process with the highest PID is picked first.
+    HighestPid,
+}
+
+/// What a scheduler does with the children of a process that exits or is killed,
+/// see [`Scheduler::set_orphan_policy`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OrphanPolicy {
+    /// The children are adopted by the process with PID 1.
+    #[default]
+    Reparent,
+
+    /// The children, and their own children, are terminated together with the parent.
+    Cascade,
+}
+
+/// How a scheduler allocates the PIDs of new processes, see
+/// [`Scheduler::set_pid_policy`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PidPolicy {
+    /// PIDs are allocated in increasing order and are never reused.
+    #[default]
+    Monotonic,
+
+    /// The PIDs of the processes that were reaped are reused, the lowest first.
+    Recycle {
+        /// The number of time units after which a released PID can be reused.
+        delay: usize,
+    },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProcessState {
+    /// The process is ready to be scheduled.
+    Ready,
+
+    /// The process is currently scheduled.
+    Running,
+
+    /// The process is waiting for an event or sleeping.
+    Waiting {
+        /// The event that the process is waiting for.
+        ///
+        /// If the event is [`None`], the process is sleeping.
+        event: Option<usize>,
+    },
+
+    /// The process was suspended by a [`Syscall::SuspendGroup`] or a
+    /// [`Syscall::Suspend`] system call and waits for a [`Syscall::ResumeGroup`] or a
+    /// [`Syscall::Resume`] system call.
+    Suspended,
+
+    /// The process waits for an I/O operation, see [`Syscall::IoWait`].
+    IoWait {
+        /// The device that performs the operation.
+        device_id: u32,
+    },
+
+    /// The process waits for a semaphore, see [`Syscall::SemWait`].
+    SemaphoreWait {
+        /// The semaphore ID.
+        semaphore: u32,
+    },
+
+    /// The process waits for a mutex, see [`Syscall::MutexLock`].
+    MutexWait {
+        /// The mutex ID.
+        mutex: u32,
+    },
+
+    /// The process has used up its CPU quota in the current accounting period and
+    /// waits for the next period, see [`Syscall::SetCpuQuota`].
+    Throttled,
+
+    /// The process waits for a child to exit, see [`Syscall::WaitChild`].
+    WaitingForChild {
+        /// The PID of the child, or [`None`] if any child is waited for.
+        pid: Option<Pid>,
+    },
+
+    /// The process has exited, but its parent has not collected the exit code yet.
+    ///
+    /// Zombies are not ready or waiting processes, they are only reported by
+    /// [`Scheduler::process_info`] until the parent issues a [`Syscall::WaitChild`]
+    /// or exits.
+    Zombie,
+}
+
+impl Display for ProcessState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProcessState::Ready => write!(f, "READY"),
+            ProcessState::Running => write!(f, "RUNNING"),
+            ProcessState::Waiting { event } => {
+                if let Some(event) = event {
+                    write!(f, "EVENT {}", event)
+                } else {
+                    write!(f, "SLEEP")
+                }
+            }
+            ProcessState::Suspended => write!(f, "SUSPENDED"),
+            ProcessState::IoWait { device_id } => write!(f, "IO {}", device_id),
+            ProcessState::SemaphoreWait { semaphore } => write!(f, "SEM {}", semaphore),
+            ProcessState::MutexWait { mutex } => write!(f, "MUTEX {}", mutex),
+            ProcessState::Throttled => write!(f, "THROTTLED"),
+            ProcessState::WaitingForChild { pid: Some(pid) } => write!(f, "CHILD {}", pid),
+            ProcessState::WaitingForChild { pid: None } => write!(f, "CHILD"),
+            ProcessState::Zombie => write!(f, "ZOMBIE"),
+        }
+    }
+}
+
+/// The trait that the Process Control Block (PCB) has to implement.
 ///
 /// The PCB can be implemented with any data structure as long as
 /// it implements this trait.
@@ -263,4 +1779,112 @@ pub trait Process: Send {
 
     /// Returns details information
     fn extra(&self) -> String;
+
+    /// Returns the time the process has spent in the ready queue since it
+    /// was last scheduled
+    ///
+    /// Schedulers that do not keep track of this value return 0.
+    fn wait_time(&self) -> usize {
+        0
+    }
+
+    /// Returns the PID of the leader of the process group that the process is
+    /// a member of, see [`Syscall::SetProcessGroup`]
+    ///
+    /// Schedulers that do not support process groups return [`None`].
+    fn pgid(&self) -> Option<Pid> {
+        None
+    }
+
+    /// Returns the PID of the process that created this process
+    ///
+    /// When a process exits, its children are adopted by the process with
+    /// PID 1. The first process has no parent. Schedulers that do not keep
+    /// track of the parents return [`None`].
+    fn parent(&self) -> Option<Pid> {
+        None
+    }
+
+    /// Returns the name of the process, set with [`Syscall::SetName`]
+    ///
+    /// Processes have no name when they are created. Schedulers that do not
+    /// keep track of the names return [`None`].
+    fn name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the CPU affinity mask of the process, set with [`Syscall::SetAffinity`]
+    ///
+    /// Bit `i` of the mask allows the process to run on core `i`. Processes are
+    /// created with all the bits set, and single core schedulers ignore the mask.
+    fn cpu_affinity(&self) -> u64 {
+        !0
+    }
+
+    /// Returns the nice value of the process, set with [`Syscall::Renice`]
+    ///
+    /// The value is between -20 and 19, a lower value giving the process a larger
+    /// share of the processor. Processes are created with the nice value 0.
+    fn nice(&self) -> i8 {
+        0
+    }
+
+    /// Returns the fraction of the processor time that the process can use in every
+    /// accounting period, set with [`Syscall::SetCpuQuota`]
+    ///
+    /// Processes are created without a quota.
+    fn cpu_quota(&self) -> Option<f64> {
+        None
+    }
+
+    /// Returns the resource counter of the process, set with [`Syscall::SetResources`]
+    ///
+    /// The counter is kept while the process changes its state. Processes, including
+    /// the ones created by a fork, start with 0.
+    fn resources(&self) -> usize {
+        0
+    }
+
+    /// Returns the number of times the process gave up the processor with a
+    /// system call
+    ///
+    /// Schedulers that do not keep track of this value return 0.
+    fn voluntary_switches(&self) -> usize {
+        0
+    }
+
+    /// Returns the number of times the process was stopped because its timeslice
+    /// expired
+    ///
+    /// Schedulers that do not keep track of this value return 0.
+    fn involuntary_switches(&self) -> usize {
+        0
+    }
+
+    /// Returns the time at which the process was created, measured with
+    /// [`Scheduler::current_time`], at the end of the [`Syscall::Fork`] system call.
+    ///
+    /// Schedulers that do not keep track of this value return 0.
+    fn arrival_tick(&self) -> usize {
+        0
+    }
+
+    /// Returns the time at which the process was scheduled for the first time, measured
+    /// with [`Scheduler::current_time`], or [`None`] if the process has not run yet.
+    ///
+    /// Schedulers that do not keep track of this value return [`None`].
+    fn first_run_tick(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the percentage of the total time of the process that it spent running
+    ///
+    /// A process that has no total time yet returns 0.
+    fn cpu_percentage(&self) -> f32 {
+        let (total, _, execution) = self.timings();
+        if total == 0 {
+            return 0.0;
+        }
+        execution as f32 * 100.0 / total as f32
+    }
 }
@@ -1,29 +1,98 @@
 use crate::scheduler::{Pid, Process};
-use crate::schedulers::ProcessControlBlock;
-use crate::{ProcessState, Scheduler, Syscall};
+use crate::schedulers::{
+    adopt_orphans, break_tie, check_signal_target, find_burst_histogram, find_next_wakeup,
+    find_process_info, find_remaining_sleep, fork_priority, record_burst, record_first_run,
+    resolve_syscall, set_affinity, sorted_pids, spawn_processes, take_pending_signal,
+    take_processes, take_wakeup_result, turnaround_times, ProcessControlBlock, TimingWheel, Zombies,
+    QUOTA_PERIOD, SINGLE_CORE,
+};
+use crate::{BurstHistogram, ProcessState, Scheduler, SpawnRequest, Syscall, TieBreak, NICE_RANGE};
 use std::cmp::Ordering;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
 
+use crate::schedulers::{Mutexes, Observer, PidAllocator, SchedulerSnapshot, Semaphores};
+use crate::OrphanPolicy;
+use crate::PidPolicy;
+use crate::ProcessInfo;
+use crate::SchedulerError;
+use crate::SchedulerEvent;
+use crate::SchedulerStats;
 use crate::SchedulingDecision;
 use crate::StopReason;
 use crate::SyscallResult;
+use crate::SIGKILL;
 
+/// A scheduler with a completely fair policy, returned by [`crate::cfs`].
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone)]
 pub struct CFS {
     pub ready_processes: VecDeque<ProcessControlBlock>,
     pub waiting_processes: VecDeque<ProcessControlBlock>,
     pub cpu_time: NonZeroUsize,
     pub minimum_remaining_timeslice: usize,
     pub remaining_timeslice: usize,
-    pub next_pid: usize,
+    pub pids: PidAllocator,
     pub sleep: bool,
     pub sleep_time: usize,
+    pub stats: SchedulerStats,
+    pub completed: HashMap<Pid, (usize, usize)>,
+    pub responses: HashMap<Pid, usize>,
+    pub tie_break: TieBreak,
+    pub observer: Observer,
+    pub draining: bool,
+    pub max_processes: Option<NonZeroUsize>,
+    pub priority_range: RangeInclusive<i8>,
+    pub semaphores: Semaphores,
+    pub mutexes: Mutexes,
+    pub zombies: Zombies,
+    pub orphan_policy: OrphanPolicy,
+}
+
+// greutatea unui proces cu prioritatea 0, fata de care este ponderat timpul de executie
+const DEFAULT_WEIGHT: usize = 1024;
+
+// virtual runtime-ul este retinut in unitati de 1 / VRUNTIME_SCALE din unitatea de timp
+const VRUNTIME_SCALE: usize = 1024;
+
+// avantajul primit de un proces trezit fata de virtual runtime-ul minim
+const WAKEUP_CREDIT: usize = VRUNTIME_SCALE;
+
+// tabelul prio_to_weight din planificatorul CFS din Linux, pentru valorile nice de la -20
+// la 19; o unitate de nice schimba timpul de procesor primit cu aproximativ 10%
+const PRIORITY_WEIGHTS: [usize; 40] = [
+    88761, 71755, 56483, 46273, 36291, 29154, 23254, 18705, 14949, 11916, 9548, 7620, 6100, 4904,
+    3906, 3121, 2501, 1991, 1586, 1277, 1024, 820, 655, 526, 423, 335, 272, 215, 172, 137, 110,
+    87, 70, 56, 45, 36, 29, 23, 18, 15,
+];
+
+// greutatea unui proces, care scade odata cu valoarea nice si creste odata cu prioritatea
+// acestuia; prioritatea p scade valoarea nice cu p
+fn weight(process: &ProcessControlBlock) -> usize {
+    let nice = (process.nice as isize - process.priority as isize).clamp(-20, 19);
+    PRIORITY_WEIGHTS[(nice + 20) as usize]
+}
+
+fn set_vruntime(process: &mut ProcessControlBlock, vruntime: usize) {
+    process.extra = vruntime;
+    process.vruntime = String::from("vruntime=") + &(vruntime / VRUNTIME_SCALE).to_string();
+}
+
+// functie care adauga la virtual runtime-ul procesului timpul de executie, ponderat cu
+// raportul dintre greutatea implicita si greutatea procesului
+fn charge_vruntime(process: &mut ProcessControlBlock, execution_time: usize) {
+    let delta = execution_time * VRUNTIME_SCALE * DEFAULT_WEIGHT / weight(process);
+    set_vruntime(process, process.extra + delta);
 }
 
 impl CFS {
     fn actualise_timings(&mut self, timeslice: usize) {
         for process in &mut self.ready_processes {
             process.total += timeslice;
+            if process.state == ProcessState::Ready {
+                self.stats.waiting_time += timeslice;
+            }
         }
         for process in &mut self.waiting_processes {
             process.total += timeslice;
@@ -44,38 +113,311 @@ impl CFS {
     }
 
     fn update_sleeping_processes(&mut self) {
-        self.waiting_processes
-            .retain(|process| match process.state {
-                ProcessState::Ready => {
-                    self.ready_processes.push_back(process.clone());
-                    false
-                }
-                _ => true,
-            })
+        let woken = take_processes(&mut self.waiting_processes, |process| {
+            process.state == ProcessState::Ready
+        });
+        for process in woken {
+            self.push_ready(process);
+        }
     }
 
     fn update_waiting_processes(&mut self, signal_code: usize) {
-        self.waiting_processes
-            .retain(|process| match process.state {
-                ProcessState::Waiting { event } => match event {
-                    Some(signal) => {
-                        if signal == signal_code {
-                            let mut copy = process.clone();
-                            copy.state = ProcessState::Ready;
-                            self.ready_processes.push_back(copy);
-                            false
-                        } else {
-                            true
+        let woken = take_processes(&mut self.waiting_processes, |process| {
+            process.state
+                == ProcessState::Waiting {
+                    event: Some(signal_code),
+                }
+        });
+        for process in woken {
+            self.push_ready(process);
+        }
+    }
+
+    // functie care aplica apelul de sistem primit asupra altor procese decat procesul curent
+    fn update_processes(&mut self, syscall: Syscall) -> SyscallResult {
+        match syscall {
+            Syscall::KillGroup(pgid) => {
+                self.kill_processes(|process| process.in_group(pgid));
+                SyscallResult::Success
+            }
+            Syscall::SuspendGroup(pgid) => {
+                let suspended = take_processes(&mut self.ready_processes, |process| {
+                    process.in_group(pgid)
+                });
+                for mut process in suspended {
+                    process.state = ProcessState::Suspended;
+                    self.waiting_processes.push_back(process);
+                }
+                SyscallResult::Success
+            }
+            Syscall::ResumeGroup(pgid) => {
+                let resumed = take_processes(&mut self.waiting_processes, |process| {
+                    process.in_group(pgid) && process.state == ProcessState::Suspended
+                });
+                for process in resumed {
+                    self.push_ready(process);
+                }
+                SyscallResult::Success
+            }
+            // procesul primit este suspendat daca este ready sau doarme
+            Syscall::Suspend(target) => {
+                let now = self.current_time();
+                let suspended = take_processes(&mut self.ready_processes, |process| {
+                    process.pid() == target
+                });
+                self.waiting_processes.extend(suspended);
+                for process in &mut self.waiting_processes {
+                    if process.pid() == target {
+                        process.suspend(now);
+                    }
+                }
+                SyscallResult::Success
+            }
+            Syscall::Resume(target) => {
+                let now = self.current_time();
+                for process in &mut self.waiting_processes {
+                    if process.pid() == target {
+                        process.resume(now);
+                    }
+                }
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            Syscall::SetAffinity(mask) => {
+                set_affinity(self.ready_processes.front_mut(), mask, SINGLE_CORE)
+            }
+            // procesul curent este mutat in coada de waiting daca a fost blocat de un
+            // semafor, de un mutex sau de asteptarea unui proces copil
+            Syscall::SemWait(_)
+            | Syscall::MutexLock(_)
+            | Syscall::WaitChild
+            | Syscall::WaitPid(_) => {
+                let blocked = take_processes(&mut self.ready_processes, |process| {
+                    process.was_blocked()
+                });
+                for process in blocked {
+                    self.observer.emit(process.blocked_event());
+                    self.waiting_processes.push_back(process);
+                }
+                SyscallResult::Success
+            }
+            // primul proces care asteapta semaforul devine ready
+            Syscall::SemPost(semaphore) => {
+                if let Some(pid) = self.semaphores.post(semaphore) {
+                    for process in &mut self.waiting_processes {
+                        if process.pid() == pid {
+                            process.state = ProcessState::Ready;
                         }
                     }
-                    None => true,
-                },
-                _ => true,
-            })
+                    self.update_sleeping_processes();
+                }
+                SyscallResult::Success
+            }
+            // procesul care a primit mutex-ul eliberat devine ready
+            Syscall::MutexUnlock(_) => {
+                self.mutexes.wake_holders(&mut self.waiting_processes);
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            // procesul curent cedeaza procesorul si este readaugat in coada de ready
+            Syscall::Yield => {
+                let yielded = take_processes(&mut self.ready_processes, |process| {
+                    process.state == ProcessState::Running
+                });
+                for process in yielded {
+                    self.stats.yields += 1;
+                    self.push_ready(process);
+                }
+                SyscallResult::Success
+            }
+            Syscall::SendSignal { target, signum } => self.send_signal(target, signum),
+            // procesele noi trebuie sa aiba o prioritate din intervalul planificatorului
+            Syscall::Fork(priority) | Syscall::ForkWithDeadline { priority, .. }
+                if !self.priority_range.contains(&priority) =>
+            {
+                SyscallResult::InvalidArgument
+            }
+            Syscall::ForkMany(0) => SyscallResult::InvalidArgument,
+            // valoarea nice trebuie sa fie din intervalul NICE_RANGE
+            Syscall::Renice(nice) if !NICE_RANGE.contains(&nice) => SyscallResult::InvalidArgument,
+            // dupa drain sau la atingerea numarului maxim de procese nu mai sunt create procese noi
+            Syscall::Fork(_) | Syscall::ForkWithDeadline { .. } | Syscall::ForkMany(_) => {
+                if self.draining {
+                    SyscallResult::Error
+                } else {
+                    SyscallResult::NoMemory
+                }
+            }
+            _ => SyscallResult::Success,
+        }
+    }
+
+    // functie care verifica daca pot fi create procese noi
+    fn accepts_processes(&self) -> bool {
+        !self.draining
+            && self
+                .max_processes
+                .is_none_or(|max| self.process_count() < max.get())
+            && self.pids.can_allocate(self.current_time())
+    }
+
+    // functie care creeaza `count` procese noi, cu prioritatea primita; timpul procesului
+    // parinte este contabilizat o singura data, ca pentru un singur apel fork
+    fn fork(&mut self, priority: Option<i8>, count: usize, remaining: usize) -> Vec<Pid> {
+        let parent = self.ready_processes.front().map(|process| Pid::new(process.pid));
+        // procesele create de un apel fork multiplu au prioritatea procesului parinte
+        let priority = priority
+            .unwrap_or_else(|| fork_priority(self.all_processes(), parent, &self.priority_range));
+        // procesele sunt create in ordinea pid-urilor, la acelasi moment
+        let now = self.current_time();
+        let mut children = Vec::new();
+        for _ in 0..count {
+            let pid = self.pids.allocate(now).unwrap();
+            let mut new_process = ProcessControlBlock::new(pid, priority);
+            new_process.pgid = self
+                .ready_processes
+                .front()
+                .and_then(|process| process.pgid);
+            new_process.parent = parent;
+
+            self.stats.processes_spawned += 1;
+            children.push(new_process);
+        }
+        let pids: Vec<Pid> = children.iter().map(|process| process.pid()).collect();
+
+        if self.ready_processes.is_empty() {
+            for mut new_process in children {
+                set_vruntime(&mut new_process, 0);
+                new_process.arrival_tick = self.current_time();
+                self.ready_processes.push_back(new_process);
+            }
+            self.remaining_timeslice = remaining;
+        } else {
+            let mut process = self.ready_processes.pop_front().unwrap();
+            let execution_time = self.remaining_timeslice - remaining - 1;
+
+            process.syscall += 1;
+            process.voluntary_switches += 1;
+            process.execute += execution_time;
+            self.stats.execution_time += execution_time + 1;
+            charge_vruntime(&mut process, 1 + execution_time);
+
+            self.ready_processes.push_front(process);
+            self.remaining_timeslice = remaining;
+            self.actualise_timings(execution_time + 1);
+            for mut new_process in children {
+                set_vruntime(&mut new_process, self.get_minimum_vruntime());
+                new_process.arrival_tick = self.current_time();
+                self.ready_processes.push_back(new_process);
+            }
+        }
+        for &child in &pids {
+            self.observer.emit(SchedulerEvent::Forked { parent, child });
+        }
+        pids
+    }
+
+    // functie care termina procesele care respecta conditia primita
+    fn kill_processes(&mut self, condition: impl Fn(&ProcessControlBlock) -> bool) -> usize {
+        let mut killed = take_processes(&mut self.ready_processes, &condition);
+        killed.extend(take_processes(&mut self.waiting_processes, &condition));
+        let count = killed.len();
+        for process in killed {
+            self.observer.emit(SchedulerEvent::Exited {
+                pid: Pid::new(process.pid),
+            });
+            self.adopt_orphans(process.pid);
+            let now = self.current_time();
+            let processes = self
+                .ready_processes
+                .iter_mut()
+                .chain(self.waiting_processes.iter_mut());
+            self.zombies
+                .exit(&process, -1, processes, &mut self.pids, now);
+            self.semaphores.remove(Pid::new(process.pid));
+            self.mutexes.remove(Pid::new(process.pid));
+            self.stats.record_exit(process.execute + process.syscall);
+            let completion = (process.arrival_tick, self.current_time());
+            self.completed.insert(Pid::new(process.pid), completion);
+        }
+
+        // procesele care au primit mutex-urile proceselor terminate devin ready
+        self.mutexes.wake_holders(&mut self.waiting_processes);
+        self.update_sleeping_processes();
+        count
     }
 
+    // functie care returneaza toate procesele din scheduler, din ambele cozi
+    fn all_processes(&self) -> impl Iterator<Item = &ProcessControlBlock> {
+        self.ready_processes.iter().chain(self.waiting_processes.iter())
+    }
+
+    // functie care adauga in coada de ready un proces scos din coada de waiting
+    fn push_ready(&mut self, mut process: ProcessControlBlock) {
+        process.state = ProcessState::Ready;
+        self.place_woken_process(&mut process);
+        self.ready_processes.push_back(process);
+    }
+
+    // functie care trimite semnalul primit procesului cu pid-ul target
+    fn send_signal(&mut self, target: Pid, signum: u8) -> SyscallResult {
+        if signum == SIGKILL {
+            if self.kill_processes(|process| process.pid() == target) > 0 {
+                self.stats.record_kill(target, signum);
+            }
+            return SyscallResult::Success;
+        }
+
+        // un proces care doarme sau asteapta un eveniment este trezit imediat
+        let interrupted = take_processes(&mut self.waiting_processes, |process| {
+            process.pid() == target && matches!(process.state, ProcessState::Waiting { .. })
+        });
+        // apelul de sistem care a blocat procesul trezit intoarce Interrupted, rezultat
+        // preluat de sistemul de operare cu take_wakeup_result
+        for mut process in interrupted {
+            process.sleep = 0;
+            process.wakeup_result = Some(SyscallResult::Interrupted);
+            self.push_ready(process);
+        }
+
+        // semnalul este raportat la urmatoarea decizie a planificatorului
+        let mut processes = self
+            .ready_processes
+            .iter_mut()
+            .chain(self.waiting_processes.iter_mut());
+        if let Some(process) = processes.find(|process| process.pid() == target) {
+            process.pending_signal = Some(signum);
+        }
+        SyscallResult::Success
+    }
+
+    // functie care returneaza semnalul in asteptare al procesului cu cel mai mic pid
+    fn take_pending_signal(&mut self) -> Option<(Pid, u8)> {
+        take_pending_signal(
+            self.ready_processes
+                .iter_mut()
+                .chain(self.waiting_processes.iter_mut()),
+        )
+    }
+
+    // functie care trateaza procesele copil ale procesului terminat: acestea sunt mutate
+    // sub procesul cu pid-ul 1 sau sunt terminate impreuna cu parintele, dupa orphan_policy
+    fn adopt_orphans(&mut self, parent: usize) {
+        match self.orphan_policy {
+            OrphanPolicy::Reparent => adopt_orphans(
+                self.ready_processes.iter_mut().chain(self.waiting_processes.iter_mut()),
+                parent,
+            ),
+            OrphanPolicy::Cascade => {
+                self.kill_processes(|process| process.parent == Some(Pid::new(parent)));
+            }
+        }
+    }
+
+    // functie care verifica daca un proces se replanifica in continuare; procesul care
+    // si-a consumat tot timpul nu se replanifica, chiar daca minimul este 0
     fn check_if_reschedule(&mut self) -> bool {
-        self.remaining_timeslice >= self.minimum_remaining_timeslice
+        self.remaining_timeslice > 0 && self.remaining_timeslice >= self.minimum_remaining_timeslice
     }
 
     fn get_first_element(&mut self) -> usize {
@@ -104,10 +446,7 @@ impl CFS {
         let first_element = self.ready_processes.pop_front().unwrap();
         let copy_first_element = first_element.clone();
         self.ready_processes.push_front(first_element);
-        match copy_first_element.state {
-            ProcessState::Running => true,
-            _ => false,
-        }
+        matches!(copy_first_element.state, ProcessState::Running)
     }
 
     fn check_if_all_processes_await_signal(&mut self) -> bool {
@@ -117,10 +456,11 @@ impl CFS {
         let mut flag = true;
         for process in &mut self.waiting_processes {
             match process.state {
-                ProcessState::Waiting { event } => match event {
-                    Some(_) => (),
-                    None => flag = false,
-                },
+                ProcessState::Waiting { event: Some(_) }
+                | ProcessState::Suspended
+                | ProcessState::SemaphoreWait { .. }
+                | ProcessState::MutexWait { .. }
+                | ProcessState::WaitingForChild { .. } => (),
                 _ => flag = false,
             }
         }
@@ -132,46 +472,85 @@ impl CFS {
             return (false, 0);
         }
         let mut flag = true;
-        let mut minimum_sleep_time = 10000_usize;
+        let mut minimum_sleep_time: Option<usize> = None;
         for process in &mut self.waiting_processes {
             match process.state {
                 ProcessState::Waiting { event } => match event {
                     Some(_) => (),
                     None => {
-                        if minimum_sleep_time > process.sleep {
-                            minimum_sleep_time = process.sleep;
-                        }
+                        minimum_sleep_time = Some(match minimum_sleep_time {
+                            Some(time) => time.min(process.sleep),
+                            None => process.sleep,
+                        });
                     }
                 },
+                ProcessState::IoWait { .. } => {
+                    minimum_sleep_time = Some(match minimum_sleep_time {
+                        Some(time) => time.min(process.io_expected),
+                        None => process.io_expected,
+                    });
+                }
+                ProcessState::Suspended => (),
                 _ => flag = false,
             }
         }
-        (flag, minimum_sleep_time)
+
+        // procesorul doarme pana la prima trezire a unui proces
+        match minimum_sleep_time {
+            Some(time) if flag && time > 0 => (true, time),
+            _ => (false, 0),
+        }
     }
 
     fn get_minimum_vruntime(&mut self) -> usize {
-        if self.ready_processes.is_empty() && self.waiting_processes.is_empty() {
-            return 0;
-        }
-        let mut minimum_vrutime: usize = 100000;
-        for process in &mut self.ready_processes {
-            if process.extra < minimum_vrutime {
-                minimum_vrutime = process.extra;
-            }
-        }
+        self.ready_processes
+            .iter()
+            .chain(self.waiting_processes.iter())
+            .map(|process| process.extra)
+            .min()
+            .unwrap_or(0)
+    }
 
-        for process in &mut self.waiting_processes {
-            if process.extra < minimum_vrutime {
-                minimum_vrutime = process.extra;
-            }
+    // un proces trezit primeste cel putin virtual runtime-ul minim al proceselor ready, mai
+    // putin WAKEUP_CREDIT, ca sa nu monopolizeze procesorul dupa o perioada lunga de asteptare
+    fn place_woken_process(&self, process: &mut ProcessControlBlock) {
+        let minimum = self
+            .ready_processes
+            .iter()
+            .map(|process| process.extra)
+            .min();
+        if let Some(minimum) = minimum {
+            let vruntime = process.extra.max(minimum.saturating_sub(WAKEUP_CREDIT));
+            set_vruntime(process, vruntime);
         }
+    }
 
-        minimum_vrutime
+    // functie care calculeaza timeslice-ul procesului de la inceputul cozii de ready, ca
+    // fractiune din cpu_time egala cu raportul dintre greutatea sa si greutatea totala
+    fn weighted_timeslice(&self) -> usize {
+        let total_weight: usize = self.ready_processes.iter().map(weight).sum();
+        let process_weight = self.ready_processes.front().map_or(0, weight);
+        self.cpu_time.get() * process_weight / total_weight
     }
-}
 
-impl Scheduler for CFS {
-    fn next(&mut self) -> SchedulingDecision {
+    // functie care pastreaza diferenta ponderata dintre virtual runtime-ul procesului si
+    // virtual runtime-ul minim la schimbarea greutatii, ca procesul sa nu primeasca
+    // dintr-o data mai mult timp de rulare decat i se cuvine
+    fn reweight(&self, process: &mut ProcessControlBlock, old_weight: usize) {
+        let Some(minimum) = self.ready_processes.iter().map(|process| process.extra).min() else {
+            return;
+        };
+        let new_weight = weight(process);
+        let vruntime = if process.extra > minimum {
+            minimum + (process.extra - minimum) * old_weight / new_weight
+        } else {
+            minimum.saturating_sub((minimum - process.extra) * old_weight / new_weight)
+        };
+        set_vruntime(process, vruntime);
+    }
+
+    // functie care alege urmatoarea decizie a planificatorului
+    fn schedule(&mut self) -> SchedulingDecision {
         if self.sleep {
             self.sleep = false;
             self.actualise_timings(self.sleep_time);
@@ -182,8 +561,7 @@ impl Scheduler for CFS {
             if self.check_if_reschedule() {
                 // se calculeaza daca durata timeslice-ului procesului curent
                 // trebuie scazuta -> s-au mai adaugat procese in coada de ready
-                let new_remaining_timeslice =
-                    self.cpu_time.get() / self.ready_processes.len();
+                let new_remaining_timeslice = self.weighted_timeslice();
                 if new_remaining_timeslice != 0
                     && new_remaining_timeslice < self.remaining_timeslice
                 {
@@ -194,29 +572,37 @@ impl Scheduler for CFS {
                 SchedulingDecision::Run {
                     pid: Pid::new(self.get_first_element()),
                     timeslice: NonZeroUsize::new(self.remaining_timeslice).unwrap(),
+                    core: 0,
                 }
             } else {
                 let mut current_element = self.ready_processes.pop_front().unwrap();
                 current_element.state = ProcessState::Ready;
+                self.observer.emit(SchedulerEvent::Preempted {
+                    pid: Pid::new(current_element.pid),
+                });
                 self.ready_processes.push_back(current_element);
-                // se calculeaza time slice-ul in functie de numarul total de procese
-                self.remaining_timeslice = self.cpu_time.get() / self.ready_processes.len();
 
-                // se sorteaza procesele dupa virtual runtime, iar in caz de egalitate, se ia procesul cu pid-ul minim
+                // se sorteaza procesele dupa virtual runtime, iar in caz de egalitate, dupa tie_break
                 self.ready_processes.make_contiguous().sort_by(|a, b| {
                     let compare_by_vruntime = a.extra.partial_cmp(&b.extra).unwrap();
                     if compare_by_vruntime == Ordering::Equal {
-                        a.pid.cmp(&b.pid)
+                        break_tie(self.tie_break, a, b)
                     } else {
                         compare_by_vruntime
                     }
                 });
+                self.stats.context_switches += 1;
+                let now = self.current_time();
                 let mut first_element = self.ready_processes.pop_front().unwrap();
                 first_element.state = ProcessState::Running;
+                record_first_run(&mut first_element, now, &mut self.stats, &mut self.responses);
                 self.ready_processes.push_front(first_element);
+                // se calculeaza time slice-ul in functie de greutatea procesului planificat
+                self.remaining_timeslice = self.weighted_timeslice().max(1);
                 SchedulingDecision::Run {
                     pid: Pid::new(self.get_first_element()),
                     timeslice: NonZeroUsize::new(self.remaining_timeslice).unwrap(),
+                    core: 0,
                 }
             }
         } else if self.ready_processes.is_empty() {
@@ -229,13 +615,14 @@ impl Scheduler for CFS {
                 }
 
                 if self.check_if_all_processes_await_signal() {
-                    return SchedulingDecision::Deadlock;
+                    return SchedulingDecision::Deadlock(sorted_pids(self.waiting_processes.iter()));
                 }
 
                 let (check_if_sleep, minimum_time) = self.check_if_all_processes_sleep();
                 if check_if_sleep {
                     self.sleep = true;
                     self.sleep_time = minimum_time;
+                    self.stats.idle_time += minimum_time;
                     return SchedulingDecision::Sleep(NonZeroUsize::new(minimum_time).unwrap());
                 }
 
@@ -245,90 +632,228 @@ impl Scheduler for CFS {
                     return SchedulingDecision::Panic;
                 }
 
-                self.remaining_timeslice = self.cpu_time.get() / self.ready_processes.len();
-                // se sorteaza procesele dupa virtual runtime, iar in caz de egalitate, se ia procesul cu pid-ul minim
+                // se sorteaza procesele dupa virtual runtime, iar in caz de egalitate, dupa tie_break
                 self.ready_processes.make_contiguous().sort_by(|a, b| {
                     let compare_by_vruntime = a.extra.partial_cmp(&b.extra).unwrap();
                     if compare_by_vruntime == Ordering::Equal {
-                        a.pid.cmp(&b.pid)
+                        break_tie(self.tie_break, a, b)
                     } else {
                         compare_by_vruntime
                     }
                 });
+                self.stats.context_switches += 1;
+                let now = self.current_time();
                 let mut first_element = self.ready_processes.pop_front().unwrap();
                 first_element.state = ProcessState::Running;
+                record_first_run(&mut first_element, now, &mut self.stats, &mut self.responses);
                 self.ready_processes.push_front(first_element);
+                // se calculeaza time slice-ul in functie de greutatea procesului planificat
+                self.remaining_timeslice = self.weighted_timeslice().max(1);
                 SchedulingDecision::Run {
                     pid: Pid::new(self.get_first_element()),
                     timeslice: NonZeroUsize::new(self.remaining_timeslice).unwrap(),
+                    core: 0,
                 }
             }
     }
+}
 
-    fn stop(&mut self, reason: StopReason) -> SyscallResult {
-        match reason {
-            StopReason::Syscall { syscall, remaining } => match syscall {
-                Syscall::Fork(priority) => {
-                    let mut new_process = ProcessControlBlock::new(self.next_pid, priority);
+impl CFS {
+    /// Returns the virtual runtime of the process with the given PID, measured in
+    /// 1/1024 of a time unit, or [`None`] if there is no such process.
+    ///
+    /// A process with priority 0 and nice value 0 accumulates one time unit of virtual
+    /// runtime for every time unit it runs, processes with a higher priority or a lower
+    /// nice value accumulate it slower.
+    pub fn vruntime(&self, pid: Pid) -> Option<usize> {
+        self.ready_processes
+            .iter()
+            .chain(self.waiting_processes.iter())
+            .find(|process| process.pid() == pid)
+            .map(|process| process.extra)
+    }
 
-                    self.next_pid += 1;
+    /// Returns a copy of the state of the scheduler, that can be restored with
+    /// [`CFS::restore`], see [`Scheduler::snapshot`].
+    pub fn snapshot(&self) -> SchedulerSnapshot {
+        SchedulerSnapshot {
+            ready_processes: self.ready_processes.clone(),
+            waiting_processes: self.waiting_processes.clone(),
+            timeslice: self.cpu_time,
+            minimum_remaining_timeslice: self.minimum_remaining_timeslice,
+            remaining_timeslice: self.remaining_timeslice,
+            pids: self.pids.clone(),
+            sleep: self.sleep,
+            sleep_time: self.sleep_time,
+            stats: self.stats,
+            completed: self.completed.clone(),
+            responses: self.responses.clone(),
+            draining: self.draining,
+            max_processes: self.max_processes,
+            priority_range: self.priority_range.clone(),
+            semaphores: self.semaphores.clone(),
+            mutexes: self.mutexes.clone(),
+            zombies: self.zombies.clone(),
+            orphan_policy: self.orphan_policy,
+            aging_interval: 0,
+            tie_break: self.tie_break,
+            nice_timeslices: false,
+            idle_process: false,
+            timing_wheel: TimingWheel::default(),
+            quota_period: NonZeroUsize::new(QUOTA_PERIOD).unwrap(),
+            period_start: 0,
+        }
+    }
 
-                    if self.ready_processes.is_empty() {
-                        new_process.vruntime = String::from("vruntime=") + &"0".to_string();
-                        self.ready_processes.push_back(new_process.clone());
-                        self.remaining_timeslice = remaining;
-                    } else {
-                        let mut process = self.ready_processes.pop_front().unwrap();
-                        let execution_time = self.remaining_timeslice - remaining - 1;
-                    
-                        process.syscall += 1;
-                        process.execute += execution_time;
-                        process.extra += 1 + execution_time;
-                        process.vruntime = String::from("vruntime=") + &process.extra.to_string();
-
-                        self.ready_processes.push_front(process);
-                        self.remaining_timeslice = remaining;
-                        self.actualise_timings(execution_time + 1);
-                        new_process.extra = self.get_minimum_vruntime();
-                        new_process.vruntime =
-                            String::from("vruntime=") + &new_process.extra.to_string();
-                        self.ready_processes.push_back(new_process.clone());
-                    }
-                    SyscallResult::Pid(Pid::new(new_process.pid))
+    /// Creates a scheduler from a state saved with [`CFS::snapshot`].
+    ///
+    /// The restored scheduler has no observer.
+    pub fn restore(snapshot: SchedulerSnapshot) -> CFS {
+        CFS {
+            ready_processes: snapshot.ready_processes,
+            waiting_processes: snapshot.waiting_processes,
+            cpu_time: snapshot.timeslice,
+            minimum_remaining_timeslice: snapshot.minimum_remaining_timeslice,
+            remaining_timeslice: snapshot.remaining_timeslice,
+            pids: snapshot.pids,
+            sleep: snapshot.sleep,
+            sleep_time: snapshot.sleep_time,
+            stats: snapshot.stats,
+            completed: snapshot.completed,
+            responses: snapshot.responses,
+            tie_break: snapshot.tie_break,
+            observer: Observer::default(),
+            draining: snapshot.draining,
+            max_processes: snapshot.max_processes,
+            priority_range: snapshot.priority_range,
+            semaphores: snapshot.semaphores,
+            mutexes: snapshot.mutexes,
+            zombies: snapshot.zombies,
+            orphan_policy: snapshot.orphan_policy,
+        }
+    }
+}
+
+impl Scheduler for CFS {
+    fn next(&mut self) -> SchedulingDecision {
+        // semnalele primite de procese sunt raportate inaintea urmatoarei planificari
+        let decision = match self.take_pending_signal() {
+            Some((pid, signum)) => SchedulingDecision::DeliverSignal(pid, signum),
+            None => self.schedule(),
+        };
+        self.observer.decision(&decision);
+        decision
+    }
+
+    fn drain(&mut self) {
+        self.draining = true;
+    }
+
+    fn kill(&mut self, pid: Pid) -> bool {
+        if self.kill_processes(|process| process.pid() == pid) == 0 {
+            return false;
+        }
+        self.stats.record_kill(pid, SIGKILL);
+        true
+    }
+
+    fn reset(&mut self) {
+        self.ready_processes.clear();
+        self.waiting_processes.clear();
+        self.remaining_timeslice = 0;
+        self.pids.reset();
+        self.sleep = false;
+        self.sleep_time = 0;
+        self.stats = SchedulerStats::default();
+        self.completed.clear();
+        self.responses.clear();
+        self.draining = false;
+        self.semaphores.clear();
+        self.mutexes.clear();
+        self.zombies.clear();
+    }
+
+    fn process_count(&self) -> usize {
+        self.ready_processes.len() + self.waiting_processes.len()
+    }
+
+    fn set_max_processes(&mut self, max_processes: Option<NonZeroUsize>) {
+        self.max_processes = max_processes;
+    }
+
+    fn set_priority_range(&mut self, priority_range: RangeInclusive<i8>) {
+        self.priority_range = priority_range;
+    }
+
+    fn set_pid_policy(&mut self, pid_policy: PidPolicy) {
+        self.pids.set_policy(pid_policy);
+    }
+
+    fn set_orphan_policy(&mut self, orphan_policy: OrphanPolicy) {
+        self.orphan_policy = orphan_policy;
+    }
+
+    fn peek(&self) -> SchedulingDecision {
+        // decizia este luata de o copie a planificatorului, care nu are observator
+        self.clone().next()
+    }
+
+    fn stop(&mut self, reason: StopReason) -> Result<SyscallResult, SchedulerError> {
+        let reason = resolve_syscall(reason);
+        check_signal_target(self, reason)?;
+        record_burst(self.ready_processes.iter_mut(), reason, self.remaining_timeslice);
+        Ok(match reason {
+            StopReason::Syscall { syscall, remaining } => match syscall {
+                Syscall::Fork(priority) | Syscall::ForkWithDeadline { priority, .. }
+                    if self.accepts_processes() && self.priority_range.contains(&priority) =>
+                {
+                    SyscallResult::Pid(self.fork(Some(priority), 1, remaining)[0])
+                }
+                Syscall::ForkMany(count) if count > 0 && self.accepts_processes() => {
+                    // sunt create atatea procese cate mai accepta planificatorul
+                    let free = self
+                        .max_processes
+                        .map_or(count, |max| max.get() - self.process_count());
+                    let count = self.pids.available(self.current_time(), count.min(free));
+                    SyscallResult::Pids(self.fork(None, count, remaining))
                 }
                 Syscall::Sleep(sleep_time) => {
-                    if self.ready_processes.is_empty() {
-                        return SyscallResult::NoRunningProcess;
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
                     }
 
                     let mut current_process = self.ready_processes.pop_front().unwrap();
                     current_process.state = ProcessState::Waiting { event: None };
                     current_process.sleep = sleep_time;
                     current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
 
                     let execution_time = self.remaining_timeslice - remaining - 1;
-                    current_process.extra += execution_time + 1;
-                    current_process.vruntime =
-                        String::from("vruntime=") + &current_process.extra.to_string();
+                    charge_vruntime(&mut current_process, execution_time + 1);
                     current_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
                     current_process.total += execution_time + 1;
                     self.actualise_timings(execution_time + 1);
 
+                    self.observer.emit(SchedulerEvent::Blocked {
+                        pid: Pid::new(current_process.pid),
+                        event: None,
+                    });
                     self.waiting_processes.push_back(current_process);
                     SyscallResult::Success
                 }
                 Syscall::Signal(signal_code) => {
-                    if self.ready_processes.is_empty() {
-                        return SyscallResult::NoRunningProcess;
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
                     }
 
                     let mut current_process = self.ready_processes.pop_front().unwrap();
                     let execution_time = self.remaining_timeslice - remaining - 1;
                     current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
                     current_process.execute += execution_time;
-                    current_process.extra += execution_time + 1;
-                    current_process.vruntime =
-                        String::from("vruntime=") + &current_process.extra.to_string();
+                    self.stats.execution_time += execution_time + 1;
+                    charge_vruntime(&mut current_process, execution_time + 1);
 
                     self.ready_processes.push_front(current_process);
                     self.remaining_timeslice = remaining;
@@ -336,56 +861,175 @@ impl Scheduler for CFS {
                     self.update_waiting_processes(signal_code);
                     SyscallResult::Success
                 }
-                Syscall::Wait(event_number) => {
-                    if self.ready_processes.is_empty() {
-                        return SyscallResult::NoRunningProcess;
+                syscall @ (Syscall::Wait(_) | Syscall::IoWait { .. }) => {
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
                     }
                     let mut current_process = self.ready_processes.pop_front().unwrap();
                     let execution_time = self.remaining_timeslice - remaining - 1;
                     current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
                     current_process.execute += execution_time;
-                    current_process.extra += execution_time + 1;
-                    current_process.vruntime =
-                        String::from("vruntime=") + &current_process.extra.to_string();
-                    current_process.state = ProcessState::Waiting {
-                        event: Some(event_number),
-                    };
+                    self.stats.execution_time += execution_time + 1;
+                    charge_vruntime(&mut current_process, execution_time + 1);
+                    current_process.block(syscall);
+                    self.observer.emit(current_process.blocked_event());
                     self.waiting_processes.push_back(current_process);
                     self.remaining_timeslice = remaining;
                     self.actualise_timings(execution_time + 1);
                     SyscallResult::Success
 
                 }
+                syscall @ (Syscall::SetBurstHint(_)
+                | Syscall::SetTickets(_)
+                | Syscall::SetDeadline(_)
+                | Syscall::SetPeriod(_)
+                | Syscall::WillSignal(_)
+                | Syscall::SetProcessGroup(_)
+                | Syscall::KillGroup(_)
+                | Syscall::SuspendGroup(_)
+                | Syscall::ResumeGroup(_)
+                | Syscall::Suspend(_)
+                | Syscall::Resume(_)
+                | Syscall::SendSignal { .. }
+                | Syscall::Exec { .. }
+                | Syscall::SetName(_)
+                | Syscall::SetAffinity(_)
+                | Syscall::Renice(_)
+                | Syscall::SetCpuQuota(_)
+                | Syscall::SetResources(_)
+                | Syscall::WaitChild
+                | Syscall::WaitPid(_)
+                | Syscall::WaitNamed(_)
+                | Syscall::SignalNamed(_)
+                | Syscall::SemWait(_)
+                | Syscall::SemPost(_)
+                | Syscall::MutexLock(_)
+                | Syscall::MutexUnlock(_)
+                | Syscall::Yield
+                | Syscall::Fork(_)
+                | Syscall::ForkWithDeadline { .. }
+                | Syscall::ForkMany(_)) => {
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
+                    }
+
+                    let mut current_process = self.ready_processes.pop_front().unwrap();
+                    let execution_time = self.remaining_timeslice - remaining - 1;
+                    current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
+                    current_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
+                    charge_vruntime(&mut current_process, execution_time + 1);
+                    let old_weight = weight(&current_process);
+                    current_process.apply_attribute(syscall);
+                    self.semaphores.wait(&mut current_process, syscall);
+                    self.mutexes.apply(&mut current_process, syscall);
+                    let now = self.current_time();
+                    let processes = self
+                        .ready_processes
+                        .iter()
+                        .chain(self.waiting_processes.iter());
+                    let waited = self.zombies.wait(
+                        &mut current_process,
+                        syscall,
+                        processes,
+                        &mut self.pids,
+                        now,
+                    );
+                    if let Syscall::Renice(_) = syscall {
+                        self.reweight(&mut current_process, old_weight);
+                    }
+
+                    self.ready_processes.push_front(current_process);
+                    self.remaining_timeslice = remaining;
+                    self.actualise_timings(execution_time + 1);
+                    let result = self.update_processes(syscall);
+                    waited.unwrap_or(result)
+                }
                 Syscall::Exit => {
-                    if self.ready_processes.is_empty() {
-                        return SyscallResult::NoRunningProcess;
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::DoubleExit);
                     }
 
-                    let mut _current_process = self.ready_processes.pop_front().unwrap();
+                    let mut current_process = self.ready_processes.pop_front().unwrap();
                     let execution_time = self.remaining_timeslice - remaining - 1;
+                    // timpul apelului de sistem Exit este inclus in timpii procesului terminat
+                    current_process.execute += execution_time;
+                    current_process.syscall += 1;
+                    current_process.total += execution_time + 1;
+                    self.observer.emit(SchedulerEvent::Exited {
+                        pid: Pid::new(current_process.pid),
+                    });
+                    self.adopt_orphans(current_process.pid);
+                    // procesul ramane zombie pana cand parintele preia codul de iesire
+                    let now = self.current_time();
+                    let processes = self
+                        .ready_processes
+                        .iter_mut()
+                        .chain(self.waiting_processes.iter_mut());
+                    self.zombies
+                        .exit(&current_process, 0, processes, &mut self.pids, now);
+                    // mutex-urile procesului terminat sunt date proceselor care le asteapta
+                    self.mutexes.remove(Pid::new(current_process.pid));
+                    self.mutexes.wake_holders(&mut self.waiting_processes);
+                    self.update_sleeping_processes();
+                    self.stats.execution_time += execution_time + 1;
 
                     self.remaining_timeslice = remaining;
                     self.actualise_timings(execution_time + 1);
+                    self.stats.record_exit(current_process.execute + current_process.syscall);
+                    let completion = (current_process.arrival_tick, self.current_time());
+                    self.completed.insert(Pid::new(current_process.pid), completion);
                     SyscallResult::Success
                 }
             },
+            StopReason::IoComplete { device_id } => {
+                // procesele care asteapta dispozitivul devin ready si sunt mutate in coada de ready
+                for process in &mut self.waiting_processes {
+                    if process.state == (ProcessState::IoWait { device_id }) {
+                        process.state = ProcessState::Ready;
+                    }
+                }
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            StopReason::Killed(signum) => {
+                let Some(running_process) = self
+                    .ready_processes
+                    .front()
+                    .filter(|process| process.state == ProcessState::Running)
+                else {
+                    return Err(SchedulerError::NoRunningProcess);
+                };
+
+                // procesul este eliminat imediat, fara a mai fi adaugat in vreo coada
+                let pid = running_process.pid();
+                self.kill_processes(|process| process.pid() == pid);
+                self.stats.record_kill(pid, signum);
+                SyscallResult::Success
+            }
             StopReason::Expired => {
-                if self.ready_processes.is_empty() {
-                    return SyscallResult::NoRunningProcess;
+                if !self.check_if_process_is_running() {
+                    return Err(SchedulerError::NoRunningProcess);
                 }
                 let mut current_process = self.ready_processes.pop_front().unwrap();
                 current_process.execute += self.remaining_timeslice;
-                current_process.extra += self.remaining_timeslice;
-                current_process.vruntime =
-                    String::from("vruntime=") + &current_process.extra.to_string();
+                current_process.preemptions += 1;
+                current_process.involuntary_switches += 1;
+                self.stats.execution_time += self.remaining_timeslice;
+                charge_vruntime(&mut current_process, self.remaining_timeslice);
                 current_process.state = ProcessState::Ready;
+                self.observer.emit(SchedulerEvent::Preempted {
+                    pid: Pid::new(current_process.pid),
+                });
                 current_process.total += self.remaining_timeslice;
                 self.actualise_timings(self.remaining_timeslice);
                 self.ready_processes.push_back(current_process);
 
                 SyscallResult::Success
             }
-        }
+        })
     }
 
     fn list(&mut self) -> Vec<&dyn Process> {
@@ -395,4 +1039,90 @@ impl Scheduler for CFS {
             .map(|process| process as &dyn Process)
             .collect()
     }
+
+    fn ready_len(&self) -> usize {
+        self.ready_processes.len()
+    }
+
+    fn waiting_len(&self) -> usize {
+        self.waiting_processes.len()
+    }
+
+    fn statistics(&self) -> SchedulerStats {
+        self.stats
+    }
+
+    fn turnaround_times(&self) -> HashMap<Pid, usize> {
+        turnaround_times(&self.completed)
+    }
+
+    fn response_times(&self) -> HashMap<Pid, usize> {
+        self.responses.clone()
+    }
+
+    fn burst_histogram(&self, pid: Pid) -> Option<BurstHistogram> {
+        find_burst_histogram(self.all_processes(), pid)
+    }
+
+    fn spawn_batch(&mut self, requests: Vec<SpawnRequest>) -> Vec<Pid> {
+        if !self.accepts_processes() {
+            return Vec::new();
+        }
+        let free = self
+            .max_processes
+            .map_or(requests.len(), |max| max.get() - self.process_count());
+        let now = self.current_time();
+        let children = spawn_processes(&mut self.pids, now, free, requests, &self.priority_range);
+        self.stats.processes_spawned += children.len();
+        let pids: Vec<Pid> = children.iter().map(|process| process.pid()).collect();
+        for mut new_process in children {
+            set_vruntime(&mut new_process, self.get_minimum_vruntime());
+            new_process.arrival_tick = self.current_time();
+            self.ready_processes.push_back(new_process);
+        }
+        for &child in &pids {
+            self.observer.emit(SchedulerEvent::Forked { parent: None, child });
+        }
+        pids
+    }
+
+    fn process_info(&self, pid: Pid) -> Option<ProcessInfo> {
+        let Some(mut info) = find_process_info(self.all_processes(), pid) else {
+            return self.zombies.info(pid);
+        };
+        info.vruntime = self.vruntime(pid).map(|vruntime| vruntime as u64);
+        Some(info)
+    }
+
+    fn processes(&self) -> Box<dyn Iterator<Item = ProcessInfo> + '_> {
+        let pids = sorted_pids(self.all_processes());
+        Box::new(pids.into_iter().filter_map(|pid| self.process_info(pid)))
+    }
+
+    fn remaining_sleep(&self, pid: Pid) -> Option<usize> {
+        find_remaining_sleep(self.waiting_processes.iter(), pid)
+    }
+
+    fn take_wakeup_result(&mut self, pid: Pid) -> Option<SyscallResult> {
+        let processes = self
+            .ready_processes
+            .iter_mut()
+            .chain(self.waiting_processes.iter_mut());
+        take_wakeup_result(processes, pid)
+    }
+
+    fn next_wakeup(&self) -> Option<(Pid, usize)> {
+        // dupa o decizie Sleep, timpul de sleep al proceselor este scazut abia la
+        // urmatorul apel next, dar este deja adaugat la timpul curent
+        let pending = if self.sleep { self.sleep_time } else { 0 };
+        find_next_wakeup(self.waiting_processes.iter(), self.current_time() - pending)
+    }
+
+    fn set_observer(&mut self, observer: Box<dyn FnMut(SchedulerEvent) + Send>) {
+        self.observer.set(observer);
+    }
+
+    fn snapshot(&self) -> Option<SchedulerSnapshot> {
+        Some(CFS::snapshot(self))
+    }
 }
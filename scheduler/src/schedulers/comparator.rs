@@ -0,0 +1,45 @@
+use crate::schedulers::ProcessControlBlock;
+use crate::{Process, ProcessComparator, ProcessView};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+type CompareFn = dyn Fn(&ProcessView, &ProcessView) -> Ordering + Send + Sync;
+
+// functia care compara procesele ready ale unui planificator; cat timp nu este setata,
+// procesele sunt planificate in ordinea cozii de ready
+#[derive(Clone, Default)]
+pub struct Comparator {
+    // copiile planificatorului, folosite de peek, trebuie sa ia aceleasi decizii
+    cmp: Option<Arc<CompareFn>>,
+}
+
+impl Comparator {
+    pub fn new(cmp: ProcessComparator) -> Self {
+        Comparator {
+            cmp: Some(Arc::from(cmp)),
+        }
+    }
+
+    // procesul cel mai mic dupa functia de comparare este mutat la inceputul cozii; la
+    // egalitate este ales primul proces din coada
+    pub fn select(&self, ready_processes: &mut VecDeque<ProcessControlBlock>) {
+        let Some(cmp) = &self.cmp else {
+            return;
+        };
+        let views: Vec<ProcessView> = ready_processes.iter().map(view).collect();
+        let least = (0..views.len()).min_by(|&a, &b| cmp(&views[a], &views[b]));
+        if let Some(process) = least.and_then(|index| ready_processes.remove(index)) {
+            ready_processes.push_front(process);
+        }
+    }
+}
+
+fn view(process: &ProcessControlBlock) -> ProcessView {
+    ProcessView {
+        pid: process.pid(),
+        priority: process.priority(),
+        timings: process.timings(),
+        state: process.state(),
+    }
+}
@@ -0,0 +1,139 @@
+use crate::schedulers::{PolicyScheduler, ProcessControlBlock, SchedulingPolicy};
+use crate::{Pid, SchedulerEvent, SchedulingDecision, Syscall};
+use std::cmp::Ordering;
+
+#[allow(clippy::upper_case_acronyms)]
+pub type EDF = PolicyScheduler<EdfPolicy>;
+
+#[derive(Clone, Default)]
+pub struct EdfPolicy {
+    pub time: usize,
+    pub missed: Vec<usize>,
+}
+
+// termenul limita al unui proces; procesele fara termen limita sunt planificate dupa celelalte
+fn deadline(process: &ProcessControlBlock) -> usize {
+    process.deadline.unwrap_or(usize::MAX)
+}
+
+// procesele care au depasit termenul limita si nu au fost raportate inca
+fn collect_missed_deadlines(scheduler: &mut EDF) -> Vec<(Pid, usize)> {
+    let time = scheduler.policy.time;
+    let reported = &scheduler.policy.missed;
+    let mut missed: Vec<(usize, usize)> = scheduler
+        .ready_processes
+        .iter()
+        .chain(scheduler.waiting_processes.iter())
+        .filter_map(|process| match process.deadline {
+            Some(deadline) if deadline <= time && !reported.contains(&process.pid) => {
+                Some((process.pid, deadline))
+            }
+            _ => None,
+        })
+        .collect();
+    missed.sort();
+    scheduler
+        .policy
+        .missed
+        .extend(missed.iter().map(|(pid, _)| *pid));
+    missed
+        .into_iter()
+        .map(|(pid, deadline)| (Pid::new(pid), deadline))
+        .collect()
+}
+
+// procesul ruleaza pana la trezirea primului proces din starea de sleep sau pana la
+// urmatorul termen limita, moment in care se verifica daca trebuie preemptat
+fn dispatch_timeslice(scheduler: &EDF) -> usize {
+    let time = scheduler.policy.time;
+    let next_wake = scheduler
+        .waiting_processes
+        .iter()
+        .filter(|process| process.sleep > 0)
+        .map(|process| process.sleep);
+    let next_deadline = scheduler
+        .ready_processes
+        .iter()
+        .chain(scheduler.waiting_processes.iter())
+        .filter_map(|process| process.deadline)
+        .filter(|deadline| *deadline > time)
+        .map(|deadline| deadline - time);
+    next_wake.chain(next_deadline).fold(usize::MAX, usize::min)
+}
+
+impl SchedulingPolicy for EdfPolicy {
+    // se sorteaza procesele dupa termenul limita, iar in caz de egalitate, se ia procesul cu pid-ul minim
+    fn select(scheduler: &mut EDF) -> Option<usize> {
+        scheduler.ready_processes.make_contiguous().sort_by(|a, b| {
+            let compare_by_deadline = deadline(a).cmp(&deadline(b));
+            if compare_by_deadline == Ordering::Equal {
+                a.pid.cmp(&b.pid)
+            } else {
+                compare_by_deadline
+            }
+        });
+        Some(0)
+    }
+
+    fn timeslice(scheduler: &EDF) -> usize {
+        dispatch_timeslice(scheduler)
+    }
+
+    // procesul curent este preemptat daca exista un proces ready cu un termen limita mai apropiat
+    fn preempts(scheduler: &EDF) -> bool {
+        let current_deadline = deadline(scheduler.ready_processes.front().unwrap());
+        scheduler
+            .ready_processes
+            .iter()
+            .skip(1)
+            .any(|process| deadline(process) < current_deadline)
+    }
+
+    fn continued_timeslice(scheduler: &EDF) -> Option<usize> {
+        scheduler.check_if_reschedule().then(|| {
+            scheduler
+                .remaining_timeslice
+                .min(dispatch_timeslice(scheduler))
+        })
+    }
+
+    fn before_schedule(scheduler: &mut EDF) -> Option<SchedulingDecision> {
+        let missed = collect_missed_deadlines(scheduler);
+        if missed.is_empty() {
+            return None;
+        }
+        for (pid, _) in &missed {
+            scheduler.observer.emit(SchedulerEvent::DeadlineMiss(*pid));
+        }
+        Some(SchedulingDecision::Deadlines(missed))
+    }
+
+    // termenul limita este relativ la momentul crearii procesului, care a fost
+    // adaugat la finalul cozii de ready
+    fn forked(scheduler: &mut EDF, fork: Syscall) {
+        if let Syscall::ForkWithDeadline { deadline, .. } = fork {
+            let time = scheduler.policy.time;
+            scheduler.ready_processes.back_mut().unwrap().deadline = Some(time + deadline);
+        }
+    }
+
+    // un termen limita nou poate fi raportat din nou
+    fn attribute_changed(&mut self, process: &ProcessControlBlock, syscall: Syscall) {
+        if let Syscall::SetDeadline(_) = syscall {
+            self.missed.retain(|pid| *pid != process.pid);
+        }
+    }
+
+    fn exited(&mut self, pid: usize) {
+        self.missed.retain(|missed| *missed != pid);
+    }
+
+    fn advance(scheduler: &mut EDF, time: usize) {
+        scheduler.policy.time += time;
+    }
+
+    fn reset(&mut self) {
+        self.time = 0;
+        self.missed.clear();
+    }
+}
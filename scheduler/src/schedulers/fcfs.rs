@@ -0,0 +1,22 @@
+use crate::schedulers::{PolicyScheduler, SchedulingPolicy};
+
+/// A scheduler with a non-preemptive first come first served policy, returned by [`crate::fcfs`].
+#[allow(clippy::upper_case_acronyms)]
+pub type FCFS = PolicyScheduler<FcfsPolicy>;
+
+// procesele ruleaza in ordinea sosirii in coada de ready, pana cand se termina, dorm sau
+// asteapta un eveniment; procesul care ruleaza nu este niciodata preemptat
+#[derive(Clone)]
+pub struct FcfsPolicy;
+
+impl SchedulingPolicy for FcfsPolicy {
+    const KEEPS_PROCESSOR: bool = true;
+
+    fn timeslice(_scheduler: &FCFS) -> usize {
+        usize::MAX
+    }
+
+    fn continued_timeslice(_scheduler: &FCFS) -> Option<usize> {
+        Some(usize::MAX)
+    }
+}
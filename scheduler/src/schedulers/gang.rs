@@ -0,0 +1,107 @@
+use crate::schedulers::{PolicyScheduler, ProcessControlBlock, SchedulingPolicy};
+use crate::{Pid, Process};
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
+
+/// A scheduler with a gang scheduling policy, returned by [`crate::gang`].
+pub type Gang = PolicyScheduler<GangPolicy>;
+
+#[derive(Clone)]
+pub struct GangPolicy {
+    pub timeslice: NonZeroUsize,
+    pub group_size: NonZeroUsize,
+    // pid-urile membrilor grupului curent care nu au rulat inca in aceasta tura
+    pub gang: VecDeque<usize>,
+}
+
+// grupul unui proces este dat de liderul grupului de procese din care face parte
+fn group_of(process: &ProcessControlBlock) -> Pid {
+    process.pgid.unwrap_or(process.pid())
+}
+
+// functie care numara procesele din grupul primit
+fn group_members(scheduler: &Gang, group: Pid) -> usize {
+    scheduler
+        .all_processes()
+        .filter(|process| group_of(process) == group)
+        .count()
+}
+
+// functie care verifica daca toti membrii grupului primit sunt ready
+fn is_group_ready(scheduler: &Gang, group: Pid) -> bool {
+    !scheduler
+        .waiting_processes
+        .iter()
+        .any(|process| group_of(process) == group)
+}
+
+impl SchedulingPolicy for GangPolicy {
+    // se planifica urmatorul membru al grupului curent, sau primul proces din coada
+    // al carui grup are toti membrii ready
+    fn select(scheduler: &mut Gang) -> Option<usize> {
+        while let Some(pid) = scheduler.policy.gang.pop_front() {
+            let Some(index) = scheduler
+                .ready_processes
+                .iter()
+                .position(|process| process.pid == pid)
+            else {
+                continue;
+            };
+
+            // daca un membru s-a blocat, tot grupul cedeaza procesorul
+            if !is_group_ready(scheduler, group_of(&scheduler.ready_processes[index])) {
+                scheduler.policy.gang.clear();
+                break;
+            }
+            return Some(index);
+        }
+
+        let index = scheduler
+            .ready_processes
+            .iter()
+            .position(|process| is_group_ready(scheduler, group_of(process)))?;
+
+        // membrii grupului ruleaza unul dupa altul, in ordinea din coada de ready
+        let group = group_of(&scheduler.ready_processes[index]);
+        scheduler.policy.gang = scheduler
+            .ready_processes
+            .iter()
+            .skip(index + 1)
+            .filter(|process| group_of(process) == group)
+            .map(|process| process.pid)
+            .collect();
+        Some(index)
+    }
+
+    fn timeslice(scheduler: &Gang) -> usize {
+        scheduler.policy.timeslice.get()
+    }
+
+    // noul proces face parte din grupul parintelui, daca acesta nu este complet,
+    // iar in caz contrar devine liderul unui grup nou; sunt numarate si procesele
+    // create de acelasi apel
+    fn init_child(
+        scheduler: &Gang,
+        child: &mut ProcessControlBlock,
+        siblings: &[ProcessControlBlock],
+    ) {
+        let parent_group = scheduler.ready_processes.front().map(group_of);
+        child.pgid = match parent_group {
+            Some(group)
+                if group_members(scheduler, group)
+                    + siblings
+                        .iter()
+                        .filter(|sibling| group_of(sibling) == group)
+                        .count()
+                    < scheduler.policy.group_size.get() =>
+            {
+                Some(group)
+            }
+            _ => Some(child.pid()),
+        };
+    }
+
+    fn reset(&mut self) {
+        self.gang.clear();
+    }
+}
@@ -0,0 +1,46 @@
+use crate::schedulers::{PolicyScheduler, ProcessControlBlock, Random, SchedulingPolicy};
+use std::num::NonZeroUsize;
+
+pub type Lottery = PolicyScheduler<LotteryPolicy>;
+
+#[derive(Clone)]
+pub struct LotteryPolicy {
+    pub timeslice: NonZeroUsize,
+    pub random: Random,
+}
+
+impl SchedulingPolicy for LotteryPolicy {
+    // se extrage un bilet aleator din totalul biletelor proceselor ready, iar procesul
+    // care detine biletul este planificat
+    fn select(scheduler: &mut Lottery) -> Option<usize> {
+        let total_tickets: usize = scheduler
+            .ready_processes
+            .iter()
+            .map(|process| process.tickets)
+            .sum();
+        let mut ticket = scheduler.policy.random.below(total_tickets);
+        scheduler.ready_processes.iter().position(|process| {
+            if ticket < process.tickets {
+                true
+            } else {
+                ticket -= process.tickets;
+                false
+            }
+        })
+    }
+
+    fn timeslice(scheduler: &Lottery) -> usize {
+        scheduler.policy.timeslice.get()
+    }
+
+    // noul proces primeste acelasi numar de bilete ca procesul parinte
+    fn init_child(
+        scheduler: &Lottery,
+        child: &mut ProcessControlBlock,
+        _siblings: &[ProcessControlBlock],
+    ) {
+        if let Some(parent) = scheduler.ready_processes.front() {
+            child.tickets = parent.tickets;
+        }
+    }
+}
@@ -0,0 +1,994 @@
+use crate::scheduler::{Pid, Process};
+use crate::schedulers::{
+    adopt_orphans, check_signal_target, find_burst_histogram, find_next_wakeup, find_process_info,
+    find_remaining_sleep, fork_priority, record_burst, record_first_run, resolve_syscall,
+    set_affinity, sorted_pids, spawn_processes, take_pending_signal, take_processes,
+    take_wakeup_result, turnaround_times, ProcessControlBlock, Zombies, SINGLE_CORE,
+};
+use crate::{BurstHistogram, ProcessState, Scheduler, SpawnRequest, Syscall, NICE_RANGE};
+use std::collections::{HashMap, VecDeque};
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+
+use crate::schedulers::{Mutexes, Observer, PidAllocator, Semaphores};
+use crate::OrphanPolicy;
+use crate::PidPolicy;
+use crate::ProcessInfo;
+use crate::SchedulerError;
+use crate::SchedulerEvent;
+use crate::SchedulerStats;
+use crate::SchedulingDecision;
+use crate::StopReason;
+use crate::SyscallResult;
+use crate::SIGKILL;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone)]
+pub struct MLFQ {
+    // cate o coada de ready pentru fiecare nivel, nivelul 0 are prioritatea cea mai mare
+    pub ready_processes: Vec<VecDeque<ProcessControlBlock>>,
+    pub waiting_processes: VecDeque<ProcessControlBlock>,
+    // timeslice-ul fiecarui nivel
+    pub timeslices: Vec<NonZeroUsize>,
+    pub boost_interval: usize,
+    pub minimum_remaining_timeslice: usize,
+    pub remaining_timeslice: usize,
+    pub pids: PidAllocator,
+    pub sleep: bool,
+    pub sleep_time: usize,
+    pub stats: SchedulerStats,
+    pub completed: HashMap<Pid, (usize, usize)>,
+    pub responses: HashMap<Pid, usize>,
+    // nivelul procesului care ruleaza, acesta se afla in varful cozii nivelului
+    pub running_level: Option<usize>,
+    pub time_since_boost: usize,
+    pub observer: Observer,
+    pub draining: bool,
+    pub max_processes: Option<NonZeroUsize>,
+    pub priority_range: RangeInclusive<i8>,
+    pub semaphores: Semaphores,
+    pub mutexes: Mutexes,
+    pub zombies: Zombies,
+    pub orphan_policy: OrphanPolicy,
+}
+
+impl MLFQ {
+    fn actualise_timings(&mut self, timeslice: usize) {
+        for queue in &mut self.ready_processes {
+            for process in queue {
+                process.total += timeslice;
+                if process.state == ProcessState::Ready {
+                    self.stats.waiting_time += timeslice;
+                }
+            }
+        }
+        for process in &mut self.waiting_processes {
+            process.total += timeslice;
+        }
+
+        for process in &mut self.waiting_processes {
+            if process.sleep > 0 {
+                if process.sleep > timeslice {
+                    process.sleep -= timeslice;
+                } else {
+                    process.sleep = 0;
+                    process.state = ProcessState::Ready;
+                }
+            }
+        }
+
+        self.update_sleeping_processes();
+        self.time_since_boost += timeslice;
+    }
+
+    // toate procesele sunt mutate pe primul nivel, pentru a evita infometarea
+    fn boost(&mut self) {
+        let running_process = self
+            .running_level
+            .map(|level| self.ready_processes[level].pop_front().unwrap());
+
+        for level in 1..self.ready_processes.len() {
+            while let Some(mut process) = self.ready_processes[level].pop_front() {
+                process.level = 0;
+                self.ready_processes[0].push_back(process);
+            }
+        }
+        for process in &mut self.waiting_processes {
+            process.level = 0;
+        }
+
+        if let Some(mut process) = running_process {
+            process.level = 0;
+            self.ready_processes[0].push_front(process);
+            self.running_level = Some(0);
+        }
+    }
+
+    fn update_sleeping_processes(&mut self) {
+        self.waiting_processes
+            .retain(|process| match process.state {
+                ProcessState::Ready => {
+                    self.ready_processes[process.level].push_back(process.clone());
+                    false
+                }
+                _ => true,
+            })
+    }
+
+    fn update_waiting_processes(&mut self, signal_code: usize) {
+        self.waiting_processes
+            .retain(|process| match process.state {
+                ProcessState::Waiting {
+                    event: Some(signal),
+                } if signal == signal_code => {
+                    let mut copy = process.clone();
+                    copy.state = ProcessState::Ready;
+                    self.ready_processes[copy.level].push_back(copy);
+                    false
+                }
+                _ => true,
+            })
+    }
+
+    // functie care aplica apelul de sistem primit asupra altor procese decat procesul curent
+    fn update_processes(&mut self, syscall: Syscall) -> SyscallResult {
+        let result = match syscall {
+            Syscall::KillGroup(pgid) => {
+                self.kill_processes(|process| process.in_group(pgid));
+                SyscallResult::Success
+            }
+            Syscall::SuspendGroup(pgid) => {
+                let suspended: Vec<ProcessControlBlock> = self
+                    .ready_processes
+                    .iter_mut()
+                    .flat_map(|queue| take_processes(queue, |process| process.in_group(pgid)))
+                    .collect();
+                for mut process in suspended {
+                    process.state = ProcessState::Suspended;
+                    self.waiting_processes.push_back(process);
+                }
+                SyscallResult::Success
+            }
+            Syscall::ResumeGroup(pgid) => {
+                let resumed = take_processes(&mut self.waiting_processes, |process| {
+                    process.in_group(pgid) && process.state == ProcessState::Suspended
+                });
+                for process in resumed {
+                    self.push_ready(process);
+                }
+                SyscallResult::Success
+            }
+            // procesul primit este suspendat daca este ready sau doarme
+            Syscall::Suspend(target) => {
+                let now = self.current_time();
+                let suspended: Vec<ProcessControlBlock> = self
+                    .ready_processes
+                    .iter_mut()
+                    .flat_map(|queue| take_processes(queue, |process| process.pid() == target))
+                    .collect();
+                self.waiting_processes.extend(suspended);
+                for process in &mut self.waiting_processes {
+                    if process.pid() == target {
+                        process.suspend(now);
+                    }
+                }
+                SyscallResult::Success
+            }
+            Syscall::Resume(target) => {
+                let now = self.current_time();
+                for process in &mut self.waiting_processes {
+                    if process.pid() == target {
+                        process.resume(now);
+                    }
+                }
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            Syscall::SetAffinity(mask) => set_affinity(
+                self.running_level
+                    .and_then(|level| self.ready_processes[level].front_mut()),
+                mask,
+                SINGLE_CORE,
+            ),
+            // procesul curent este mutat in coada de waiting daca a fost blocat de un
+            // semafor, de un mutex sau de asteptarea unui proces copil
+            Syscall::SemWait(_)
+            | Syscall::MutexLock(_)
+            | Syscall::WaitChild
+            | Syscall::WaitPid(_) => {
+                let blocked: Vec<ProcessControlBlock> = self
+                    .ready_processes
+                    .iter_mut()
+                    .flat_map(|queue| take_processes(queue, |process| process.was_blocked()))
+                    .collect();
+                for process in blocked {
+                    self.observer.emit(process.blocked_event());
+                    self.waiting_processes.push_back(process);
+                }
+                SyscallResult::Success
+            }
+            // primul proces care asteapta semaforul devine ready
+            Syscall::SemPost(semaphore) => {
+                if let Some(pid) = self.semaphores.post(semaphore) {
+                    for process in &mut self.waiting_processes {
+                        if process.pid() == pid {
+                            process.state = ProcessState::Ready;
+                        }
+                    }
+                    self.update_sleeping_processes();
+                }
+                SyscallResult::Success
+            }
+            // procesul care a primit mutex-ul eliberat devine ready
+            Syscall::MutexUnlock(_) => {
+                self.mutexes.wake_holders(&mut self.waiting_processes);
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            // procesul curent cedeaza procesorul si este readaugat in coada de ready
+            Syscall::Yield => {
+                let yielded: Vec<ProcessControlBlock> = self
+                    .ready_processes
+                    .iter_mut()
+                    .flat_map(|queue| {
+                        take_processes(queue, |process| process.state == ProcessState::Running)
+                    })
+                    .collect();
+                for process in yielded {
+                    self.stats.yields += 1;
+                    self.push_ready(process);
+                }
+                SyscallResult::Success
+            }
+            Syscall::SendSignal { target, signum } => self.send_signal(target, signum),
+            // procesele noi trebuie sa aiba o prioritate din intervalul planificatorului
+            Syscall::Fork(priority) | Syscall::ForkWithDeadline { priority, .. }
+                if !self.priority_range.contains(&priority) =>
+            {
+                SyscallResult::InvalidArgument
+            }
+            Syscall::ForkMany(0) => SyscallResult::InvalidArgument,
+            // valoarea nice trebuie sa fie din intervalul NICE_RANGE
+            Syscall::Renice(nice) if !NICE_RANGE.contains(&nice) => SyscallResult::InvalidArgument,
+            // dupa drain sau la atingerea numarului maxim de procese nu mai sunt create procese noi
+            Syscall::Fork(_) | Syscall::ForkWithDeadline { .. } | Syscall::ForkMany(_) => {
+                if self.draining {
+                    SyscallResult::Error
+                } else {
+                    SyscallResult::NoMemory
+                }
+            }
+            _ => SyscallResult::Success,
+        };
+
+        // procesul curent nu mai ruleaza daca a fost oprit sau suspendat
+        if let Some(level) = self.running_level {
+            let running = self.ready_processes[level].front();
+            if !matches!(running, Some(process) if process.state == ProcessState::Running) {
+                self.running_level = None;
+            }
+        }
+        result
+    }
+
+    // functie care verifica daca pot fi create procese noi
+    fn accepts_processes(&self) -> bool {
+        !self.draining
+            && self
+                .max_processes
+                .is_none_or(|max| self.process_count() < max.get())
+            && self.pids.can_allocate(self.current_time())
+    }
+
+    // functie care creeaza `count` procese noi, cu prioritatea primita; timpul procesului
+    // parinte este contabilizat o singura data, ca pentru un singur apel fork
+    fn fork(&mut self, priority: Option<i8>, count: usize, remaining: usize) -> Vec<Pid> {
+        let parent = self
+            .running_level
+            .map(|level| Pid::new(self.ready_processes[level].front().unwrap().pid));
+        // procesele create de un apel fork multiplu au prioritatea procesului parinte
+        let priority = priority
+            .unwrap_or_else(|| fork_priority(self.all_processes(), parent, &self.priority_range));
+        // procesele noi intra pe nivelul cel mai prioritar
+        let now = self.current_time();
+        let mut children = Vec::new();
+        for _ in 0..count {
+            let pid = self.pids.allocate(now).unwrap();
+            let mut new_process = ProcessControlBlock::new(pid, priority);
+            new_process.pgid = self
+                .running_level
+                .and_then(|level| self.ready_processes[level].front())
+                .and_then(|process| process.pgid);
+            new_process.parent = parent;
+            self.stats.processes_spawned += 1;
+            children.push(new_process);
+        }
+        let pids: Vec<Pid> = children.iter().map(|process| process.pid()).collect();
+
+        match self.running_level {
+            None => {
+                for mut new_process in children {
+                    new_process.arrival_tick = self.current_time();
+                    self.ready_processes[0].push_back(new_process);
+                }
+                self.remaining_timeslice = remaining;
+            }
+            Some(level) => {
+                let mut process = self.ready_processes[level].pop_front().unwrap();
+                let execution_time = self.remaining_timeslice - remaining - 1;
+
+                process.syscall += 1;
+                process.voluntary_switches += 1;
+                process.execute += execution_time;
+                self.stats.execution_time += execution_time + 1;
+                self.ready_processes[level].push_front(process);
+                self.remaining_timeslice = remaining;
+                self.actualise_timings(execution_time + 1);
+                for mut new_process in children {
+                    new_process.arrival_tick = self.current_time();
+                    self.ready_processes[0].push_back(new_process);
+                }
+            }
+        }
+        for &child in &pids {
+            self.observer.emit(SchedulerEvent::Forked { parent, child });
+        }
+        pids
+    }
+
+    // functie care termina procesele care respecta conditia primita
+    fn kill_processes(&mut self, condition: impl Fn(&ProcessControlBlock) -> bool) -> usize {
+        let mut killed: Vec<ProcessControlBlock> = self
+            .ready_processes
+            .iter_mut()
+            .flat_map(|queue| take_processes(queue, &condition))
+            .collect();
+        killed.extend(take_processes(&mut self.waiting_processes, &condition));
+        let count = killed.len();
+        // procesul care rula a fost terminat, asa ca nivelul sau nu mai are un proces running
+        if killed.iter().any(|process| process.state == ProcessState::Running) {
+            self.running_level = None;
+        }
+        for process in killed {
+            self.observer.emit(SchedulerEvent::Exited {
+                pid: Pid::new(process.pid),
+            });
+            self.adopt_orphans(process.pid);
+            let now = self.current_time();
+            let processes = self
+                .ready_processes
+                .iter_mut()
+                .flatten()
+                .chain(self.waiting_processes.iter_mut());
+            self.zombies
+                .exit(&process, -1, processes, &mut self.pids, now);
+            self.semaphores.remove(Pid::new(process.pid));
+            self.mutexes.remove(Pid::new(process.pid));
+            self.stats.record_exit(process.execute + process.syscall);
+            let completion = (process.arrival_tick, self.current_time());
+            self.completed.insert(Pid::new(process.pid), completion);
+        }
+
+        // procesele care au primit mutex-urile proceselor terminate devin ready
+        self.mutexes.wake_holders(&mut self.waiting_processes);
+        self.update_sleeping_processes();
+        count
+    }
+
+    // functie care returneaza toate procesele din scheduler, din ambele cozi
+    fn all_processes(&self) -> impl Iterator<Item = &ProcessControlBlock> {
+        self.ready_processes.iter().flatten().chain(self.waiting_processes.iter())
+    }
+
+    // functie care adauga in coada de ready un proces scos din coada de waiting
+    fn push_ready(&mut self, mut process: ProcessControlBlock) {
+        process.state = ProcessState::Ready;
+        self.ready_processes[process.level].push_back(process);
+    }
+
+    // functie care trimite semnalul primit procesului cu pid-ul target
+    fn send_signal(&mut self, target: Pid, signum: u8) -> SyscallResult {
+        if signum == SIGKILL {
+            if self.kill_processes(|process| process.pid() == target) > 0 {
+                self.stats.record_kill(target, signum);
+            }
+            return SyscallResult::Success;
+        }
+
+        // un proces care doarme sau asteapta un eveniment este trezit imediat
+        let interrupted = take_processes(&mut self.waiting_processes, |process| {
+            process.pid() == target && matches!(process.state, ProcessState::Waiting { .. })
+        });
+        // apelul de sistem care a blocat procesul trezit intoarce Interrupted, rezultat
+        // preluat de sistemul de operare cu take_wakeup_result
+        for mut process in interrupted {
+            process.sleep = 0;
+            process.wakeup_result = Some(SyscallResult::Interrupted);
+            self.push_ready(process);
+        }
+
+        // semnalul este raportat la urmatoarea decizie a planificatorului
+        let mut processes = self
+            .ready_processes
+            .iter_mut()
+            .flatten()
+            .chain(self.waiting_processes.iter_mut());
+        if let Some(process) = processes.find(|process| process.pid() == target) {
+            process.pending_signal = Some(signum);
+        }
+        SyscallResult::Success
+    }
+
+    // functie care returneaza semnalul in asteptare al procesului cu cel mai mic pid
+    fn take_pending_signal(&mut self) -> Option<(Pid, u8)> {
+        take_pending_signal(
+            self.ready_processes
+                .iter_mut()
+                .flatten()
+                .chain(self.waiting_processes.iter_mut()),
+        )
+    }
+
+    // functie care trateaza procesele copil ale procesului terminat: acestea sunt mutate
+    // sub procesul cu pid-ul 1 sau sunt terminate impreuna cu parintele, dupa orphan_policy
+    fn adopt_orphans(&mut self, parent: usize) {
+        match self.orphan_policy {
+            OrphanPolicy::Reparent => adopt_orphans(
+                self.ready_processes
+                    .iter_mut()
+                    .flatten()
+                    .chain(self.waiting_processes.iter_mut()),
+                parent,
+            ),
+            OrphanPolicy::Cascade => {
+                self.kill_processes(|process| process.parent == Some(Pid::new(parent)));
+            }
+        }
+    }
+
+    fn level_timeslice(&self, level: usize) -> usize {
+        self.timeslices[level].get()
+    }
+
+    // primul nivel care are procese in starea ready
+    fn highest_ready_level(&self) -> Option<usize> {
+        self.ready_processes
+            .iter()
+            .position(|queue| !queue.is_empty())
+    }
+
+    // functie care verifica daca un proces se replanifica in continuare; procesul care
+    // si-a consumat tot timpul nu se replanifica, chiar daca minimul este 0
+    fn check_if_reschedule(&mut self) -> bool {
+        self.remaining_timeslice > 0 && self.remaining_timeslice >= self.minimum_remaining_timeslice
+    }
+
+    fn check_if_first_process_exists(&mut self) -> bool {
+        self.waiting_processes.iter().any(|process| process.pid == 1)
+            || self
+                .ready_processes
+                .iter()
+                .any(|queue| queue.iter().any(|process| process.pid == 1))
+    }
+
+    fn check_if_all_processes_await_signal(&mut self) -> bool {
+        let mut flag = true;
+        for process in &mut self.waiting_processes {
+            match process.state {
+                ProcessState::Waiting { event: Some(_) }
+                | ProcessState::Suspended
+                | ProcessState::SemaphoreWait { .. }
+                | ProcessState::MutexWait { .. }
+                | ProcessState::WaitingForChild { .. } => (),
+                _ => flag = false,
+            }
+        }
+        flag
+    }
+
+    fn check_if_all_processes_sleep(&mut self) -> (bool, usize) {
+        let mut flag = true;
+        let mut minimum_sleep_time: Option<usize> = None;
+        for process in &mut self.waiting_processes {
+            match process.state {
+                ProcessState::Waiting { event } => match event {
+                    Some(_) => (),
+                    None => {
+                        minimum_sleep_time = Some(match minimum_sleep_time {
+                            Some(time) => time.min(process.sleep),
+                            None => process.sleep,
+                        });
+                    }
+                },
+                ProcessState::IoWait { .. } => {
+                    minimum_sleep_time = Some(match minimum_sleep_time {
+                        Some(time) => time.min(process.io_expected),
+                        None => process.io_expected,
+                    });
+                }
+                ProcessState::Suspended => (),
+                _ => flag = false,
+            }
+        }
+
+        // procesorul doarme pana la prima trezire a unui proces
+        match minimum_sleep_time {
+            Some(time) if flag && time > 0 => (true, time),
+            _ => (false, 0),
+        }
+    }
+
+    // se planifica primul proces de pe cel mai prioritar nivel care nu este gol
+    fn schedule_highest_level(&mut self, level: usize) -> SchedulingDecision {
+        self.remaining_timeslice = self.level_timeslice(level);
+        self.running_level = Some(level);
+        self.stats.context_switches += 1;
+        let now = self.current_time();
+        let first_element = self.ready_processes[level].front_mut().unwrap();
+        first_element.state = ProcessState::Running;
+        record_first_run(first_element, now, &mut self.stats, &mut self.responses);
+        SchedulingDecision::Run {
+            pid: Pid::new(first_element.pid),
+            timeslice: NonZeroUsize::new(self.remaining_timeslice).unwrap(),
+            core: 0,
+        }
+    }
+
+    // functie care alege urmatoarea decizie a planificatorului
+    fn schedule(&mut self) -> SchedulingDecision {
+        if self.sleep {
+            self.sleep = false;
+            self.actualise_timings(self.sleep_time);
+            self.sleep_time = 0;
+        }
+
+        if self.boost_interval > 0 && self.time_since_boost >= self.boost_interval {
+            self.time_since_boost = 0;
+            self.boost();
+        }
+
+        if let Some(level) = self.running_level {
+            // procesul curent continua daca nu exista un proces ready pe un nivel mai prioritar
+            let preempted = self.highest_ready_level().is_some_and(|best| best < level);
+            if self.check_if_reschedule() && !preempted {
+                return SchedulingDecision::Run {
+                    pid: Pid::new(self.ready_processes[level].front().unwrap().pid),
+                    timeslice: NonZeroUsize::new(self.remaining_timeslice).unwrap(),
+                    core: 0,
+                };
+            }
+
+            // procesul nu si-a consumat tot timeslice-ul, asa ca ramane pe acelasi nivel
+            let mut current_element = self.ready_processes[level].pop_front().unwrap();
+            current_element.state = ProcessState::Ready;
+            self.observer.emit(SchedulerEvent::Preempted {
+                pid: Pid::new(current_element.pid),
+            });
+            self.ready_processes[level].push_back(current_element);
+            self.running_level = None;
+        }
+
+        match self.highest_ready_level() {
+            Some(level) => {
+                if !self.check_if_first_process_exists() {
+                    return SchedulingDecision::Panic;
+                }
+                self.schedule_highest_level(level)
+            }
+            None => {
+                if self.waiting_processes.is_empty() {
+                    return SchedulingDecision::Done;
+                }
+
+                if !self.check_if_first_process_exists() {
+                    return SchedulingDecision::Panic;
+                }
+
+                if self.check_if_all_processes_await_signal() {
+                    return SchedulingDecision::Deadlock(sorted_pids(self.waiting_processes.iter()));
+                }
+
+                let (check_if_sleep, minimum_time) = self.check_if_all_processes_sleep();
+                if check_if_sleep {
+                    self.sleep = true;
+                    self.sleep_time = minimum_time;
+                    self.stats.idle_time += minimum_time;
+                    return SchedulingDecision::Sleep(NonZeroUsize::new(minimum_time).unwrap());
+                }
+
+                SchedulingDecision::Panic
+            }
+        }
+    }
+}
+
+impl Scheduler for MLFQ {
+    fn next(&mut self) -> SchedulingDecision {
+        // semnalele primite de procese sunt raportate inaintea urmatoarei planificari
+        let decision = match self.take_pending_signal() {
+            Some((pid, signum)) => SchedulingDecision::DeliverSignal(pid, signum),
+            None => self.schedule(),
+        };
+        self.observer.decision(&decision);
+        decision
+    }
+
+    fn drain(&mut self) {
+        self.draining = true;
+    }
+
+    fn kill(&mut self, pid: Pid) -> bool {
+        if self.kill_processes(|process| process.pid() == pid) == 0 {
+            return false;
+        }
+        self.stats.record_kill(pid, SIGKILL);
+        true
+    }
+
+    fn reset(&mut self) {
+        for queue in &mut self.ready_processes {
+            queue.clear();
+        }
+        self.waiting_processes.clear();
+        self.remaining_timeslice = 0;
+        self.pids.reset();
+        self.sleep = false;
+        self.sleep_time = 0;
+        self.stats = SchedulerStats::default();
+        self.completed.clear();
+        self.responses.clear();
+        self.running_level = None;
+        self.time_since_boost = 0;
+        self.draining = false;
+        self.semaphores.clear();
+        self.mutexes.clear();
+        self.zombies.clear();
+    }
+
+    fn process_count(&self) -> usize {
+        self.ready_processes.iter().map(|queue| queue.len()).sum::<usize>() + self.waiting_processes.len()
+    }
+
+    fn set_max_processes(&mut self, max_processes: Option<NonZeroUsize>) {
+        self.max_processes = max_processes;
+    }
+
+    fn set_priority_range(&mut self, priority_range: RangeInclusive<i8>) {
+        self.priority_range = priority_range;
+    }
+
+    fn set_pid_policy(&mut self, pid_policy: PidPolicy) {
+        self.pids.set_policy(pid_policy);
+    }
+
+    fn set_orphan_policy(&mut self, orphan_policy: OrphanPolicy) {
+        self.orphan_policy = orphan_policy;
+    }
+
+    fn peek(&self) -> SchedulingDecision {
+        // decizia este luata de o copie a planificatorului, care nu are observator
+        self.clone().next()
+    }
+
+    fn stop(&mut self, reason: StopReason) -> Result<SyscallResult, SchedulerError> {
+        let reason = resolve_syscall(reason);
+        check_signal_target(self, reason)?;
+        record_burst(self.ready_processes.iter_mut().flatten(), reason, self.remaining_timeslice);
+        Ok(match reason {
+            StopReason::Syscall { syscall, remaining } => match syscall {
+                Syscall::Fork(priority) | Syscall::ForkWithDeadline { priority, .. }
+                    if self.accepts_processes() && self.priority_range.contains(&priority) =>
+                {
+                    SyscallResult::Pid(self.fork(Some(priority), 1, remaining)[0])
+                }
+                Syscall::ForkMany(count) if count > 0 && self.accepts_processes() => {
+                    // sunt create atatea procese cate mai accepta planificatorul
+                    let free = self
+                        .max_processes
+                        .map_or(count, |max| max.get() - self.process_count());
+                    let count = self.pids.available(self.current_time(), count.min(free));
+                    SyscallResult::Pids(self.fork(None, count, remaining))
+                }
+                Syscall::Sleep(sleep_time) => {
+                    let Some(level) = self.running_level.take() else {
+                        return Err(SchedulerError::NoRunningProcess);
+                    };
+
+                    let mut current_process = self.ready_processes[level].pop_front().unwrap();
+                    current_process.state = ProcessState::Waiting { event: None };
+                    current_process.sleep = sleep_time;
+                    current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
+
+                    let execution_time = self.remaining_timeslice - remaining - 1;
+                    current_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
+                    current_process.total += execution_time + 1;
+
+                    self.actualise_timings(execution_time + 1);
+
+                    self.observer.emit(SchedulerEvent::Blocked {
+                        pid: Pid::new(current_process.pid),
+                        event: None,
+                    });
+                    self.waiting_processes.push_back(current_process);
+                    SyscallResult::Success
+                }
+                Syscall::Signal(signal_code) => {
+                    let Some(level) = self.running_level else {
+                        return Err(SchedulerError::NoRunningProcess);
+                    };
+
+                    let mut current_process = self.ready_processes[level].pop_front().unwrap();
+                    let execution_time = self.remaining_timeslice - remaining - 1;
+                    current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
+                    current_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
+
+                    self.ready_processes[level].push_front(current_process);
+                    self.remaining_timeslice = remaining;
+                    self.actualise_timings(execution_time + 1);
+                    self.update_waiting_processes(signal_code);
+                    SyscallResult::Success
+                }
+                syscall @ (Syscall::Wait(_) | Syscall::IoWait { .. }) => {
+                    let Some(level) = self.running_level.take() else {
+                        return Err(SchedulerError::NoRunningProcess);
+                    };
+
+                    let mut current_process = self.ready_processes[level].pop_front().unwrap();
+                    let execution_time = self.remaining_timeslice - remaining - 1;
+                    current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
+                    current_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
+                    current_process.block(syscall);
+
+                    self.observer.emit(current_process.blocked_event());
+                    self.waiting_processes.push_back(current_process);
+                    self.remaining_timeslice = remaining;
+                    self.actualise_timings(execution_time + 1);
+                    SyscallResult::Success
+                }
+                syscall @ (Syscall::SetBurstHint(_)
+                | Syscall::SetTickets(_)
+                | Syscall::SetDeadline(_)
+                | Syscall::SetPeriod(_)
+                | Syscall::WillSignal(_)
+                | Syscall::SetProcessGroup(_)
+                | Syscall::KillGroup(_)
+                | Syscall::SuspendGroup(_)
+                | Syscall::ResumeGroup(_)
+                | Syscall::Suspend(_)
+                | Syscall::Resume(_)
+                | Syscall::SendSignal { .. }
+                | Syscall::Exec { .. }
+                | Syscall::SetName(_)
+                | Syscall::SetAffinity(_)
+                | Syscall::Renice(_)
+                | Syscall::SetCpuQuota(_)
+                | Syscall::SetResources(_)
+                | Syscall::WaitChild
+                | Syscall::WaitPid(_)
+                | Syscall::WaitNamed(_)
+                | Syscall::SignalNamed(_)
+                | Syscall::SemWait(_)
+                | Syscall::SemPost(_)
+                | Syscall::MutexLock(_)
+                | Syscall::MutexUnlock(_)
+                | Syscall::Yield
+                | Syscall::Fork(_)
+                | Syscall::ForkWithDeadline { .. }
+                | Syscall::ForkMany(_)) => {
+                    let Some(level) = self.running_level else {
+                        return Err(SchedulerError::NoRunningProcess);
+                    };
+
+                    let mut current_process = self.ready_processes[level].pop_front().unwrap();
+                    let execution_time = self.remaining_timeslice - remaining - 1;
+                    current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
+                    current_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
+                    current_process.apply_attribute(syscall);
+                    self.semaphores.wait(&mut current_process, syscall);
+                    self.mutexes.apply(&mut current_process, syscall);
+                    let now = self.current_time();
+                    let processes = self
+                        .ready_processes
+                        .iter()
+                        .flatten()
+                        .chain(self.waiting_processes.iter());
+                    let waited = self.zombies.wait(
+                        &mut current_process,
+                        syscall,
+                        processes,
+                        &mut self.pids,
+                        now,
+                    );
+
+                    // dupa exec, procesul continua sa ruleze din coada de pe primul nivel
+                    let level = current_process.level;
+                    self.running_level = Some(level);
+                    self.ready_processes[level].push_front(current_process);
+                    self.remaining_timeslice = remaining;
+                    self.actualise_timings(execution_time + 1);
+                    let result = self.update_processes(syscall);
+                    waited.unwrap_or(result)
+                }
+                Syscall::Exit => {
+                    let Some(level) = self.running_level.take() else {
+                        return Err(SchedulerError::DoubleExit);
+                    };
+
+                    let mut current_process = self.ready_processes[level].pop_front().unwrap();
+                    let execution_time = self.remaining_timeslice - remaining - 1;
+                    // timpul apelului de sistem Exit este inclus in timpii procesului terminat
+                    current_process.execute += execution_time;
+                    current_process.syscall += 1;
+                    current_process.total += execution_time + 1;
+                    self.observer.emit(SchedulerEvent::Exited {
+                        pid: Pid::new(current_process.pid),
+                    });
+                    self.adopt_orphans(current_process.pid);
+                    // procesul ramane zombie pana cand parintele preia codul de iesire
+                    let now = self.current_time();
+                    let processes = self
+                        .ready_processes
+                        .iter_mut()
+                        .flatten()
+                        .chain(self.waiting_processes.iter_mut());
+                    self.zombies
+                        .exit(&current_process, 0, processes, &mut self.pids, now);
+                    // mutex-urile procesului terminat sunt date proceselor care le asteapta
+                    self.mutexes.remove(Pid::new(current_process.pid));
+                    self.mutexes.wake_holders(&mut self.waiting_processes);
+                    self.update_sleeping_processes();
+                    self.stats.execution_time += execution_time + 1;
+
+                    self.remaining_timeslice = remaining;
+                    self.actualise_timings(execution_time + 1);
+                    self.stats.record_exit(current_process.execute + current_process.syscall);
+                    let completion = (current_process.arrival_tick, self.current_time());
+                    self.completed.insert(Pid::new(current_process.pid), completion);
+                    SyscallResult::Success
+                }
+            },
+            StopReason::IoComplete { device_id } => {
+                // procesele care asteapta dispozitivul devin ready si sunt mutate in coada de ready
+                for process in &mut self.waiting_processes {
+                    if process.state == (ProcessState::IoWait { device_id }) {
+                        process.state = ProcessState::Ready;
+                    }
+                }
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            StopReason::Killed(signum) => {
+                let Some(level) = self.running_level.take() else {
+                    return Err(SchedulerError::NoRunningProcess);
+                };
+
+                // procesul este eliminat imediat, fara a mai fi adaugat in vreo coada
+                let pid = self.ready_processes[level].front().unwrap().pid();
+                self.kill_processes(|process| process.pid() == pid);
+                self.stats.record_kill(pid, signum);
+                SyscallResult::Success
+            }
+            StopReason::Expired => {
+                let Some(level) = self.running_level.take() else {
+                    return Err(SchedulerError::NoRunningProcess);
+                };
+
+                // procesul si-a consumat tot timeslice-ul, asa ca trece pe nivelul urmator
+                let mut current_process = self.ready_processes[level].pop_front().unwrap();
+                current_process.execute += self.remaining_timeslice;
+                current_process.preemptions += 1;
+                current_process.involuntary_switches += 1;
+                self.stats.execution_time += self.remaining_timeslice;
+                current_process.state = ProcessState::Ready;
+                self.observer.emit(SchedulerEvent::Preempted {
+                    pid: Pid::new(current_process.pid),
+                });
+                current_process.total += self.remaining_timeslice;
+                current_process.level = (level + 1).min(self.ready_processes.len() - 1);
+                self.actualise_timings(self.remaining_timeslice);
+                self.ready_processes[current_process.level].push_back(current_process);
+
+                SyscallResult::Success
+            }
+        })
+    }
+
+    fn list(&mut self) -> Vec<&dyn Process> {
+        self.ready_processes
+            .iter()
+            .flatten()
+            .chain(self.waiting_processes.iter())
+            .map(|process| process as &dyn Process)
+            .collect()
+    }
+
+    fn ready_len(&self) -> usize {
+        self.ready_processes.iter().map(|queue| queue.len()).sum()
+    }
+
+    fn waiting_len(&self) -> usize {
+        self.waiting_processes.len()
+    }
+
+    fn statistics(&self) -> SchedulerStats {
+        self.stats
+    }
+
+    fn turnaround_times(&self) -> HashMap<Pid, usize> {
+        turnaround_times(&self.completed)
+    }
+
+    fn response_times(&self) -> HashMap<Pid, usize> {
+        self.responses.clone()
+    }
+
+    fn burst_histogram(&self, pid: Pid) -> Option<BurstHistogram> {
+        find_burst_histogram(self.all_processes(), pid)
+    }
+
+    fn spawn_batch(&mut self, requests: Vec<SpawnRequest>) -> Vec<Pid> {
+        if !self.accepts_processes() {
+            return Vec::new();
+        }
+        let free = self
+            .max_processes
+            .map_or(requests.len(), |max| max.get() - self.process_count());
+        let now = self.current_time();
+        let children = spawn_processes(&mut self.pids, now, free, requests, &self.priority_range);
+        self.stats.processes_spawned += children.len();
+        let pids: Vec<Pid> = children.iter().map(|process| process.pid()).collect();
+        for mut new_process in children {
+            new_process.arrival_tick = self.current_time();
+            self.ready_processes[0].push_back(new_process);
+        }
+        for &child in &pids {
+            self.observer.emit(SchedulerEvent::Forked { parent: None, child });
+        }
+        pids
+    }
+
+    fn process_info(&self, pid: Pid) -> Option<ProcessInfo> {
+        find_process_info(self.all_processes(), pid).or_else(|| self.zombies.info(pid))
+    }
+
+    fn processes(&self) -> Box<dyn Iterator<Item = ProcessInfo> + '_> {
+        let pids = sorted_pids(self.all_processes());
+        Box::new(pids.into_iter().filter_map(|pid| self.process_info(pid)))
+    }
+
+    fn remaining_sleep(&self, pid: Pid) -> Option<usize> {
+        find_remaining_sleep(self.waiting_processes.iter(), pid)
+    }
+
+    fn take_wakeup_result(&mut self, pid: Pid) -> Option<SyscallResult> {
+        let processes = self
+            .ready_processes
+            .iter_mut()
+            .flatten()
+            .chain(self.waiting_processes.iter_mut());
+        take_wakeup_result(processes, pid)
+    }
+
+    fn next_wakeup(&self) -> Option<(Pid, usize)> {
+        // dupa o decizie Sleep, timpul de sleep al proceselor este scazut abia la
+        // urmatorul apel next, dar este deja adaugat la timpul curent
+        let pending = if self.sleep { self.sleep_time } else { 0 };
+        find_next_wakeup(self.waiting_processes.iter(), self.current_time() - pending)
+    }
+
+    fn set_observer(&mut self, observer: Box<dyn FnMut(SchedulerEvent) + Send>) {
+        self.observer.set(observer);
+    }
+
+    fn queue_depths(&self) -> Vec<usize> {
+        self.ready_processes.iter().map(|queue| queue.len()).collect()
+    }
+}
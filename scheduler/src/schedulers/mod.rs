@@ -4,10 +4,43 @@
 //! ```
 
 mod process_control_block;
-pub use process_control_block::ProcessControlBlock;
+pub use process_control_block::{
+    adopt_orphans, break_tie, check_signal_target, find_burst_histogram, find_next_wakeup,
+    find_process_info, find_remaining_sleep, fork_priority, nice_priority, record_burst,
+    record_first_run, resolve_syscall, set_affinity, sorted_pids, spawn_processes,
+    take_pending_signal, take_processes, take_wakeup_result, turnaround_times, ProcessControlBlock,
+    SINGLE_CORE,
+};
+
+mod observer;
+pub use observer::Observer;
+
+mod comparator;
+pub use comparator::Comparator;
+
+mod pid_allocator;
+pub use pid_allocator::PidAllocator;
+
+mod mutexes;
+pub use mutexes::Mutexes;
+
+mod semaphores;
+pub use semaphores::Semaphores;
+
+mod zombies;
+pub use zombies::Zombies;
+
+mod timing_wheel;
+pub use timing_wheel::{TimingWheel, TIMING_WHEEL_SIZE};
+
+mod snapshot;
+pub use snapshot::SchedulerSnapshot;
+
+mod random;
+pub use random::Random;
 
 mod round_robin;
-pub use round_robin::RoundRobin;
+pub use round_robin::{RoundRobin, QUOTA_PERIOD};
 
 mod priority_queue_round_robin;
 pub use priority_queue_round_robin::PriorityQueueRoundRobin;
@@ -15,4 +48,43 @@ pub use priority_queue_round_robin::PriorityQueueRoundRobin;
 mod cfs;
 pub use cfs::CFS;
 
+mod policy_scheduler;
+pub use policy_scheduler::{PolicyScheduler, SchedulingPolicy};
+
+mod fcfs;
+pub use fcfs::{FcfsPolicy, FCFS};
+
+mod rt_fifo;
+pub use rt_fifo::{RealTimeFifo, RealTimeFifoPolicy};
+
+mod sjf;
+pub use sjf::{SjfPolicy, SJF};
+
+mod mlfq;
+pub use mlfq::MLFQ;
+
+mod srtf;
+pub use srtf::{SrtfPolicy, SRTF};
+
+mod lottery;
+pub use lottery::{Lottery, LotteryPolicy};
+
+mod stride;
+pub use stride::{Stride, StridePolicy};
+
+mod edf;
+pub use edf::{EdfPolicy, EDF};
+
+mod rms;
+pub use rms::{RateMonotonic, RateMonotonicPolicy};
+
+mod gang;
+pub use gang::{Gang, GangPolicy};
+
+mod multicore_round_robin;
+pub use multicore_round_robin::{Balancing, MultiCoreRoundRobin};
+
+mod work_stealing_round_robin;
+pub use work_stealing_round_robin::WorkStealingRoundRobin;
+
 // TODO import your schedulers here
@@ -0,0 +1,1226 @@
+use crate::scheduler::{Pid, Process};
+use crate::schedulers::{
+    adopt_orphans, check_signal_target, find_burst_histogram, find_next_wakeup, find_process_info,
+    find_remaining_sleep, fork_priority, record_burst, record_first_run, resolve_syscall,
+    set_affinity, sorted_pids, spawn_processes, take_pending_signal, take_processes,
+    take_wakeup_result, turnaround_times, ProcessControlBlock, Zombies,
+};
+use crate::{
+    BurstHistogram, MultiScheduler, ProcessState, Scheduler, SpawnRequest, Syscall, NICE_RANGE,
+};
+use std::collections::{HashMap, VecDeque};
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+
+use crate::schedulers::{Mutexes, Observer, PidAllocator, Semaphores};
+use crate::OrphanPolicy;
+use crate::PidPolicy;
+use crate::ProcessInfo;
+use crate::SchedulerError;
+use crate::SchedulerEvent;
+use crate::SchedulerStats;
+use crate::SchedulingDecision;
+use crate::StopReason;
+use crate::SyscallResult;
+use crate::SIGKILL;
+
+/// A scheduler with a round robin policy on several processor cores, returned by
+/// [`crate::round_robin_multicore`] and [`crate::multicore_round_robin`].
+#[derive(Clone)]
+pub struct MultiCoreRoundRobin {
+    // cate o coada de ready pentru fiecare core
+    pub ready_processes: Vec<VecDeque<ProcessControlBlock>>,
+    pub waiting_processes: VecDeque<ProcessControlBlock>,
+    pub timeslice: NonZeroUsize,
+    pub minimum_remaining_timeslice: usize,
+    // timeslice-ul ramas al procesului din varful cozii fiecarui core
+    pub remaining_timeslices: Vec<usize>,
+    pub pids: PidAllocator,
+    pub sleep: bool,
+    pub sleep_time: usize,
+    pub stats: SchedulerStats,
+    pub completed: HashMap<Pid, (usize, usize)>,
+    pub responses: HashMap<Pid, usize>,
+    // core-ul procesului care face apelul de sistem tratat de stop, acesta se afla
+    // in varful cozii core-ului
+    pub running_core: Option<usize>,
+    // core-urile din lotul curent de decizii al caror proces nu a fost inca oprit
+    pub running_cores: Vec<bool>,
+    // core-ul ultimei decizii returnate de next, oprit de urmatorul apel stop
+    pub current_core: Option<usize>,
+    // timpul rulat de fiecare core in lotul curent de decizii
+    pub batch_elapsed: Vec<usize>,
+    // momentul din lotul curent la care procesele s-au oprit din rulare sau au fost create
+    pub batch_times: HashMap<Pid, usize>,
+    // ceasul global, care avanseaza o singura data pentru fiecare lot de decizii
+    pub time: usize,
+    pub balancing: Balancing,
+    pub observer: Observer,
+    pub draining: bool,
+    pub max_processes: Option<NonZeroUsize>,
+    pub priority_range: RangeInclusive<i8>,
+    pub semaphores: Semaphores,
+    pub mutexes: Mutexes,
+    pub zombies: Zombies,
+    pub orphan_policy: OrphanPolicy,
+}
+
+// modul in care sunt echilibrate cozile de ready ale core-urilor inaintea fiecarui lot
+#[derive(Clone, Copy)]
+pub enum Balancing {
+    // ultimul proces ready din coada cea mai lunga este mutat pe core-ul cu coada cea mai
+    // scurta, daca diferenta dintre cele doua cozi depaseste pragul
+    Migration(usize),
+    // un core cu coada goala fura jumatate din procesele core-ului cu coada cea mai lunga,
+    // daca aceasta are cel putin atatea procese cat pragul
+    WorkStealing(usize),
+}
+
+impl MultiCoreRoundRobin {
+    /// Sets the imbalance between the longest and the shortest run queue above
+    /// which a ready process is migrated from the longest queue to the shortest one.
+    ///
+    /// Schedulers are created with a threshold of 1. For a scheduler returned by
+    /// [`crate::round_robin_work_stealing`], this sets the minimum number of processes
+    /// in the run queue of a core before other cores may steal processes from it.
+    pub fn set_balance_threshold(&mut self, balance_threshold: usize) {
+        self.balancing = match self.balancing {
+            Balancing::Migration(_) => Balancing::Migration(balance_threshold),
+            Balancing::WorkStealing(_) => Balancing::WorkStealing(balance_threshold),
+        };
+    }
+
+    /// Returns the number of processes migrated between the run queues of the cores,
+    /// see [`SchedulerStats::migrations`].
+    pub fn migrations(&self) -> usize {
+        self.stats.migrations
+    }
+}
+
+impl MultiCoreRoundRobin {
+    // timpul primit este scazut cu momentul din lot la care procesul s-a oprit din rulare
+    // sau a fost creat, timpul rulat fiind deja adaugat la oprirea procesului
+    fn actualise_timings(&mut self, timeslice: usize) {
+        let batch_times = &self.batch_times;
+        let elapsed = |process: &ProcessControlBlock| {
+            timeslice - batch_times.get(&process.pid()).copied().unwrap_or(0)
+        };
+        for queue in &mut self.ready_processes {
+            for process in queue {
+                let elapsed = elapsed(process);
+                process.total += elapsed;
+                if process.state == ProcessState::Ready {
+                    self.stats.waiting_time += elapsed;
+                }
+            }
+        }
+        for process in &mut self.waiting_processes {
+            process.total += elapsed(process);
+        }
+
+        for process in &mut self.waiting_processes {
+            let elapsed = elapsed(process);
+            if process.sleep > 0 {
+                if process.sleep > elapsed {
+                    process.sleep -= elapsed;
+                } else {
+                    process.sleep = 0;
+                    process.state = ProcessState::Ready;
+                }
+            }
+        }
+
+        self.update_sleeping_processes();
+    }
+
+    // procesul core-ului a rulat timpul primit din lotul curent; ceasul global avanseaza
+    // abia la finalul lotului
+    fn run_core(&mut self, core: usize, pid: Pid, time: usize) {
+        self.batch_elapsed[core] += time;
+        self.batch_times.insert(pid, self.batch_elapsed[core]);
+    }
+
+    // momentul la care a ajuns core-ul primit in lotul curent
+    fn core_time(&self, core: usize) -> usize {
+        self.time + self.batch_elapsed[core]
+    }
+
+    // core-urile al caror proces nu mai ruleaza, fiind terminat, suspendat sau blocat de
+    // alt proces, sunt eliberate; lotul se incheie cand nu mai ruleaza niciun proces
+    fn release_cores(&mut self) {
+        for core in 0..self.ready_processes.len() {
+            let running = self.ready_processes[core].front();
+            if !matches!(running, Some(process) if process.state == ProcessState::Running) {
+                self.running_cores[core] = false;
+            }
+        }
+        if !self.running_cores.contains(&true) {
+            self.finish_batch();
+        }
+    }
+
+    // ceasul avanseaza cu cel mai lung timp rulat de un core in lot, iar core-urile care
+    // au rulat mai putin au stat libere in restul lotului
+    fn finish_batch(&mut self) {
+        let duration = self.batch_elapsed.iter().copied().max().unwrap_or(0);
+        self.stats.idle_time += self
+            .batch_elapsed
+            .iter()
+            .map(|elapsed| duration - elapsed)
+            .sum::<usize>();
+        self.time += duration;
+        self.actualise_timings(duration);
+        self.batch_elapsed.fill(0);
+        self.batch_times.clear();
+    }
+
+    // decizia de rulare a procesului din varful cozii core-ului primit
+    fn run_decision(&self, core: usize) -> SchedulingDecision {
+        SchedulingDecision::Run {
+            pid: self.ready_processes[core].front().unwrap().pid(),
+            timeslice: NonZeroUsize::new(self.remaining_timeslices[core]).unwrap(),
+            core,
+        }
+    }
+
+    fn update_sleeping_processes(&mut self) {
+        self.waiting_processes
+            .retain(|process| match process.state {
+                ProcessState::Ready => {
+                    self.ready_processes[process.core].push_back(process.clone());
+                    false
+                }
+                _ => true,
+            })
+    }
+
+    fn update_waiting_processes(&mut self, signal_code: usize) {
+        self.waiting_processes
+            .retain(|process| match process.state {
+                ProcessState::Waiting {
+                    event: Some(signal),
+                } if signal == signal_code => {
+                    let mut copy = process.clone();
+                    copy.state = ProcessState::Ready;
+                    self.ready_processes[copy.core].push_back(copy);
+                    false
+                }
+                _ => true,
+            })
+    }
+
+    // functie care aplica apelul de sistem primit asupra altor procese decat procesul curent
+    fn update_processes(&mut self, syscall: Syscall) -> SyscallResult {
+        let result = match syscall {
+            Syscall::KillGroup(pgid) => {
+                self.kill_processes(|process| process.in_group(pgid));
+                SyscallResult::Success
+            }
+            Syscall::SuspendGroup(pgid) => {
+                let suspended: Vec<ProcessControlBlock> = self
+                    .ready_processes
+                    .iter_mut()
+                    .flat_map(|queue| take_processes(queue, |process| process.in_group(pgid)))
+                    .collect();
+                for mut process in suspended {
+                    process.state = ProcessState::Suspended;
+                    self.waiting_processes.push_back(process);
+                }
+                SyscallResult::Success
+            }
+            Syscall::ResumeGroup(pgid) => {
+                let resumed = take_processes(&mut self.waiting_processes, |process| {
+                    process.in_group(pgid) && process.state == ProcessState::Suspended
+                });
+                for process in resumed {
+                    self.push_ready(process);
+                }
+                SyscallResult::Success
+            }
+            // procesul primit este suspendat daca este ready sau doarme
+            Syscall::Suspend(target) => {
+                let now = self.current_time();
+                let suspended: Vec<ProcessControlBlock> = self
+                    .ready_processes
+                    .iter_mut()
+                    .flat_map(|queue| take_processes(queue, |process| process.pid() == target))
+                    .collect();
+                self.waiting_processes.extend(suspended);
+                for process in &mut self.waiting_processes {
+                    if process.pid() == target {
+                        process.suspend(now);
+                    }
+                }
+                SyscallResult::Success
+            }
+            Syscall::Resume(target) => {
+                let now = self.current_time();
+                for process in &mut self.waiting_processes {
+                    if process.pid() == target {
+                        process.resume(now);
+                    }
+                }
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            Syscall::SetAffinity(mask) => self.set_affinity(mask),
+            // procesul curent este mutat in coada de waiting daca a fost blocat de un
+            // semafor, de un mutex sau de asteptarea unui proces copil
+            Syscall::SemWait(_)
+            | Syscall::MutexLock(_)
+            | Syscall::WaitChild
+            | Syscall::WaitPid(_) => {
+                let blocked: Vec<ProcessControlBlock> = self
+                    .ready_processes
+                    .iter_mut()
+                    .flat_map(|queue| take_processes(queue, |process| process.was_blocked()))
+                    .collect();
+                for process in blocked {
+                    self.observer.emit(process.blocked_event());
+                    self.waiting_processes.push_back(process);
+                }
+                SyscallResult::Success
+            }
+            // primul proces care asteapta semaforul devine ready
+            Syscall::SemPost(semaphore) => {
+                if let Some(pid) = self.semaphores.post(semaphore) {
+                    for process in &mut self.waiting_processes {
+                        if process.pid() == pid {
+                            process.state = ProcessState::Ready;
+                        }
+                    }
+                    self.update_sleeping_processes();
+                }
+                SyscallResult::Success
+            }
+            // procesul care a primit mutex-ul eliberat devine ready
+            Syscall::MutexUnlock(_) => {
+                self.mutexes.wake_holders(&mut self.waiting_processes);
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            // procesul curent cedeaza procesorul si este readaugat in coada de ready; procesele
+            // celorlalte core-uri din lot continua sa ruleze
+            Syscall::Yield => {
+                if let Some(core) = self.running_core {
+                    let process = self.ready_processes[core].pop_front().unwrap();
+                    self.stats.yields += 1;
+                    self.push_ready(process);
+                }
+                SyscallResult::Success
+            }
+            Syscall::SendSignal { target, signum } => self.send_signal(target, signum),
+            // procesele noi trebuie sa aiba o prioritate din intervalul planificatorului
+            Syscall::Fork(priority) | Syscall::ForkWithDeadline { priority, .. }
+                if !self.priority_range.contains(&priority) =>
+            {
+                SyscallResult::InvalidArgument
+            }
+            Syscall::ForkMany(0) => SyscallResult::InvalidArgument,
+            // valoarea nice trebuie sa fie din intervalul NICE_RANGE
+            Syscall::Renice(nice) if !NICE_RANGE.contains(&nice) => SyscallResult::InvalidArgument,
+            // dupa drain sau la atingerea numarului maxim de procese nu mai sunt create procese noi
+            Syscall::Fork(_) | Syscall::ForkWithDeadline { .. } | Syscall::ForkMany(_) => {
+                if self.draining {
+                    SyscallResult::Error
+                } else {
+                    SyscallResult::NoMemory
+                }
+            }
+            _ => SyscallResult::Success,
+        };
+
+        // procesul curent nu mai ruleaza daca a fost oprit sau suspendat
+        if let Some(core) = self.running_core {
+            let running = self.ready_processes[core].front();
+            if !matches!(running, Some(process) if process.state == ProcessState::Running) {
+                self.running_core = None;
+            }
+        }
+        result
+    }
+
+    // functie care verifica daca pot fi create procese noi
+    fn accepts_processes(&self) -> bool {
+        !self.draining
+            && self
+                .max_processes
+                .is_none_or(|max| self.process_count() < max.get())
+            && self.pids.can_allocate(self.current_time())
+    }
+
+    // functie care creeaza `count` procese noi, cu prioritatea primita; timpul procesului
+    // parinte este contabilizat o singura data, ca pentru un singur apel fork
+    fn fork(&mut self, priority: Option<i8>, count: usize, remaining: usize) -> Vec<Pid> {
+        let parent = self
+            .running_core
+            .map(|core| Pid::new(self.ready_processes[core].front().unwrap().pid));
+        // procesele create de un apel fork multiplu au prioritatea procesului parinte
+        let priority = priority
+            .unwrap_or_else(|| fork_priority(self.all_processes(), parent, &self.priority_range));
+        // procesele noi intra in coada core-ului cu cele mai putine procese
+        let now = self.current_time();
+        let mut children = Vec::new();
+        for _ in 0..count {
+            let pid = self.pids.allocate(now).unwrap();
+            let mut new_process = ProcessControlBlock::new(pid, priority);
+            new_process.pgid = self
+                .running_core
+                .and_then(|core| self.ready_processes[core].front())
+                .and_then(|process| process.pgid);
+            new_process.parent = parent;
+            // procesul nou mosteneste masca de afinitate a parintelui
+            if let Some(core) = self.running_core {
+                new_process.cpu_affinity = self.ready_processes[core][0].cpu_affinity;
+            }
+            self.stats.processes_spawned += 1;
+            children.push(new_process);
+        }
+        let pids: Vec<Pid> = children.iter().map(|process| process.pid()).collect();
+
+        match self.running_core {
+            None => {
+                for mut new_process in children {
+                    new_process.arrival_tick = self.current_time();
+                    self.ready_processes[0].push_back(new_process);
+                }
+            }
+            Some(core) => {
+                let mut process = self.ready_processes[core].pop_front().unwrap();
+                let execution_time = self.remaining_timeslices[core] - remaining - 1;
+
+                process.syscall += 1;
+                process.voluntary_switches += 1;
+                process.execute += execution_time;
+                process.total += execution_time + 1;
+                self.stats.execution_time += execution_time + 1;
+                self.run_core(core, process.pid(), execution_time + 1);
+                self.ready_processes[core].push_front(process);
+                self.remaining_timeslices[core] = remaining;
+                // procesele noi sunt create la momentul la care a ajuns core-ul parintelui
+                for mut new_process in children {
+                    new_process.core = self.shortest_core(&new_process);
+                    new_process.arrival_tick = self.core_time(core);
+                    self.batch_times
+                        .insert(new_process.pid(), self.batch_elapsed[core]);
+                    self.ready_processes[new_process.core].push_back(new_process);
+                }
+            }
+        }
+        for &child in &pids {
+            self.observer.emit(SchedulerEvent::Forked { parent, child });
+        }
+        pids
+    }
+
+    // functie care termina procesele care respecta conditia primita
+    fn kill_processes(&mut self, condition: impl Fn(&ProcessControlBlock) -> bool) -> usize {
+        let mut killed: Vec<ProcessControlBlock> = self
+            .ready_processes
+            .iter_mut()
+            .flat_map(|queue| take_processes(queue, &condition))
+            .collect();
+        killed.extend(take_processes(&mut self.waiting_processes, &condition));
+        let count = killed.len();
+        for process in killed {
+            self.observer.emit(SchedulerEvent::Exited {
+                pid: Pid::new(process.pid),
+            });
+            self.adopt_orphans(process.pid);
+            let now = self.current_time();
+            let processes = self
+                .ready_processes
+                .iter_mut()
+                .flatten()
+                .chain(self.waiting_processes.iter_mut());
+            self.zombies
+                .exit(&process, -1, processes, &mut self.pids, now);
+            self.semaphores.remove(Pid::new(process.pid));
+            self.mutexes.remove(Pid::new(process.pid));
+            self.stats.record_exit(process.execute + process.syscall);
+            let completion = (process.arrival_tick, self.current_time());
+            self.completed.insert(Pid::new(process.pid), completion);
+        }
+
+        // procesul care rula a fost terminat, asa ca nucleul sau nu mai are un proces running
+        if let Some(core) = self.running_core {
+            let running = self.ready_processes[core].front();
+            if !matches!(running, Some(process) if process.state == ProcessState::Running) {
+                self.running_core = None;
+            }
+        }
+
+        // procesele care au primit mutex-urile proceselor terminate devin ready
+        self.mutexes.wake_holders(&mut self.waiting_processes);
+        self.update_sleeping_processes();
+        count
+    }
+
+    // functie care returneaza toate procesele din scheduler, din ambele cozi
+    fn all_processes(&self) -> impl Iterator<Item = &ProcessControlBlock> {
+        self.ready_processes.iter().flatten().chain(self.waiting_processes.iter())
+    }
+
+    // functie care adauga in coada de ready un proces scos din coada de waiting
+    fn push_ready(&mut self, mut process: ProcessControlBlock) {
+        process.state = ProcessState::Ready;
+        self.ready_processes[process.core].push_back(process);
+    }
+
+    // functie care trimite semnalul primit procesului cu pid-ul target
+    fn send_signal(&mut self, target: Pid, signum: u8) -> SyscallResult {
+        if signum == SIGKILL {
+            if self.kill_processes(|process| process.pid() == target) > 0 {
+                self.stats.record_kill(target, signum);
+            }
+            return SyscallResult::Success;
+        }
+
+        // un proces care doarme sau asteapta un eveniment este trezit imediat
+        let interrupted = take_processes(&mut self.waiting_processes, |process| {
+            process.pid() == target && matches!(process.state, ProcessState::Waiting { .. })
+        });
+        // apelul de sistem care a blocat procesul trezit intoarce Interrupted, rezultat
+        // preluat de sistemul de operare cu take_wakeup_result
+        for mut process in interrupted {
+            process.sleep = 0;
+            process.wakeup_result = Some(SyscallResult::Interrupted);
+            self.push_ready(process);
+        }
+
+        // semnalul este raportat la urmatoarea decizie a planificatorului
+        let mut processes = self
+            .ready_processes
+            .iter_mut()
+            .flatten()
+            .chain(self.waiting_processes.iter_mut());
+        if let Some(process) = processes.find(|process| process.pid() == target) {
+            process.pending_signal = Some(signum);
+        }
+        SyscallResult::Success
+    }
+
+    // functie care returneaza semnalul in asteptare al procesului cu cel mai mic pid
+    fn take_pending_signal(&mut self) -> Option<(Pid, u8)> {
+        take_pending_signal(
+            self.ready_processes
+                .iter_mut()
+                .flatten()
+                .chain(self.waiting_processes.iter_mut()),
+        )
+    }
+
+    // functie care trateaza procesele copil ale procesului terminat: acestea sunt mutate
+    // sub procesul cu pid-ul 1 sau sunt terminate impreuna cu parintele, dupa orphan_policy
+    fn adopt_orphans(&mut self, parent: usize) {
+        match self.orphan_policy {
+            OrphanPolicy::Reparent => adopt_orphans(
+                self.ready_processes
+                    .iter_mut()
+                    .flatten()
+                    .chain(self.waiting_processes.iter_mut()),
+                parent,
+            ),
+            OrphanPolicy::Cascade => {
+                self.kill_processes(|process| process.parent == Some(Pid::new(parent)));
+            }
+        }
+    }
+
+    // masca tuturor core-urilor planificatorului
+    fn available_cores(&self) -> u64 {
+        (0..self.ready_processes.len().min(64)).fold(0, |mask, core| mask | (1 << core))
+    }
+
+    // functie care verifica daca masca de afinitate a procesului permite core-ul primit
+    pub(crate) fn allows(process: &ProcessControlBlock, core: usize) -> bool {
+        core < 64 && process.cpu_affinity & (1 << core) != 0
+    }
+
+    // core-ul permis procesului care are cele mai putine procese in coada,
+    // procesele noi sunt adaugate pe acesta
+    fn shortest_core(&self, process: &ProcessControlBlock) -> usize {
+        (0..self.ready_processes.len())
+            .filter(|&core| MultiCoreRoundRobin::allows(process, core))
+            .min_by_key(|&core| self.ready_processes[core].len())
+            .unwrap()
+    }
+
+    // functie care seteaza masca de afinitate a procesului curent; daca acesta nu mai
+    // poate rula pe core-ul sau, este mutat pe un core permis la urmatoarea preemptare
+    fn set_affinity(&mut self, mask: u64) -> SyscallResult {
+        let available_cores = self.available_cores();
+        let Some(core) = self.running_core else {
+            return set_affinity(None, mask, available_cores);
+        };
+
+        let result = set_affinity(self.ready_processes[core].front_mut(), mask, available_cores);
+        let process = self.ready_processes[core].front().unwrap();
+        if !MultiCoreRoundRobin::allows(process, process.core) {
+            let new_core = self.shortest_core(process);
+            self.ready_processes[core].front_mut().unwrap().core = new_core;
+        }
+        result
+    }
+
+    // cozile sunt echilibrate prin migrare sau prin work stealing, dupa modul ales
+    fn balance(&mut self) {
+        match self.balancing {
+            Balancing::Migration(threshold) => self.migrate(threshold),
+            Balancing::WorkStealing(threshold) => self.steal(threshold),
+        }
+    }
+
+    // daca diferenta dintre coada cea mai lunga si cea mai scurta depaseste pragul,
+    // ultimul proces ready din coada cea mai lunga este mutat pe core-ul cel mai liber,
+    // daca masca sa de afinitate il permite
+    fn migrate(&mut self, threshold: usize) {
+        let longest = (0..self.ready_processes.len())
+            .max_by_key(|&core| (self.ready_processes[core].len(), usize::MAX - core))
+            .unwrap();
+        let shortest = (0..self.ready_processes.len())
+            .min_by_key(|&core| self.ready_processes[core].len())
+            .unwrap();
+        if self.ready_processes[longest].len() - self.ready_processes[shortest].len() <= threshold {
+            return;
+        }
+
+        let queue = &mut self.ready_processes[longest];
+        if queue.back().is_some_and(|process| {
+            process.state == ProcessState::Ready && MultiCoreRoundRobin::allows(process, shortest)
+        }) {
+            let mut process = queue.pop_back().unwrap();
+            process.core = shortest;
+            self.ready_processes[shortest].push_back(process);
+            self.stats.migrations += 1;
+        }
+    }
+
+    // functie care verifica daca procesul core-ului se replanifica in continuare; procesul
+    // care si-a consumat tot timpul nu se replanifica, chiar daca minimul este 0
+    fn check_if_reschedule(&mut self, core: usize) -> bool {
+        let remaining_timeslice = self.remaining_timeslices[core];
+        remaining_timeslice > 0 && remaining_timeslice >= self.minimum_remaining_timeslice
+    }
+
+    fn check_if_first_process_exists(&mut self) -> bool {
+        self.waiting_processes.iter().any(|process| process.pid == 1)
+            || self
+                .ready_processes
+                .iter()
+                .any(|queue| queue.iter().any(|process| process.pid == 1))
+    }
+
+    fn check_if_all_processes_await_signal(&mut self) -> bool {
+        let mut flag = true;
+        for process in &mut self.waiting_processes {
+            match process.state {
+                ProcessState::Waiting { event: Some(_) }
+                | ProcessState::Suspended
+                | ProcessState::SemaphoreWait { .. }
+                | ProcessState::MutexWait { .. }
+                | ProcessState::WaitingForChild { .. } => (),
+                _ => flag = false,
+            }
+        }
+        flag
+    }
+
+    fn check_if_all_processes_sleep(&mut self) -> (bool, usize) {
+        let mut flag = true;
+        let mut minimum_sleep_time: Option<usize> = None;
+        for process in &mut self.waiting_processes {
+            match process.state {
+                ProcessState::Waiting { event } => match event {
+                    Some(_) => (),
+                    None => {
+                        minimum_sleep_time = Some(match minimum_sleep_time {
+                            Some(time) => time.min(process.sleep),
+                            None => process.sleep,
+                        });
+                    }
+                },
+                ProcessState::IoWait { .. } => {
+                    minimum_sleep_time = Some(match minimum_sleep_time {
+                        Some(time) => time.min(process.io_expected),
+                        None => process.io_expected,
+                    });
+                }
+                ProcessState::Suspended => (),
+                _ => flag = false,
+            }
+        }
+
+        // procesorul doarme pana la prima trezire a unui proces
+        match minimum_sleep_time {
+            Some(time) if flag && time > 0 => (true, time),
+            _ => (false, 0),
+        }
+    }
+
+    // se planifica primul proces din coada core-ului primit; procesul care ruleaza deja
+    // continua cu timeslice-ul ramas
+    fn schedule_core(&mut self, core: usize) -> SchedulingDecision {
+        self.running_cores[core] = true;
+        let now = self.current_time();
+        let first_element = self.ready_processes[core].front_mut().unwrap();
+        if first_element.state != ProcessState::Running {
+            self.remaining_timeslices[core] = self.timeslice.get();
+            self.stats.context_switches += 1;
+            first_element.state = ProcessState::Running;
+            record_first_run(first_element, now, &mut self.stats, &mut self.responses);
+        }
+        self.run_decision(core)
+    }
+
+    // functie care alege urmatorul lot de decizii, cate o decizie de rulare pentru fiecare
+    // core care are procese ready, sau o singura decizie daca niciun proces nu poate rula
+    fn schedule(&mut self) -> Vec<SchedulingDecision> {
+        self.current_core = None;
+        if self.sleep {
+            self.sleep = false;
+            self.actualise_timings(self.sleep_time);
+            self.sleep_time = 0;
+        }
+
+        // procesele care nu isi continua timeslice-ul trec la finalul cozii core-ului lor
+        for core in 0..self.ready_processes.len() {
+            let running = self.ready_processes[core].front();
+            if !matches!(running, Some(process) if process.state == ProcessState::Running)
+                || self.check_if_reschedule(core)
+            {
+                continue;
+            }
+
+            let mut current_element = self.ready_processes[core].pop_front().unwrap();
+            current_element.state = ProcessState::Ready;
+            self.observer.emit(SchedulerEvent::Preempted {
+                pid: Pid::new(current_element.pid),
+            });
+            self.ready_processes[current_element.core].push_back(current_element);
+        }
+
+        self.balance();
+
+        let cores: Vec<usize> = (0..self.ready_processes.len())
+            .filter(|&core| !self.ready_processes[core].is_empty())
+            .collect();
+        if !cores.is_empty() {
+            if !self.check_if_first_process_exists() {
+                return vec![SchedulingDecision::Panic];
+            }
+            return cores
+                .into_iter()
+                .map(|core| self.schedule_core(core))
+                .collect();
+        }
+
+        if self.waiting_processes.is_empty() {
+            return vec![SchedulingDecision::Done];
+        }
+
+        if !self.check_if_first_process_exists() {
+            return vec![SchedulingDecision::Panic];
+        }
+
+        if self.check_if_all_processes_await_signal() {
+            return vec![SchedulingDecision::Deadlock(sorted_pids(
+                self.waiting_processes.iter(),
+            ))];
+        }
+
+        // toate core-urile dorm pana la prima trezire a unui proces
+        let (check_if_sleep, minimum_time) = self.check_if_all_processes_sleep();
+        if check_if_sleep {
+            self.sleep = true;
+            self.sleep_time = minimum_time;
+            self.time += minimum_time;
+            self.stats.idle_time += minimum_time * self.ready_processes.len();
+            return vec![SchedulingDecision::Sleep(
+                NonZeroUsize::new(minimum_time).unwrap(),
+            )];
+        }
+
+        vec![SchedulingDecision::Panic]
+    }
+
+    // functie care opreste procesul care ruleaza pe core-ul primit
+    fn stop_on_core(
+        &mut self,
+        core: usize,
+        reason: StopReason,
+    ) -> Result<SyscallResult, SchedulerError> {
+        let reason = resolve_syscall(reason);
+        check_signal_target(self, reason)?;
+        if let StopReason::IoComplete { device_id } = reason {
+            // procesele care asteapta dispozitivul devin ready si sunt mutate in coada de
+            // ready, iar procesele care ruleaza nu sunt afectate
+            for process in &mut self.waiting_processes {
+                if process.state == (ProcessState::IoWait { device_id }) {
+                    process.state = ProcessState::Ready;
+                }
+            }
+            self.update_sleeping_processes();
+            return Ok(SyscallResult::Success);
+        }
+
+        self.running_core = (self.running_cores.get(core) == Some(&true)).then_some(core);
+        if let Some(core) = self.running_core {
+            record_burst(
+                self.ready_processes[core].iter_mut(),
+                reason,
+                self.remaining_timeslices[core],
+            );
+        }
+        let result = self.stop_running(reason);
+        self.running_core = None;
+        if let Some(running) = self.running_cores.get_mut(core) {
+            *running = false;
+        }
+        self.release_cores();
+        result
+    }
+
+    // functie care trateaza motivul opririi procesului de pe core-ul running_core
+    fn stop_running(&mut self, reason: StopReason) -> Result<SyscallResult, SchedulerError> {
+        Ok(match reason {
+            StopReason::Syscall { syscall, remaining } => match syscall {
+                Syscall::Fork(priority) | Syscall::ForkWithDeadline { priority, .. }
+                    if self.accepts_processes() && self.priority_range.contains(&priority) =>
+                {
+                    SyscallResult::Pid(self.fork(Some(priority), 1, remaining)[0])
+                }
+                Syscall::ForkMany(count) if count > 0 && self.accepts_processes() => {
+                    // sunt create atatea procese cate mai accepta planificatorul
+                    let free = self
+                        .max_processes
+                        .map_or(count, |max| max.get() - self.process_count());
+                    let count = self.pids.available(self.current_time(), count.min(free));
+                    SyscallResult::Pids(self.fork(None, count, remaining))
+                }
+                Syscall::Sleep(sleep_time) => {
+                    let Some(core) = self.running_core.take() else {
+                        return Err(SchedulerError::NoRunningProcess);
+                    };
+
+                    let mut current_process = self.ready_processes[core].pop_front().unwrap();
+                    current_process.state = ProcessState::Waiting { event: None };
+                    current_process.sleep = sleep_time;
+                    current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
+
+                    let execution_time = self.remaining_timeslices[core] - remaining - 1;
+                    current_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
+                    current_process.total += execution_time + 1;
+                    self.run_core(core, current_process.pid(), execution_time + 1);
+
+                    self.observer.emit(SchedulerEvent::Blocked {
+                        pid: Pid::new(current_process.pid),
+                        event: None,
+                    });
+                    self.waiting_processes.push_back(current_process);
+                    SyscallResult::Success
+                }
+                Syscall::Signal(signal_code) => {
+                    let Some(core) = self.running_core else {
+                        return Err(SchedulerError::NoRunningProcess);
+                    };
+
+                    let mut current_process = self.ready_processes[core].pop_front().unwrap();
+                    let execution_time = self.remaining_timeslices[core] - remaining - 1;
+                    current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
+                    current_process.execute += execution_time;
+                    current_process.total += execution_time + 1;
+                    self.stats.execution_time += execution_time + 1;
+                    self.run_core(core, current_process.pid(), execution_time + 1);
+
+                    self.ready_processes[core].push_front(current_process);
+                    self.remaining_timeslices[core] = remaining;
+                    self.update_waiting_processes(signal_code);
+                    SyscallResult::Success
+                }
+                syscall @ (Syscall::Wait(_) | Syscall::IoWait { .. }) => {
+                    let Some(core) = self.running_core.take() else {
+                        return Err(SchedulerError::NoRunningProcess);
+                    };
+
+                    let mut current_process = self.ready_processes[core].pop_front().unwrap();
+                    let execution_time = self.remaining_timeslices[core] - remaining - 1;
+                    current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
+                    current_process.execute += execution_time;
+                    current_process.total += execution_time + 1;
+                    self.stats.execution_time += execution_time + 1;
+                    self.run_core(core, current_process.pid(), execution_time + 1);
+                    current_process.block(syscall);
+
+                    self.observer.emit(current_process.blocked_event());
+                    self.waiting_processes.push_back(current_process);
+                    SyscallResult::Success
+                }
+                syscall @ (Syscall::SetBurstHint(_)
+                | Syscall::SetTickets(_)
+                | Syscall::SetDeadline(_)
+                | Syscall::SetPeriod(_)
+                | Syscall::WillSignal(_)
+                | Syscall::SetProcessGroup(_)
+                | Syscall::KillGroup(_)
+                | Syscall::SuspendGroup(_)
+                | Syscall::ResumeGroup(_)
+                | Syscall::Suspend(_)
+                | Syscall::Resume(_)
+                | Syscall::SendSignal { .. }
+                | Syscall::Exec { .. }
+                | Syscall::SetName(_)
+                | Syscall::SetAffinity(_)
+                | Syscall::Renice(_)
+                | Syscall::SetCpuQuota(_)
+                | Syscall::SetResources(_)
+                | Syscall::WaitChild
+                | Syscall::WaitPid(_)
+                | Syscall::WaitNamed(_)
+                | Syscall::SignalNamed(_)
+                | Syscall::SemWait(_)
+                | Syscall::SemPost(_)
+                | Syscall::MutexLock(_)
+                | Syscall::MutexUnlock(_)
+                | Syscall::Yield
+                | Syscall::Fork(_)
+                | Syscall::ForkWithDeadline { .. }
+                | Syscall::ForkMany(_)) => {
+                    let Some(core) = self.running_core else {
+                        return Err(SchedulerError::NoRunningProcess);
+                    };
+
+                    let mut current_process = self.ready_processes[core].pop_front().unwrap();
+                    let execution_time = self.remaining_timeslices[core] - remaining - 1;
+                    current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
+                    current_process.execute += execution_time;
+                    current_process.total += execution_time + 1;
+                    self.stats.execution_time += execution_time + 1;
+                    self.run_core(core, current_process.pid(), execution_time + 1);
+                    current_process.apply_attribute(syscall);
+                    self.semaphores.wait(&mut current_process, syscall);
+                    self.mutexes.apply(&mut current_process, syscall);
+                    let now = self.current_time();
+                    let processes = self
+                        .ready_processes
+                        .iter()
+                        .flatten()
+                        .chain(self.waiting_processes.iter());
+                    let waited = self.zombies.wait(
+                        &mut current_process,
+                        syscall,
+                        processes,
+                        &mut self.pids,
+                        now,
+                    );
+
+                    self.ready_processes[core].push_front(current_process);
+                    self.remaining_timeslices[core] = remaining;
+                    let result = self.update_processes(syscall);
+                    waited.unwrap_or(result)
+                }
+                Syscall::Exit => {
+                    let Some(core) = self.running_core.take() else {
+                        return Err(SchedulerError::DoubleExit);
+                    };
+
+                    let mut current_process = self.ready_processes[core].pop_front().unwrap();
+                    let execution_time = self.remaining_timeslices[core] - remaining - 1;
+                    // timpul apelului de sistem Exit este inclus in timpii procesului terminat
+                    current_process.execute += execution_time;
+                    current_process.syscall += 1;
+                    current_process.total += execution_time + 1;
+                    self.stats.execution_time += execution_time + 1;
+                    self.run_core(core, current_process.pid(), execution_time + 1);
+                    self.observer.emit(SchedulerEvent::Exited {
+                        pid: Pid::new(current_process.pid),
+                    });
+                    self.adopt_orphans(current_process.pid);
+                    // procesul ramane zombie pana cand parintele preia codul de iesire
+                    let now = self.core_time(core);
+                    let processes = self
+                        .ready_processes
+                        .iter_mut()
+                        .flatten()
+                        .chain(self.waiting_processes.iter_mut());
+                    self.zombies
+                        .exit(&current_process, 0, processes, &mut self.pids, now);
+                    // mutex-urile procesului terminat sunt date proceselor care le asteapta
+                    self.mutexes.remove(Pid::new(current_process.pid));
+                    self.mutexes.wake_holders(&mut self.waiting_processes);
+                    self.update_sleeping_processes();
+
+                    self.stats.record_exit(current_process.execute + current_process.syscall);
+                    let completion = (current_process.arrival_tick, now);
+                    self.completed.insert(Pid::new(current_process.pid), completion);
+                    SyscallResult::Success
+                }
+            },
+            // intreruperile sunt tratate de stop_core, fara a opri procesele care ruleaza
+            StopReason::IoComplete { .. } => SyscallResult::Success,
+            StopReason::Killed(signum) => {
+                let Some(core) = self.running_core.take() else {
+                    return Err(SchedulerError::NoRunningProcess);
+                };
+
+                // procesul este eliminat imediat, fara a mai fi adaugat in vreo coada
+                let pid = self.ready_processes[core].front().unwrap().pid();
+                self.kill_processes(|process| process.pid() == pid);
+                self.stats.record_kill(pid, signum);
+                SyscallResult::Success
+            }
+            StopReason::Expired => {
+                let Some(core) = self.running_core.take() else {
+                    return Err(SchedulerError::NoRunningProcess);
+                };
+
+                // procesul si-a consumat tot timeslice-ul, asa ca trece la finalul cozii core-ului
+                let mut current_process = self.ready_processes[core].pop_front().unwrap();
+                let execution_time = self.remaining_timeslices[core];
+                current_process.execute += execution_time;
+                current_process.preemptions += 1;
+                current_process.involuntary_switches += 1;
+                self.stats.execution_time += execution_time;
+                current_process.state = ProcessState::Ready;
+                self.observer.emit(SchedulerEvent::Preempted {
+                    pid: Pid::new(current_process.pid),
+                });
+                current_process.total += execution_time;
+                self.run_core(core, current_process.pid(), execution_time);
+                self.ready_processes[current_process.core].push_back(current_process);
+
+                SyscallResult::Success
+            }
+        })
+    }
+}
+
+impl MultiScheduler for MultiCoreRoundRobin {
+    fn cores(&self) -> usize {
+        self.ready_processes.len()
+    }
+
+    fn next_batch(&mut self) -> Vec<SchedulingDecision> {
+        // semnalele primite de procese sunt raportate inaintea urmatoarei planificari, iar
+        // procesele din lotul curent care nu au fost inca oprite continua sa ruleze
+        let decisions = match self.take_pending_signal() {
+            Some((pid, signum)) => vec![SchedulingDecision::DeliverSignal(pid, signum)],
+            None if self.running_cores.contains(&true) => (0..self.ready_processes.len())
+                .filter(|&core| self.running_cores[core])
+                .map(|core| self.run_decision(core))
+                .collect(),
+            None => self.schedule(),
+        };
+        for decision in &decisions {
+            self.observer.decision(decision);
+        }
+        decisions
+    }
+
+    fn stop_core(
+        &mut self,
+        core: usize,
+        reason: StopReason,
+    ) -> Result<SyscallResult, SchedulerError> {
+        self.stop_on_core(core, reason)
+    }
+}
+
+impl Scheduler for MultiCoreRoundRobin {
+    fn next(&mut self) -> SchedulingDecision {
+        // deciziile unui lot sunt returnate pe rand, cate una la fiecare apel next, iar
+        // urmatorul lot este planificat dupa ce toate procesele lotului au fost oprite
+        let decision = match self.take_pending_signal() {
+            Some((pid, signum)) => SchedulingDecision::DeliverSignal(pid, signum),
+            None => {
+                let mut batch = Vec::new();
+                if !self.running_cores.contains(&true) {
+                    batch = self.schedule();
+                }
+                let core = self
+                    .current_core
+                    .filter(|&core| self.running_cores[core])
+                    .or_else(|| self.running_cores.iter().position(|&running| running));
+                match core {
+                    Some(core) => {
+                        self.current_core = Some(core);
+                        self.run_decision(core)
+                    }
+                    None => batch.swap_remove(0),
+                }
+            }
+        };
+        self.observer.decision(&decision);
+        decision
+    }
+
+    fn drain(&mut self) {
+        self.draining = true;
+    }
+
+    fn kill(&mut self, pid: Pid) -> bool {
+        if self.kill_processes(|process| process.pid() == pid) == 0 {
+            return false;
+        }
+        self.stats.record_kill(pid, SIGKILL);
+        self.release_cores();
+        true
+    }
+
+    fn reset(&mut self) {
+        for queue in &mut self.ready_processes {
+            queue.clear();
+        }
+        self.waiting_processes.clear();
+        self.remaining_timeslices.fill(0);
+        self.pids.reset();
+        self.sleep = false;
+        self.sleep_time = 0;
+        self.stats = SchedulerStats::default();
+        self.completed.clear();
+        self.responses.clear();
+        self.running_core = None;
+        self.running_cores.fill(false);
+        self.current_core = None;
+        self.batch_elapsed.fill(0);
+        self.batch_times.clear();
+        self.time = 0;
+        self.draining = false;
+        self.semaphores.clear();
+        self.mutexes.clear();
+        self.zombies.clear();
+    }
+
+    fn process_count(&self) -> usize {
+        self.ready_processes.iter().map(|queue| queue.len()).sum::<usize>() + self.waiting_processes.len()
+    }
+
+    fn set_max_processes(&mut self, max_processes: Option<NonZeroUsize>) {
+        self.max_processes = max_processes;
+    }
+
+    fn set_priority_range(&mut self, priority_range: RangeInclusive<i8>) {
+        self.priority_range = priority_range;
+    }
+
+    fn set_pid_policy(&mut self, pid_policy: PidPolicy) {
+        self.pids.set_policy(pid_policy);
+    }
+
+    fn set_orphan_policy(&mut self, orphan_policy: OrphanPolicy) {
+        self.orphan_policy = orphan_policy;
+    }
+
+    fn peek(&self) -> SchedulingDecision {
+        // decizia este luata de o copie a planificatorului, care nu are observator
+        self.clone().next()
+    }
+
+    fn stop(&mut self, reason: StopReason) -> Result<SyscallResult, SchedulerError> {
+        // se opreste procesul ultimei decizii returnate de next; un proces creat fara ca
+        // vreun proces sa ruleze este adaugat pe core-ul 0
+        let core = self.current_core.take().unwrap_or(0);
+        self.stop_on_core(core, reason)
+    }
+
+    fn list(&mut self) -> Vec<&dyn Process> {
+        self.ready_processes
+            .iter()
+            .flatten()
+            .chain(self.waiting_processes.iter())
+            .map(|process| process as &dyn Process)
+            .collect()
+    }
+
+    fn ready_len(&self) -> usize {
+        self.ready_processes.iter().map(|queue| queue.len()).sum()
+    }
+
+    fn waiting_len(&self) -> usize {
+        self.waiting_processes.len()
+    }
+
+    fn statistics(&self) -> SchedulerStats {
+        self.stats
+    }
+
+    fn current_time(&self) -> usize {
+        self.time
+    }
+
+    fn turnaround_times(&self) -> HashMap<Pid, usize> {
+        turnaround_times(&self.completed)
+    }
+
+    fn response_times(&self) -> HashMap<Pid, usize> {
+        self.responses.clone()
+    }
+
+    fn burst_histogram(&self, pid: Pid) -> Option<BurstHistogram> {
+        find_burst_histogram(self.all_processes(), pid)
+    }
+
+    fn spawn_batch(&mut self, requests: Vec<SpawnRequest>) -> Vec<Pid> {
+        if !self.accepts_processes() {
+            return Vec::new();
+        }
+        let free = self
+            .max_processes
+            .map_or(requests.len(), |max| max.get() - self.process_count());
+        let now = self.current_time();
+        let children = spawn_processes(&mut self.pids, now, free, requests, &self.priority_range);
+        self.stats.processes_spawned += children.len();
+        let pids: Vec<Pid> = children.iter().map(|process| process.pid()).collect();
+        for mut new_process in children {
+            new_process.core = self.shortest_core(&new_process);
+            new_process.arrival_tick = self.current_time();
+            self.ready_processes[new_process.core].push_back(new_process);
+        }
+        for &child in &pids {
+            self.observer.emit(SchedulerEvent::Forked { parent: None, child });
+        }
+        pids
+    }
+
+    fn process_info(&self, pid: Pid) -> Option<ProcessInfo> {
+        find_process_info(self.all_processes(), pid).or_else(|| self.zombies.info(pid))
+    }
+
+    fn processes(&self) -> Box<dyn Iterator<Item = ProcessInfo> + '_> {
+        let pids = sorted_pids(self.all_processes());
+        Box::new(pids.into_iter().filter_map(|pid| self.process_info(pid)))
+    }
+
+    fn remaining_sleep(&self, pid: Pid) -> Option<usize> {
+        find_remaining_sleep(self.waiting_processes.iter(), pid)
+    }
+
+    fn take_wakeup_result(&mut self, pid: Pid) -> Option<SyscallResult> {
+        let processes = self
+            .ready_processes
+            .iter_mut()
+            .flatten()
+            .chain(self.waiting_processes.iter_mut());
+        take_wakeup_result(processes, pid)
+    }
+
+    fn next_wakeup(&self) -> Option<(Pid, usize)> {
+        // dupa o decizie Sleep, timpul de sleep al proceselor este scazut abia la
+        // urmatorul apel next, dar este deja adaugat la timpul curent
+        let pending = if self.sleep { self.sleep_time } else { 0 };
+        find_next_wakeup(self.waiting_processes.iter(), self.current_time() - pending)
+    }
+
+    fn set_observer(&mut self, observer: Box<dyn FnMut(SchedulerEvent) + Send>) {
+        self.observer.set(observer);
+    }
+
+    fn queue_depths(&self) -> Vec<usize> {
+        self.ready_processes.iter().map(|queue| queue.len()).collect()
+    }
+}
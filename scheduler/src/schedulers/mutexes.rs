@@ -0,0 +1,84 @@
+use crate::schedulers::ProcessControlBlock;
+use crate::{Pid, Process, ProcessState, Syscall};
+use std::collections::{HashMap, VecDeque};
+
+// mutex-urile unui planificator; fiecare mutex blocat retine procesul care il detine si
+// coada proceselor care il asteapta, in ordinea in care au apelat MutexLock
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mutexes {
+    holders: HashMap<u32, (Pid, VecDeque<Pid>)>,
+}
+
+impl Mutexes {
+    // pentru MutexLock, procesul primeste mutex-ul daca acesta este liber, altfel este
+    // blocat si adaugat in coada mutex-ului; pentru MutexUnlock, mutex-ul este dat primului
+    // proces care il asteapta; apelurile procesului care nu detine mutex-ul sunt ignorate
+    pub fn apply(&mut self, process: &mut ProcessControlBlock, syscall: Syscall) {
+        let pid = process.pid();
+        match syscall {
+            Syscall::MutexLock(mutex) => match self.holders.get_mut(&mutex) {
+                None => {
+                    self.holders.insert(mutex, (pid, VecDeque::new()));
+                }
+                Some((holder, waiting)) if *holder != pid => {
+                    waiting.push_back(pid);
+                    process.block(syscall);
+                }
+                Some(_) => (),
+            },
+            Syscall::MutexUnlock(mutex) if self.holder(mutex) == Some(pid) => self.release(mutex),
+            _ => (),
+        }
+    }
+
+    // returneaza procesul care detine mutex-ul
+    pub fn holder(&self, mutex: u32) -> Option<Pid> {
+        self.holders.get(&mutex).map(|&(holder, _)| holder)
+    }
+
+    // procesele care asteptau un mutex si l-au primit devin ready
+    pub fn wake_holders(&self, waiting_processes: &mut VecDeque<ProcessControlBlock>) {
+        for process in waiting_processes {
+            if let ProcessState::MutexWait { mutex } = process.state {
+                if self.holder(mutex) == Some(process.pid()) {
+                    process.state = ProcessState::Ready;
+                }
+            }
+        }
+    }
+
+    // scoate procesul terminat din cozile mutex-urilor; mutex-urile detinute de acesta
+    // sunt date proceselor care le asteapta
+    pub fn remove(&mut self, pid: Pid) {
+        for (_, waiting) in self.holders.values_mut() {
+            waiting.retain(|&waiter| waiter != pid);
+        }
+        let held: Vec<u32> = self
+            .holders
+            .iter()
+            .filter(|(_, &(holder, _))| holder == pid)
+            .map(|(&mutex, _)| mutex)
+            .collect();
+        for mutex in held {
+            self.release(mutex);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.holders.clear();
+    }
+
+    // mutex-ul este dat primului proces din coada sau devine liber
+    fn release(&mut self, mutex: u32) {
+        let Some((holder, waiting)) = self.holders.get_mut(&mutex) else {
+            return;
+        };
+        match waiting.pop_front() {
+            Some(next) => *holder = next,
+            None => {
+                self.holders.remove(&mutex);
+            }
+        }
+    }
+}
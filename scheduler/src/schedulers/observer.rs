@@ -0,0 +1,34 @@
+use crate::{SchedulerEvent, SchedulingDecision};
+
+// functia apelata de planificator pentru fiecare eveniment; cat timp nu este setata,
+// evenimentele nu sunt generate
+#[derive(Default)]
+pub struct Observer {
+    callback: Option<Box<dyn FnMut(SchedulerEvent) + Send>>,
+}
+
+impl Observer {
+    pub fn set(&mut self, callback: Box<dyn FnMut(SchedulerEvent) + Send>) {
+        self.callback = Some(callback);
+    }
+
+    pub fn emit(&mut self, event: SchedulerEvent) {
+        if let Some(callback) = &mut self.callback {
+            callback(event);
+        }
+    }
+
+    // fiecare decizie de rulare a unui proces genereaza un eveniment
+    pub fn decision(&mut self, decision: &SchedulingDecision) {
+        if let SchedulingDecision::Run { pid, timeslice, .. } = *decision {
+            self.emit(SchedulerEvent::Scheduled { pid, timeslice });
+        }
+    }
+}
+
+// copia unui planificator nu transmite evenimentele catre functia originala
+impl Clone for Observer {
+    fn clone(&self) -> Observer {
+        Observer::default()
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
+
+use crate::PidPolicy;
+
+// alocatorul de pid-uri al unui planificator; pid-urile noi sunt date de un contor,
+// iar pid-urile proceselor terminate sunt refolosite doar cu PidPolicy::Recycle
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PidAllocator {
+    // urmatorul pid dat de contor, None dupa ce a fost dat pid-ul Pid::MAX
+    next_pid: Option<NonZeroUsize>,
+    // pid-urile eliberate, impreuna cu momentul de la care pot fi refolosite
+    free_pids: VecDeque<(usize, usize)>,
+    policy: PidPolicy,
+}
+
+impl Default for PidAllocator {
+    fn default() -> PidAllocator {
+        PidAllocator {
+            next_pid: NonZeroUsize::new(1),
+            free_pids: VecDeque::new(),
+            policy: PidPolicy::Monotonic,
+        }
+    }
+}
+
+impl PidAllocator {
+    pub fn set_policy(&mut self, policy: PidPolicy) {
+        self.policy = policy;
+        if policy == PidPolicy::Monotonic {
+            self.free_pids.clear();
+        }
+    }
+
+    // pozitia celui mai mic pid eliberat care poate fi refolosit la momentul now
+    fn free_pid(&self, now: usize) -> Option<usize> {
+        self.free_pids
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(_, time))| time <= now)
+            .min_by_key(|&(_, &(pid, _))| pid)
+            .map(|(index, _)| index)
+    }
+
+    // functie care verifica daca mai poate fi alocat un pid la momentul now
+    pub fn can_allocate(&self, now: usize) -> bool {
+        self.free_pid(now).is_some() || self.next_pid.is_some()
+    }
+
+    // numarul de pid-uri, cel mult `count`, care mai pot fi alocate la momentul now
+    pub fn available(&self, now: usize, count: usize) -> usize {
+        let free = self.free_pids.iter().filter(|&&(_, time)| time <= now).count();
+        let new = self.next_pid.map_or(0, |pid| usize::MAX - pid.get() + 1);
+        free.saturating_add(new).min(count)
+    }
+
+    // functie care aloca un pid, refolosind mai intai pid-urile eliberate
+    pub fn allocate(&mut self, now: usize) -> Option<usize> {
+        if let Some(index) = self.free_pid(now) {
+            let (pid, _) = self.free_pids.remove(index).unwrap();
+            return Some(pid);
+        }
+
+        let pid = self.next_pid?;
+        self.next_pid = pid.checked_add(1);
+        Some(pid.get())
+    }
+
+    // pid-ul unui proces terminat poate fi refolosit dupa intarzierea din PidPolicy::Recycle;
+    // pid-ul 1 nu este refolosit niciodata
+    pub fn release(&mut self, pid: usize, now: usize) {
+        if let PidPolicy::Recycle { delay } = self.policy {
+            if pid != 1 {
+                self.free_pids.push_back((pid, now.saturating_add(delay)));
+            }
+        }
+    }
+
+    // la resetarea planificatorului se pastreaza doar politica de alocare
+    pub fn reset(&mut self) {
+        *self = PidAllocator {
+            policy: self.policy,
+            ..PidAllocator::default()
+        };
+    }
+}
@@ -0,0 +1,1068 @@
+use crate::scheduler::{Pid, Process};
+use crate::schedulers::{
+    adopt_orphans, check_signal_target, find_burst_histogram, find_next_wakeup, find_process_info,
+    find_remaining_sleep, fork_priority, record_burst, record_first_run, resolve_syscall,
+    set_affinity, sorted_pids, spawn_processes, take_pending_signal, take_processes,
+    take_wakeup_result, turnaround_times, ProcessControlBlock, Zombies, SINGLE_CORE,
+};
+use crate::{
+    BurstHistogram, ProcessState, Scheduler, SpawnRequest, Syscall, DEFAULT_PRIORITY_RANGE,
+    NICE_RANGE,
+};
+use std::collections::{HashMap, VecDeque};
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+
+use crate::schedulers::{Mutexes, Observer, PidAllocator, Semaphores};
+use crate::OrphanPolicy;
+use crate::PidPolicy;
+use crate::ProcessInfo;
+use crate::SchedulerError;
+use crate::SchedulerEvent;
+use crate::SchedulerStats;
+use crate::SchedulingDecision;
+use crate::StopReason;
+use crate::SyscallResult;
+use crate::SIGKILL;
+
+// politica unui PolicyScheduler: alege procesul care ruleaza si timpul pentru care ruleaza
+// acesta; apelurile de sistem, procesele zombie, mutex-urile, semafoarele si semnalele sunt
+// tratate de PolicyScheduler, care apeleaza politica doar pentru deciziile care difera intre
+// planificatoare; procesul care ruleaza este mereu in varful cozii de ready
+pub trait SchedulingPolicy: Clone + Send + Sized {
+    // daca este adevarat, procesul al carui timeslice a expirat ramane in varful cozii in
+    // starea de running, in loc sa fie mutat la finalul cozii de ready
+    const KEEPS_PROCESSOR: bool = false;
+
+    // pozitia din coada de ready a procesului care va rula, sau None daca niciun proces
+    // ready nu poate rula
+    fn select(_scheduler: &mut PolicyScheduler<Self>) -> Option<usize> {
+        Some(0)
+    }
+
+    // timeslice-ul procesului din varful cozii de ready, care incepe sa ruleze
+    fn timeslice(scheduler: &PolicyScheduler<Self>) -> usize;
+
+    // verifica daca procesul care ruleaza este preemptat de un proces ready
+    fn preempts(_scheduler: &PolicyScheduler<Self>) -> bool {
+        false
+    }
+
+    // timeslice-ul cu care procesul care ruleaza continua, sau None daca acesta cedeaza procesorul
+    fn continued_timeslice(scheduler: &PolicyScheduler<Self>) -> Option<usize> {
+        scheduler
+            .check_if_reschedule()
+            .then_some(scheduler.remaining_timeslice)
+    }
+
+    // decizie luata inaintea planificarii unui proces, de exemplu raportarea termenelor depasite
+    fn before_schedule(_scheduler: &mut PolicyScheduler<Self>) -> Option<SchedulingDecision> {
+        None
+    }
+
+    // adauga in coada de ready un proces care a devenit ready
+    fn enqueue(scheduler: &mut PolicyScheduler<Self>, process: ProcessControlBlock) {
+        scheduler.ready_processes.push_back(process);
+    }
+
+    // readauga in coada de ready procesul care a cedat procesorul
+    fn requeue(scheduler: &mut PolicyScheduler<Self>, process: ProcessControlBlock) {
+        scheduler.ready_processes.push_back(process);
+    }
+
+    // initializeaza un proces creat de procesul care ruleaza; `siblings` sunt procesele
+    // create inaintea lui de acelasi apel de sistem
+    fn init_child(
+        _scheduler: &PolicyScheduler<Self>,
+        _child: &mut ProcessControlBlock,
+        _siblings: &[ProcessControlBlock],
+    ) {
+    }
+
+    // apelata dupa ce procesul care ruleaza a creat un singur proces cu apelul `fork`
+    fn forked(_scheduler: &mut PolicyScheduler<Self>, _fork: Syscall) {}
+
+    // contabilizeaza timpul petrecut de proces pe procesor: `execution` unitati de executie
+    // si `syscall` unitati ale apelului de sistem care l-a oprit
+    fn charge(&mut self, _process: &mut ProcessControlBlock, _execution: usize, _syscall: usize) {}
+
+    // apelata cand procesul care ruleaza doarme sau asteapta un eveniment
+    fn blocked(&mut self, _process: &mut ProcessControlBlock) {}
+
+    // apelata cand procesul care ruleaza cedeaza procesorul fara sa se blocheze
+    fn preempted(&mut self, _process: &mut ProcessControlBlock) {}
+
+    // apelata dupa ce procesul care ruleaza si-a schimbat un atribut cu apelul `syscall`
+    fn attribute_changed(&mut self, _process: &ProcessControlBlock, _syscall: Syscall) {}
+
+    // apelata cand un proces se termina sau este omorat
+    fn exited(&mut self, _pid: usize) {}
+
+    // apelata cand trec `time` unitati de timp, inaintea actualizarii timpilor proceselor
+    fn advance(_scheduler: &mut PolicyScheduler<Self>, _time: usize) {}
+
+    // reinitializeaza starea politicii
+    fn reset(&mut self) {}
+}
+
+/// A single core scheduler with one ready queue, that takes its decisions with a
+/// [`SchedulingPolicy`].
+#[derive(Clone)]
+pub struct PolicyScheduler<P: SchedulingPolicy> {
+    pub ready_processes: VecDeque<ProcessControlBlock>,
+    pub waiting_processes: VecDeque<ProcessControlBlock>,
+    pub minimum_remaining_timeslice: usize,
+    pub remaining_timeslice: usize,
+    pub pids: PidAllocator,
+    pub sleep: bool,
+    pub sleep_time: usize,
+    pub stats: SchedulerStats,
+    pub completed: HashMap<Pid, (usize, usize)>,
+    pub responses: HashMap<Pid, usize>,
+    pub observer: Observer,
+    pub draining: bool,
+    pub max_processes: Option<NonZeroUsize>,
+    pub priority_range: RangeInclusive<i8>,
+    pub semaphores: Semaphores,
+    pub mutexes: Mutexes,
+    pub zombies: Zombies,
+    pub orphan_policy: OrphanPolicy,
+    pub policy: P,
+}
+
+impl<P: SchedulingPolicy> PolicyScheduler<P> {
+    pub fn new(minimum_remaining_timeslice: usize, policy: P) -> PolicyScheduler<P> {
+        PolicyScheduler {
+            ready_processes: VecDeque::new(),
+            waiting_processes: VecDeque::new(),
+            minimum_remaining_timeslice,
+            remaining_timeslice: 0,
+            pids: PidAllocator::default(),
+            sleep: false,
+            sleep_time: 0,
+            stats: SchedulerStats::default(),
+            observer: Observer::default(),
+            draining: false,
+            max_processes: None,
+            priority_range: DEFAULT_PRIORITY_RANGE,
+            completed: HashMap::new(),
+            responses: HashMap::new(),
+            semaphores: Semaphores::default(),
+            mutexes: Mutexes::default(),
+            zombies: Zombies::default(),
+            orphan_policy: OrphanPolicy::Reparent,
+            policy,
+        }
+    }
+
+    // functie care actualizeaza timpul total pentru toate procesele din Scheduler
+    fn actualise_timings(&mut self, timeslice: usize) {
+        P::advance(self, timeslice);
+        for process in &mut self.ready_processes {
+            process.total += timeslice;
+            if process.state == ProcessState::Ready {
+                self.stats.waiting_time += timeslice;
+            }
+        }
+        for process in &mut self.waiting_processes {
+            process.total += timeslice;
+        }
+
+        for process in &mut self.waiting_processes {
+            if process.sleep > 0 {
+                if process.sleep > timeslice {
+                    process.sleep -= timeslice;
+                } else {
+                    process.sleep = 0;
+                    process.state = ProcessState::Ready;
+                }
+            }
+        }
+
+        self.update_sleeping_processes();
+    }
+
+    // functie care muta in coada de ready procesele care si-au incheiat perioada de sleep
+    fn update_sleeping_processes(&mut self) {
+        let woken = take_processes(&mut self.waiting_processes, |process| {
+            process.state == ProcessState::Ready
+        });
+        for process in woken {
+            P::enqueue(self, process);
+        }
+    }
+
+    // functie care muta din coada de waiting procesele care asteptau semnalul primit ca paramentru
+    fn update_waiting_processes(&mut self, signal_code: usize) {
+        let woken = take_processes(&mut self.waiting_processes, |process| {
+            process.state
+                == ProcessState::Waiting {
+                    event: Some(signal_code),
+                }
+        });
+        for process in woken {
+            self.push_ready(process);
+        }
+    }
+
+    // functie care aplica apelul de sistem primit asupra altor procese decat procesul curent
+    fn update_processes(&mut self, syscall: Syscall) -> SyscallResult {
+        match syscall {
+            Syscall::KillGroup(pgid) => {
+                self.kill_processes(|process| process.in_group(pgid));
+                SyscallResult::Success
+            }
+            Syscall::SuspendGroup(pgid) => {
+                let suspended =
+                    take_processes(&mut self.ready_processes, |process| process.in_group(pgid));
+                for mut process in suspended {
+                    process.state = ProcessState::Suspended;
+                    self.waiting_processes.push_back(process);
+                }
+                SyscallResult::Success
+            }
+            Syscall::ResumeGroup(pgid) => {
+                let resumed = take_processes(&mut self.waiting_processes, |process| {
+                    process.in_group(pgid) && process.state == ProcessState::Suspended
+                });
+                for process in resumed {
+                    self.push_ready(process);
+                }
+                SyscallResult::Success
+            }
+            // procesul primit este suspendat daca este ready sau doarme
+            Syscall::Suspend(target) => {
+                let now = self.current_time();
+                let suspended =
+                    take_processes(&mut self.ready_processes, |process| process.pid() == target);
+                self.waiting_processes.extend(suspended);
+                for process in &mut self.waiting_processes {
+                    if process.pid() == target {
+                        process.suspend(now);
+                    }
+                }
+                SyscallResult::Success
+            }
+            Syscall::Resume(target) => {
+                let now = self.current_time();
+                for process in &mut self.waiting_processes {
+                    if process.pid() == target {
+                        process.resume(now);
+                    }
+                }
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            Syscall::SetAffinity(mask) => {
+                set_affinity(self.ready_processes.front_mut(), mask, SINGLE_CORE)
+            }
+            // procesul curent este mutat in coada de waiting daca a fost blocat de un
+            // semafor, de un mutex sau de asteptarea unui proces copil
+            Syscall::SemWait(_)
+            | Syscall::MutexLock(_)
+            | Syscall::WaitChild
+            | Syscall::WaitPid(_) => {
+                let blocked =
+                    take_processes(&mut self.ready_processes, |process| process.was_blocked());
+                for process in blocked {
+                    self.observer.emit(process.blocked_event());
+                    self.waiting_processes.push_back(process);
+                }
+                SyscallResult::Success
+            }
+            // primul proces care asteapta semaforul devine ready
+            Syscall::SemPost(semaphore) => {
+                if let Some(pid) = self.semaphores.post(semaphore) {
+                    for process in &mut self.waiting_processes {
+                        if process.pid() == pid {
+                            process.state = ProcessState::Ready;
+                        }
+                    }
+                    self.update_sleeping_processes();
+                }
+                SyscallResult::Success
+            }
+            // procesul care a primit mutex-ul eliberat devine ready
+            Syscall::MutexUnlock(_) => {
+                self.mutexes.wake_holders(&mut self.waiting_processes);
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            // procesul curent cedeaza procesorul si este readaugat in coada de ready
+            Syscall::Yield => {
+                let yielded = take_processes(&mut self.ready_processes, |process| {
+                    process.state == ProcessState::Running
+                });
+                for process in yielded {
+                    self.stats.yields += 1;
+                    self.push_ready(process);
+                }
+                SyscallResult::Success
+            }
+            Syscall::SendSignal { target, signum } => self.send_signal(target, signum),
+            // procesele noi trebuie sa aiba o prioritate din intervalul planificatorului
+            Syscall::Fork(priority) | Syscall::ForkWithDeadline { priority, .. }
+                if !self.priority_range.contains(&priority) =>
+            {
+                SyscallResult::InvalidArgument
+            }
+            Syscall::ForkMany(0) => SyscallResult::InvalidArgument,
+            // valoarea nice trebuie sa fie din intervalul NICE_RANGE
+            Syscall::Renice(nice) if !NICE_RANGE.contains(&nice) => SyscallResult::InvalidArgument,
+            // dupa drain sau la atingerea numarului maxim de procese nu mai sunt create procese noi
+            Syscall::Fork(_) | Syscall::ForkWithDeadline { .. } | Syscall::ForkMany(_) => {
+                if self.draining {
+                    SyscallResult::Error
+                } else {
+                    SyscallResult::NoMemory
+                }
+            }
+            _ => SyscallResult::Success,
+        }
+    }
+
+    // functie care verifica daca pot fi create procese noi
+    fn accepts_processes(&self) -> bool {
+        !self.draining
+            && self
+                .max_processes
+                .is_none_or(|max| self.process_count() < max.get())
+            && self.pids.can_allocate(self.current_time())
+    }
+
+    // functie care scoate procesul care ruleaza din coada de ready si contabilizeaza timpul
+    // petrecut de acesta pe procesor pana la apelul de sistem; returneaza procesul si timpul
+    // sau de executie, fara unitatea de timp a apelului de sistem
+    fn take_running_process(&mut self, remaining: usize) -> (ProcessControlBlock, usize) {
+        let mut running_process = self.ready_processes.pop_front().unwrap();
+        let execution_time = self.remaining_timeslice - remaining - 1;
+        running_process.syscall += 1;
+        running_process.voluntary_switches += 1;
+        running_process.execute += execution_time;
+        self.stats.execution_time += execution_time + 1;
+        self.policy.charge(&mut running_process, execution_time, 1);
+        (running_process, execution_time)
+    }
+
+    // functie care creeaza `count` procese noi, cu prioritatea primita; timpul procesului
+    // parinte este contabilizat o singura data, ca pentru un singur apel fork
+    fn fork(&mut self, priority: Option<i8>, count: usize, remaining: usize) -> Vec<Pid> {
+        let parent = self
+            .ready_processes
+            .front()
+            .map(|process| Pid::new(process.pid));
+        // procesele create de un apel fork multiplu au prioritatea procesului parinte
+        let priority = priority
+            .unwrap_or_else(|| fork_priority(self.all_processes(), parent, &self.priority_range));
+        // procesele sunt create in ordinea pid-urilor, la acelasi moment
+        let now = self.current_time();
+        let mut children = Vec::new();
+        for _ in 0..count {
+            let pid = self.pids.allocate(now).unwrap();
+            let mut new_process = ProcessControlBlock::new(pid, priority);
+            new_process.pgid = self
+                .ready_processes
+                .front()
+                .and_then(|process| process.pgid);
+            new_process.parent = parent;
+            P::init_child(self, &mut new_process, &children);
+            self.stats.processes_spawned += 1;
+            children.push(new_process);
+        }
+        let pids: Vec<Pid> = children.iter().map(|process| process.pid()).collect();
+
+        if self.ready_processes.is_empty() {
+            for mut new_process in children {
+                new_process.arrival_tick = self.current_time();
+                P::enqueue(self, new_process);
+            }
+            self.remaining_timeslice = remaining;
+        } else {
+            let (running_process, execution_time) = self.take_running_process(remaining);
+
+            // parintele ramane in varful cozii, iar copilul este adaugat in coada de ready
+            self.ready_processes.push_front(running_process);
+            self.remaining_timeslice = remaining;
+            self.actualise_timings(execution_time + 1);
+
+            for mut new_process in children {
+                new_process.arrival_tick = self.current_time();
+                P::enqueue(self, new_process);
+            }
+        }
+        for &child in &pids {
+            self.observer.emit(SchedulerEvent::Forked { parent, child });
+        }
+        pids
+    }
+
+    // functie care termina procesele care respecta conditia primita
+    fn kill_processes(&mut self, condition: impl Fn(&ProcessControlBlock) -> bool) -> usize {
+        let mut killed = take_processes(&mut self.ready_processes, &condition);
+        killed.extend(take_processes(&mut self.waiting_processes, &condition));
+        let count = killed.len();
+        for process in killed {
+            self.observer.emit(SchedulerEvent::Exited {
+                pid: Pid::new(process.pid),
+            });
+            self.adopt_orphans(process.pid);
+            let now = self.current_time();
+            let processes = self
+                .ready_processes
+                .iter_mut()
+                .chain(self.waiting_processes.iter_mut());
+            self.zombies
+                .exit(&process, -1, processes, &mut self.pids, now);
+            self.semaphores.remove(Pid::new(process.pid));
+            self.mutexes.remove(Pid::new(process.pid));
+            self.policy.exited(process.pid);
+            self.stats.record_exit(process.execute + process.syscall);
+            let completion = (process.arrival_tick, self.current_time());
+            self.completed.insert(Pid::new(process.pid), completion);
+        }
+
+        // procesele care au primit mutex-urile proceselor terminate devin ready
+        self.mutexes.wake_holders(&mut self.waiting_processes);
+        self.update_sleeping_processes();
+        count
+    }
+
+    // functie care returneaza toate procesele din scheduler, din ambele cozi
+    pub fn all_processes(&self) -> impl Iterator<Item = &ProcessControlBlock> {
+        self.ready_processes
+            .iter()
+            .chain(self.waiting_processes.iter())
+    }
+
+    // functie care adauga in coada de ready un proces scos din coada de waiting
+    fn push_ready(&mut self, mut process: ProcessControlBlock) {
+        process.state = ProcessState::Ready;
+        P::enqueue(self, process);
+    }
+
+    // functie care trimite semnalul primit procesului cu pid-ul target
+    fn send_signal(&mut self, target: Pid, signum: u8) -> SyscallResult {
+        if signum == SIGKILL {
+            if self.kill_processes(|process| process.pid() == target) > 0 {
+                self.stats.record_kill(target, signum);
+            }
+            return SyscallResult::Success;
+        }
+
+        // un proces care doarme sau asteapta un eveniment este trezit imediat
+        let interrupted = take_processes(&mut self.waiting_processes, |process| {
+            process.pid() == target && matches!(process.state, ProcessState::Waiting { .. })
+        });
+        // apelul de sistem care a blocat procesul trezit intoarce Interrupted, rezultat
+        // preluat de sistemul de operare cu take_wakeup_result
+        for mut process in interrupted {
+            process.sleep = 0;
+            process.wakeup_result = Some(SyscallResult::Interrupted);
+            self.push_ready(process);
+        }
+
+        // semnalul este raportat la urmatoarea decizie a planificatorului
+        let mut processes = self
+            .ready_processes
+            .iter_mut()
+            .chain(self.waiting_processes.iter_mut());
+        if let Some(process) = processes.find(|process| process.pid() == target) {
+            process.pending_signal = Some(signum);
+        }
+        SyscallResult::Success
+    }
+
+    // functie care returneaza semnalul in asteptare al procesului cu cel mai mic pid
+    fn take_pending_signal(&mut self) -> Option<(Pid, u8)> {
+        take_pending_signal(
+            self.ready_processes
+                .iter_mut()
+                .chain(self.waiting_processes.iter_mut()),
+        )
+    }
+
+    // functie care trateaza procesele copil ale procesului terminat: acestea sunt mutate
+    // sub procesul cu pid-ul 1 sau sunt terminate impreuna cu parintele, dupa orphan_policy
+    fn adopt_orphans(&mut self, parent: usize) {
+        match self.orphan_policy {
+            OrphanPolicy::Reparent => adopt_orphans(
+                self.ready_processes
+                    .iter_mut()
+                    .chain(self.waiting_processes.iter_mut()),
+                parent,
+            ),
+            OrphanPolicy::Cascade => {
+                self.kill_processes(|process| process.parent == Some(Pid::new(parent)));
+            }
+        }
+    }
+
+    // functie care verifica daca procesul care ruleaza se replanifica in continuare; procesul
+    // care si-a consumat tot timpul nu se replanifica, chiar daca minimul este 0
+    pub fn check_if_reschedule(&self) -> bool {
+        self.remaining_timeslice > 0 && self.remaining_timeslice >= self.minimum_remaining_timeslice
+    }
+
+    // functie care returneaza pid-ul primului proces din coada de ready
+    fn get_running_process_pid(&self) -> usize {
+        self.ready_processes.front().unwrap().pid
+    }
+
+    // functie care veridica daca procesul cu pid-ul 1 exista
+    fn check_if_first_process_exists(&self) -> bool {
+        self.all_processes().any(|process| process.pid == 1)
+    }
+
+    // functie care verifica daca primul proces din coada este in starea de running
+    fn check_if_process_is_running(&self) -> bool {
+        self.ready_processes
+            .front()
+            .is_some_and(|process| process.state == ProcessState::Running)
+    }
+
+    // functie care verifica daca s-a ajuns la starea de Deadlock
+    // verifica daca toate procesele asteapta un semnal
+    fn check_if_all_processes_await_signal(&self) -> bool {
+        self.ready_processes.is_empty()
+            && self.waiting_processes.iter().all(|process| {
+                matches!(
+                    process.state,
+                    ProcessState::Waiting { event: Some(_) }
+                        | ProcessState::Suspended
+                        | ProcessState::SemaphoreWait { .. }
+                        | ProcessState::MutexWait { .. }
+                        | ProcessState::WaitingForChild { .. }
+                )
+            })
+    }
+
+    // functie care verifica daca toate procesele se afla in starea de sleep
+    // in caz afirmativ, returneaza si timpul minim pentru care procesorul trebuie sa intre in starea de sleep
+    fn check_if_all_processes_sleep(&self) -> (bool, usize) {
+        if !self.ready_processes.is_empty() {
+            return (false, 0);
+        }
+        let mut flag = true;
+        let mut minimum_sleep_time: Option<usize> = None;
+        for process in &self.waiting_processes {
+            match process.state {
+                ProcessState::Waiting { event } => match event {
+                    Some(_) => (),
+                    None => {
+                        minimum_sleep_time = Some(match minimum_sleep_time {
+                            Some(time) => time.min(process.sleep),
+                            None => process.sleep,
+                        });
+                    }
+                },
+                ProcessState::IoWait { .. } => {
+                    minimum_sleep_time = Some(match minimum_sleep_time {
+                        Some(time) => time.min(process.io_expected),
+                        None => process.io_expected,
+                    });
+                }
+                ProcessState::Suspended => (),
+                _ => flag = false,
+            }
+        }
+
+        // procesorul doarme pana la prima trezire a unui proces
+        match minimum_sleep_time {
+            Some(time) if flag && time > 0 => (true, time),
+            _ => (false, 0),
+        }
+    }
+
+    // functie care intoarce decizia de sleep a procesorului pentru timpul primit
+    fn sleep_for(&mut self, time: NonZeroUsize) -> SchedulingDecision {
+        self.sleep = true;
+        self.sleep_time = time.get();
+        self.stats.idle_time += time.get();
+        SchedulingDecision::Sleep(time)
+    }
+
+    // functie care planifica procesul de pe pozitia primita din coada de ready
+    fn run_process(&mut self, index: usize) -> SchedulingDecision {
+        let now = self.current_time();
+        let mut process = self.ready_processes.remove(index).unwrap();
+        process.state = ProcessState::Running;
+        record_first_run(&mut process, now, &mut self.stats, &mut self.responses);
+        self.ready_processes.push_front(process);
+        self.remaining_timeslice = P::timeslice(self);
+        self.stats.context_switches += 1;
+        SchedulingDecision::Run {
+            pid: Pid::new(self.get_running_process_pid()),
+            timeslice: NonZeroUsize::new(self.remaining_timeslice).unwrap(),
+            core: 0,
+        }
+    }
+
+    // functie care alege urmatoarea decizie a planificatorului
+    fn schedule(&mut self) -> SchedulingDecision {
+        // se verifica daca iteratia trecuta procesul a intrat in starea de sleep
+        if self.sleep {
+            self.sleep = false;
+            self.actualise_timings(self.sleep_time);
+            self.sleep_time = 0;
+        }
+
+        if let Some(decision) = P::before_schedule(self) {
+            return decision;
+        }
+
+        if self.check_if_process_is_running() {
+            if !P::preempts(self) {
+                if let Some(timeslice) = P::continued_timeslice(self) {
+                    self.remaining_timeslice = timeslice;
+                    return SchedulingDecision::Run {
+                        pid: Pid::new(self.get_running_process_pid()),
+                        timeslice: NonZeroUsize::new(timeslice).unwrap(),
+                        core: 0,
+                    };
+                }
+            }
+
+            // procesul care ruleaza cedeaza procesorul si este readaugat in coada de ready
+            let mut running_process = self.ready_processes.pop_front().unwrap();
+            running_process.state = ProcessState::Ready;
+            self.observer.emit(SchedulerEvent::Preempted {
+                pid: Pid::new(running_process.pid),
+            });
+            self.policy.preempted(&mut running_process);
+            P::requeue(self, running_process);
+        }
+
+        // nu exista procese care pot fi planificate
+        if self.ready_processes.is_empty() {
+            if self.waiting_processes.is_empty() {
+                return SchedulingDecision::Done;
+            }
+
+            if !self.check_if_first_process_exists() {
+                return SchedulingDecision::Panic;
+            }
+
+            if self.check_if_all_processes_await_signal() {
+                return SchedulingDecision::Deadlock(sorted_pids(self.waiting_processes.iter()));
+            }
+
+            // verificare daca procesorul intra in starea de sleep
+            let (check_if_sleep, minimum_time) = self.check_if_all_processes_sleep();
+            if check_if_sleep {
+                return self.sleep_for(NonZeroUsize::new(minimum_time).unwrap());
+            }
+
+            return SchedulingDecision::Panic;
+        }
+
+        if !self.check_if_first_process_exists() {
+            return SchedulingDecision::Panic;
+        }
+
+        if let Some(index) = P::select(self) {
+            return self.run_process(index);
+        }
+
+        // niciun proces ready nu poate rula, procesorul doarme pana la prima trezire a unui
+        // proces sau pana la terminarea unei operatii I/O, iar daca niciun proces nu doarme,
+        // procesele sunt blocate
+        let minimum_sleep_time = self
+            .waiting_processes
+            .iter()
+            .filter_map(|process| match process.state {
+                ProcessState::Waiting { event: None } => Some(process.sleep),
+                ProcessState::IoWait { .. } => Some(process.io_expected),
+                _ => None,
+            })
+            .min();
+        match minimum_sleep_time.and_then(NonZeroUsize::new) {
+            Some(time) => self.sleep_for(time),
+            None => SchedulingDecision::Deadlock(sorted_pids(self.all_processes())),
+        }
+    }
+}
+
+impl<P: SchedulingPolicy> Scheduler for PolicyScheduler<P> {
+    fn next(&mut self) -> SchedulingDecision {
+        // semnalele primite de procese sunt raportate inaintea urmatoarei planificari
+        let decision = match self.take_pending_signal() {
+            Some((pid, signum)) => SchedulingDecision::DeliverSignal(pid, signum),
+            None => self.schedule(),
+        };
+        self.observer.decision(&decision);
+        decision
+    }
+
+    fn drain(&mut self) {
+        self.draining = true;
+    }
+
+    fn kill(&mut self, pid: Pid) -> bool {
+        if self.kill_processes(|process| process.pid() == pid) == 0 {
+            return false;
+        }
+        self.stats.record_kill(pid, SIGKILL);
+        true
+    }
+
+    fn reset(&mut self) {
+        self.ready_processes.clear();
+        self.waiting_processes.clear();
+        self.remaining_timeslice = 0;
+        self.pids.reset();
+        self.sleep = false;
+        self.sleep_time = 0;
+        self.stats = SchedulerStats::default();
+        self.completed.clear();
+        self.responses.clear();
+        self.draining = false;
+        self.semaphores.clear();
+        self.mutexes.clear();
+        self.zombies.clear();
+        self.policy.reset();
+    }
+
+    fn process_count(&self) -> usize {
+        self.ready_processes.len() + self.waiting_processes.len()
+    }
+
+    fn set_max_processes(&mut self, max_processes: Option<NonZeroUsize>) {
+        self.max_processes = max_processes;
+    }
+
+    fn set_priority_range(&mut self, priority_range: RangeInclusive<i8>) {
+        self.priority_range = priority_range;
+    }
+
+    fn set_pid_policy(&mut self, pid_policy: PidPolicy) {
+        self.pids.set_policy(pid_policy);
+    }
+
+    fn set_orphan_policy(&mut self, orphan_policy: OrphanPolicy) {
+        self.orphan_policy = orphan_policy;
+    }
+
+    fn peek(&self) -> SchedulingDecision {
+        // decizia este luata de o copie a planificatorului, care nu are observator
+        self.clone().next()
+    }
+
+    fn stop(&mut self, reason: StopReason) -> Result<SyscallResult, SchedulerError> {
+        let reason = resolve_syscall(reason);
+        check_signal_target(self, reason)?;
+        record_burst(
+            self.ready_processes.iter_mut(),
+            reason,
+            self.remaining_timeslice,
+        );
+        Ok(match reason {
+            StopReason::Syscall { syscall, remaining } => match syscall {
+                Syscall::Fork(priority) | Syscall::ForkWithDeadline { priority, .. }
+                    if self.accepts_processes() && self.priority_range.contains(&priority) =>
+                {
+                    let pid = self.fork(Some(priority), 1, remaining)[0];
+                    P::forked(self, syscall);
+                    SyscallResult::Pid(pid)
+                }
+                Syscall::ForkMany(count) if count > 0 && self.accepts_processes() => {
+                    // sunt create atatea procese cate mai accepta planificatorul
+                    let free = self
+                        .max_processes
+                        .map_or(count, |max| max.get() - self.process_count());
+                    let count = self.pids.available(self.current_time(), count.min(free));
+                    SyscallResult::Pids(self.fork(None, count, remaining))
+                }
+                Syscall::Sleep(sleep_time) => {
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
+                    }
+
+                    let (mut running_process, execution_time) =
+                        self.take_running_process(remaining);
+                    running_process.state = ProcessState::Waiting { event: None };
+                    running_process.sleep = sleep_time;
+                    running_process.total += execution_time + 1;
+                    self.policy.blocked(&mut running_process);
+
+                    self.actualise_timings(execution_time + 1);
+
+                    self.observer.emit(SchedulerEvent::Blocked {
+                        pid: Pid::new(running_process.pid),
+                        event: None,
+                    });
+                    self.waiting_processes.push_back(running_process);
+                    SyscallResult::Success
+                }
+                Syscall::Signal(signal_code) => {
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
+                    }
+
+                    let (running_process, execution_time) = self.take_running_process(remaining);
+                    self.ready_processes.push_front(running_process);
+                    self.remaining_timeslice = remaining;
+                    self.actualise_timings(execution_time + 1);
+                    self.update_waiting_processes(signal_code);
+                    SyscallResult::Success
+                }
+                syscall @ (Syscall::Wait(_) | Syscall::IoWait { .. }) => {
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
+                    }
+
+                    let (mut running_process, execution_time) =
+                        self.take_running_process(remaining);
+                    self.policy.blocked(&mut running_process);
+                    running_process.block(syscall);
+
+                    self.observer.emit(running_process.blocked_event());
+                    self.waiting_processes.push_back(running_process);
+                    self.remaining_timeslice = remaining;
+                    self.actualise_timings(execution_time + 1);
+                    SyscallResult::Success
+                }
+                syscall @ (Syscall::SetBurstHint(_)
+                | Syscall::SetTickets(_)
+                | Syscall::SetDeadline(_)
+                | Syscall::SetPeriod(_)
+                | Syscall::WillSignal(_)
+                | Syscall::SetProcessGroup(_)
+                | Syscall::KillGroup(_)
+                | Syscall::SuspendGroup(_)
+                | Syscall::ResumeGroup(_)
+                | Syscall::Suspend(_)
+                | Syscall::Resume(_)
+                | Syscall::SendSignal { .. }
+                | Syscall::Exec { .. }
+                | Syscall::SetName(_)
+                | Syscall::SetAffinity(_)
+                | Syscall::Renice(_)
+                | Syscall::SetCpuQuota(_)
+                | Syscall::SetResources(_)
+                | Syscall::WaitChild
+                | Syscall::WaitPid(_)
+                | Syscall::WaitNamed(_)
+                | Syscall::SignalNamed(_)
+                | Syscall::SemWait(_)
+                | Syscall::SemPost(_)
+                | Syscall::MutexLock(_)
+                | Syscall::MutexUnlock(_)
+                | Syscall::Yield
+                | Syscall::Fork(_)
+                | Syscall::ForkWithDeadline { .. }
+                | Syscall::ForkMany(_)) => {
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
+                    }
+
+                    let (mut running_process, execution_time) =
+                        self.take_running_process(remaining);
+                    running_process.apply_attribute(syscall);
+                    self.policy.attribute_changed(&running_process, syscall);
+                    self.semaphores.wait(&mut running_process, syscall);
+                    self.mutexes.apply(&mut running_process, syscall);
+                    let now = self.current_time();
+                    let processes = self
+                        .ready_processes
+                        .iter()
+                        .chain(self.waiting_processes.iter());
+                    let waited = self.zombies.wait(
+                        &mut running_process,
+                        syscall,
+                        processes,
+                        &mut self.pids,
+                        now,
+                    );
+
+                    self.ready_processes.push_front(running_process);
+                    self.remaining_timeslice = remaining;
+                    self.actualise_timings(execution_time + 1);
+                    let result = self.update_processes(syscall);
+                    waited.unwrap_or(result)
+                }
+                Syscall::Exit => {
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::DoubleExit);
+                    }
+
+                    let mut running_process = self.ready_processes.pop_front().unwrap();
+                    let execution_time = self.remaining_timeslice - remaining - 1;
+                    // timpul apelului de sistem Exit este inclus in timpii procesului terminat
+                    running_process.execute += execution_time;
+                    running_process.syscall += 1;
+                    running_process.total += execution_time + 1;
+                    self.observer.emit(SchedulerEvent::Exited {
+                        pid: Pid::new(running_process.pid),
+                    });
+                    self.adopt_orphans(running_process.pid);
+                    // procesul ramane zombie pana cand parintele preia codul de iesire
+                    let now = self.current_time();
+                    let processes = self
+                        .ready_processes
+                        .iter_mut()
+                        .chain(self.waiting_processes.iter_mut());
+                    self.zombies
+                        .exit(&running_process, 0, processes, &mut self.pids, now);
+                    // mutex-urile procesului terminat sunt date proceselor care le asteapta
+                    self.mutexes.remove(Pid::new(running_process.pid));
+                    self.mutexes.wake_holders(&mut self.waiting_processes);
+                    self.update_sleeping_processes();
+                    self.policy.exited(running_process.pid);
+                    self.stats.execution_time += execution_time + 1;
+
+                    self.remaining_timeslice = remaining;
+                    self.actualise_timings(execution_time + 1);
+                    self.stats
+                        .record_exit(running_process.execute + running_process.syscall);
+                    let completion = (running_process.arrival_tick, self.current_time());
+                    self.completed
+                        .insert(Pid::new(running_process.pid), completion);
+                    SyscallResult::Success
+                }
+            },
+            StopReason::IoComplete { device_id } => {
+                // procesele care asteapta dispozitivul devin ready si sunt mutate in coada de ready
+                for process in &mut self.waiting_processes {
+                    if process.state == (ProcessState::IoWait { device_id }) {
+                        process.state = ProcessState::Ready;
+                    }
+                }
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            StopReason::Killed(signum) => {
+                let Some(running_process) = self
+                    .ready_processes
+                    .front()
+                    .filter(|process| process.state == ProcessState::Running)
+                else {
+                    return Err(SchedulerError::NoRunningProcess);
+                };
+
+                // procesul este eliminat imediat, fara a mai fi adaugat in vreo coada
+                let pid = running_process.pid();
+                self.kill_processes(|process| process.pid() == pid);
+                self.stats.record_kill(pid, signum);
+                SyscallResult::Success
+            }
+            StopReason::Expired => {
+                if !self.check_if_process_is_running() {
+                    return Err(SchedulerError::NoRunningProcess);
+                }
+
+                let mut running_process = self.ready_processes.pop_front().unwrap();
+                running_process.execute += self.remaining_timeslice;
+                running_process.involuntary_switches += 1;
+                self.stats.execution_time += self.remaining_timeslice;
+                running_process.total += self.remaining_timeslice;
+                self.policy
+                    .charge(&mut running_process, self.remaining_timeslice, 0);
+                self.actualise_timings(self.remaining_timeslice);
+
+                if P::KEEPS_PROCESSOR {
+                    // procesul nu este preemptat, ramane in varful cozii in starea de running
+                    // si primeste un timeslice nou la urmatorul apel next
+                    self.remaining_timeslice = 0;
+                    self.ready_processes.push_front(running_process);
+                } else {
+                    running_process.preemptions += 1;
+                    running_process.state = ProcessState::Ready;
+                    self.observer.emit(SchedulerEvent::Preempted {
+                        pid: Pid::new(running_process.pid),
+                    });
+                    self.policy.preempted(&mut running_process);
+                    self.ready_processes.push_back(running_process);
+                }
+
+                SyscallResult::Success
+            }
+        })
+    }
+
+    // functie care returneaza o lista cu toate procesele din scheduler
+    fn list(&mut self) -> Vec<&dyn Process> {
+        self.all_processes()
+            .map(|process| process as &dyn Process)
+            .collect()
+    }
+
+    fn ready_len(&self) -> usize {
+        self.ready_processes.len()
+    }
+
+    fn waiting_len(&self) -> usize {
+        self.waiting_processes.len()
+    }
+
+    fn statistics(&self) -> SchedulerStats {
+        self.stats
+    }
+
+    fn turnaround_times(&self) -> HashMap<Pid, usize> {
+        turnaround_times(&self.completed)
+    }
+
+    fn response_times(&self) -> HashMap<Pid, usize> {
+        self.responses.clone()
+    }
+
+    fn burst_histogram(&self, pid: Pid) -> Option<BurstHistogram> {
+        find_burst_histogram(self.all_processes(), pid)
+    }
+
+    fn spawn_batch(&mut self, requests: Vec<SpawnRequest>) -> Vec<Pid> {
+        if !self.accepts_processes() {
+            return Vec::new();
+        }
+        let free = self
+            .max_processes
+            .map_or(requests.len(), |max| max.get() - self.process_count());
+        let now = self.current_time();
+        let children = spawn_processes(&mut self.pids, now, free, requests, &self.priority_range);
+        self.stats.processes_spawned += children.len();
+        let pids: Vec<Pid> = children.iter().map(|process| process.pid()).collect();
+        for mut new_process in children {
+            new_process.arrival_tick = self.current_time();
+            P::enqueue(self, new_process);
+        }
+        for &child in &pids {
+            self.observer.emit(SchedulerEvent::Forked {
+                parent: None,
+                child,
+            });
+        }
+        pids
+    }
+
+    fn process_info(&self, pid: Pid) -> Option<ProcessInfo> {
+        find_process_info(self.all_processes(), pid).or_else(|| self.zombies.info(pid))
+    }
+
+    fn processes(&self) -> Box<dyn Iterator<Item = ProcessInfo> + '_> {
+        let pids = sorted_pids(self.all_processes());
+        Box::new(pids.into_iter().filter_map(|pid| self.process_info(pid)))
+    }
+
+    fn remaining_sleep(&self, pid: Pid) -> Option<usize> {
+        find_remaining_sleep(self.waiting_processes.iter(), pid)
+    }
+
+    fn take_wakeup_result(&mut self, pid: Pid) -> Option<SyscallResult> {
+        let processes = self
+            .ready_processes
+            .iter_mut()
+            .chain(self.waiting_processes.iter_mut());
+        take_wakeup_result(processes, pid)
+    }
+
+    fn next_wakeup(&self) -> Option<(Pid, usize)> {
+        // dupa o decizie Sleep, timpul de sleep al proceselor este scazut abia la
+        // urmatorul apel next, dar este deja adaugat la timpul curent
+        let pending = if self.sleep { self.sleep_time } else { 0 };
+        find_next_wakeup(self.waiting_processes.iter(), self.current_time() - pending)
+    }
+
+    fn set_observer(&mut self, observer: Box<dyn FnMut(SchedulerEvent) + Send>) {
+        self.observer.set(observer);
+    }
+}
@@ -1,28 +1,65 @@
 use crate::scheduler::{Pid, Process};
-use crate::schedulers::ProcessControlBlock;
-use crate::{ProcessState, Scheduler, Syscall};
-use std::collections::VecDeque;
+use crate::schedulers::{
+    adopt_orphans, break_tie, check_signal_target, find_burst_histogram, find_next_wakeup,
+    find_process_info, find_remaining_sleep, fork_priority, nice_priority, record_burst,
+    record_first_run, resolve_syscall, set_affinity, sorted_pids, spawn_processes,
+    take_pending_signal, take_processes, take_wakeup_result, turnaround_times, ProcessControlBlock,
+    TimingWheel, Zombies, QUOTA_PERIOD, SINGLE_CORE,
+};
+use crate::{
+    BurstHistogram, ProcessState, Scheduler, SpawnRequest, Syscall, TieBreak, TimeUnit, NICE_RANGE,
+};
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
 use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
 
+use crate::schedulers::{Mutexes, Observer, PidAllocator, SchedulerSnapshot, Semaphores};
+use crate::OrphanPolicy;
+use crate::PidPolicy;
+use crate::ProcessInfo;
+use crate::SchedulerError;
+use crate::SchedulerEvent;
+use crate::SchedulerStats;
 use crate::SchedulingDecision;
 use crate::StopReason;
 use crate::SyscallResult;
+use crate::SIGKILL;
 
+/// A scheduler with a priority queue policy, returned by [`crate::priority_queue`].
+#[derive(Clone)]
 pub struct PriorityQueueRoundRobin {
     pub ready_processes: VecDeque<ProcessControlBlock>,
     pub waiting_processes: VecDeque<ProcessControlBlock>,
     pub timeslice: NonZeroUsize,
     pub minimum_remaining_timeslice: usize,
     pub remaining_timeslice: usize,
-    pub next_pid: usize,
+    pub pids: PidAllocator,
     pub sleep: bool,
     pub sleep_time: usize,
+    pub stats: SchedulerStats,
+    pub completed: HashMap<Pid, (usize, usize)>,
+    pub responses: HashMap<Pid, usize>,
+    pub aging_interval: usize,
+    pub tie_break: TieBreak,
+    pub observer: Observer,
+    pub draining: bool,
+    pub max_processes: Option<NonZeroUsize>,
+    pub priority_range: RangeInclusive<i8>,
+    pub semaphores: Semaphores,
+    pub mutexes: Mutexes,
+    pub zombies: Zombies,
+    pub orphan_policy: OrphanPolicy,
 }
 
 impl PriorityQueueRoundRobin {
     fn actualise_timings(&mut self, timeslice: usize) {
         for process in &mut self.ready_processes {
             process.total += timeslice;
+            if process.state == ProcessState::Ready {
+                process.wait_time = process.wait_time.saturating_add(timeslice);
+                self.stats.waiting_time += timeslice;
+            }
         }
         for process in &mut self.waiting_processes {
             process.total += timeslice;
@@ -56,25 +93,343 @@ impl PriorityQueueRoundRobin {
     fn update_waiting_processes(&mut self, signal_code: usize) {
         self.waiting_processes
             .retain(|process| match process.state {
-                ProcessState::Waiting { event } => match event {
-                    Some(signal) => {
-                        if signal == signal_code {
-                            let mut copy = process.clone();
-                            copy.state = ProcessState::Ready;
-                            self.ready_processes.push_back(copy);
-                            false
-                        } else {
-                            true
+                ProcessState::Waiting {
+                    event: Some(signal),
+                } if signal == signal_code => {
+                    let mut copy = process.clone();
+                    copy.state = ProcessState::Ready;
+                    self.ready_processes.push_back(copy);
+                    false
+                }
+                _ => true,
+            })
+    }
+
+    // functie care aplica apelul de sistem primit asupra altor procese decat procesul curent
+    fn update_processes(&mut self, syscall: Syscall) -> SyscallResult {
+        match syscall {
+            Syscall::KillGroup(pgid) => {
+                self.kill_processes(|process| process.in_group(pgid));
+                SyscallResult::Success
+            }
+            Syscall::SuspendGroup(pgid) => {
+                let suspended = take_processes(&mut self.ready_processes, |process| {
+                    process.in_group(pgid)
+                });
+                for mut process in suspended {
+                    process.state = ProcessState::Suspended;
+                    self.waiting_processes.push_back(process);
+                }
+                SyscallResult::Success
+            }
+            Syscall::ResumeGroup(pgid) => {
+                let resumed = take_processes(&mut self.waiting_processes, |process| {
+                    process.in_group(pgid) && process.state == ProcessState::Suspended
+                });
+                for process in resumed {
+                    self.push_ready(process);
+                }
+                SyscallResult::Success
+            }
+            // procesul primit este suspendat daca este ready sau doarme
+            Syscall::Suspend(target) => {
+                let now = self.current_time();
+                let suspended = take_processes(&mut self.ready_processes, |process| {
+                    process.pid() == target
+                });
+                self.waiting_processes.extend(suspended);
+                for process in &mut self.waiting_processes {
+                    if process.pid() == target {
+                        process.suspend(now);
+                    }
+                }
+                SyscallResult::Success
+            }
+            Syscall::Resume(target) => {
+                let now = self.current_time();
+                for process in &mut self.waiting_processes {
+                    if process.pid() == target {
+                        process.resume(now);
+                    }
+                }
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            Syscall::SetAffinity(mask) => {
+                set_affinity(self.ready_processes.front_mut(), mask, SINGLE_CORE)
+            }
+            // procesul curent este mutat in coada de waiting daca a fost blocat de un
+            // semafor, de un mutex sau de asteptarea unui proces copil
+            Syscall::SemWait(_)
+            | Syscall::MutexLock(_)
+            | Syscall::WaitChild
+            | Syscall::WaitPid(_) => {
+                let blocked = take_processes(&mut self.ready_processes, |process| {
+                    process.was_blocked()
+                });
+                for process in blocked {
+                    self.observer.emit(process.blocked_event());
+                    self.waiting_processes.push_back(process);
+                }
+                SyscallResult::Success
+            }
+            // primul proces care asteapta semaforul devine ready
+            Syscall::SemPost(semaphore) => {
+                if let Some(pid) = self.semaphores.post(semaphore) {
+                    for process in &mut self.waiting_processes {
+                        if process.pid() == pid {
+                            process.state = ProcessState::Ready;
                         }
                     }
-                    None => true,
-                },
-                _ => true,
+                    self.update_sleeping_processes();
+                }
+                SyscallResult::Success
+            }
+            // procesul care a primit mutex-ul eliberat devine ready
+            Syscall::MutexUnlock(_) => {
+                self.mutexes.wake_holders(&mut self.waiting_processes);
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            // procesul curent cedeaza procesorul si este readaugat in coada de ready
+            Syscall::Yield => {
+                let yielded = take_processes(&mut self.ready_processes, |process| {
+                    process.state == ProcessState::Running
+                });
+                for process in yielded {
+                    self.stats.yields += 1;
+                    self.push_ready(process);
+                }
+                SyscallResult::Success
+            }
+            Syscall::SendSignal { target, signum } => self.send_signal(target, signum),
+            // procesele noi trebuie sa aiba o prioritate din intervalul planificatorului
+            Syscall::Fork(priority) | Syscall::ForkWithDeadline { priority, .. }
+                if !self.priority_range.contains(&priority) =>
+            {
+                SyscallResult::InvalidArgument
+            }
+            Syscall::ForkMany(0) => SyscallResult::InvalidArgument,
+            // valoarea nice trebuie sa fie din intervalul NICE_RANGE
+            Syscall::Renice(nice) if !NICE_RANGE.contains(&nice) => SyscallResult::InvalidArgument,
+            // dupa drain sau la atingerea numarului maxim de procese nu mai sunt create procese noi
+            Syscall::Fork(_) | Syscall::ForkWithDeadline { .. } | Syscall::ForkMany(_) => {
+                if self.draining {
+                    SyscallResult::Error
+                } else {
+                    SyscallResult::NoMemory
+                }
+            }
+            _ => SyscallResult::Success,
+        }
+    }
+
+    // functie care verifica daca pot fi create procese noi
+    fn accepts_processes(&self) -> bool {
+        !self.draining
+            && self
+                .max_processes
+                .is_none_or(|max| self.process_count() < max.get())
+            && self.pids.can_allocate(self.current_time())
+    }
+
+    // functie care creeaza `count` procese noi, cu prioritatea primita; timpul procesului
+    // parinte este contabilizat o singura data, ca pentru un singur apel fork
+    fn fork(&mut self, priority: Option<i8>, count: usize, remaining: usize) -> Vec<Pid> {
+        let parent = self.ready_processes.front().map(|process| Pid::new(process.pid));
+        // procesele create de un apel fork multiplu au prioritatea procesului parinte
+        let priority = priority
+            .unwrap_or_else(|| fork_priority(self.all_processes(), parent, &self.priority_range));
+        // procesele sunt create in ordinea pid-urilor, la acelasi moment
+        let now = self.current_time();
+        let mut children = Vec::new();
+        for _ in 0..count {
+            let pid = self.pids.allocate(now).unwrap();
+            let mut new_process = ProcessControlBlock::new(pid, priority);
+            new_process.pgid = self
+                .ready_processes
+                .front()
+                .and_then(|process| process.pgid);
+            new_process.parent = parent;
+
+            self.stats.processes_spawned += 1;
+            children.push(new_process);
+        }
+        let pids: Vec<Pid> = children.iter().map(|process| process.pid()).collect();
+
+        if self.ready_processes.is_empty() {
+            for mut new_process in children {
+                new_process.arrival_tick = self.current_time();
+                self.ready_processes.push_back(new_process);
+            }
+            self.remaining_timeslice = remaining;
+        } else {
+            let mut process = self.ready_processes.pop_front().unwrap();
+            let execution_time = self.remaining_timeslice - remaining - 1;
+
+            process.syscall += 1;
+            process.voluntary_switches += 1;
+            process.execute += execution_time;
+            self.stats.execution_time += execution_time + 1;
+            process.inc_priority();
+            self.ready_processes.push_front(process);
+            self.remaining_timeslice = remaining;
+            self.actualise_timings(execution_time + 1);
+            for mut new_process in children {
+                new_process.arrival_tick = self.current_time();
+                self.ready_processes.push_back(new_process);
+            }
+        }
+        for &child in &pids {
+            self.observer.emit(SchedulerEvent::Forked { parent, child });
+        }
+        pids
+    }
+
+    // functie care termina procesele care respecta conditia primita
+    fn kill_processes(&mut self, condition: impl Fn(&ProcessControlBlock) -> bool) -> usize {
+        let mut killed = take_processes(&mut self.ready_processes, &condition);
+        killed.extend(take_processes(&mut self.waiting_processes, &condition));
+        let count = killed.len();
+        for process in killed {
+            self.observer.emit(SchedulerEvent::Exited {
+                pid: Pid::new(process.pid),
+            });
+            self.adopt_orphans(process.pid);
+            let now = self.current_time();
+            let processes = self
+                .ready_processes
+                .iter_mut()
+                .chain(self.waiting_processes.iter_mut());
+            self.zombies
+                .exit(&process, -1, processes, &mut self.pids, now);
+            self.semaphores.remove(Pid::new(process.pid));
+            self.mutexes.remove(Pid::new(process.pid));
+            self.stats.record_exit(process.execute + process.syscall);
+            let completion = (process.arrival_tick, self.current_time());
+            self.completed.insert(Pid::new(process.pid), completion);
+        }
+
+        // procesele care au primit mutex-urile proceselor terminate devin ready
+        self.mutexes.wake_holders(&mut self.waiting_processes);
+        self.update_sleeping_processes();
+        count
+    }
+
+    // functie care returneaza toate procesele din scheduler, din ambele cozi
+    fn all_processes(&self) -> impl Iterator<Item = &ProcessControlBlock> {
+        self.ready_processes.iter().chain(self.waiting_processes.iter())
+    }
+
+    // functie care adauga in coada de ready un proces scos din coada de waiting
+    fn push_ready(&mut self, mut process: ProcessControlBlock) {
+        process.state = ProcessState::Ready;
+        self.ready_processes.push_back(process);
+    }
+
+    // functie care trimite semnalul primit procesului cu pid-ul target
+    fn send_signal(&mut self, target: Pid, signum: u8) -> SyscallResult {
+        if signum == SIGKILL {
+            if self.kill_processes(|process| process.pid() == target) > 0 {
+                self.stats.record_kill(target, signum);
+            }
+            return SyscallResult::Success;
+        }
+
+        // un proces care doarme sau asteapta un eveniment este trezit imediat
+        let interrupted = take_processes(&mut self.waiting_processes, |process| {
+            process.pid() == target && matches!(process.state, ProcessState::Waiting { .. })
+        });
+        // apelul de sistem care a blocat procesul trezit intoarce Interrupted, rezultat
+        // preluat de sistemul de operare cu take_wakeup_result
+        for mut process in interrupted {
+            process.sleep = 0;
+            process.wakeup_result = Some(SyscallResult::Interrupted);
+            self.push_ready(process);
+        }
+
+        // semnalul este raportat la urmatoarea decizie a planificatorului
+        let mut processes = self
+            .ready_processes
+            .iter_mut()
+            .chain(self.waiting_processes.iter_mut());
+        if let Some(process) = processes.find(|process| process.pid() == target) {
+            process.pending_signal = Some(signum);
+        }
+        SyscallResult::Success
+    }
+
+    // functie care returneaza semnalul in asteptare al procesului cu cel mai mic pid
+    fn take_pending_signal(&mut self) -> Option<(Pid, u8)> {
+        take_pending_signal(
+            self.ready_processes
+                .iter_mut()
+                .chain(self.waiting_processes.iter_mut()),
+        )
+    }
+
+    // functie care trateaza procesele copil ale procesului terminat: acestea sunt mutate
+    // sub procesul cu pid-ul 1 sau sunt terminate impreuna cu parintele, dupa orphan_policy
+    fn adopt_orphans(&mut self, parent: usize) {
+        match self.orphan_policy {
+            OrphanPolicy::Reparent => adopt_orphans(
+                self.ready_processes.iter_mut().chain(self.waiting_processes.iter_mut()),
+                parent,
+            ),
+            OrphanPolicy::Cascade => {
+                self.kill_processes(|process| process.parent == Some(Pid::new(parent)));
+            }
+        }
+    }
+
+    // prioritatea unui proces creste cu un nivel pentru fiecare `aging_interval` unitati
+    // de timp petrecute in coada de ready, pana cand este planificat din nou, fara a depasi
+    // nivelul maxim al intervalului de prioritati
+    fn aged_priority(&self, process: &ProcessControlBlock) -> i8 {
+        let highest = *self.priority_range.end();
+        if self.aging_interval == 0 || process.priority >= highest {
+            return process.priority;
+        }
+        let age = process.wait_time / self.aging_interval;
+        let age = i8::try_from(age).unwrap_or(i8::MAX);
+        process.priority.saturating_add(age).min(highest)
+    }
+
+    // un proces care a anuntat ca va semnala un eveniment, sau care detine un mutex,
+    // mosteneste prioritatea proceselor care asteapta evenimentul, pana cand il semnaleaza,
+    // respectiv mutex-ul, pana cand il elibereaza
+    fn effective_priority(&self, process: &ProcessControlBlock) -> i8 {
+        self.waiting_processes
+            .iter()
+            .filter(|waiter| match waiter.state {
+                ProcessState::Waiting { event: Some(event) } => {
+                    process.announced_signals.contains(&event)
+                }
+                ProcessState::MutexWait { mutex } => {
+                    self.mutexes.holder(mutex) == Some(process.pid())
+                }
+                _ => false,
             })
+            .map(|waiter| waiter.priority)
+            .fold(self.aged_priority(process), i8::max)
+    }
+
+    // sortarea proceselor in functie de prioritatea acestora, iar in caz de egalitate, in
+    // functie de tie_break
+    fn sort_by_priority(&mut self) {
+        let mut processes: Vec<ProcessControlBlock> = self.ready_processes.drain(..).collect();
+        processes.sort_by(|a, b| {
+            Reverse(self.effective_priority(a))
+                .cmp(&Reverse(self.effective_priority(b)))
+                .then_with(|| break_tie(self.tie_break, a, b))
+        });
+        self.ready_processes = processes.into();
     }
 
+    // functie care verifica daca un proces se replanifica in continuare; procesul care
+    // si-a consumat tot timpul nu se replanifica, chiar daca minimul este 0
     fn check_if_reschedule(&mut self) -> bool {
-        self.remaining_timeslice >= self.minimum_remaining_timeslice
+        self.remaining_timeslice > 0 && self.remaining_timeslice >= self.minimum_remaining_timeslice
     }
 
     fn get_first_element(&mut self) -> usize {
@@ -103,10 +458,7 @@ impl PriorityQueueRoundRobin {
         let first_element = self.ready_processes.pop_front().unwrap();
         let copy_first_element = first_element.clone();
         self.ready_processes.push_front(first_element);
-        match copy_first_element.state {
-            ProcessState::Running => true,
-            _ => false,
-        }
+        matches!(copy_first_element.state, ProcessState::Running)
     }
 
     fn check_if_all_processes_await_signal(&mut self) -> bool {
@@ -116,10 +468,11 @@ impl PriorityQueueRoundRobin {
         let mut flag = true;
         for process in &mut self.waiting_processes {
             match process.state {
-                ProcessState::Waiting { event } => match event {
-                    Some(_) => (),
-                    None => flag = false,
-                },
+                ProcessState::Waiting { event: Some(_) }
+                | ProcessState::Suspended
+                | ProcessState::SemaphoreWait { .. }
+                | ProcessState::MutexWait { .. }
+                | ProcessState::WaitingForChild { .. } => (),
                 _ => flag = false,
             }
         }
@@ -131,26 +484,38 @@ impl PriorityQueueRoundRobin {
             return (false, 0);
         }
         let mut flag = true;
-        let mut minimum_sleep_time = 10000_usize;
+        let mut minimum_sleep_time: Option<usize> = None;
         for process in &mut self.waiting_processes {
             match process.state {
                 ProcessState::Waiting { event } => match event {
                     Some(_) => (),
                     None => {
-                        if minimum_sleep_time > process.sleep {
-                            minimum_sleep_time = process.sleep;
-                        }
+                        minimum_sleep_time = Some(match minimum_sleep_time {
+                            Some(time) => time.min(process.sleep),
+                            None => process.sleep,
+                        });
                     }
                 },
+                ProcessState::IoWait { .. } => {
+                    minimum_sleep_time = Some(match minimum_sleep_time {
+                        Some(time) => time.min(process.io_expected),
+                        None => process.io_expected,
+                    });
+                }
+                ProcessState::Suspended => (),
                 _ => flag = false,
             }
         }
-        (flag, minimum_sleep_time)
+
+        // procesorul doarme pana la prima trezire a unui proces
+        match minimum_sleep_time {
+            Some(time) if flag && time > 0 => (true, time),
+            _ => (false, 0),
+        }
     }
-}
 
-impl Scheduler for PriorityQueueRoundRobin {
-    fn next(&mut self) -> SchedulingDecision {
+    // functie care alege urmatoarea decizie a planificatorului
+    fn schedule(&mut self) -> SchedulingDecision {
         if self.sleep {
             self.sleep = false;
             self.actualise_timings(self.sleep_time);
@@ -161,23 +526,29 @@ impl Scheduler for PriorityQueueRoundRobin {
                 SchedulingDecision::Run {
                     pid: Pid::new(self.get_first_element()),
                     timeslice: NonZeroUsize::new(self.remaining_timeslice).unwrap(),
+                    core: 0,
                 }
             } else {
                 let mut current_element = self.ready_processes.pop_front().unwrap();
                 current_element.state = ProcessState::Ready;
+                self.observer.emit(SchedulerEvent::Preempted {
+                    pid: Pid::new(current_element.pid),
+                });
                 self.ready_processes.push_back(current_element);
                 self.remaining_timeslice = self.timeslice.get();
-                // sortarea proceselor in functie de prioritatea acestora
-                self.ready_processes
-                    .make_contiguous()
-                    .sort_by(|a, b| b.priority.cmp(&a.priority));
+                self.sort_by_priority();
 
+                self.stats.context_switches += 1;
+                let now = self.current_time();
                 let mut first_element = self.ready_processes.pop_front().unwrap();
                 first_element.state = ProcessState::Running;
+                record_first_run(&mut first_element, now, &mut self.stats, &mut self.responses);
+                first_element.wait_time = 0;
                 self.ready_processes.push_front(first_element);
                 SchedulingDecision::Run {
                     pid: Pid::new(self.get_first_element()),
                     timeslice: NonZeroUsize::new(self.remaining_timeslice).unwrap(),
+                    core: 0,
                 }
             }
         } else if self.ready_processes.is_empty() {
@@ -190,13 +561,14 @@ impl Scheduler for PriorityQueueRoundRobin {
             }
 
             if self.check_if_all_processes_await_signal() {
-                return SchedulingDecision::Deadlock;
+                return SchedulingDecision::Deadlock(sorted_pids(self.waiting_processes.iter()));
             }
 
             let (check_if_sleep, minimum_time) = self.check_if_all_processes_sleep();
             if check_if_sleep {
                 self.sleep = true;
                 self.sleep_time = minimum_time;
+                self.stats.idle_time += minimum_time;
                 return SchedulingDecision::Sleep(NonZeroUsize::new(minimum_time).unwrap());
             }
 
@@ -207,74 +579,223 @@ impl Scheduler for PriorityQueueRoundRobin {
             }
 
             self.remaining_timeslice = self.timeslice.get();
-            // sortarea proceselor in functie de prioritatea acestora
-            self.ready_processes
-                .make_contiguous()
-                .sort_by(|a, b| b.priority.partial_cmp(&a.priority).unwrap());
+            self.sort_by_priority();
+            self.stats.context_switches += 1;
+            let now = self.current_time();
             let mut first_element = self.ready_processes.pop_front().unwrap();
             first_element.state = ProcessState::Running;
+            record_first_run(&mut first_element, now, &mut self.stats, &mut self.responses);
+            first_element.wait_time = 0;
             self.ready_processes.push_front(first_element);
             SchedulingDecision::Run {
                 pid: Pid::new(self.get_first_element()),
                 timeslice: NonZeroUsize::new(self.remaining_timeslice).unwrap(),
+                core: 0,
             }
         }
     }
+}
+
+impl PriorityQueueRoundRobin {
+    /// Returns the time quanta that a newly scheduled process receives.
+    pub fn timeslice(&self) -> NonZeroUsize {
+        self.timeslice
+    }
+
+    /// Changes the time quanta that a process receives when it is scheduled.
+    ///
+    /// The currently running process keeps its remaining time quanta, the new
+    /// value is used starting with the next process that is scheduled. Like the
+    /// constructors, this accepts a [`TimeUnit`] or a [`NonZeroUsize`].
+    pub fn set_timeslice(&mut self, timeslice: impl Into<TimeUnit>) {
+        self.timeslice = timeslice.into().get();
+    }
+
+    /// Returns a copy of the state of the scheduler, that can be restored with
+    /// [`PriorityQueueRoundRobin::restore`], see [`Scheduler::snapshot`].
+    pub fn snapshot(&self) -> SchedulerSnapshot {
+        SchedulerSnapshot {
+            ready_processes: self.ready_processes.clone(),
+            waiting_processes: self.waiting_processes.clone(),
+            timeslice: self.timeslice,
+            minimum_remaining_timeslice: self.minimum_remaining_timeslice,
+            remaining_timeslice: self.remaining_timeslice,
+            pids: self.pids.clone(),
+            sleep: self.sleep,
+            sleep_time: self.sleep_time,
+            stats: self.stats,
+            completed: self.completed.clone(),
+            responses: self.responses.clone(),
+            draining: self.draining,
+            max_processes: self.max_processes,
+            priority_range: self.priority_range.clone(),
+            semaphores: self.semaphores.clone(),
+            mutexes: self.mutexes.clone(),
+            zombies: self.zombies.clone(),
+            orphan_policy: self.orphan_policy,
+            aging_interval: self.aging_interval,
+            tie_break: self.tie_break,
+            nice_timeslices: false,
+            idle_process: false,
+            timing_wheel: TimingWheel::default(),
+            quota_period: NonZeroUsize::new(QUOTA_PERIOD).unwrap(),
+            period_start: 0,
+        }
+    }
+
+    /// Creates a scheduler from a state saved with [`PriorityQueueRoundRobin::snapshot`].
+    ///
+    /// The restored scheduler has no observer.
+    pub fn restore(snapshot: SchedulerSnapshot) -> PriorityQueueRoundRobin {
+        PriorityQueueRoundRobin {
+            ready_processes: snapshot.ready_processes,
+            waiting_processes: snapshot.waiting_processes,
+            timeslice: snapshot.timeslice,
+            minimum_remaining_timeslice: snapshot.minimum_remaining_timeslice,
+            remaining_timeslice: snapshot.remaining_timeslice,
+            pids: snapshot.pids,
+            sleep: snapshot.sleep,
+            sleep_time: snapshot.sleep_time,
+            stats: snapshot.stats,
+            completed: snapshot.completed,
+            responses: snapshot.responses,
+            aging_interval: snapshot.aging_interval,
+            tie_break: snapshot.tie_break,
+            observer: Observer::default(),
+            draining: snapshot.draining,
+            max_processes: snapshot.max_processes,
+            priority_range: snapshot.priority_range,
+            semaphores: snapshot.semaphores,
+            mutexes: snapshot.mutexes,
+            zombies: snapshot.zombies,
+            orphan_policy: snapshot.orphan_policy,
+        }
+    }
+}
+
+impl Scheduler for PriorityQueueRoundRobin {
+    fn next(&mut self) -> SchedulingDecision {
+        // semnalele primite de procese sunt raportate inaintea urmatoarei planificari
+        let decision = match self.take_pending_signal() {
+            Some((pid, signum)) => SchedulingDecision::DeliverSignal(pid, signum),
+            None => self.schedule(),
+        };
+        self.observer.decision(&decision);
+        decision
+    }
 
-    fn stop(&mut self, reason: StopReason) -> SyscallResult {
-        match reason {
+    fn drain(&mut self) {
+        self.draining = true;
+    }
+
+    fn kill(&mut self, pid: Pid) -> bool {
+        if self.kill_processes(|process| process.pid() == pid) == 0 {
+            return false;
+        }
+        self.stats.record_kill(pid, SIGKILL);
+        true
+    }
+
+    fn reset(&mut self) {
+        self.ready_processes.clear();
+        self.waiting_processes.clear();
+        self.remaining_timeslice = 0;
+        self.pids.reset();
+        self.sleep = false;
+        self.sleep_time = 0;
+        self.stats = SchedulerStats::default();
+        self.completed.clear();
+        self.responses.clear();
+        self.draining = false;
+        self.semaphores.clear();
+        self.mutexes.clear();
+        self.zombies.clear();
+    }
+
+    fn process_count(&self) -> usize {
+        self.ready_processes.len() + self.waiting_processes.len()
+    }
+
+    fn set_max_processes(&mut self, max_processes: Option<NonZeroUsize>) {
+        self.max_processes = max_processes;
+    }
+
+    fn set_priority_range(&mut self, priority_range: RangeInclusive<i8>) {
+        self.priority_range = priority_range;
+    }
+
+    fn set_pid_policy(&mut self, pid_policy: PidPolicy) {
+        self.pids.set_policy(pid_policy);
+    }
+
+    fn set_orphan_policy(&mut self, orphan_policy: OrphanPolicy) {
+        self.orphan_policy = orphan_policy;
+    }
+
+    fn peek(&self) -> SchedulingDecision {
+        // decizia este luata de o copie a planificatorului, care nu are observator
+        self.clone().next()
+    }
+
+    fn stop(&mut self, reason: StopReason) -> Result<SyscallResult, SchedulerError> {
+        let reason = resolve_syscall(reason);
+        check_signal_target(self, reason)?;
+        record_burst(self.ready_processes.iter_mut(), reason, self.remaining_timeslice);
+        Ok(match reason {
             StopReason::Syscall { syscall, remaining } => match syscall {
-                Syscall::Fork(priority) => {
-                    let new_process = ProcessControlBlock::new(self.next_pid, priority);
-
-                    self.next_pid += 1;
-
-                    if self.ready_processes.is_empty() {
-                        self.ready_processes.push_back(new_process.clone());
-                        self.remaining_timeslice = remaining;
-                    } else {
-                        let mut process = self.ready_processes.pop_front().unwrap();
-                        let execution_time = self.remaining_timeslice - remaining - 1;
-
-                        process.syscall += 1;
-                        process.execute += execution_time;
-                        process.inc_priority();
-                        self.ready_processes.push_front(process);
-                        self.remaining_timeslice = remaining;
-                        self.actualise_timings(execution_time + 1);
-                        self.ready_processes.push_back(new_process.clone());
-                    }
-                    SyscallResult::Pid(Pid::new(new_process.pid))
+                Syscall::Fork(priority) | Syscall::ForkWithDeadline { priority, .. }
+                    if self.accepts_processes() && self.priority_range.contains(&priority) =>
+                {
+                    SyscallResult::Pid(self.fork(Some(priority), 1, remaining)[0])
+                }
+                Syscall::ForkMany(count) if count > 0 && self.accepts_processes() => {
+                    // sunt create atatea procese cate mai accepta planificatorul
+                    let free = self
+                        .max_processes
+                        .map_or(count, |max| max.get() - self.process_count());
+                    let count = self.pids.available(self.current_time(), count.min(free));
+                    SyscallResult::Pids(self.fork(None, count, remaining))
                 }
                 Syscall::Sleep(sleep_time) => {
-                    if self.ready_processes.is_empty() {
-                        return SyscallResult::NoRunningProcess;
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
                     }
 
                     let mut current_process = self.ready_processes.pop_front().unwrap();
                     current_process.state = ProcessState::Waiting { event: None };
                     current_process.sleep = sleep_time;
                     current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
                     current_process.inc_priority();
 
                     let execution_time = self.remaining_timeslice - remaining - 1;
                     current_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
                     current_process.total += execution_time + 1;
                     self.actualise_timings(execution_time + 1);
 
+                    self.observer.emit(SchedulerEvent::Blocked {
+                        pid: Pid::new(current_process.pid),
+                        event: None,
+                    });
                     self.waiting_processes.push_back(current_process);
                     SyscallResult::Success
                 }
                 Syscall::Signal(signal_code) => {
-                    if self.ready_processes.is_empty() {
-                        return SyscallResult::NoRunningProcess;
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
                     }
 
                     let mut current_process = self.ready_processes.pop_front().unwrap();
                     let execution_time = self.remaining_timeslice - remaining - 1;
                     current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
                     current_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
                     current_process.inc_priority();
+                    current_process
+                        .announced_signals
+                        .retain(|event| *event != signal_code);
 
                     self.ready_processes.push_front(current_process);
                     self.remaining_timeslice = remaining;
@@ -282,52 +803,182 @@ impl Scheduler for PriorityQueueRoundRobin {
                     self.update_waiting_processes(signal_code);
                     SyscallResult::Success
                 }
-                Syscall::Wait(event_number) => {
-                    if self.ready_processes.is_empty() {
-                        return SyscallResult::NoRunningProcess;
+                syscall @ (Syscall::Wait(_) | Syscall::IoWait { .. }) => {
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
                     }
                     let mut current_process = self.ready_processes.pop_front().unwrap();
                     let execution_time = self.remaining_timeslice - remaining - 1;
                     current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
                     current_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
                     current_process.inc_priority();
-                    current_process.state = ProcessState::Waiting {
-                        event: Some(event_number),
-                    };
+                    current_process.block(syscall);
+                    self.observer.emit(current_process.blocked_event());
                     self.waiting_processes.push_back(current_process);
                     self.remaining_timeslice = remaining;
                     self.actualise_timings(execution_time + 1);
                     SyscallResult::Success
 
                 }
+                syscall @ (Syscall::SetBurstHint(_)
+                | Syscall::SetTickets(_)
+                | Syscall::SetDeadline(_)
+                | Syscall::SetPeriod(_)
+                | Syscall::WillSignal(_)
+                | Syscall::SetProcessGroup(_)
+                | Syscall::KillGroup(_)
+                | Syscall::SuspendGroup(_)
+                | Syscall::ResumeGroup(_)
+                | Syscall::Suspend(_)
+                | Syscall::Resume(_)
+                | Syscall::SendSignal { .. }
+                | Syscall::Exec { .. }
+                | Syscall::SetName(_)
+                | Syscall::SetAffinity(_)
+                | Syscall::Renice(_)
+                | Syscall::SetCpuQuota(_)
+                | Syscall::SetResources(_)
+                | Syscall::WaitChild
+                | Syscall::WaitPid(_)
+                | Syscall::WaitNamed(_)
+                | Syscall::SignalNamed(_)
+                | Syscall::SemWait(_)
+                | Syscall::SemPost(_)
+                | Syscall::MutexLock(_)
+                | Syscall::MutexUnlock(_)
+                | Syscall::Yield
+                | Syscall::Fork(_)
+                | Syscall::ForkWithDeadline { .. }
+                | Syscall::ForkMany(_)) => {
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
+                    }
+
+                    let mut current_process = self.ready_processes.pop_front().unwrap();
+                    let execution_time = self.remaining_timeslice - remaining - 1;
+                    current_process.syscall += 1;
+                    current_process.voluntary_switches += 1;
+                    current_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
+                    current_process.inc_priority();
+                    current_process.apply_attribute(syscall);
+                    // valoarea nice devine prioritatea procesului, in intervalul planificatorului
+                    if let Syscall::Renice(nice) = syscall {
+                        if NICE_RANGE.contains(&nice) {
+                            let priority = nice_priority(nice, &self.priority_range);
+                            current_process.priority = priority;
+                            current_process.actual_priority = priority;
+                        }
+                    }
+                    self.semaphores.wait(&mut current_process, syscall);
+                    self.mutexes.apply(&mut current_process, syscall);
+                    let now = self.current_time();
+                    let processes = self
+                        .ready_processes
+                        .iter()
+                        .chain(self.waiting_processes.iter());
+                    let waited = self.zombies.wait(
+                        &mut current_process,
+                        syscall,
+                        processes,
+                        &mut self.pids,
+                        now,
+                    );
+
+                    self.ready_processes.push_front(current_process);
+                    self.remaining_timeslice = remaining;
+                    self.actualise_timings(execution_time + 1);
+                    let result = self.update_processes(syscall);
+                    waited.unwrap_or(result)
+                }
                 Syscall::Exit => {
-                    if self.ready_processes.is_empty() {
-                        return SyscallResult::NoRunningProcess;
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::DoubleExit);
                     }
 
-                    let mut _current_process = self.ready_processes.pop_front().unwrap();
+                    let mut current_process = self.ready_processes.pop_front().unwrap();
                     let execution_time = self.remaining_timeslice - remaining - 1;
+                    // timpul apelului de sistem Exit este inclus in timpii procesului terminat
+                    current_process.execute += execution_time;
+                    current_process.syscall += 1;
+                    current_process.total += execution_time + 1;
+                    self.observer.emit(SchedulerEvent::Exited {
+                        pid: Pid::new(current_process.pid),
+                    });
+                    self.adopt_orphans(current_process.pid);
+                    // procesul ramane zombie pana cand parintele preia codul de iesire
+                    let now = self.current_time();
+                    let processes = self
+                        .ready_processes
+                        .iter_mut()
+                        .chain(self.waiting_processes.iter_mut());
+                    self.zombies
+                        .exit(&current_process, 0, processes, &mut self.pids, now);
+                    // mutex-urile procesului terminat sunt date proceselor care le asteapta
+                    self.mutexes.remove(Pid::new(current_process.pid));
+                    self.mutexes.wake_holders(&mut self.waiting_processes);
+                    self.update_sleeping_processes();
+                    self.stats.execution_time += execution_time + 1;
 
                     self.remaining_timeslice = remaining;
                     self.actualise_timings(execution_time + 1);
+                    self.stats.record_exit(current_process.execute + current_process.syscall);
+                    let completion = (current_process.arrival_tick, self.current_time());
+                    self.completed.insert(Pid::new(current_process.pid), completion);
                     SyscallResult::Success
                 }
             },
+            StopReason::IoComplete { device_id } => {
+                // procesele care asteapta dispozitivul devin ready si sunt mutate in coada de ready
+                for process in &mut self.waiting_processes {
+                    if process.state == (ProcessState::IoWait { device_id }) {
+                        process.state = ProcessState::Ready;
+                    }
+                }
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            StopReason::Killed(signum) => {
+                let Some(running_process) = self
+                    .ready_processes
+                    .front()
+                    .filter(|process| process.state == ProcessState::Running)
+                else {
+                    return Err(SchedulerError::NoRunningProcess);
+                };
+
+                // procesul este eliminat imediat, fara a mai fi adaugat in vreo coada
+                let pid = running_process.pid();
+                self.kill_processes(|process| process.pid() == pid);
+                self.stats.record_kill(pid, signum);
+                SyscallResult::Success
+            }
             StopReason::Expired => {
-                if self.ready_processes.is_empty() {
-                    return SyscallResult::NoRunningProcess;
+                if !self.check_if_process_is_running() {
+                    return Err(SchedulerError::NoRunningProcess);
                 }
                 let mut current_process = self.ready_processes.pop_front().unwrap();
                 current_process.execute += self.remaining_timeslice;
+                current_process.preemptions += 1;
+                current_process.involuntary_switches += 1;
+                self.stats.execution_time += self.remaining_timeslice;
                 current_process.state = ProcessState::Ready;
+                self.observer.emit(SchedulerEvent::Preempted {
+                    pid: Pid::new(current_process.pid),
+                });
                 current_process.total += self.remaining_timeslice;
-                current_process.dec_priority();
+                // prioritatea nu scade sub nivelul minim al intervalului de prioritati
+                if current_process.priority > *self.priority_range.start() {
+                    current_process.dec_priority();
+                }
                 self.actualise_timings(self.remaining_timeslice);
                 self.ready_processes.push_back(current_process);
 
                 SyscallResult::Success
             }
-        }
+        })
     }
 
     fn list(&mut self) -> Vec<&dyn Process> {
@@ -337,4 +988,85 @@ impl Scheduler for PriorityQueueRoundRobin {
             .map(|process| process as &dyn Process)
             .collect()
     }
+
+    fn ready_len(&self) -> usize {
+        self.ready_processes.len()
+    }
+
+    fn waiting_len(&self) -> usize {
+        self.waiting_processes.len()
+    }
+
+    fn statistics(&self) -> SchedulerStats {
+        self.stats
+    }
+
+    fn turnaround_times(&self) -> HashMap<Pid, usize> {
+        turnaround_times(&self.completed)
+    }
+
+    fn response_times(&self) -> HashMap<Pid, usize> {
+        self.responses.clone()
+    }
+
+    fn burst_histogram(&self, pid: Pid) -> Option<BurstHistogram> {
+        find_burst_histogram(self.all_processes(), pid)
+    }
+
+    fn spawn_batch(&mut self, requests: Vec<SpawnRequest>) -> Vec<Pid> {
+        if !self.accepts_processes() {
+            return Vec::new();
+        }
+        let free = self
+            .max_processes
+            .map_or(requests.len(), |max| max.get() - self.process_count());
+        let now = self.current_time();
+        let children = spawn_processes(&mut self.pids, now, free, requests, &self.priority_range);
+        self.stats.processes_spawned += children.len();
+        let pids: Vec<Pid> = children.iter().map(|process| process.pid()).collect();
+        for mut new_process in children {
+            new_process.arrival_tick = self.current_time();
+            self.ready_processes.push_back(new_process);
+        }
+        for &child in &pids {
+            self.observer.emit(SchedulerEvent::Forked { parent: None, child });
+        }
+        pids
+    }
+
+    fn process_info(&self, pid: Pid) -> Option<ProcessInfo> {
+        find_process_info(self.all_processes(), pid).or_else(|| self.zombies.info(pid))
+    }
+
+    fn processes(&self) -> Box<dyn Iterator<Item = ProcessInfo> + '_> {
+        let pids = sorted_pids(self.all_processes());
+        Box::new(pids.into_iter().filter_map(|pid| self.process_info(pid)))
+    }
+
+    fn remaining_sleep(&self, pid: Pid) -> Option<usize> {
+        find_remaining_sleep(self.waiting_processes.iter(), pid)
+    }
+
+    fn take_wakeup_result(&mut self, pid: Pid) -> Option<SyscallResult> {
+        let processes = self
+            .ready_processes
+            .iter_mut()
+            .chain(self.waiting_processes.iter_mut());
+        take_wakeup_result(processes, pid)
+    }
+
+    fn next_wakeup(&self) -> Option<(Pid, usize)> {
+        // dupa o decizie Sleep, timpul de sleep al proceselor este scazut abia la
+        // urmatorul apel next, dar este deja adaugat la timpul curent
+        let pending = if self.sleep { self.sleep_time } else { 0 };
+        find_next_wakeup(self.waiting_processes.iter(), self.current_time() - pending)
+    }
+
+    fn set_observer(&mut self, observer: Box<dyn FnMut(SchedulerEvent) + Send>) {
+        self.observer.set(observer);
+    }
+
+    fn snapshot(&self) -> Option<SchedulerSnapshot> {
+        Some(PriorityQueueRoundRobin::snapshot(self))
+    }
 }
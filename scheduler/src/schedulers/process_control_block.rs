@@ -1,7 +1,18 @@
 use crate::Process;
-use crate::{Pid, ProcessState};
+use crate::{
+    BurstHistogram, Name, Pid, ProcessInfo, ProcessState, Scheduler, SchedulerError,
+    SchedulerEvent, SchedulerStats, SpawnRequest, StopReason, Syscall, SyscallResult, TieBreak,
+    NICE_RANGE,
+};
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+
+use crate::schedulers::PidAllocator;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProcessControlBlock {
     pub pid: usize,
     pub priority: i8,
@@ -13,6 +24,41 @@ pub struct ProcessControlBlock {
     pub state: ProcessState,
     pub vruntime: String,
     pub extra: usize,
+    pub burst: usize,
+    pub burst_estimate: usize,
+    pub burst_hint: Option<usize>,
+    pub level: usize,
+    pub remaining_burst: Option<usize>,
+    pub tickets: usize,
+    pub pass: usize,
+    pub deadline: Option<usize>,
+    pub wait_time: usize,
+    pub period: Option<NonZeroUsize>,
+    pub next_activation: usize,
+    pub period_execution: usize,
+    pub max_period_execution: usize,
+    pub preemptions: usize,
+    pub pgid: Option<Pid>,
+    pub parent: Option<Pid>,
+    pub pending_signal: Option<u8>,
+    pub wakeup_result: Option<SyscallResult>,
+    pub announced_signals: Vec<usize>,
+    pub name: Option<String>,
+    pub core: usize,
+    pub cpu_affinity: u64,
+    pub nice: i8,
+    pub voluntary_switches: usize,
+    pub involuntary_switches: usize,
+    pub io_expected: usize,
+    pub wake_time: Option<usize>,
+    pub cpu_quota: Option<f64>,
+    // timpul de procesor folosit pana la inceputul perioadei curente de contabilizare
+    pub period_base: usize,
+    pub throttled_ticks: usize,
+    pub arrival_tick: usize,
+    pub first_run_tick: Option<usize>,
+    pub bursts: BurstHistogram,
+    pub resources: usize,
 }
 
 // impl PartialEq for ProcessControlBlock {
@@ -54,6 +100,43 @@ impl Process for ProcessControlBlock {
     fn extra(&self) -> String {
         self.clone().vruntime
     }
+    fn wait_time(&self) -> usize {
+        self.wait_time
+    }
+    fn pgid(&self) -> Option<Pid> {
+        self.pgid
+    }
+    fn parent(&self) -> Option<Pid> {
+        self.parent
+    }
+    fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+    fn cpu_affinity(&self) -> u64 {
+        self.cpu_affinity
+    }
+    fn nice(&self) -> i8 {
+        self.nice
+    }
+    fn voluntary_switches(&self) -> usize {
+        self.voluntary_switches
+    }
+    fn involuntary_switches(&self) -> usize {
+        self.involuntary_switches
+    }
+    fn cpu_quota(&self) -> Option<f64> {
+        self.cpu_quota
+    }
+    fn resources(&self) -> usize {
+        self.resources
+    }
+    fn arrival_tick(&self) -> usize {
+        self.arrival_tick
+    }
+
+    fn first_run_tick(&self) -> Option<usize> {
+        self.first_run_tick
+    }
 }
 
 impl ProcessControlBlock {
@@ -68,6 +151,172 @@ impl ProcessControlBlock {
             self.priority += 1;
         }
     }
+
+    // la finalul unei rafale de executie, estimarea urmatoarei rafale devine
+    // media exponentiala (alpha = 1/2) dintre rafala curenta si estimarea anterioara
+    pub fn end_burst(&mut self) {
+        self.burst_estimate = (self.burst + self.burst_estimate) / 2;
+        self.burst = 0;
+    }
+
+    // actualizeaza atributele procesului in urma unui apel de sistem care nu il blocheaza
+    pub fn apply_attribute(&mut self, syscall: Syscall) {
+        match syscall {
+            Syscall::SetBurstHint(burst) => {
+                self.burst_hint = Some(burst);
+                self.remaining_burst = Some(burst);
+            }
+            Syscall::SetTickets(tickets) => self.tickets = tickets.max(1),
+            Syscall::SetDeadline(deadline) => self.deadline = Some(deadline),
+            Syscall::SetPeriod(period) => {
+                // procesul este reactivat la urmatoarea actualizare a timpului
+                self.period = Some(period);
+                self.next_activation = 0;
+                self.period_execution = 0;
+                self.max_period_execution = 0;
+            }
+            Syscall::WillSignal(event) if !self.announced_signals.contains(&event) => {
+                self.announced_signals.push(event);
+            }
+            Syscall::SetProcessGroup(pgid) => self.pgid = Some(pgid),
+            Syscall::SetName(name) => self.name = Some(name.to_string()),
+            Syscall::Renice(nice) if NICE_RANGE.contains(&nice) => self.nice = nice,
+            Syscall::SetCpuQuota(quota) => {
+                self.cpu_quota = quota
+                    .filter(|quota| !quota.is_nan())
+                    .map(|quota| quota.clamp(0.0, 1.0));
+            }
+            Syscall::SetResources(resources) => self.resources = resources,
+            Syscall::Exec { new_priority } => {
+                // noua imagine a procesului porneste ca un proces nou, cu acelasi pid
+                self.total = 0;
+                self.syscall = 0;
+                self.execute = 0;
+                self.period_base = 0;
+                self.wait_time = 0;
+                self.burst = 0;
+                self.burst_estimate = 0;
+                self.burst_hint = None;
+                self.remaining_burst = None;
+                self.level = 0;
+                if let Some(priority) = new_priority {
+                    self.priority = priority;
+                    self.actual_priority = priority;
+                }
+            }
+            _ => (),
+        }
+    }
+
+    // trece procesul in starea de asteptare ceruta de apelul de sistem primit
+    pub fn block(&mut self, syscall: Syscall) {
+        match syscall {
+            Syscall::Wait(event) => self.state = ProcessState::Waiting { event: Some(event) },
+            Syscall::IoWait {
+                device_id,
+                expected_duration,
+            } => {
+                self.state = ProcessState::IoWait { device_id };
+                // procesorul doarme cel putin o unitate de timp in asteptarea dispozitivului
+                self.io_expected = expected_duration.max(1);
+            }
+            Syscall::SemWait(semaphore) => self.state = ProcessState::SemaphoreWait { semaphore },
+            Syscall::MutexLock(mutex) => self.state = ProcessState::MutexWait { mutex },
+            _ => (),
+        }
+    }
+
+    // evenimentul transmis observatorului pentru procesul blocat
+    pub fn blocked_event(&self) -> SchedulerEvent {
+        match self.state {
+            ProcessState::IoWait { device_id } => SchedulerEvent::BlockedOnDevice {
+                pid: self.pid(),
+                device_id,
+            },
+            ProcessState::Waiting { event } => SchedulerEvent::Blocked {
+                pid: self.pid(),
+                event,
+            },
+            _ => SchedulerEvent::Blocked {
+                pid: self.pid(),
+                event: None,
+            },
+        }
+    }
+
+    // verifica daca procesul a fost blocat de un semafor, de un mutex sau de asteptarea
+    // unui proces copil
+    pub fn was_blocked(&self) -> bool {
+        matches!(
+            self.state,
+            ProcessState::SemaphoreWait { .. }
+                | ProcessState::MutexWait { .. }
+                | ProcessState::WaitingForChild { .. }
+        )
+    }
+
+    // suspenda procesul daca acesta este ready sau doarme; pentru un proces care doarme
+    // este retinut momentul la care acesta trebuie trezit
+    pub fn suspend(&mut self, now: usize) {
+        match self.state {
+            ProcessState::Ready | ProcessState::Running => self.wake_time = None,
+            ProcessState::Waiting { event: None } => self.wake_time = Some(now + self.sleep),
+            _ => return,
+        }
+        self.sleep = 0;
+        self.state = ProcessState::Suspended;
+    }
+
+    // procesul suspendat revine in starea dinaintea suspendarii; un proces care dormea
+    // doarme in continuare pana la momentul trezirii sale
+    pub fn resume(&mut self, now: usize) {
+        if self.state != ProcessState::Suspended {
+            return;
+        }
+        self.sleep = self
+            .wake_time
+            .take()
+            .map_or(0, |wake_time| wake_time.saturating_sub(now));
+        self.state = if self.sleep > 0 {
+            ProcessState::Waiting { event: None }
+        } else {
+            ProcessState::Ready
+        };
+    }
+
+    // verifica daca procesul face parte din grupul de procese al liderului primit
+    pub fn in_group(&self, pgid: Pid) -> bool {
+        self.pgid == Some(pgid)
+    }
+
+    // informatiile despre executia procesului; timpul de asteptare include si timpul
+    // in care procesul a fost blocat
+    pub fn info(&self) -> ProcessInfo {
+        ProcessInfo {
+            pid: self.pid(),
+            state: self.state,
+            cpu_ticks_consumed: self.execute + self.syscall,
+            waiting_ticks: self.total.saturating_sub(self.execute + self.syscall),
+            timeslice_preemptions: self.preemptions,
+            throttled_ticks: self.throttled_ticks,
+            vruntime: None,
+        }
+    }
+
+    // procesul a folosit in perioada curenta tot timpul de procesor pe care i-l permite
+    // cota sa, dintr-o perioada de `period` unitati de timp
+    pub fn over_quota(&self, period: usize) -> bool {
+        let usage = (self.execute + self.syscall).saturating_sub(self.period_base);
+        self.cpu_quota
+            .is_some_and(|quota| usage as f64 >= quota * period as f64)
+    }
+
+    // se scade din durata ramasa a rafalei curente timpul in care procesul a rulat
+    pub fn consume_burst(&mut self, execution_time: usize) {
+        if let Some(remaining_burst) = self.remaining_burst {
+            self.remaining_burst = Some(remaining_burst.saturating_sub(execution_time));
+        }
+    }
 }
 
 impl ProcessControlBlock {
@@ -83,6 +332,310 @@ impl ProcessControlBlock {
             state: ProcessState::Ready,
             extra: 0,
             vruntime: String::from(""),
+            burst: 0,
+            burst_estimate: 0,
+            burst_hint: None,
+            level: 0,
+            remaining_burst: None,
+            tickets: 1,
+            pass: 0,
+            deadline: None,
+            wait_time: 0,
+            period: None,
+            next_activation: 0,
+            period_execution: 0,
+            max_period_execution: 0,
+            preemptions: 0,
+            pgid: None,
+            parent: None,
+            pending_signal: None,
+            wakeup_result: None,
+            announced_signals: Vec::new(),
+            name: None,
+            core: 0,
+            cpu_affinity: !0,
+            nice: 0,
+            voluntary_switches: 0,
+            involuntary_switches: 0,
+            io_expected: 0,
+            wake_time: None,
+            cpu_quota: None,
+            period_base: 0,
+            throttled_ticks: 0,
+            arrival_tick: 0,
+            first_run_tick: None,
+            bursts: BurstHistogram::default(),
+            resources: 0,
+        }
+    }
+}
+
+// returneaza pid-urile proceselor, in ordine crescatoare
+pub fn sorted_pids<'a>(processes: impl Iterator<Item = &'a ProcessControlBlock>) -> Vec<Pid> {
+    let mut pids: Vec<Pid> = processes.map(|process| process.pid()).collect();
+    pids.sort();
+    pids
+}
+
+// returneaza informatiile despre procesul cu pid-ul primit, daca acesta exista
+pub fn find_process_info<'a>(
+    mut processes: impl Iterator<Item = &'a ProcessControlBlock>,
+    pid: Pid,
+) -> Option<ProcessInfo> {
+    processes
+        .find(|process| process.pid() == pid)
+        .map(ProcessControlBlock::info)
+}
+
+// ordinea a doua procese altfel egale; pentru Fifo procesele sunt egale, iar sortarea
+// stabila pastreaza ordinea din coada
+pub fn break_tie(
+    tie_break: TieBreak,
+    a: &ProcessControlBlock,
+    b: &ProcessControlBlock,
+) -> Ordering {
+    match tie_break {
+        TieBreak::Fifo => Ordering::Equal,
+        TieBreak::LowestPid => a.pid.cmp(&b.pid),
+        TieBreak::HighestPid => b.pid.cmp(&a.pid),
+    }
+}
+
+// returneaza prioritatea proceselor create de un apel fork multiplu: prioritatea procesului
+// parinte, adusa in intervalul planificatorului, sau cea mai mica prioritate a intervalului
+// daca nu ruleaza niciun proces
+pub fn fork_priority<'a>(
+    mut processes: impl Iterator<Item = &'a ProcessControlBlock>,
+    parent: Option<Pid>,
+    priority_range: &RangeInclusive<i8>,
+) -> i8 {
+    let (&start, &end) = (priority_range.start(), priority_range.end());
+    processes
+        .find(|process| Some(process.pid()) == parent)
+        .map_or(start, |process| process.actual_priority.clamp(start, end))
+}
+
+// returneaza prioritatea corespunzatoare valorii nice, impartind uniform intervalul
+// NICE_RANGE pe intervalul de prioritati al planificatorului: valoarea nice cea mai mica
+// primeste prioritatea cea mai mare, iar valoarea nice cea mai mare pe cea mai mica
+pub fn nice_priority(nice: i8, priority_range: &RangeInclusive<i8>) -> i8 {
+    let (start, end) = (i32::from(*priority_range.start()), i32::from(*priority_range.end()));
+    let (lowest, highest) = (i32::from(*NICE_RANGE.start()), i32::from(*NICE_RANGE.end()));
+    let offset = (i32::from(nice) - lowest) * (end - start) / (highest - lowest);
+    (end - offset) as i8
+}
+
+// returneaza timpul de turnaround al fiecarui proces terminat, din momentele sosirii si
+// terminarii acestuia
+pub fn turnaround_times(completed: &HashMap<Pid, (usize, usize)>) -> HashMap<Pid, usize> {
+    completed
+        .iter()
+        .map(|(&pid, &(arrival, completion))| (pid, completion - arrival))
+        .collect()
+}
+
+// la prima planificare a unui proces se retine momentul acesteia, iar timpul de raspuns,
+// masurat de la sosirea procesului, este adaugat in statistici
+pub fn record_first_run(
+    process: &mut ProcessControlBlock,
+    now: usize,
+    stats: &mut SchedulerStats,
+    responses: &mut HashMap<Pid, usize>,
+) {
+    if process.first_run_tick.is_none() {
+        process.first_run_tick = Some(now);
+        let response_time = now - process.arrival_tick;
+        stats.record_response(response_time);
+        responses.insert(process.pid(), response_time);
+    }
+}
+
+// creeaza procesele cerute de un apel spawn_batch, cel mult `free`, cu pid-uri consecutive
+// si fara proces parinte
+pub fn spawn_processes(
+    pids: &mut PidAllocator,
+    now: usize,
+    free: usize,
+    requests: Vec<SpawnRequest>,
+    priority_range: &RangeInclusive<i8>,
+) -> Vec<ProcessControlBlock> {
+    let count = pids.available(now, free.min(requests.len()));
+    let (&start, &end) = (priority_range.start(), priority_range.end());
+    requests
+        .into_iter()
+        .take(count)
+        .map(|request| {
+            let pid = pids.allocate(now).unwrap();
+            let mut process = ProcessControlBlock::new(pid, request.priority.clamp(start, end));
+            process.burst_hint = request.burst_hint;
+            process.remaining_burst = request.burst_hint;
+            process.nice = request.nice.clamp(*NICE_RANGE.start(), *NICE_RANGE.end());
+            process
+        })
+        .collect()
+}
+
+// rafala procesului care ruleaza se incheie la un apel de sistem sau la expirarea
+// timeslice-ului, iar lungimea ei este partea folosita din timeslice-ul ramas
+pub fn record_burst<'a>(
+    mut processes: impl Iterator<Item = &'a mut ProcessControlBlock>,
+    reason: StopReason,
+    remaining_timeslice: usize,
+) {
+    let length = match reason {
+        StopReason::Syscall { remaining, .. } => remaining_timeslice.saturating_sub(remaining),
+        StopReason::Expired => remaining_timeslice,
+        _ => return,
+    };
+    if let Some(process) = processes.find(|process| process.state == ProcessState::Running) {
+        process.bursts.record(length);
+    }
+}
+
+// returneaza rafalele procesului cu pid-ul primit, daca acesta exista
+pub fn find_burst_histogram<'a>(
+    mut processes: impl Iterator<Item = &'a ProcessControlBlock>,
+    pid: Pid,
+) -> Option<BurstHistogram> {
+    processes
+        .find(|process| process.pid() == pid)
+        .map(|process| process.bursts.clone())
+}
+
+// returneaza timpul de sleep ramas procesului cu pid-ul primit, daca acesta doarme
+pub fn find_remaining_sleep<'a>(
+    mut processes: impl Iterator<Item = &'a ProcessControlBlock>,
+    pid: Pid,
+) -> Option<usize> {
+    processes
+        .find(|process| {
+            process.pid() == pid && process.state == ProcessState::Waiting { event: None }
+        })
+        .map(|process| process.sleep)
+}
+
+// returneaza procesul care doarme si se trezeste primul, impreuna cu momentul trezirii;
+// `now` este momentul pana la care a fost scazut timpul de sleep al proceselor, iar la
+// momente egale este ales procesul cu pid-ul mai mic
+pub fn find_next_wakeup<'a>(
+    processes: impl Iterator<Item = &'a ProcessControlBlock>,
+    now: usize,
+) -> Option<(Pid, usize)> {
+    processes
+        .filter(|process| process.state == ProcessState::Waiting { event: None })
+        .map(|process| (now + process.sleep, process.pid()))
+        .min()
+        .map(|(wake_time, pid)| (pid, wake_time))
+}
+
+// masca core-urilor planificatorilor care folosesc un singur core
+pub const SINGLE_CORE: u64 = 1;
+
+// functie care seteaza masca de afinitate a procesului curent; masca trebuie sa permita
+// rularea procesului pe cel putin unul dintre core-urile disponibile
+pub fn set_affinity(
+    process: Option<&mut ProcessControlBlock>,
+    mask: u64,
+    available_cores: u64,
+) -> SyscallResult {
+    if mask & available_cores == 0 {
+        return SyscallResult::InvalidArgument;
+    }
+    if let Some(process) = process {
+        process.cpu_affinity = mask;
+    }
+    SyscallResult::Success
+}
+
+// semnalele trimise unor procese care nu exista, precum si suspendarea sau reluarea
+// acestora, sunt refuzate, inainte de a modifica planificatorul
+pub fn check_signal_target(
+    scheduler: &impl Scheduler,
+    reason: StopReason,
+) -> Result<(), SchedulerError> {
+    if let StopReason::Syscall {
+        syscall:
+            Syscall::SendSignal { target, .. } | Syscall::Suspend(target) | Syscall::Resume(target),
+        ..
+    } = reason
+    {
+        if scheduler.process_info(target).is_none() {
+            return Err(SchedulerError::InvalidPid(target));
         }
     }
+    Ok(())
+}
+
+// numele evenimentelor primesc numere de la usize::MAX in jos, dupa pozitia lor in
+// tabelul de nume, ca sa nu se suprapuna cu evenimentele numerice
+fn named_event(name: Name) -> usize {
+    usize::MAX - name.as_usize()
+}
+
+// apelurile WaitNamed si SignalNamed sunt inlocuite cu apelurile Wait si Signal pentru
+// numarul evenimentului, iar un apel Sleep(0) cu un apel Yield, ca planificatorii sa le
+// trateze la fel
+pub fn resolve_syscall(reason: StopReason) -> StopReason {
+    let StopReason::Syscall { syscall, remaining } = reason else {
+        return reason;
+    };
+    let syscall = match syscall {
+        Syscall::WaitNamed(name) => Syscall::Wait(named_event(name)),
+        Syscall::SignalNamed(name) => Syscall::Signal(named_event(name)),
+        Syscall::Sleep(0) => Syscall::Yield,
+        syscall => syscall,
+    };
+    StopReason::Syscall { syscall, remaining }
+}
+
+// procesele copil ale procesului cu pid-ul primit sunt adoptate de procesul cu pid-ul 1
+pub fn adopt_orphans<'a>(
+    processes: impl Iterator<Item = &'a mut ProcessControlBlock>,
+    parent: usize,
+) {
+    for process in processes {
+        if process.parent == Some(Pid::new(parent)) {
+            process.parent = Some(Pid::new(1));
+        }
+    }
+}
+
+// returneaza si sterge semnalul in asteptare al procesului cu cel mai mic pid
+pub fn take_pending_signal<'a>(
+    processes: impl Iterator<Item = &'a mut ProcessControlBlock>,
+) -> Option<(Pid, u8)> {
+    let process = processes
+        .filter(|process| process.pending_signal.is_some())
+        .min_by_key(|process| process.pid)?;
+    let signum = process.pending_signal.take()?;
+    Some((process.pid(), signum))
+}
+
+// returneaza si sterge rezultatul apelului de sistem al procesului cu pid-ul primit,
+// daca procesul a fost trezit de un semnal
+pub fn take_wakeup_result<'a>(
+    mut processes: impl Iterator<Item = &'a mut ProcessControlBlock>,
+    pid: Pid,
+) -> Option<SyscallResult> {
+    processes
+        .find(|process| process.pid() == pid)?
+        .wakeup_result
+        .take()
+}
+
+// scoate din coada procesele care respecta conditia primita si le returneaza, in ordinea din coada
+pub fn take_processes(
+    queue: &mut VecDeque<ProcessControlBlock>,
+    condition: impl Fn(&ProcessControlBlock) -> bool,
+) -> Vec<ProcessControlBlock> {
+    let mut taken = Vec::new();
+    queue.retain(|process| {
+        if condition(process) {
+            taken.push(process.clone());
+            false
+        } else {
+            true
+        }
+    });
+    taken
 }
@@ -0,0 +1,140 @@
+use crate::schedulers::{PolicyScheduler, ProcessControlBlock, SchedulingPolicy};
+use crate::SchedulingDecision;
+use std::cmp::Ordering;
+use std::num::NonZeroUsize;
+
+pub type RateMonotonic = PolicyScheduler<RateMonotonicPolicy>;
+
+#[derive(Clone, Default)]
+pub struct RateMonotonicPolicy {
+    pub time: usize,
+    pub utilization_exceeded: bool,
+}
+
+// perioada unui proces; procesele fara perioada sunt planificate dupa cele periodice
+fn period(process: &ProcessControlBlock) -> usize {
+    process.period.map_or(usize::MAX, NonZeroUsize::get)
+}
+
+// factorul de utilizare al proceselor periodice, suma Ci / Ti, si limita n(2^(1/n) - 1);
+// Ci este durata anuntata a rafalei sau cel mai lung timp de executie dintr-o perioada
+fn utilization(scheduler: &RateMonotonic) -> Option<(f64, f64)> {
+    let periodic: Vec<&ProcessControlBlock> = scheduler
+        .all_processes()
+        .filter(|process| process.period.is_some())
+        .collect();
+    if periodic.is_empty() {
+        return None;
+    }
+    let utilization = periodic
+        .iter()
+        .map(|process| {
+            let execution = process
+                .burst_hint
+                .unwrap_or(process.max_period_execution.max(process.period_execution));
+            execution as f64 / period(process) as f64
+        })
+        .sum();
+    let n = periodic.len() as f64;
+    Some((utilization, n * (2_f64.powf(1.0 / n) - 1.0)))
+}
+
+// procesul ruleaza pana la trezirea primului proces din starea de sleep sau pana la
+// urmatoarea reactivare a unui proces periodic, moment in care se verifica daca trebuie preemptat
+fn dispatch_timeslice(scheduler: &RateMonotonic) -> usize {
+    let time = scheduler.policy.time;
+    let next_wake = scheduler
+        .waiting_processes
+        .iter()
+        .filter(|process| process.sleep > 0)
+        .map(|process| process.sleep);
+    let next_activation = scheduler
+        .all_processes()
+        .filter(|process| process.period.is_some())
+        .map(|process| process.next_activation.saturating_sub(time).max(1));
+    next_wake
+        .chain(next_activation)
+        .fold(usize::MAX, usize::min)
+}
+
+impl SchedulingPolicy for RateMonotonicPolicy {
+    // prioritatile sunt statice: se sorteaza procesele dupa perioada,
+    // iar in caz de egalitate, se ia procesul cu pid-ul minim
+    fn select(scheduler: &mut RateMonotonic) -> Option<usize> {
+        scheduler.ready_processes.make_contiguous().sort_by(|a, b| {
+            let compare_by_period = period(a).cmp(&period(b));
+            if compare_by_period == Ordering::Equal {
+                a.pid.cmp(&b.pid)
+            } else {
+                compare_by_period
+            }
+        });
+        Some(0)
+    }
+
+    fn timeslice(scheduler: &RateMonotonic) -> usize {
+        dispatch_timeslice(scheduler)
+    }
+
+    // procesul curent este preemptat daca exista un proces ready cu o perioada mai scurta
+    fn preempts(scheduler: &RateMonotonic) -> bool {
+        let current_period = period(scheduler.ready_processes.front().unwrap());
+        scheduler
+            .ready_processes
+            .iter()
+            .skip(1)
+            .any(|process| period(process) < current_period)
+    }
+
+    fn continued_timeslice(scheduler: &RateMonotonic) -> Option<usize> {
+        scheduler.check_if_reschedule().then(|| {
+            scheduler
+                .remaining_timeslice
+                .min(dispatch_timeslice(scheduler))
+        })
+    }
+
+    // depasirea limitei este raportata o singura data, pana cand factorul de utilizare scade
+    fn before_schedule(scheduler: &mut RateMonotonic) -> Option<SchedulingDecision> {
+        match utilization(scheduler) {
+            Some((utilization, bound)) if utilization > bound => {
+                if !scheduler.policy.utilization_exceeded {
+                    scheduler.policy.utilization_exceeded = true;
+                    return Some(SchedulingDecision::UtilizationExceeded { utilization, bound });
+                }
+            }
+            _ => scheduler.policy.utilization_exceeded = false,
+        }
+        None
+    }
+
+    fn charge(&mut self, process: &mut ProcessControlBlock, execution: usize, syscall: usize) {
+        process.period_execution += execution + syscall;
+    }
+
+    // la fiecare inceput de perioada, procesele periodice sunt reactivate, iar timpul de executie
+    // din perioada care s-a incheiat este retinut pentru calcularea factorului de utilizare
+    fn advance(scheduler: &mut RateMonotonic, time: usize) {
+        scheduler.policy.time += time;
+        let time = scheduler.policy.time;
+        for process in scheduler
+            .ready_processes
+            .iter_mut()
+            .chain(scheduler.waiting_processes.iter_mut())
+        {
+            if let Some(period) = process.period {
+                if process.next_activation <= time {
+                    process.max_period_execution =
+                        process.max_period_execution.max(process.period_execution);
+                    process.period_execution = 0;
+                    process.next_activation = time + period.get();
+                }
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.time = 0;
+        self.utilization_exceeded = false;
+    }
+}
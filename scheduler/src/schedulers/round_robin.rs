@@ -1,40 +1,91 @@
 use crate::scheduler::{Pid, Process};
-use crate::schedulers::ProcessControlBlock;
-use crate::{ProcessState, Scheduler, Syscall};
-use std::collections::VecDeque;
+use crate::schedulers::{
+    adopt_orphans, check_signal_target, find_burst_histogram, find_next_wakeup, find_process_info,
+    find_remaining_sleep, fork_priority, record_burst, record_first_run, resolve_syscall,
+    set_affinity, sorted_pids, spawn_processes, take_pending_signal, take_processes,
+    take_wakeup_result, turnaround_times, ProcessControlBlock, Zombies, SINGLE_CORE,
+};
+use crate::{
+    BurstHistogram, ProcessState, Scheduler, SpawnRequest, Syscall, TieBreak, TimeUnit, NICE_RANGE,
+};
+use std::collections::{HashMap, VecDeque};
 use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
 
+use crate::schedulers::{
+    Comparator, Mutexes, Observer, PidAllocator, SchedulerSnapshot, Semaphores, TimingWheel,
+};
+use crate::OrphanPolicy;
+use crate::PidPolicy;
+use crate::ProcessInfo;
+use crate::SchedulerError;
+use crate::SchedulerEvent;
+use crate::SchedulerStats;
 use crate::SchedulingDecision;
 use crate::StopReason;
 use crate::SyscallResult;
+use crate::SIGKILL;
 
+/// A scheduler with a round robin policy, returned by [`crate::round_robin`].
+#[derive(Clone)]
 pub struct RoundRobin {
     pub ready_processes: VecDeque<ProcessControlBlock>,
     pub waiting_processes: VecDeque<ProcessControlBlock>,
     pub timeslice: NonZeroUsize,
     pub minimum_remaining_timeslice: usize,
     pub remaining_timeslice: usize,
-    pub next_pid: usize,
+    pub pids: PidAllocator,
     pub sleep: bool,
     pub sleep_time: usize,
+    pub stats: SchedulerStats,
+    pub completed: HashMap<Pid, (usize, usize)>,
+    pub responses: HashMap<Pid, usize>,
+    pub observer: Observer,
+    pub draining: bool,
+    pub max_processes: Option<NonZeroUsize>,
+    pub priority_range: RangeInclusive<i8>,
+    pub semaphores: Semaphores,
+    pub mutexes: Mutexes,
+    pub zombies: Zombies,
+    pub orphan_policy: OrphanPolicy,
+    pub nice_timeslices: bool,
+    pub idle_process: bool,
+    pub timing_wheel: TimingWheel,
+    pub comparator: Comparator,
+    pub quota_period: NonZeroUsize,
+    pub period_start: usize,
 }
 
+// durata implicita a perioadei de contabilizare a cotelor de procesor
+pub const QUOTA_PERIOD: usize = 100;
+
 impl RoundRobin {
     // functie care actualizeaza timpul total pentru toate procesele din Scheduler
     fn actualise_timings(&mut self, timeslice: usize) {
         for process in &mut self.ready_processes {
             process.total += timeslice;
+            if process.state == ProcessState::Ready {
+                self.stats.waiting_time += timeslice;
+            }
         }
         for process in &mut self.waiting_processes {
             process.total += timeslice;
+            process.sleep = process.sleep.saturating_sub(timeslice);
+            if process.state == ProcessState::Throttled {
+                process.throttled_ticks += timeslice;
+                self.stats.throttled_ticks += timeslice;
+            }
         }
 
-        for process in &mut self.waiting_processes {
-            if process.sleep > 0 {
-                if process.sleep > timeslice {
-                    process.sleep -= timeslice;
-                } else {
-                    process.sleep = 0;
+        // procesele care se trezesc sunt date de roata de timp; procesele intrerupte
+        // intre timp nu mai dorm si sunt ignorate
+        let woken = self.timing_wheel.advance(timeslice);
+        if !woken.is_empty() {
+            for process in &mut self.waiting_processes {
+                if woken.contains(&process.pid)
+                    && process.state == (ProcessState::Waiting { event: None })
+                    && process.sleep == 0
+                {
                     process.state = ProcessState::Ready;
                 }
             }
@@ -59,26 +110,353 @@ impl RoundRobin {
     fn update_waiting_processes(&mut self, signal_code: usize) {
         self.waiting_processes
             .retain(|process| match process.state {
-                ProcessState::Waiting { event } => match event {
-                    Some(signal) => {
-                        if signal == signal_code {
-                            let mut copy = process.clone();
-                            copy.state = ProcessState::Ready;
-                            self.ready_processes.push_back(copy);
-                            false
-                        } else {
-                            true
-                        }
-                    }
-                    None => true,
-                },
+                ProcessState::Waiting {
+                    event: Some(signal),
+                } if signal == signal_code => {
+                    let mut copy = process.clone();
+                    copy.state = ProcessState::Ready;
+                    self.ready_processes.push_back(copy);
+                    false
+                }
                 _ => true,
             })
     }
 
-    // functie care verifica daca un proces se replanifica in continuare
+    // functie care aplica apelul de sistem primit asupra altor procese decat procesul curent
+    fn update_processes(&mut self, syscall: Syscall) -> SyscallResult {
+        match syscall {
+            Syscall::KillGroup(pgid) => {
+                self.kill_processes(|process| process.in_group(pgid));
+                SyscallResult::Success
+            }
+            Syscall::SuspendGroup(pgid) => {
+                let suspended = take_processes(&mut self.ready_processes, |process| {
+                    process.in_group(pgid)
+                });
+                for mut process in suspended {
+                    process.state = ProcessState::Suspended;
+                    self.waiting_processes.push_back(process);
+                }
+                SyscallResult::Success
+            }
+            Syscall::ResumeGroup(pgid) => {
+                let resumed = take_processes(&mut self.waiting_processes, |process| {
+                    process.in_group(pgid) && process.state == ProcessState::Suspended
+                });
+                for process in resumed {
+                    self.push_ready(process);
+                }
+                SyscallResult::Success
+            }
+            // procesul primit este suspendat daca este ready sau doarme
+            Syscall::Suspend(target) => {
+                let now = self.current_time();
+                let suspended = take_processes(&mut self.ready_processes, |process| {
+                    process.pid() == target
+                });
+                self.waiting_processes.extend(suspended);
+                for process in &mut self.waiting_processes {
+                    if process.pid() == target {
+                        process.suspend(now);
+                    }
+                }
+                SyscallResult::Success
+            }
+            Syscall::Resume(target) => {
+                let now = self.current_time();
+                for process in &mut self.waiting_processes {
+                    if process.pid() == target {
+                        process.resume(now);
+                        if process.sleep > 0 {
+                            self.timing_wheel.schedule(process.pid, process.sleep);
+                        }
+                    }
+                }
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            Syscall::SetAffinity(mask) => {
+                set_affinity(self.ready_processes.front_mut(), mask, SINGLE_CORE)
+            }
+            // procesul curent este mutat in coada de waiting daca a fost blocat de un
+            // semafor, de un mutex sau de asteptarea unui proces copil
+            Syscall::SemWait(_)
+            | Syscall::MutexLock(_)
+            | Syscall::WaitChild
+            | Syscall::WaitPid(_) => {
+                let blocked = take_processes(&mut self.ready_processes, |process| {
+                    process.was_blocked()
+                });
+                for process in blocked {
+                    self.observer.emit(process.blocked_event());
+                    self.waiting_processes.push_back(process);
+                }
+                SyscallResult::Success
+            }
+            // primul proces care asteapta semaforul devine ready
+            Syscall::SemPost(semaphore) => {
+                if let Some(pid) = self.semaphores.post(semaphore) {
+                    for process in &mut self.waiting_processes {
+                        if process.pid() == pid {
+                            process.state = ProcessState::Ready;
+                        }
+                    }
+                    self.update_sleeping_processes();
+                }
+                SyscallResult::Success
+            }
+            // procesul care a primit mutex-ul eliberat devine ready
+            Syscall::MutexUnlock(_) => {
+                self.mutexes.wake_holders(&mut self.waiting_processes);
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            // procesul curent cedeaza procesorul si este readaugat in coada de ready
+            Syscall::Yield => {
+                let yielded = take_processes(&mut self.ready_processes, |process| {
+                    process.state == ProcessState::Running
+                });
+                for process in yielded {
+                    self.stats.yields += 1;
+                    self.push_ready(process);
+                }
+                SyscallResult::Success
+            }
+            Syscall::SendSignal { target, signum } => self.send_signal(target, signum),
+            // procesele noi trebuie sa aiba o prioritate din intervalul planificatorului
+            Syscall::Fork(priority) | Syscall::ForkWithDeadline { priority, .. }
+                if !self.priority_range.contains(&priority) =>
+            {
+                SyscallResult::InvalidArgument
+            }
+            Syscall::ForkMany(0) => SyscallResult::InvalidArgument,
+            // valoarea nice trebuie sa fie din intervalul NICE_RANGE
+            Syscall::Renice(nice) if !NICE_RANGE.contains(&nice) => SyscallResult::InvalidArgument,
+            // dupa drain sau la atingerea numarului maxim de procese nu mai sunt create procese noi
+            Syscall::Fork(_) | Syscall::ForkWithDeadline { .. } | Syscall::ForkMany(_) => {
+                if self.draining {
+                    SyscallResult::Error
+                } else {
+                    SyscallResult::NoMemory
+                }
+            }
+            _ => SyscallResult::Success,
+        }
+    }
+
+    // functie care verifica daca pot fi create procese noi
+    fn accepts_processes(&self) -> bool {
+        !self.draining
+            && self
+                .max_processes
+                .is_none_or(|max| self.process_count() < max.get())
+            && self.pids.can_allocate(self.current_time())
+    }
+
+    // functie care creeaza `count` procese noi, cu prioritatea primita; timpul procesului
+    // parinte este contabilizat o singura data, ca pentru un singur apel fork
+    fn fork(&mut self, priority: Option<i8>, count: usize, remaining: usize) -> Vec<Pid> {
+        let parent = self.ready_processes.front().map(|process| Pid::new(process.pid));
+        // procesele create de un apel fork multiplu au prioritatea procesului parinte
+        let priority = priority
+            .unwrap_or_else(|| fork_priority(self.all_processes(), parent, &self.priority_range));
+        // creare nou proces cu urmatorul pid valabil si prioritatea primita
+        let now = self.current_time();
+        let mut children = Vec::new();
+        for _ in 0..count {
+            let pid = self.pids.allocate(now).unwrap();
+            let mut new_process = ProcessControlBlock::new(pid, priority);
+            new_process.pgid = self
+                .ready_processes
+                .front()
+                .and_then(|process| process.pgid);
+            new_process.parent = parent;
+
+            // adaugare in coada de ready
+            self.stats.processes_spawned += 1;
+            children.push(new_process);
+        }
+        let pids: Vec<Pid> = children.iter().map(|process| process.pid()).collect();
+
+        // se verifica daca este primul proces creat
+        if self.ready_processes.is_empty() {
+            for mut new_process in children {
+                new_process.arrival_tick = self.current_time();
+                self.ready_processes.push_back(new_process);
+            }
+            self.remaining_timeslice = remaining;
+        } else {
+            let mut running_process = self.ready_processes.pop_front().unwrap();
+
+            // actualizarea timpului de rulare si syscall pentru procesul curent
+            let execution_time = self.remaining_timeslice - remaining - 1;
+
+            running_process.syscall += 1;
+            running_process.voluntary_switches += 1;
+            running_process.execute += execution_time;
+            self.stats.execution_time += execution_time + 1;
+
+            // readaugarea acestuia in coada si actualizarea timpului total pentru toate procesele
+            self.ready_processes.push_front(running_process);
+            self.remaining_timeslice = remaining;
+            self.actualise_timings(execution_time + 1);
+
+            // adaugarea noului proces in coada
+            for mut new_process in children {
+                new_process.arrival_tick = self.current_time();
+                self.ready_processes.push_back(new_process);
+            }
+        }
+        for &child in &pids {
+            self.observer.emit(SchedulerEvent::Forked { parent, child });
+        }
+        pids
+    }
+
+    // functie care termina procesele care respecta conditia primita
+    fn kill_processes(&mut self, condition: impl Fn(&ProcessControlBlock) -> bool) -> usize {
+        let mut killed = take_processes(&mut self.ready_processes, &condition);
+        killed.extend(take_processes(&mut self.waiting_processes, &condition));
+        let count = killed.len();
+        for process in killed {
+            self.observer.emit(SchedulerEvent::Exited {
+                pid: Pid::new(process.pid),
+            });
+            self.adopt_orphans(process.pid);
+            let now = self.current_time();
+            let processes = self
+                .ready_processes
+                .iter_mut()
+                .chain(self.waiting_processes.iter_mut());
+            self.zombies
+                .exit(&process, -1, processes, &mut self.pids, now);
+            self.semaphores.remove(Pid::new(process.pid));
+            self.mutexes.remove(Pid::new(process.pid));
+            self.stats.record_exit(process.execute + process.syscall);
+            let completion = (process.arrival_tick, self.current_time());
+            self.completed.insert(Pid::new(process.pid), completion);
+        }
+
+        // procesele care au primit mutex-urile proceselor terminate devin ready
+        self.mutexes.wake_holders(&mut self.waiting_processes);
+        self.update_sleeping_processes();
+        count
+    }
+
+    // functie care returneaza toate procesele din scheduler, din ambele cozi
+    fn all_processes(&self) -> impl Iterator<Item = &ProcessControlBlock> {
+        self.ready_processes.iter().chain(self.waiting_processes.iter())
+    }
+
+    // functie care adauga in coada de ready un proces scos din coada de waiting
+    fn push_ready(&mut self, mut process: ProcessControlBlock) {
+        process.state = ProcessState::Ready;
+        self.ready_processes.push_back(process);
+    }
+
+    // functie care trimite semnalul primit procesului cu pid-ul target
+    fn send_signal(&mut self, target: Pid, signum: u8) -> SyscallResult {
+        if signum == SIGKILL {
+            if self.kill_processes(|process| process.pid() == target) > 0 {
+                self.stats.record_kill(target, signum);
+            }
+            return SyscallResult::Success;
+        }
+
+        // un proces care doarme sau asteapta un eveniment este trezit imediat
+        let interrupted = take_processes(&mut self.waiting_processes, |process| {
+            process.pid() == target && matches!(process.state, ProcessState::Waiting { .. })
+        });
+        // apelul de sistem care a blocat procesul trezit intoarce Interrupted, rezultat
+        // preluat de sistemul de operare cu take_wakeup_result
+        for mut process in interrupted {
+            process.sleep = 0;
+            process.wakeup_result = Some(SyscallResult::Interrupted);
+            self.push_ready(process);
+        }
+
+        // semnalul este raportat la urmatoarea decizie a planificatorului
+        let mut processes = self
+            .ready_processes
+            .iter_mut()
+            .chain(self.waiting_processes.iter_mut());
+        if let Some(process) = processes.find(|process| process.pid() == target) {
+            process.pending_signal = Some(signum);
+        }
+        SyscallResult::Success
+    }
+
+    // functie care returneaza semnalul in asteptare al procesului cu cel mai mic pid
+    fn take_pending_signal(&mut self) -> Option<(Pid, u8)> {
+        take_pending_signal(
+            self.ready_processes
+                .iter_mut()
+                .chain(self.waiting_processes.iter_mut()),
+        )
+    }
+
+    // functie care trateaza procesele copil ale procesului terminat: acestea sunt mutate
+    // sub procesul cu pid-ul 1 sau sunt terminate impreuna cu parintele, dupa orphan_policy
+    fn adopt_orphans(&mut self, parent: usize) {
+        match self.orphan_policy {
+            OrphanPolicy::Reparent => adopt_orphans(
+                self.ready_processes.iter_mut().chain(self.waiting_processes.iter_mut()),
+                parent,
+            ),
+            OrphanPolicy::Cascade => {
+                self.kill_processes(|process| process.parent == Some(Pid::new(parent)));
+            }
+        }
+    }
+
+    // functie care verifica daca un proces se replanifica in continuare; procesul care
+    // si-a consumat tot timpul nu se replanifica, chiar daca minimul este 0
     fn check_if_reschedule(&mut self) -> bool {
-        self.remaining_timeslice >= self.minimum_remaining_timeslice
+        self.remaining_timeslice > 0 && self.remaining_timeslice >= self.minimum_remaining_timeslice
+    }
+
+    // functie care calculeaza timpul primit de primul proces din coada de ready; cu
+    // nice_timeslices, timeslice-ul este inmultit cu (20 - nice) / 20, deci se dubleaza
+    // pentru nice -20 si scade pana la cel putin o unitate pentru nice 19
+    fn quantum(&self) -> usize {
+        match self.ready_processes.front() {
+            Some(process) if self.nice_timeslices => {
+                let scale = (20 - process.nice as isize) as usize;
+                (self.timeslice.get() * scale / 20).max(1)
+            }
+            _ => self.timeslice.get(),
+        }
+    }
+
+    // functie care aplica cotele de procesor; la inceputul unei perioade noi procesele
+    // oprite redevin ready, iar procesele care si-au folosit cota in perioada curenta
+    // asteapta in coada de waiting pana la perioada urmatoare
+    fn enforce_cpu_quotas(&mut self) {
+        let now = self.current_time();
+        let period = self.quota_period.get();
+        if now >= self.period_start + period {
+            self.period_start = now - (now - self.period_start) % period;
+            for process in self.ready_processes.iter_mut().chain(&mut self.waiting_processes) {
+                process.period_base = process.execute + process.syscall;
+                if process.state == ProcessState::Throttled {
+                    process.state = ProcessState::Ready;
+                }
+            }
+            self.update_sleeping_processes();
+        }
+
+        let throttled = take_processes(&mut self.ready_processes, |process| {
+            process.over_quota(period)
+        });
+        for mut process in throttled {
+            if process.state == ProcessState::Running {
+                self.observer.emit(SchedulerEvent::Preempted {
+                    pid: Pid::new(process.pid),
+                });
+            }
+            process.state = ProcessState::Throttled;
+            self.waiting_processes.push_back(process);
+        }
     }
 
     // functie care returneaza pid-ul primului proces din coada de ready
@@ -110,10 +488,7 @@ impl RoundRobin {
         let first_element = self.ready_processes.pop_front().unwrap();
         let copy_first_element = first_element.clone();
         self.ready_processes.push_front(first_element);
-        match copy_first_element.state {
-            ProcessState::Running => true,
-            _ => false,
-        }
+        matches!(copy_first_element.state, ProcessState::Running)
     }
 
     // functie care verifica daca s-a ajuns la starea de Deadlock
@@ -125,10 +500,11 @@ impl RoundRobin {
         let mut flag = true;
         for process in &mut self.waiting_processes {
             match process.state {
-                ProcessState::Waiting { event } => match event {
-                    Some(_) => (),
-                    None => flag = false,
-                },
+                ProcessState::Waiting { event: Some(_) }
+                | ProcessState::Suspended
+                | ProcessState::SemaphoreWait { .. }
+                | ProcessState::MutexWait { .. }
+                | ProcessState::WaitingForChild { .. } => (),
                 _ => flag = false,
             }
         }
@@ -141,33 +517,56 @@ impl RoundRobin {
         if !self.ready_processes.is_empty() {
             return (false, 0);
         }
+        let now = self.current_time();
         let mut flag = true;
-        let mut minimum_sleep_time = 10000_usize;
+        let mut minimum_sleep_time: Option<usize> = None;
         for process in &mut self.waiting_processes {
             match process.state {
                 ProcessState::Waiting { event } => match event {
                     Some(_) => (),
                     None => {
-                        if minimum_sleep_time > process.sleep {
-                            minimum_sleep_time = process.sleep;
-                        }
+                        minimum_sleep_time = Some(match minimum_sleep_time {
+                            Some(time) => time.min(process.sleep),
+                            None => process.sleep,
+                        });
                     }
                 },
+                ProcessState::IoWait { .. } => {
+                    minimum_sleep_time = Some(match minimum_sleep_time {
+                        Some(time) => time.min(process.io_expected),
+                        None => process.io_expected,
+                    });
+                }
+                // procesele oprite se trezesc la inceputul perioadei urmatoare
+                ProcessState::Throttled => {
+                    let period_end = self.period_start + self.quota_period.get();
+                    let throttle_time = period_end - now;
+                    minimum_sleep_time = Some(match minimum_sleep_time {
+                        Some(time) => time.min(throttle_time),
+                        None => throttle_time,
+                    });
+                }
+                ProcessState::Suspended => (),
                 _ => flag = false,
             }
         }
-        (flag, minimum_sleep_time)
+
+        // procesorul doarme pana la prima trezire a unui proces
+        match minimum_sleep_time {
+            Some(time) if flag && time > 0 => (true, time),
+            _ => (false, 0),
+        }
     }
-}
 
-impl Scheduler for RoundRobin {
-    fn next(&mut self) -> SchedulingDecision {
+    // functie care alege urmatoarea decizie a planificatorului
+    fn schedule(&mut self) -> SchedulingDecision {
         // se verifica daca iteratia trecuta procesul a intrat in starea de sleep
         if self.sleep {
             self.sleep = false;
             self.actualise_timings(self.sleep_time);
             self.sleep_time = 0;
         }
+        self.enforce_cpu_quotas();
 
         // se verifica daca exista proces in stare de running
         if self.check_if_process_is_running() {
@@ -176,20 +575,29 @@ impl Scheduler for RoundRobin {
                 SchedulingDecision::Run {
                     pid: Pid::new(self.get_running_process_pid()),
                     timeslice: NonZeroUsize::new(self.remaining_timeslice).unwrap(),
+                    core: 0,
                 }
             } else {
                 let mut current_element = self.ready_processes.pop_front().unwrap();
                 current_element.state = ProcessState::Ready;
+                self.observer.emit(SchedulerEvent::Preempted {
+                    pid: Pid::new(current_element.pid),
+                });
                 self.ready_processes.push_back(current_element);
-                self.remaining_timeslice = self.timeslice.get();
+                self.comparator.select(&mut self.ready_processes);
+                self.remaining_timeslice = self.quantum();
 
                 // se alege primul proces din coada de ready si il ruleaza pe acesta
+                self.stats.context_switches += 1;
+                let now = self.current_time();
                 let mut first_element = self.ready_processes.pop_front().unwrap();
                 first_element.state = ProcessState::Running;
+                record_first_run(&mut first_element, now, &mut self.stats, &mut self.responses);
                 self.ready_processes.push_front(first_element);
                 SchedulingDecision::Run {
                     pid: Pid::new(self.get_running_process_pid()),
                     timeslice: NonZeroUsize::new(self.remaining_timeslice).unwrap(),
+                    core: 0,
                 }
             }
         // nu exista procese care pot fi planificate
@@ -203,14 +611,26 @@ impl Scheduler for RoundRobin {
             }
 
             if self.check_if_all_processes_await_signal() {
-                return SchedulingDecision::Deadlock;
+                return SchedulingDecision::Deadlock(sorted_pids(self.waiting_processes.iter()));
             }
 
             // verificare daca procesorul intra in starea de sleep
             let (check_if_sleep, minimum_time) = self.check_if_all_processes_sleep();
             if check_if_sleep {
                 self.sleep = true;
+                // procesul idle ruleaza cate o unitate de timp in locul perioadei de sleep
+                if self.idle_process {
+                    self.sleep_time = 1;
+                    self.stats.idle_time += 1;
+                    self.stats.idle_ticks += 1;
+                    return SchedulingDecision::Run {
+                        pid: Pid::IDLE,
+                        timeslice: NonZeroUsize::new(1).unwrap(),
+                        core: 0,
+                    };
+                }
                 self.sleep_time = minimum_time;
+                self.stats.idle_time += minimum_time;
                 return SchedulingDecision::Sleep(NonZeroUsize::new(minimum_time).unwrap());
             }
 
@@ -221,53 +641,227 @@ impl Scheduler for RoundRobin {
             }
 
             // se ia primul proces din coada de ready si se planifica acesta
-            self.remaining_timeslice = self.timeslice.get();
+            self.comparator.select(&mut self.ready_processes);
+            self.remaining_timeslice = self.quantum();
+            self.stats.context_switches += 1;
+            let now = self.current_time();
             let mut first_element = self.ready_processes.pop_front().unwrap();
             first_element.state = ProcessState::Running;
+            record_first_run(&mut first_element, now, &mut self.stats, &mut self.responses);
             self.ready_processes.push_front(first_element);
             SchedulingDecision::Run {
                 pid: Pid::new(self.get_running_process_pid()),
                 timeslice: NonZeroUsize::new(self.remaining_timeslice).unwrap(),
+                core: 0,
             }
         }
     }
+}
 
-    fn stop(&mut self, reason: StopReason) -> SyscallResult {
-        match reason {
-            StopReason::Syscall { syscall, remaining } => match syscall {
-                Syscall::Fork(priority) => {
-                    // creare nou proces cu urmatorul pid valabil si prioritatea primita
-                    let new_process = ProcessControlBlock::new(self.next_pid, priority);
+impl RoundRobin {
+    /// Returns the time quanta that a newly scheduled process receives.
+    pub fn timeslice(&self) -> NonZeroUsize {
+        self.timeslice
+    }
 
-                    // incrementare urmatorul pid valid si adaugare in coada de ready
-                    self.next_pid += 1;
+    /// Changes the time quanta that a process receives when it is scheduled.
+    ///
+    /// The currently running process keeps its remaining time quanta, the new
+    /// value is used starting with the next process that is scheduled. Like the
+    /// constructors, this accepts a [`TimeUnit`] or a [`NonZeroUsize`].
+    pub fn set_timeslice(&mut self, timeslice: impl Into<TimeUnit>) {
+        self.timeslice = timeslice.into().get();
+    }
 
-                    // se verifica daca este primul proces creat
-                    if self.ready_processes.is_empty() {
-                        self.ready_processes.push_back(new_process.clone());
-                        self.remaining_timeslice = remaining;
-                    } else {
-                        let mut running_process = self.ready_processes.pop_front().unwrap();
+    /// Sets whether the time quanta of a process is scaled with its nice value, set
+    /// with [`Syscall::Renice`].
+    ///
+    /// When enabled, a scheduled process receives `timeslice * (20 - nice) / 20` time
+    /// units, but at least 1: twice the timeslice for a nice value of -20 and the
+    /// timeslice for the default nice value of 0. The processes are still scheduled in
+    /// the order of the ready queue. This is disabled by default.
+    pub fn set_nice_timeslices(&mut self, nice_timeslices: bool) {
+        self.nice_timeslices = nice_timeslices;
+    }
 
-                        // actualizarea timpului de rulare si syscall pentru procesul curent
-                        let execution_time = self.remaining_timeslice - remaining - 1;
+    /// Sets whether the idle process runs when all the processes sleep.
+    ///
+    /// When enabled, instead of a [`SchedulingDecision::Sleep`] decision, `next`
+    /// schedules the idle process, with PID [`Pid::IDLE`], for one time unit. The idle
+    /// process is not part of the process list; its time is ended by stopping it with
+    /// [`StopReason::Expired`] and is counted in [`SchedulerStats::idle_ticks`]. This
+    /// is disabled by default.
+    pub fn set_idle_process(&mut self, idle_process: bool) {
+        self.idle_process = idle_process;
+    }
 
-                        running_process.syscall += 1;
-                        running_process.execute += execution_time;
+    /// Sets the length of the accounting period of the CPU quotas, set by the processes
+    /// with [`Syscall::SetCpuQuota`].
+    ///
+    /// A process with the quota `q` that has run for at least `q * quota_period` time
+    /// units in the current period is throttled: it waits in the
+    /// [`ProcessState::Throttled`] state until the next period starts, the periods
+    /// starting at multiples of `quota_period`. The quotas are checked when `next` is
+    /// called, so a process can exceed its quota by at most one timeslice. The default
+    /// period is 100 time units.
+    pub fn set_quota_period(&mut self, quota_period: NonZeroUsize) {
+        self.quota_period = quota_period;
+    }
 
-                        // readaugarea acestuia in coada si actualizarea timpului total pentru toate procesele
-                        self.ready_processes.push_front(running_process);
-                        self.remaining_timeslice = remaining;
-                        self.actualise_timings(execution_time + 1);
+    /// Returns a copy of the state of the scheduler, that can be restored with
+    /// [`RoundRobin::restore`], see [`Scheduler::snapshot`].
+    pub fn snapshot(&self) -> SchedulerSnapshot {
+        SchedulerSnapshot {
+            ready_processes: self.ready_processes.clone(),
+            waiting_processes: self.waiting_processes.clone(),
+            timeslice: self.timeslice,
+            minimum_remaining_timeslice: self.minimum_remaining_timeslice,
+            remaining_timeslice: self.remaining_timeslice,
+            pids: self.pids.clone(),
+            sleep: self.sleep,
+            sleep_time: self.sleep_time,
+            stats: self.stats,
+            completed: self.completed.clone(),
+            responses: self.responses.clone(),
+            draining: self.draining,
+            max_processes: self.max_processes,
+            priority_range: self.priority_range.clone(),
+            semaphores: self.semaphores.clone(),
+            mutexes: self.mutexes.clone(),
+            zombies: self.zombies.clone(),
+            orphan_policy: self.orphan_policy,
+            aging_interval: 0,
+            tie_break: TieBreak::Fifo,
+            nice_timeslices: self.nice_timeslices,
+            idle_process: self.idle_process,
+            timing_wheel: self.timing_wheel.clone(),
+            quota_period: self.quota_period,
+            period_start: self.period_start,
+        }
+    }
 
-                        // adaugarea noului proces in coada
-                        self.ready_processes.push_back(new_process.clone());
-                    }
-                    SyscallResult::Pid(Pid::new(new_process.pid))
+    /// Creates a scheduler from a state saved with [`RoundRobin::snapshot`].
+    ///
+    /// The restored scheduler has no observer.
+    pub fn restore(snapshot: SchedulerSnapshot) -> RoundRobin {
+        RoundRobin {
+            ready_processes: snapshot.ready_processes,
+            waiting_processes: snapshot.waiting_processes,
+            timeslice: snapshot.timeslice,
+            minimum_remaining_timeslice: snapshot.minimum_remaining_timeslice,
+            remaining_timeslice: snapshot.remaining_timeslice,
+            pids: snapshot.pids,
+            sleep: snapshot.sleep,
+            sleep_time: snapshot.sleep_time,
+            stats: snapshot.stats,
+            completed: snapshot.completed,
+            responses: snapshot.responses,
+            observer: Observer::default(),
+            draining: snapshot.draining,
+            max_processes: snapshot.max_processes,
+            priority_range: snapshot.priority_range,
+            semaphores: snapshot.semaphores,
+            mutexes: snapshot.mutexes,
+            zombies: snapshot.zombies,
+            orphan_policy: snapshot.orphan_policy,
+            nice_timeslices: snapshot.nice_timeslices,
+            idle_process: snapshot.idle_process,
+            timing_wheel: snapshot.timing_wheel,
+            comparator: Comparator::default(),
+            quota_period: snapshot.quota_period,
+            period_start: snapshot.period_start,
+        }
+    }
+}
+
+impl Scheduler for RoundRobin {
+    fn next(&mut self) -> SchedulingDecision {
+        // semnalele primite de procese sunt raportate inaintea urmatoarei planificari
+        let decision = match self.take_pending_signal() {
+            Some((pid, signum)) => SchedulingDecision::DeliverSignal(pid, signum),
+            None => self.schedule(),
+        };
+        self.observer.decision(&decision);
+        decision
+    }
+
+    fn drain(&mut self) {
+        self.draining = true;
+    }
+
+    fn kill(&mut self, pid: Pid) -> bool {
+        if self.kill_processes(|process| process.pid() == pid) == 0 {
+            return false;
+        }
+        self.stats.record_kill(pid, SIGKILL);
+        true
+    }
+
+    fn reset(&mut self) {
+        self.ready_processes.clear();
+        self.waiting_processes.clear();
+        self.remaining_timeslice = 0;
+        self.pids.reset();
+        self.sleep = false;
+        self.sleep_time = 0;
+        self.stats = SchedulerStats::default();
+        self.completed.clear();
+        self.responses.clear();
+        self.draining = false;
+        self.semaphores.clear();
+        self.mutexes.clear();
+        self.zombies.clear();
+        self.timing_wheel.clear();
+        self.period_start = 0;
+    }
+
+    fn process_count(&self) -> usize {
+        self.ready_processes.len() + self.waiting_processes.len()
+    }
+
+    fn set_max_processes(&mut self, max_processes: Option<NonZeroUsize>) {
+        self.max_processes = max_processes;
+    }
+
+    fn set_priority_range(&mut self, priority_range: RangeInclusive<i8>) {
+        self.priority_range = priority_range;
+    }
+
+    fn set_pid_policy(&mut self, pid_policy: PidPolicy) {
+        self.pids.set_policy(pid_policy);
+    }
+
+    fn set_orphan_policy(&mut self, orphan_policy: OrphanPolicy) {
+        self.orphan_policy = orphan_policy;
+    }
+
+    fn peek(&self) -> SchedulingDecision {
+        // decizia este luata de o copie a planificatorului, care nu are observator
+        self.clone().next()
+    }
+
+    fn stop(&mut self, reason: StopReason) -> Result<SyscallResult, SchedulerError> {
+        let reason = resolve_syscall(reason);
+        check_signal_target(self, reason)?;
+        record_burst(self.ready_processes.iter_mut(), reason, self.remaining_timeslice);
+        Ok(match reason {
+            StopReason::Syscall { syscall, remaining } => match syscall {
+                Syscall::Fork(priority) | Syscall::ForkWithDeadline { priority, .. }
+                    if self.accepts_processes() && self.priority_range.contains(&priority) =>
+                {
+                    SyscallResult::Pid(self.fork(Some(priority), 1, remaining)[0])
+                }
+                Syscall::ForkMany(count) if count > 0 && self.accepts_processes() => {
+                    // sunt create atatea procese cate mai accepta planificatorul
+                    let free = self
+                        .max_processes
+                        .map_or(count, |max| max.get() - self.process_count());
+                    let count = self.pids.available(self.current_time(), count.min(free));
+                    SyscallResult::Pids(self.fork(None, count, remaining))
                 }
                 Syscall::Sleep(sleep_time) => {
-                    if self.ready_processes.is_empty() {
-                        return SyscallResult::NoRunningProcess;
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
                     }
 
                     // se extrage din coada de procese procesul care ruleaza
@@ -280,18 +874,25 @@ impl Scheduler for RoundRobin {
                     // se actualizeaza timpii aferente
                     let execution_time = self.remaining_timeslice - remaining - 1;
                     running_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
                     running_process.total += execution_time + 1;
                     running_process.syscall += 1;
+                    running_process.voluntary_switches += 1;
 
                     // se actualizeaza timpul total pentru toate procesele
                     self.actualise_timings(execution_time + 1);
+                    self.timing_wheel.schedule(running_process.pid, sleep_time);
 
+                    self.observer.emit(SchedulerEvent::Blocked {
+                        pid: Pid::new(running_process.pid),
+                        event: None,
+                    });
                     self.waiting_processes.push_back(running_process);
                     SyscallResult::Success
                 }
                 Syscall::Signal(signal_code) => {
-                    if self.ready_processes.is_empty() {
-                        return SyscallResult::NoRunningProcess;
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
                     }
 
                     let mut running_process = self.ready_processes.pop_front().unwrap();
@@ -299,7 +900,9 @@ impl Scheduler for RoundRobin {
                     // se actualizeaza timpul de executie pentru procesul running
                     let execution_time = self.remaining_timeslice - remaining - 1;
                     running_process.syscall += 1;
+                    running_process.voluntary_switches += 1;
                     running_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
 
                     // se actualizeaza timpul total pentru toate procesele si se scot din coada
                     // de waiting procesele care asteptau semnalul 
@@ -309,49 +912,171 @@ impl Scheduler for RoundRobin {
                     self.update_waiting_processes(signal_code);
                     SyscallResult::Success
                 }
-                Syscall::Wait(event_number) => {
-                    if self.ready_processes.is_empty() {
-                        return SyscallResult::NoRunningProcess;
+                syscall @ (Syscall::Wait(_) | Syscall::IoWait { .. }) => {
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
                     }
                     let mut running_process = self.ready_processes.pop_front().unwrap();
 
                      // se actualizeaza timpul de executie pentru procesul running
                     let execution_time = self.remaining_timeslice - remaining - 1;
                     running_process.syscall += 1;
+                    running_process.voluntary_switches += 1;
                     running_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
 
                     // se schimba starea acestuia
-                    running_process.state = ProcessState::Waiting {
-                        event: Some(event_number),
-                    };
+                    running_process.block(syscall);
 
+                    self.observer.emit(running_process.blocked_event());
                     self.waiting_processes.push_back(running_process);
                     self.remaining_timeslice = remaining;
                     self.actualise_timings(execution_time + 1);
                     SyscallResult::Success
                 }
+                syscall @ (Syscall::SetBurstHint(_)
+                | Syscall::SetTickets(_)
+                | Syscall::SetDeadline(_)
+                | Syscall::SetPeriod(_)
+                | Syscall::WillSignal(_)
+                | Syscall::SetProcessGroup(_)
+                | Syscall::KillGroup(_)
+                | Syscall::SuspendGroup(_)
+                | Syscall::ResumeGroup(_)
+                | Syscall::Suspend(_)
+                | Syscall::Resume(_)
+                | Syscall::SendSignal { .. }
+                | Syscall::Exec { .. }
+                | Syscall::SetName(_)
+                | Syscall::SetAffinity(_)
+                | Syscall::Renice(_)
+                | Syscall::SetCpuQuota(_)
+                | Syscall::SetResources(_)
+                | Syscall::WaitChild
+                | Syscall::WaitPid(_)
+                | Syscall::WaitNamed(_)
+                | Syscall::SignalNamed(_)
+                | Syscall::SemWait(_)
+                | Syscall::SemPost(_)
+                | Syscall::MutexLock(_)
+                | Syscall::MutexUnlock(_)
+                | Syscall::Yield
+                | Syscall::Fork(_)
+                | Syscall::ForkWithDeadline { .. }
+                | Syscall::ForkMany(_)) => {
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::NoRunningProcess);
+                    }
+
+                    let mut running_process = self.ready_processes.pop_front().unwrap();
+                    let execution_time = self.remaining_timeslice - remaining - 1;
+                    running_process.syscall += 1;
+                    running_process.voluntary_switches += 1;
+                    running_process.execute += execution_time;
+                    self.stats.execution_time += execution_time + 1;
+                    running_process.apply_attribute(syscall);
+                    self.semaphores.wait(&mut running_process, syscall);
+                    self.mutexes.apply(&mut running_process, syscall);
+                    let now = self.current_time();
+                    let processes = self
+                        .ready_processes
+                        .iter()
+                        .chain(self.waiting_processes.iter());
+                    let waited = self.zombies.wait(
+                        &mut running_process,
+                        syscall,
+                        processes,
+                        &mut self.pids,
+                        now,
+                    );
+
+                    self.ready_processes.push_front(running_process);
+                    self.remaining_timeslice = remaining;
+                    self.actualise_timings(execution_time + 1);
+                    let result = self.update_processes(syscall);
+                    waited.unwrap_or(result)
+                }
                 Syscall::Exit => {
-                    if self.ready_processes.is_empty() {
-                        return SyscallResult::NoRunningProcess;
+                    if !self.check_if_process_is_running() {
+                        return Err(SchedulerError::DoubleExit);
                     }
 
                     // se scoate din coada procesul running
-                    let _running_process = self.ready_processes.pop_front().unwrap();
+                    let mut running_process = self.ready_processes.pop_front().unwrap();
                     let execution_time = self.remaining_timeslice - remaining - 1;
+                    // timpul apelului de sistem Exit este inclus in timpii procesului terminat
+                    running_process.execute += execution_time;
+                    running_process.syscall += 1;
+                    running_process.total += execution_time + 1;
+                    self.observer.emit(SchedulerEvent::Exited {
+                        pid: Pid::new(running_process.pid),
+                    });
+                    self.adopt_orphans(running_process.pid);
+                    // procesul ramane zombie pana cand parintele preia codul de iesire
+                    let now = self.current_time();
+                    let processes = self
+                        .ready_processes
+                        .iter_mut()
+                        .chain(self.waiting_processes.iter_mut());
+                    self.zombies
+                        .exit(&running_process, 0, processes, &mut self.pids, now);
+                    // mutex-urile procesului terminat sunt date proceselor care le asteapta
+                    self.mutexes.remove(Pid::new(running_process.pid));
+                    self.mutexes.wake_holders(&mut self.waiting_processes);
+                    self.update_sleeping_processes();
+                    self.stats.execution_time += execution_time + 1;
 
                     self.remaining_timeslice = remaining;
                     self.actualise_timings(execution_time + 1);
+                    self.stats.record_exit(running_process.execute + running_process.syscall);
+                    let completion = (running_process.arrival_tick, self.current_time());
+                    self.completed.insert(Pid::new(running_process.pid), completion);
                     SyscallResult::Success
                 }
             },
+            StopReason::IoComplete { device_id } => {
+                // procesele care asteapta dispozitivul devin ready si sunt mutate in coada de ready
+                for process in &mut self.waiting_processes {
+                    if process.state == (ProcessState::IoWait { device_id }) {
+                        process.state = ProcessState::Ready;
+                    }
+                }
+                self.update_sleeping_processes();
+                SyscallResult::Success
+            }
+            StopReason::Killed(signum) => {
+                let Some(running_process) = self
+                    .ready_processes
+                    .front()
+                    .filter(|process| process.state == ProcessState::Running)
+                else {
+                    return Err(SchedulerError::NoRunningProcess);
+                };
+
+                // procesul este eliminat imediat, fara a mai fi adaugat in vreo coada
+                let pid = running_process.pid();
+                self.kill_processes(|process| process.pid() == pid);
+                self.stats.record_kill(pid, signum);
+                SyscallResult::Success
+            }
             StopReason::Expired => {
-                if self.ready_processes.is_empty() {
-                    return SyscallResult::NoRunningProcess;
+                // timpul procesului idle a fost adaugat deja, la planificarea acestuia
+                if !self.check_if_process_is_running() && self.idle_process && self.sleep {
+                    return Ok(SyscallResult::Success);
+                }
+                if !self.check_if_process_is_running() {
+                    return Err(SchedulerError::NoRunningProcess);
                 }
 
                 let mut running_process = self.ready_processes.pop_front().unwrap();
                 running_process.execute += self.remaining_timeslice;
+                running_process.preemptions += 1;
+                running_process.involuntary_switches += 1;
+                self.stats.execution_time += self.remaining_timeslice;
                 running_process.state = ProcessState::Ready;
+                self.observer.emit(SchedulerEvent::Preempted {
+                    pid: Pid::new(running_process.pid),
+                });
                 running_process.total += self.remaining_timeslice;
                 self.actualise_timings(self.remaining_timeslice);
 
@@ -360,7 +1085,7 @@ impl Scheduler for RoundRobin {
 
                 SyscallResult::Success
             }
-        }
+        })
     }
 
     // functie care returneaza o lista cu toate procesele din scheduler
@@ -371,4 +1096,85 @@ impl Scheduler for RoundRobin {
             .map(|process| process as &dyn Process)
             .collect()
     }
+
+    fn ready_len(&self) -> usize {
+        self.ready_processes.len()
+    }
+
+    fn waiting_len(&self) -> usize {
+        self.waiting_processes.len()
+    }
+
+    fn statistics(&self) -> SchedulerStats {
+        self.stats
+    }
+
+    fn turnaround_times(&self) -> HashMap<Pid, usize> {
+        turnaround_times(&self.completed)
+    }
+
+    fn response_times(&self) -> HashMap<Pid, usize> {
+        self.responses.clone()
+    }
+
+    fn burst_histogram(&self, pid: Pid) -> Option<BurstHistogram> {
+        find_burst_histogram(self.all_processes(), pid)
+    }
+
+    fn spawn_batch(&mut self, requests: Vec<SpawnRequest>) -> Vec<Pid> {
+        if !self.accepts_processes() {
+            return Vec::new();
+        }
+        let free = self
+            .max_processes
+            .map_or(requests.len(), |max| max.get() - self.process_count());
+        let now = self.current_time();
+        let children = spawn_processes(&mut self.pids, now, free, requests, &self.priority_range);
+        self.stats.processes_spawned += children.len();
+        let pids: Vec<Pid> = children.iter().map(|process| process.pid()).collect();
+        for mut new_process in children {
+            new_process.arrival_tick = self.current_time();
+            self.ready_processes.push_back(new_process);
+        }
+        for &child in &pids {
+            self.observer.emit(SchedulerEvent::Forked { parent: None, child });
+        }
+        pids
+    }
+
+    fn process_info(&self, pid: Pid) -> Option<ProcessInfo> {
+        find_process_info(self.all_processes(), pid).or_else(|| self.zombies.info(pid))
+    }
+
+    fn processes(&self) -> Box<dyn Iterator<Item = ProcessInfo> + '_> {
+        let pids = sorted_pids(self.all_processes());
+        Box::new(pids.into_iter().filter_map(|pid| self.process_info(pid)))
+    }
+
+    fn remaining_sleep(&self, pid: Pid) -> Option<usize> {
+        find_remaining_sleep(self.waiting_processes.iter(), pid)
+    }
+
+    fn take_wakeup_result(&mut self, pid: Pid) -> Option<SyscallResult> {
+        let processes = self
+            .ready_processes
+            .iter_mut()
+            .chain(self.waiting_processes.iter_mut());
+        take_wakeup_result(processes, pid)
+    }
+
+    fn next_wakeup(&self) -> Option<(Pid, usize)> {
+        // dupa o decizie Sleep, timpul de sleep al proceselor este scazut abia la
+        // urmatorul apel next, dar este deja adaugat la timpul curent
+        let pending = if self.sleep { self.sleep_time } else { 0 };
+        find_next_wakeup(self.waiting_processes.iter(), self.current_time() - pending)
+    }
+
+    fn set_observer(&mut self, observer: Box<dyn FnMut(SchedulerEvent) + Send>) {
+        self.observer.set(observer);
+    }
+
+    fn snapshot(&self) -> Option<SchedulerSnapshot> {
+        Some(RoundRobin::snapshot(self))
+    }
 }
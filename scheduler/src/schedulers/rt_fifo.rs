@@ -0,0 +1,85 @@
+use crate::schedulers::{PolicyScheduler, ProcessControlBlock, SchedulingPolicy};
+use crate::ProcessState;
+
+/// A scheduler with a real-time first in first out policy, returned by [`crate::rt_fifo`],
+/// or with a non-preemptive priority policy, returned by [`crate::priority_nonpreemptive`].
+pub type RealTimeFifo = PolicyScheduler<RealTimeFifoPolicy>;
+
+// coada de ready este ordonata descrescator dupa prioritate, cu procesul care ruleaza in varf
+#[derive(Clone)]
+pub struct RealTimeFifoPolicy {
+    // daca este fals, procesul care ruleaza nu este preemptat de procesele cu prioritate
+    // mai mare, ci ruleaza pana cand se blocheaza, cedeaza procesorul sau se termina
+    pub preemptive: bool,
+}
+
+impl SchedulingPolicy for RealTimeFifoPolicy {
+    // procesul ramane in varful cozii in starea de running; daca s-a trezit un proces
+    // cu prioritate mai mare, acesta il preempteaza la urmatorul apel next
+    const KEEPS_PROCESSOR: bool = true;
+
+    // un proces ruleaza pana la trezirea primului proces cu prioritate mai mare, care il
+    // poate preempta; daca nu exista un astfel de proces, timeslice-ul este nelimitat
+    fn timeslice(scheduler: &RealTimeFifo) -> usize {
+        if !scheduler.policy.preemptive {
+            return usize::MAX;
+        }
+        let priority = scheduler.ready_processes.front().unwrap().priority;
+        scheduler
+            .waiting_processes
+            .iter()
+            .filter(|process| {
+                process.priority > priority
+                    && process.state == ProcessState::Waiting { event: None }
+            })
+            .map(|process| process.sleep)
+            .filter(|&sleep| sleep > 0)
+            .min()
+            .unwrap_or(usize::MAX)
+    }
+
+    // procesul care ruleaza este preemptat doar de un proces cu prioritate strict mai mare
+    fn preempts(scheduler: &RealTimeFifo) -> bool {
+        let running_priority = scheduler.ready_processes.front().unwrap().priority;
+        scheduler.policy.preemptive
+            && scheduler
+                .ready_processes
+                .iter()
+                .skip(1)
+                .any(|process| process.priority > running_priority)
+    }
+
+    fn continued_timeslice(scheduler: &RealTimeFifo) -> Option<usize> {
+        if scheduler.check_if_reschedule() {
+            Some(scheduler.remaining_timeslice)
+        } else {
+            Some(Self::timeslice(scheduler))
+        }
+    }
+
+    // procesul ready este adaugat la finalul nivelului sau de prioritate
+    fn enqueue(scheduler: &mut RealTimeFifo, process: ProcessControlBlock) {
+        let index = scheduler
+            .ready_processes
+            .iter()
+            .position(|other| {
+                other.state != ProcessState::Running && other.priority < process.priority
+            })
+            .unwrap_or(scheduler.ready_processes.len());
+        scheduler.ready_processes.insert(index, process);
+    }
+
+    // procesul preemptat este readaugat la inceputul nivelului sau de prioritate
+    fn requeue(scheduler: &mut RealTimeFifo, process: ProcessControlBlock) {
+        let index = scheduler
+            .ready_processes
+            .iter()
+            .position(|other| other.priority <= process.priority)
+            .unwrap_or(scheduler.ready_processes.len());
+        scheduler.ready_processes.insert(index, process);
+    }
+
+    fn preempted(&mut self, process: &mut ProcessControlBlock) {
+        process.preemptions += 1;
+    }
+}
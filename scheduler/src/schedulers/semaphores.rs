@@ -0,0 +1,49 @@
+use crate::schedulers::ProcessControlBlock;
+use crate::{Pid, Process, Syscall};
+use std::collections::{HashMap, VecDeque};
+
+// semafoarele unui planificator; fiecare semafor are o valoare, care porneste de la 0,
+// si coada proceselor blocate, in ordinea in care au apelat SemWait
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Semaphores {
+    values: HashMap<u32, (isize, VecDeque<Pid>)>,
+}
+
+impl Semaphores {
+    // scade valoarea semaforului pentru un apel SemWait; daca valoarea devine negativa,
+    // procesul este blocat si adaugat in coada semaforului
+    pub fn wait(&mut self, process: &mut ProcessControlBlock, syscall: Syscall) {
+        let Syscall::SemWait(semaphore) = syscall else {
+            return;
+        };
+        let (value, waiting) = self.values.entry(semaphore).or_default();
+        *value -= 1;
+        if *value < 0 {
+            waiting.push_back(process.pid());
+            process.block(syscall);
+        }
+    }
+
+    // creste valoarea semaforului si returneaza primul proces care trebuie trezit
+    pub fn post(&mut self, semaphore: u32) -> Option<Pid> {
+        let (value, waiting) = self.values.entry(semaphore).or_default();
+        *value += 1;
+        waiting.pop_front()
+    }
+
+    // scoate procesul terminat din cozile semafoarelor; valoarea semaforului creste,
+    // ca procesul sa nu mai fie numarat printre cele care asteapta
+    pub fn remove(&mut self, pid: Pid) {
+        for (value, waiting) in self.values.values_mut() {
+            if let Some(position) = waiting.iter().position(|&waiter| waiter == pid) {
+                waiting.remove(position);
+                *value += 1;
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
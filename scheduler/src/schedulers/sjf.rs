@@ -0,0 +1,69 @@
+use crate::schedulers::{PolicyScheduler, ProcessControlBlock, SchedulingPolicy};
+use std::cmp::Ordering;
+use std::num::NonZeroUsize;
+
+#[allow(clippy::upper_case_acronyms)]
+pub type SJF = PolicyScheduler<SjfPolicy>;
+
+#[derive(Clone)]
+pub struct SjfPolicy {
+    pub timeslice: NonZeroUsize,
+    pub default_estimate: usize,
+    pub preemptive: bool,
+}
+
+impl SjfPolicy {
+    // durata estimata a urmatoarei rafale de executie a unui proces; daca procesul
+    // nu a anuntat durata rafalei, varianta fara preemptare il planifica dupa
+    // procesele care au anuntat-o, in ordinea sosirii
+    fn predicted_burst(&self, process: &ProcessControlBlock) -> usize {
+        match process.burst_hint {
+            Some(burst) => burst,
+            None if self.preemptive => process.burst_estimate,
+            None => usize::MAX,
+        }
+    }
+}
+
+impl SchedulingPolicy for SjfPolicy {
+    // se sorteaza procesele dupa durata estimata a urmatoarei rafale de executie,
+    // iar in caz de egalitate, se ia procesul cu pid-ul minim
+    fn select(scheduler: &mut SJF) -> Option<usize> {
+        let policy = &scheduler.policy;
+        scheduler.ready_processes.make_contiguous().sort_by(|a, b| {
+            let compare_by_estimate = policy.predicted_burst(a).cmp(&policy.predicted_burst(b));
+            if compare_by_estimate == Ordering::Equal {
+                a.pid.cmp(&b.pid)
+            } else {
+                compare_by_estimate
+            }
+        });
+        Some(0)
+    }
+
+    fn timeslice(scheduler: &SJF) -> usize {
+        scheduler.policy.timeslice.get()
+    }
+
+    // procesele noi nu au istoric, asa ca primesc estimarea implicita
+    fn init_child(
+        scheduler: &SJF,
+        child: &mut ProcessControlBlock,
+        _siblings: &[ProcessControlBlock],
+    ) {
+        child.burst_estimate = scheduler.policy.default_estimate;
+    }
+
+    fn charge(&mut self, process: &mut ProcessControlBlock, execution: usize, _syscall: usize) {
+        process.burst += execution;
+    }
+
+    // rafala procesului curent s-a incheiat
+    fn blocked(&mut self, process: &mut ProcessControlBlock) {
+        process.end_burst();
+    }
+
+    fn preempted(&mut self, process: &mut ProcessControlBlock) {
+        process.end_burst();
+    }
+}
@@ -0,0 +1,78 @@
+use crate::schedulers::{
+    Mutexes, PidAllocator, ProcessControlBlock, Semaphores, TimingWheel, Zombies,
+};
+use crate::{OrphanPolicy, Pid, SchedulerStats, TieBreak};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Display};
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+
+/// The saved state of a scheduler, returned by [`crate::Scheduler::snapshot`] for the
+/// [`crate::RoundRobin`], [`crate::PriorityQueueRoundRobin`] and [`crate::CFS`] schedulers.
+///
+/// A snapshot can only be restored by the same kind of scheduler that created it,
+/// with its `restore` function. The restored scheduler takes exactly the same
+/// decisions as the original one would have taken. Observers are not saved.
+///
+/// A snapshot is displayed as a table with one row for every process, followed by
+/// the statistics of the scheduler. With the `serde` feature, snapshots can be
+/// serialized and deserialized.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchedulerSnapshot {
+    pub(crate) ready_processes: VecDeque<ProcessControlBlock>,
+    pub(crate) waiting_processes: VecDeque<ProcessControlBlock>,
+    // timeslice-ul pentru Round Robin, respectiv timpul total de procesor pentru CFS
+    pub(crate) timeslice: NonZeroUsize,
+    pub(crate) minimum_remaining_timeslice: usize,
+    pub(crate) remaining_timeslice: usize,
+    pub(crate) pids: PidAllocator,
+    pub(crate) sleep: bool,
+    pub(crate) sleep_time: usize,
+    pub(crate) stats: SchedulerStats,
+    pub(crate) completed: HashMap<Pid, (usize, usize)>,
+    pub(crate) responses: HashMap<Pid, usize>,
+    pub(crate) draining: bool,
+    pub(crate) max_processes: Option<NonZeroUsize>,
+    pub(crate) priority_range: RangeInclusive<i8>,
+    pub(crate) semaphores: Semaphores,
+    pub(crate) mutexes: Mutexes,
+    pub(crate) zombies: Zombies,
+    pub(crate) orphan_policy: OrphanPolicy,
+    // intervalul de imbatranire pentru Round Robin cu prioritati, 0 pentru ceilalti planificatori
+    pub(crate) aging_interval: usize,
+    // Round Robin planifica mereu procesele in ordinea din coada
+    pub(crate) tie_break: TieBreak,
+    // timeslice-urile scalate cu valoarea nice, doar pentru Round Robin
+    pub(crate) nice_timeslices: bool,
+    // procesul idle ruleaza in locul perioadelor de sleep, doar pentru Round Robin
+    pub(crate) idle_process: bool,
+    // trezirile proceselor care dorm, doar pentru Round Robin
+    pub(crate) timing_wheel: TimingWheel,
+    // perioada de contabilizare a cotelor de procesor, doar pentru Round Robin
+    pub(crate) quota_period: NonZeroUsize,
+    pub(crate) period_start: usize,
+}
+
+impl Display for SchedulerSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "PID   STATE       PRIORITY  EXECUTE  SYSCALL  TOTAL")?;
+        for process in self.ready_processes.iter().chain(self.waiting_processes.iter()) {
+            writeln!(
+                f,
+                "{:<6}{:<12}{:<10}{:<9}{:<9}{}",
+                process.pid,
+                process.state.to_string(),
+                process.priority,
+                process.execute,
+                process.syscall,
+                process.total
+            )?;
+        }
+        write!(
+            f,
+            "remaining timeslice {}, context switches {}, processes spawned {}",
+            self.remaining_timeslice, self.stats.context_switches, self.stats.processes_spawned
+        )
+    }
+}
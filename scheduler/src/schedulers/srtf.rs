@@ -0,0 +1,61 @@
+use crate::schedulers::{PolicyScheduler, ProcessControlBlock, SchedulingPolicy};
+use std::cmp::Ordering;
+
+#[allow(clippy::upper_case_acronyms)]
+pub type SRTF = PolicyScheduler<SrtfPolicy>;
+
+#[derive(Clone)]
+pub struct SrtfPolicy;
+
+// durata ramasa din rafala curenta a unui proces; procesele care nu au anuntat
+// durata rafalei sunt planificate dupa celelalte
+fn remaining_burst(process: &ProcessControlBlock) -> usize {
+    process.remaining_burst.unwrap_or(usize::MAX)
+}
+
+impl SchedulingPolicy for SrtfPolicy {
+    // se sorteaza procesele dupa durata ramasa din rafala curenta,
+    // iar in caz de egalitate, se ia procesul cu pid-ul minim
+    fn select(scheduler: &mut SRTF) -> Option<usize> {
+        scheduler.ready_processes.make_contiguous().sort_by(|a, b| {
+            let compare_by_remaining = remaining_burst(a).cmp(&remaining_burst(b));
+            if compare_by_remaining == Ordering::Equal {
+                a.pid.cmp(&b.pid)
+            } else {
+                compare_by_remaining
+            }
+        });
+        Some(0)
+    }
+
+    // procesul ruleaza pana la finalul rafalei sau pana la trezirea primului proces
+    // din starea de sleep, moment in care se verifica daca trebuie preemptat
+    fn timeslice(scheduler: &SRTF) -> usize {
+        let burst = remaining_burst(scheduler.ready_processes.front().unwrap()).max(1);
+        scheduler
+            .waiting_processes
+            .iter()
+            .filter(|process| process.sleep > 0)
+            .map(|process| process.sleep)
+            .fold(burst, usize::min)
+    }
+
+    // procesul curent este preemptat daca exista un proces ready cu o rafala ramasa mai scurta
+    fn preempts(scheduler: &SRTF) -> bool {
+        let current_burst = remaining_burst(scheduler.ready_processes.front().unwrap());
+        scheduler
+            .ready_processes
+            .iter()
+            .skip(1)
+            .any(|process| remaining_burst(process) < current_burst)
+    }
+
+    fn charge(&mut self, process: &mut ProcessControlBlock, execution: usize, _syscall: usize) {
+        process.consume_burst(execution);
+    }
+
+    // rafala s-a incheiat, la trezire procesul porneste o rafala noua
+    fn blocked(&mut self, process: &mut ProcessControlBlock) {
+        process.remaining_burst = process.burst_hint;
+    }
+}
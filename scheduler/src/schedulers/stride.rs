@@ -0,0 +1,74 @@
+use crate::schedulers::{PolicyScheduler, ProcessControlBlock, SchedulingPolicy};
+use std::cmp::Ordering;
+use std::num::NonZeroUsize;
+
+// numarul din care se calculeaza stride-ul unui proces, stride = STRIDE1 / bilete
+const STRIDE1: usize = 1 << 20;
+
+pub type Stride = PolicyScheduler<StridePolicy>;
+
+#[derive(Clone)]
+pub struct StridePolicy {
+    pub timeslice: NonZeroUsize,
+}
+
+// pass-ul minim al proceselor ready, sau al tuturor proceselor daca niciun proces nu este ready
+fn get_minimum_pass(scheduler: &Stride) -> usize {
+    scheduler
+        .ready_processes
+        .iter()
+        .map(|process| process.pass)
+        .min()
+        .or_else(|| {
+            scheduler
+                .waiting_processes
+                .iter()
+                .map(|process| process.pass)
+                .min()
+        })
+        .unwrap_or(0)
+}
+
+impl SchedulingPolicy for StridePolicy {
+    // se alege procesul cu pass-ul minim, iar in caz de egalitate, procesul cu pid-ul minim
+    fn select(scheduler: &mut Stride) -> Option<usize> {
+        scheduler.ready_processes.make_contiguous().sort_by(|a, b| {
+            let compare_by_pass = a.pass.cmp(&b.pass);
+            if compare_by_pass == Ordering::Equal {
+                a.pid.cmp(&b.pid)
+            } else {
+                compare_by_pass
+            }
+        });
+        Some(0)
+    }
+
+    fn timeslice(scheduler: &Stride) -> usize {
+        scheduler.policy.timeslice.get()
+    }
+
+    // un proces care devine ready nu poate avea un pass mai mic decat procesele ready,
+    // pentru a nu acumula credit cat timp nu a fost ready
+    fn enqueue(scheduler: &mut Stride, mut process: ProcessControlBlock) {
+        process.pass = process.pass.max(get_minimum_pass(scheduler));
+        scheduler.ready_processes.push_back(process);
+    }
+
+    // noul proces primeste cate un bilet pentru fiecare nivel de prioritate si
+    // pass-ul minim, pentru a nu monopoliza procesorul
+    fn init_child(
+        scheduler: &Stride,
+        child: &mut ProcessControlBlock,
+        _siblings: &[ProcessControlBlock],
+    ) {
+        child.tickets = child.priority.max(0) as usize + 1;
+        child.pass = get_minimum_pass(scheduler);
+    }
+
+    // pass-ul creste cu stride-ul procesului pentru fiecare unitate de timp petrecuta pe procesor
+    fn charge(&mut self, process: &mut ProcessControlBlock, execution: usize, syscall: usize) {
+        process.pass = process
+            .pass
+            .saturating_add((STRIDE1 / process.tickets).saturating_mul(execution + syscall));
+    }
+}
@@ -0,0 +1,82 @@
+use std::num::NonZeroUsize;
+
+// roata de timp a proceselor care dorm; un proces care se trezeste peste cel mult
+// `size` unitati de timp este pus in galeata momentului trezirii, modulo `size`, iar
+// celelalte procese sunt puse intr-o lista de depasire
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimingWheel {
+    // fiecare galeata retine pid-ul procesului si momentul trezirii acestuia
+    buckets: Vec<Vec<(usize, usize)>>,
+    overflow: Vec<(usize, usize)>,
+    now: usize,
+}
+
+// numarul de galeti al rotii de timp, daca acesta nu este setat
+pub const TIMING_WHEEL_SIZE: usize = 64;
+
+impl Default for TimingWheel {
+    fn default() -> TimingWheel {
+        TimingWheel::new(NonZeroUsize::new(TIMING_WHEEL_SIZE).unwrap())
+    }
+}
+
+impl TimingWheel {
+    pub fn new(size: NonZeroUsize) -> TimingWheel {
+        TimingWheel {
+            buckets: vec![Vec::new(); size.get()],
+            overflow: Vec::new(),
+            now: 0,
+        }
+    }
+
+    // procesul cu pid-ul primit trebuie trezit peste `delay` unitati de timp
+    pub fn schedule(&mut self, pid: usize, delay: usize) {
+        let wake_time = self.now + delay;
+        if delay > 0 && delay <= self.buckets.len() {
+            let bucket = wake_time % self.buckets.len();
+            self.buckets[bucket].push((pid, wake_time));
+        } else {
+            self.overflow.push((pid, wake_time));
+        }
+    }
+
+    // avanseaza timpul roata cu `elapsed` unitati si returneaza pid-urile proceselor
+    // care trebuie trezite; un proces care a fost trezit intre timp, printr-un semnal,
+    // poate fi returnat, asa ca planificatorul verifica starea acestuia
+    pub fn advance(&mut self, elapsed: usize) -> Vec<usize> {
+        let size = self.buckets.len();
+        let mut woken = Vec::new();
+        // sunt parcurse cel mult size galeti, chiar daca timpul a avansat mai mult
+        for time in self.now + 1..=self.now + elapsed.min(size) {
+            woken.extend(self.buckets[time % size].drain(..).map(|(pid, _)| pid));
+        }
+        self.now += elapsed;
+
+        // lista de depasire este verificata doar cand roata incepe o tura noua, iar
+        // procesele care se trezesc in tura urmatoare sunt mutate in galeti
+        if (self.now - elapsed) / size != self.now / size {
+            let now = self.now;
+            let mut pending = Vec::new();
+            for (pid, wake_time) in self.overflow.drain(..) {
+                if wake_time <= now {
+                    woken.push(pid);
+                } else if wake_time - now <= size {
+                    self.buckets[wake_time % size].push((pid, wake_time));
+                } else {
+                    pending.push((pid, wake_time));
+                }
+            }
+            self.overflow = pending;
+        }
+        woken
+    }
+
+    pub fn clear(&mut self) {
+        for bucket in &mut self.buckets {
+            bucket.clear();
+        }
+        self.overflow.clear();
+        self.now = 0;
+    }
+}
@@ -0,0 +1,47 @@
+use crate::schedulers::MultiCoreRoundRobin;
+use crate::ProcessState;
+
+/// A scheduler with a round robin policy on several processor cores, that balances
+/// the run queues by work stealing, returned by [`crate::round_robin_work_stealing`].
+pub type WorkStealingRoundRobin = MultiCoreRoundRobin;
+
+impl MultiCoreRoundRobin {
+    // fiecare core cu coada goala fura jumatate din procesele de pe core-ul cu cea mai lunga
+    // coada, daca aceasta are cel putin `threshold` procese; sunt furate procesele ready
+    // adaugate cel mai recent, de la finalul cozii, pe care masca de afinitate le permite
+    pub(crate) fn steal(&mut self, threshold: usize) {
+        for thief in 0..self.ready_processes.len() {
+            if !self.ready_processes[thief].is_empty() {
+                continue;
+            }
+
+            let victim = (0..self.ready_processes.len())
+                .max_by_key(|&core| (self.ready_processes[core].len(), usize::MAX - core))
+                .unwrap();
+            let length = self.ready_processes[victim].len();
+            if length < threshold {
+                continue;
+            }
+
+            let mut wanted = length / 2;
+            let mut index = length;
+            while wanted > 0 && index > 0 {
+                index -= 1;
+                let process = &self.ready_processes[victim][index];
+                if process.state == ProcessState::Ready
+                    && MultiCoreRoundRobin::allows(process, thief)
+                {
+                    let mut process = self.ready_processes[victim].remove(index).unwrap();
+                    process.core = thief;
+                    self.ready_processes[thief].push_front(process);
+                    self.stats.migrations += 1;
+                    wanted -= 1;
+                }
+            }
+
+            if !self.ready_processes[thief].is_empty() {
+                self.stats.steals_performed += 1;
+            }
+        }
+    }
+}
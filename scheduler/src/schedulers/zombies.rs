@@ -0,0 +1,99 @@
+use crate::schedulers::{PidAllocator, ProcessControlBlock};
+use crate::{Pid, Process, ProcessInfo, ProcessState, Syscall, SyscallResult};
+
+// procesele terminate al caror cod de iesire nu a fost preluat inca de parinte, in
+// ordinea in care s-au terminat; fiecare proces zombie retine pid-ul parintelui, iar
+// pid-ul sau este eliberat abia dupa ce procesul zombie este preluat
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Zombies {
+    exited: Vec<(Pid, ProcessInfo, i32)>,
+}
+
+impl Zombies {
+    // procesul terminat devine zombie daca parintele sau exista, iar parintele care il
+    // asteapta devine ready; procesele zombie ale procesului terminat sunt eliminate,
+    // iar pid-urile lor, ca si pid-ul unui proces fara parinte, sunt eliberate
+    pub fn exit<'a>(
+        &mut self,
+        process: &ProcessControlBlock,
+        code: i32,
+        processes: impl Iterator<Item = &'a mut ProcessControlBlock>,
+        pids: &mut PidAllocator,
+        now: usize,
+    ) {
+        let pid = process.pid();
+        self.exited.retain(|&(parent, info, _)| {
+            if parent == pid {
+                pids.release(info.pid.as_usize(), now);
+            }
+            parent != pid
+        });
+        let mut processes = processes;
+        let Some(parent_process) = process
+            .parent
+            .and_then(|parent| processes.find(|process| process.pid() == parent))
+        else {
+            pids.release(process.pid, now);
+            return;
+        };
+        let parent = parent_process.pid();
+        if let ProcessState::WaitingForChild { pid: child } = parent_process.state {
+            if child.is_none_or(|child| child == pid) {
+                parent_process.state = ProcessState::Ready;
+            }
+        }
+        let mut info = process.info();
+        info.state = ProcessState::Zombie;
+        self.exited.push((parent, info, code));
+    }
+
+    // pentru WaitChild si WaitPid, procesul preia codul de iesire al primului copil
+    // terminat, al carui pid este eliberat, sau este blocat daca are un copil care inca
+    // ruleaza; pentru celelalte apeluri de sistem returneaza None
+    pub fn wait<'a>(
+        &mut self,
+        process: &mut ProcessControlBlock,
+        syscall: Syscall,
+        processes: impl Iterator<Item = &'a ProcessControlBlock>,
+        pids: &mut PidAllocator,
+        now: usize,
+    ) -> Option<SyscallResult> {
+        let child = match syscall {
+            Syscall::WaitChild => None,
+            Syscall::WaitPid(pid) => Some(pid),
+            _ => return None,
+        };
+        let parent = process.pid();
+        let matches = |pid: Pid| child.is_none_or(|child| child == pid);
+        if let Some(position) = self
+            .exited
+            .iter()
+            .position(|&(zombie_parent, info, _)| zombie_parent == parent && matches(info.pid))
+        {
+            let (_, info, code) = self.exited.remove(position);
+            pids.release(info.pid.as_usize(), now);
+            return Some(SyscallResult::ExitCode(code));
+        }
+
+        let mut processes = processes;
+        if processes.any(|process| process.parent == Some(parent) && matches(process.pid())) {
+            process.state = ProcessState::WaitingForChild { pid: child };
+            Some(SyscallResult::Success)
+        } else {
+            Some(SyscallResult::Error)
+        }
+    }
+
+    // returneaza informatiile procesului zombie cu pid-ul primit
+    pub fn info(&self, pid: Pid) -> Option<ProcessInfo> {
+        self.exited
+            .iter()
+            .find(|(_, info, _)| info.pid == pid)
+            .map(|&(_, info, _)| info)
+    }
+
+    pub fn clear(&mut self) {
+        self.exited.clear();
+    }
+}
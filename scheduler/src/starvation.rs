@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+
+use crate::{
+    BurstHistogram, OrphanPolicy, Pid, PidPolicy, Process, ProcessInfo, ProcessState, Scheduler,
+    SchedulerError, SchedulerEvent, SchedulerSnapshot, SchedulerStats, SchedulingDecision,
+    SpawnRequest, StopReason, SyscallResult,
+};
+
+/// A scheduler that reports the processes that have been waiting in the ready queue
+/// for too long.
+///
+/// Every call is forwarded unchanged to the inner scheduler. The wrapper counts, for
+/// every process, the time units it has spent ready since it last ran, measured with
+/// [`Scheduler::current_time`] of the inner scheduler. The time spent sleeping or
+/// waiting for an event is not counted. Before every `next` call, `on_starvation` is
+/// called with the PID and the waiting time of every process that has waited for more
+/// than `starvation_threshold` time units.
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use std::sync::{Arc, Mutex};
+/// use scheduler::{round_robin, Scheduler, StarvationDetector, StopReason, Syscall};
+///
+/// let starved = Arc::new(Mutex::new(Vec::new()));
+/// let report = starved.clone();
+/// let mut scheduler = StarvationDetector::new(
+///     round_robin(NonZeroUsize::new(3).unwrap(), 1),
+///     2,
+///     Box::new(move |pid, ticks| report.lock().unwrap().push((pid, ticks))),
+/// );
+/// scheduler.stop(StopReason::syscall(Syscall::Fork(0))).unwrap();
+/// scheduler.next();
+/// scheduler.stop(StopReason::syscall(Syscall::Fork(0))).unwrap();
+/// scheduler.next();
+/// scheduler.stop(StopReason::expired()).unwrap();
+/// scheduler.next();
+/// let starved = starved.lock().unwrap();
+/// assert_eq!(starved.len(), 1);
+/// assert!(starved[0].0 == 1 && starved[0].1 == 3);
+/// ```
+pub struct StarvationDetector<S: Scheduler> {
+    inner: S,
+    starvation_threshold: usize,
+    on_starvation: Box<dyn Fn(Pid, usize) + Send>,
+    // timpul petrecut in starea ready de fiecare proces, de la ultima rulare
+    waits: HashMap<Pid, usize>,
+    ready: Vec<Pid>,
+    last_time: usize,
+}
+
+impl<S: Scheduler> StarvationDetector<S> {
+    /// Returns a scheduler that reports the processes of `inner` that wait in the
+    /// ready queue for more than `starvation_threshold` time units.
+    pub fn new(
+        inner: S,
+        starvation_threshold: usize,
+        on_starvation: Box<dyn Fn(Pid, usize) + Send>,
+    ) -> StarvationDetector<S> {
+        StarvationDetector {
+            inner,
+            starvation_threshold,
+            on_starvation,
+            waits: HashMap::new(),
+            ready: Vec::new(),
+            last_time: 0,
+        }
+    }
+
+    /// Returns the number of time units that the process with PID `pid` has spent
+    /// ready since it last ran, or [`None`] if the process does not exist.
+    pub fn ticks_since_last_run(&self, pid: Pid) -> Option<usize> {
+        self.waits.get(&pid).copied()
+    }
+
+    /// Returns the inner scheduler.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    // functie care adauga timpul scurs de la ultimul apel proceselor care erau in
+    // starea ready
+    fn account(&mut self) {
+        let now = self.inner.current_time();
+        let elapsed = now.saturating_sub(self.last_time);
+        self.last_time = now;
+        for pid in &self.ready {
+            if let Some(wait) = self.waits.get_mut(pid) {
+                *wait += elapsed;
+            }
+        }
+    }
+
+    // functie care retine procesele aflate in starea ready dupa un apel al
+    // planificatorului; procesele noi pornesc de la 0, iar cele terminate sunt sterse
+    fn record_ready(&mut self) {
+        let mut waits = HashMap::new();
+        self.ready.clear();
+        for process in self.inner.list() {
+            let pid = process.pid();
+            let wait = match process.state() {
+                ProcessState::Running => 0,
+                _ => self.waits.get(&pid).copied().unwrap_or(0),
+            };
+            if process.state() == ProcessState::Ready {
+                self.ready.push(pid);
+            }
+            waits.insert(pid, wait);
+        }
+        self.waits = waits;
+    }
+
+    // functie care raporteaza procesele care au asteptat mai mult decat pragul
+    fn report(&self) {
+        for &pid in &self.ready {
+            match self.waits.get(&pid) {
+                Some(&wait) if wait > self.starvation_threshold => (self.on_starvation)(pid, wait),
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<S: Scheduler> Scheduler for StarvationDetector<S> {
+    fn next(&mut self) -> SchedulingDecision {
+        self.account();
+        self.report();
+        let decision = self.inner.next();
+        self.account();
+        self.record_ready();
+        if let SchedulingDecision::Run { pid, .. } = decision {
+            self.waits.insert(pid, 0);
+        }
+        decision
+    }
+
+    fn peek(&self) -> SchedulingDecision {
+        self.inner.peek()
+    }
+
+    fn stop(&mut self, reason: StopReason) -> Result<SyscallResult, SchedulerError> {
+        // timpul de executie este adaugat de stop, cand procesele ready sunt cele de dinainte
+        let result = self.inner.stop(reason);
+        self.account();
+        self.record_ready();
+        result
+    }
+
+    fn drain(&mut self) {
+        self.inner.drain()
+    }
+
+    fn kill(&mut self, pid: Pid) -> bool {
+        self.inner.kill(pid)
+    }
+
+    fn process_count(&self) -> usize {
+        self.inner.process_count()
+    }
+
+    fn set_max_processes(&mut self, max_processes: Option<NonZeroUsize>) {
+        self.inner.set_max_processes(max_processes)
+    }
+
+    fn set_priority_range(&mut self, priority_range: RangeInclusive<i8>) {
+        self.inner.set_priority_range(priority_range)
+    }
+
+    fn set_pid_policy(&mut self, pid_policy: PidPolicy) {
+        self.inner.set_pid_policy(pid_policy)
+    }
+
+    fn set_orphan_policy(&mut self, orphan_policy: OrphanPolicy) {
+        self.inner.set_orphan_policy(orphan_policy)
+    }
+
+    // timpul reincepe de la 0, asa ca timpii de asteptare sunt stersi
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.waits.clear();
+        self.ready.clear();
+        self.last_time = 0;
+    }
+
+    fn snapshot(&self) -> Option<SchedulerSnapshot> {
+        self.inner.snapshot()
+    }
+
+    fn list(&mut self) -> Vec<&dyn Process> {
+        self.inner.list()
+    }
+
+    fn ready_len(&self) -> usize {
+        self.inner.ready_len()
+    }
+
+    fn waiting_len(&self) -> usize {
+        self.inner.waiting_len()
+    }
+
+    fn statistics(&self) -> SchedulerStats {
+        self.inner.statistics()
+    }
+
+    fn current_time(&self) -> usize {
+        self.inner.current_time()
+    }
+
+    fn process_info(&self, pid: Pid) -> Option<ProcessInfo> {
+        self.inner.process_info(pid)
+    }
+
+    fn processes(&self) -> Box<dyn Iterator<Item = ProcessInfo> + '_> {
+        self.inner.processes()
+    }
+
+    fn remaining_sleep(&self, pid: Pid) -> Option<usize> {
+        self.inner.remaining_sleep(pid)
+    }
+
+    fn take_wakeup_result(&mut self, pid: Pid) -> Option<SyscallResult> {
+        self.inner.take_wakeup_result(pid)
+    }
+
+    fn next_wakeup(&self) -> Option<(Pid, usize)> {
+        self.inner.next_wakeup()
+    }
+
+    fn queue_depths(&self) -> Vec<usize> {
+        self.inner.queue_depths()
+    }
+
+    fn fairness_index(&self) -> f32 {
+        self.inner.fairness_index()
+    }
+
+    fn turnaround_times(&self) -> HashMap<Pid, usize> {
+        self.inner.turnaround_times()
+    }
+
+    fn response_times(&self) -> HashMap<Pid, usize> {
+        self.inner.response_times()
+    }
+
+    fn burst_histogram(&self, pid: Pid) -> Option<BurstHistogram> {
+        self.inner.burst_histogram(pid)
+    }
+
+    fn spawn_batch(&mut self, requests: Vec<SpawnRequest>) -> Vec<Pid> {
+        self.inner.spawn_batch(requests)
+    }
+
+    fn wait_graph(&self) -> Vec<(Pid, usize)> {
+        self.inner.wait_graph()
+    }
+
+    fn set_observer(&mut self, observer: Box<dyn FnMut(SchedulerEvent) + Send>) {
+        self.inner.set_observer(observer)
+    }
+}
@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+
+use crate::{
+    BurstHistogram, OrphanPolicy, Pid, PidPolicy, Process, ProcessInfo, Scheduler, SchedulerError,
+    SchedulerEvent, SchedulerSnapshot, SchedulerStats, SchedulingDecision, SpawnRequest,
+    StopReason, SyscallResult,
+};
+
+/// A time interval in which a process ran on a processor core, recorded by a
+/// [`TracingScheduler`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GanttEntry {
+    /// The PID of the process.
+    pub pid: Pid,
+
+    /// The time at which the process started running.
+    pub start_tick: usize,
+
+    /// The time at which the process stopped running.
+    pub end_tick: usize,
+
+    /// The core on which the process ran.
+    pub core: usize,
+}
+
+/// A time interval of the execution recorded by a [`TracingScheduler`], see
+/// [`TracingScheduler::timeline`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimelineEntry {
+    /// The PID of the process that ran, or [`None`] if the processor was idle.
+    pub pid: Option<Pid>,
+
+    /// The time at which the interval started.
+    pub start: usize,
+
+    /// The length of the interval.
+    pub duration: usize,
+}
+
+/// A scheduler that records the time intervals in which every process runs, so that
+/// the execution can be drawn as a Gantt chart.
+///
+/// Every call is forwarded unchanged to the inner scheduler. An interval starts when
+/// `next` returns [`SchedulingDecision::Run`], at the [`Scheduler::current_time`] of the
+/// inner scheduler, and ends at the following successful `stop`, after the time that the
+/// process used from its timeslice. A [`StopReason::IoComplete`] interrupt does not end
+/// the interval. Consecutive intervals of the same process on the same core are merged.
+/// The decisions returned by `next` are recorded as well, so that the execution can be
+/// replayed with a [`crate::ReplayScheduler`].
+///
+/// ```
+/// use std::num::NonZeroUsize;
+/// use scheduler::{round_robin, Scheduler, StopReason, Syscall, TracingScheduler};
+///
+/// let mut scheduler = TracingScheduler::new(round_robin(NonZeroUsize::new(3).unwrap(), 1));
+/// scheduler.stop(StopReason::syscall(Syscall::Fork(0))).unwrap();
+/// scheduler.next();
+/// scheduler.stop(StopReason::expired()).unwrap();
+///
+/// let mut csv = Vec::new();
+/// scheduler.export_csv(&mut csv).unwrap();
+/// assert_eq!(csv, b"pid,start_tick,end_tick,core\n1,0,3,0\n");
+/// ```
+#[derive(Clone)]
+pub struct TracingScheduler<S: Scheduler> {
+    inner: S,
+    // intervalul procesului planificat si timeslice-ul sau, intervalul este incheiat
+    // la urmatorul apel stop
+    running: Option<(GanttEntry, usize)>,
+    gantt: Vec<GanttEntry>,
+    decisions: Vec<SchedulingDecision>,
+}
+
+impl<S: Scheduler> TracingScheduler<S> {
+    /// Returns a scheduler that records the execution of `inner`.
+    pub fn new(inner: S) -> TracingScheduler<S> {
+        TracingScheduler {
+            inner,
+            running: None,
+            gantt: Vec::new(),
+            decisions: Vec::new(),
+        }
+    }
+
+    /// Returns the intervals recorded so far, in the order in which they ended.
+    pub fn gantt(&self) -> &[GanttEntry] {
+        &self.gantt
+    }
+
+    /// Returns the decisions returned by `next` so far, in the order in which they
+    /// were taken.
+    pub fn decisions(&self) -> &[SchedulingDecision] {
+        &self.decisions
+    }
+
+    /// Returns the recorded intervals in the order of time, together with the intervals
+    /// in which no process ran, so that the intervals follow each other without gaps
+    /// from time 0 to [`Scheduler::current_time`].
+    pub fn timeline(&self) -> Vec<TimelineEntry> {
+        let mut timeline = Vec::new();
+        let mut time = 0;
+        let idle = |start, end| TimelineEntry {
+            pid: None,
+            start,
+            duration: end - start,
+        };
+        for entry in &self.gantt {
+            // pe un singur core procesele ruleaza pe rand, asa ca intervalele nu se suprapun
+            if entry.start_tick > time {
+                timeline.push(idle(time, entry.start_tick));
+            }
+            timeline.push(TimelineEntry {
+                pid: Some(entry.pid),
+                start: entry.start_tick,
+                duration: entry.end_tick - entry.start_tick,
+            });
+            time = entry.end_tick;
+        }
+        let now = self.inner.current_time();
+        if now > time {
+            timeline.push(idle(time, now));
+        }
+        timeline
+    }
+
+    /// Writes the recorded intervals as CSV, with a `pid,start_tick,end_tick,core`
+    /// header line.
+    pub fn export_csv(&self, w: &mut impl Write) -> io::Result<()> {
+        writeln!(w, "pid,start_tick,end_tick,core")?;
+        for entry in &self.gantt {
+            writeln!(
+                w,
+                "{},{},{},{}",
+                entry.pid, entry.start_tick, entry.end_tick, entry.core
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Returns the inner scheduler.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    // functie care incheie intervalul procesului planificat dupa timpul folosit din
+    // timeslice; pe mai multe core-uri, ceasul planificatorului avanseaza abia la
+    // finalul lotului, asa ca nu poate fi folosit pentru finalul intervalului
+    fn record_stop(&mut self, reason: StopReason) {
+        if let StopReason::IoComplete { .. } = reason {
+            return;
+        }
+        let Some((mut entry, timeslice)) = self.running.take() else {
+            return;
+        };
+        let used = match reason {
+            StopReason::Syscall { remaining, .. } => timeslice.saturating_sub(remaining),
+            StopReason::Expired => timeslice,
+            _ => 0,
+        };
+        entry.end_tick = entry.start_tick.saturating_add(used);
+        if entry.end_tick == entry.start_tick {
+            return;
+        }
+
+        // intervalele consecutive ale aceluiasi proces, pe acelasi core, sunt unite
+        match self.gantt.last_mut() {
+            Some(last)
+                if last.pid == entry.pid
+                    && last.core == entry.core
+                    && last.end_tick == entry.start_tick =>
+            {
+                last.end_tick = entry.end_tick;
+            }
+            _ => self.gantt.push(entry),
+        }
+    }
+}
+
+impl<S: Scheduler> Scheduler for TracingScheduler<S> {
+    fn next(&mut self) -> SchedulingDecision {
+        let decision = self.inner.next();
+        self.running = match decision {
+            SchedulingDecision::Run {
+                pid,
+                timeslice,
+                core,
+            } => {
+                let start_tick = self.inner.current_time();
+                let entry = GanttEntry {
+                    pid,
+                    start_tick,
+                    end_tick: start_tick,
+                    core,
+                };
+                Some((entry, timeslice.get()))
+            }
+            _ => None,
+        };
+        self.decisions.push(decision.clone());
+        decision
+    }
+
+    fn peek(&self) -> SchedulingDecision {
+        self.inner.peek()
+    }
+
+    fn stop(&mut self, reason: StopReason) -> Result<SyscallResult, SchedulerError> {
+        let result = self.inner.stop(reason)?;
+        self.record_stop(reason);
+        Ok(result)
+    }
+
+    fn drain(&mut self) {
+        self.inner.drain()
+    }
+
+    fn kill(&mut self, pid: Pid) -> bool {
+        self.inner.kill(pid)
+    }
+
+    fn process_count(&self) -> usize {
+        self.inner.process_count()
+    }
+
+    fn set_max_processes(&mut self, max_processes: Option<NonZeroUsize>) {
+        self.inner.set_max_processes(max_processes)
+    }
+
+    fn set_priority_range(&mut self, priority_range: RangeInclusive<i8>) {
+        self.inner.set_priority_range(priority_range)
+    }
+
+    fn set_pid_policy(&mut self, pid_policy: PidPolicy) {
+        self.inner.set_pid_policy(pid_policy)
+    }
+
+    fn set_orphan_policy(&mut self, orphan_policy: OrphanPolicy) {
+        self.inner.set_orphan_policy(orphan_policy)
+    }
+
+    // timpul reincepe de la 0, asa ca intervalele inregistrate sunt sterse
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.running = None;
+        self.gantt.clear();
+        self.decisions.clear();
+    }
+
+    fn snapshot(&self) -> Option<SchedulerSnapshot> {
+        self.inner.snapshot()
+    }
+
+    fn list(&mut self) -> Vec<&dyn Process> {
+        self.inner.list()
+    }
+
+    fn ready_len(&self) -> usize {
+        self.inner.ready_len()
+    }
+
+    fn waiting_len(&self) -> usize {
+        self.inner.waiting_len()
+    }
+
+    fn statistics(&self) -> SchedulerStats {
+        self.inner.statistics()
+    }
+
+    fn current_time(&self) -> usize {
+        self.inner.current_time()
+    }
+
+    fn process_info(&self, pid: Pid) -> Option<ProcessInfo> {
+        self.inner.process_info(pid)
+    }
+
+    fn processes(&self) -> Box<dyn Iterator<Item = ProcessInfo> + '_> {
+        self.inner.processes()
+    }
+
+    fn remaining_sleep(&self, pid: Pid) -> Option<usize> {
+        self.inner.remaining_sleep(pid)
+    }
+
+    fn take_wakeup_result(&mut self, pid: Pid) -> Option<SyscallResult> {
+        self.inner.take_wakeup_result(pid)
+    }
+
+    fn next_wakeup(&self) -> Option<(Pid, usize)> {
+        self.inner.next_wakeup()
+    }
+
+    fn queue_depths(&self) -> Vec<usize> {
+        self.inner.queue_depths()
+    }
+
+    fn fairness_index(&self) -> f32 {
+        self.inner.fairness_index()
+    }
+
+    fn turnaround_times(&self) -> HashMap<Pid, usize> {
+        self.inner.turnaround_times()
+    }
+
+    fn response_times(&self) -> HashMap<Pid, usize> {
+        self.inner.response_times()
+    }
+
+    fn burst_histogram(&self, pid: Pid) -> Option<BurstHistogram> {
+        self.inner.burst_histogram(pid)
+    }
+
+    fn spawn_batch(&mut self, requests: Vec<SpawnRequest>) -> Vec<Pid> {
+        self.inner.spawn_batch(requests)
+    }
+
+    fn wait_graph(&self) -> Vec<(Pid, usize)> {
+        self.inner.wait_graph()
+    }
+
+    fn set_observer(&mut self, observer: Box<dyn FnMut(SchedulerEvent) + Send>) {
+        self.inner.set_observer(observer)
+    }
+}